@@ -208,6 +208,10 @@ impl<T: std::fmt::Debug> BordersConfig<T> {
             .or(self.global.as_ref())
     }
 
+    pub(crate) fn has_cell_intersection(&self, pos: Position) -> bool {
+        self.cells.intersection.contains_key(&pos)
+    }
+
     pub(crate) fn get_intersection(
         &self,
         pos: Position,
@@ -7,6 +7,12 @@ pub struct Formatting {
     pub vertical_trim: bool,
     /// An setting to allow alignment per line.
     pub allow_lines_alignement: bool,
+    /// An setting to keep a content as it is.
+    ///
+    /// When it's set trim and alignment per line are not applied,
+    /// so spaces at the end of lines are preserved
+    /// and a padding is added only beyond the existing text.
+    pub preserve_whitespace: bool,
 }
 
 impl Formatting {
@@ -16,6 +22,24 @@ impl Formatting {
             horizontal_trim,
             vertical_trim,
             allow_lines_alignement,
+            preserve_whitespace: false,
+        }
+    }
+
+    /// Creates a [`Formatting`] which keeps a content as it is.
+    pub fn preserved() -> Self {
+        Self {
+            preserve_whitespace: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a formatting which is applied on rendering.
+    pub(crate) fn resolve(self) -> Self {
+        if self.preserve_whitespace {
+            Self::preserved()
+        } else {
+            self
         }
     }
 }
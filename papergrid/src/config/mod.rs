@@ -19,6 +19,9 @@ pub use self::{
     sides::{Indent, Sides},
 };
 
+#[cfg(feature = "color")]
+use std::borrow::Cow;
+
 #[cfg(feature = "color")]
 use crate::AnsiColor;
 
@@ -26,10 +29,72 @@ use self::{borders::BordersConfig, entity_map::EntityMap};
 
 /// This structure represents a settings of a grid.
 ///
-/// grid: crate::Grid.
+/// It keeps everything [`Grid`] needs to know to render records besides the records themselves:
+/// borders and their overrides, margin, paddings, alignments, formatting and spans.
+///
+/// Cell related settings are set via an [`Entity`], so they can be applied to a single cell,
+/// a row, a column or to the whole grid at once.
+/// A more specific [`Entity`] takes precedence over a more general one.
+///
+/// Some getters take a grid `shape` - `(count_rows, count_columns)` -
+/// as the config itself is not bound to any records.
+///
+/// # Example
+///
+/// ```
+/// use papergrid::{
+///     height::HeightEstimator,
+///     records::vec_records::VecRecords,
+///     width::{CfgWidthFunction, WidthEstimator},
+///     AlignmentHorizontal, Borders, Entity, Estimate, Grid, GridConfig, Indent, Padding,
+/// };
+///
+/// let mut cfg = GridConfig::default();
+/// cfg.set_borders(Borders {
+///     vertical: Some('|'),
+///     horizontal: Some('-'),
+///     intersection: Some('+'),
+///     ..Default::default()
+/// });
+/// cfg.set_padding(
+///     Entity::Global,
+///     Padding::new(Indent::spaced(1), Indent::spaced(1), Indent::default(), Indent::default()),
+/// );
+/// cfg.set_alignment_horizontal(Entity::Column(1), AlignmentHorizontal::Right);
+/// cfg.set_column_span((2, 0), 2);
+///
+/// assert_eq!(cfg.get_column_span((2, 0), (3, 2)), Some(2));
+/// assert_eq!(cfg.get_alignment_horizontal(Entity::Cell(0, 1)), &AlignmentHorizontal::Right);
+/// assert!(!cfg.is_cell_visible((2, 1), (3, 2)));
+///
+/// let records = vec![vec!["name", "count"], vec!["apple", "1"], vec!["total", ""]];
+/// let records = VecRecords::new(&records, (3, 2), CfgWidthFunction::from_cfg(&cfg));
+///
+/// let mut width = WidthEstimator::default();
+/// width.estimate(&records, &cfg);
+///
+/// let mut height = HeightEstimator::default();
+/// height.estimate(&records, &cfg);
+///
+/// let grid = Grid::new(&records, &cfg, &width, &height).to_string();
+///
+/// assert_eq!(
+///     grid,
+///     concat!(
+///         " name  | count \n",
+///         "-------+-------\n",
+///         " apple |     1 \n",
+///         "-------+-------\n",
+///         " total         ",
+///     ),
+/// );
+/// ```
+///
+/// [`Grid`]: crate::Grid
 #[derive(Debug, Clone)]
 pub struct GridConfig {
     tab_width: usize,
+    ambiguous_wide: bool,
     margin: Margin,
     margin_offset: Sides<Offset>,
     padding: EntityMap<Padding>,
@@ -49,6 +114,12 @@ pub struct GridConfig {
     padding_color: EntityMap<PaddingColor<'static>>,
     #[cfg(feature = "color")]
     border_colors: BordersConfig<AnsiColor<'static>>,
+    #[cfg(feature = "color")]
+    text_colors: EntityMap<Option<AnsiColor<'static>>>,
+    #[cfg(feature = "color")]
+    fill_colors: EntityMap<Option<AnsiColor<'static>>>,
+    #[cfg(feature = "color")]
+    colors_enabled: bool,
 }
 
 impl Default for GridConfig {
@@ -62,6 +133,7 @@ impl Default for GridConfig {
 
         Self {
             tab_width: 4,
+            ambiguous_wide: false,
             margin: Margin::default(),
             margin_offset,
             padding: EntityMap::default(),
@@ -81,17 +153,28 @@ impl Default for GridConfig {
             padding_color: EntityMap::default(),
             #[cfg(feature = "color")]
             border_colors: BordersConfig::default(),
+            #[cfg(feature = "color")]
+            text_colors: EntityMap::default(),
+            #[cfg(feature = "color")]
+            fill_colors: EntityMap::default(),
+            #[cfg(feature = "color")]
+            colors_enabled: true,
         }
     }
 }
 
 impl GridConfig {
-    /// Set a column span to a given cells.
+    /// Set a column span to a given cell.
+    ///
+    /// A span of `1` removes a span set before.
+    /// A span of `0` makes the cell a part of the closest visible cell on the left.
     pub fn set_column_span(&mut self, pos: Position, span: usize) {
         set_cell_column_span(self, pos, span);
     }
 
-    /// Get a span value of the cell, if any is set.
+    /// Get a column span value of the cell, if any is set.
+    ///
+    /// Spans which are out of the `shape` boundaries are ignored.
     pub fn get_column_span(&self, pos: Position, shape: (usize, usize)) -> Option<usize> {
         match self.span_columns.get(&pos) {
             Some(&span) if is_column_span_valid(pos, span, shape) => Some(span),
@@ -99,12 +182,12 @@ impl GridConfig {
         }
     }
 
-    /// Verifies if there's any spans set.
+    /// Verifies if there's any column spans set.
     pub fn has_column_spans(&self) -> bool {
         !self.span_columns.is_empty()
     }
 
-    /// Get a span value of the cell, if any is set.
+    /// Iterates over all column spans valid for a given `shape`.
     pub fn iter_column_spans(
         &self,
         shape: (usize, usize),
@@ -115,12 +198,17 @@ impl GridConfig {
             .filter(move |&(pos, span)| is_column_span_valid(pos, span, shape))
     }
 
-    /// Set a column span to a given cells.
+    /// Set a row span to a given cell.
+    ///
+    /// A span of `1` removes a span set before.
+    /// A span of `0` makes the cell a part of the closest visible cell above.
     pub fn set_row_span(&mut self, pos: Position, span: usize) {
         set_cell_row_span(self, pos, span);
     }
 
-    /// Get a span value of the cell, if any is set.
+    /// Get a row span value of the cell, if any is set.
+    ///
+    /// Spans which are out of the `shape` boundaries are ignored.
     pub fn get_row_span(&self, pos: Position, shape: (usize, usize)) -> Option<usize> {
         match self.span_rows.get(&pos) {
             Some(&span) if is_row_span_valid(pos, span, shape) => Some(span),
@@ -128,12 +216,12 @@ impl GridConfig {
         }
     }
 
-    /// Verifies if there's any spans set.
+    /// Verifies if there's any row spans set.
     pub fn has_row_spans(&self) -> bool {
         !self.span_rows.is_empty()
     }
 
-    /// Get a span value of the cell, if any is set.
+    /// Iterates over all row spans valid for a given `shape`.
     pub fn iter_row_spans(
         &self,
         shape: (usize, usize),
@@ -198,6 +286,19 @@ impl GridConfig {
         self.tab_width
     }
 
+    /// Set whether characters of an East Asian ambiguous width are considered to be wide,
+    /// as it's done by CJK terminals.
+    ///
+    /// By default they're considered to be narrow.
+    pub fn set_ambiguous_wide(&mut self, wide: bool) {
+        self.ambiguous_wide = wide;
+    }
+
+    /// Checks whether characters of an East Asian ambiguous width are considered to be wide.
+    pub fn is_ambiguous_wide(&self) -> bool {
+        self.ambiguous_wide
+    }
+
     /// Returns a current [`Borders`] structure.
     pub fn get_borders(&self) -> &Borders<char> {
         self.borders.get_borders()
@@ -211,7 +312,7 @@ impl GridConfig {
         self.borders.insert_horizontal_line(row, line);
     }
 
-    /// Sets off the border line by row index if any were set
+    /// Sets off the border line by row index if any were set.
     ///
     /// Row `0` means the top row.
     /// Row `grid.count_rows()` means the bottom row.
@@ -219,31 +320,31 @@ impl GridConfig {
         self.borders.remove_horizontal_line(row);
     }
 
-    /// Gets a overriden vertical line.
+    /// Gets an overriden vertical line.
     ///
-    /// Row `0` means the top row.
-    /// Row `grid.count_rows()` means the bottom row.
+    /// Column `0` means the left column.
+    /// Column `grid.count_columns()` means the right column.
     pub fn get_vertical_line(&self, row: usize) -> Option<&VerticalLine<char>> {
         self.borders.get_vertical_line(row)
     }
 
     /// Set the border line by column index.
     ///
-    /// Row `0` means the top row.
-    /// Row `grid.count_rows()` means the bottom row.
+    /// Column `0` means the left column.
+    /// Column `grid.count_columns()` means the right column.
     pub fn set_vertical_line(&mut self, row: usize, line: VerticalLine<char>) {
         self.borders.insert_vertical_line(row, line);
     }
 
-    /// Sets off the border line by row index if any were set
+    /// Sets off the border line by column index if any were set.
     ///
-    /// Row `0` means the top row.
-    /// Row `grid.count_rows()` means the bottom row.
+    /// Column `0` means the left column.
+    /// Column `grid.count_columns()` means the right column.
     pub fn remove_vertical_line(&mut self, row: usize) {
         self.borders.remove_vertical_line(row);
     }
 
-    /// Gets a overriden line.
+    /// Gets an overriden horizontal line.
     ///
     /// Row `0` means the top row.
     /// Row `grid.count_rows()` means the bottom row.
@@ -259,14 +360,14 @@ impl GridConfig {
             .insert(row, (line.into(), offset));
     }
 
-    /// Gets a set text to a border line by index
+    /// Gets a text set to a horizontal border line by row index.
     pub fn get_split_line_text(&self, row: usize) -> Option<&str> {
         self.override_horizontal_lines
             .get(&row)
             .map(|(s, _)| s.as_str())
     }
 
-    /// Gets a set text to a border line by index
+    /// Gets an offset of a text set to a horizontal border line by row index.
     pub fn get_split_line_offset(&self, row: usize) -> Option<Offset> {
         self.override_horizontal_lines
             .get(&row)
@@ -338,7 +439,7 @@ impl GridConfig {
         chars.insert(offset, c);
     }
 
-    /// Get a list of overriden chars in a horizontal border.
+    /// Get a list of overriden chars in a vertical border.
     pub fn lookup_overidden_vertical(
         &self,
         pos: Position,
@@ -363,17 +464,17 @@ impl GridConfig {
             .copied()
     }
 
-    /// Checks if there any char in a horizontal border being overriden.
+    /// Checks if there any char in a vertical border being overriden.
     pub fn is_overidden_vertical(&self, pos: Position) -> bool {
         self.override_vertical_borders.get(&pos).is_some()
     }
 
-    /// Removes a list of overriden chars in a horizontal border.
+    /// Removes a list of overriden chars in a vertical border.
     pub fn remove_overidden_vertical(&mut self, pos: Position) {
         self.override_vertical_borders.remove(&pos);
     }
 
-    /// Set a padding to a given cells.
+    /// Set a padding to a given [`Entity`].
     pub fn set_padding(&mut self, entity: Entity, padding: Padding) {
         self.padding.set(entity, padding);
     }
@@ -383,7 +484,7 @@ impl GridConfig {
         self.padding.lookup(entity)
     }
 
-    /// Set a formatting to a given cells.
+    /// Set a formatting to a given [`Entity`].
     pub fn set_formatting(&mut self, entity: Entity, formatting: Formatting) {
         self.formatting.set(entity, formatting);
     }
@@ -393,7 +494,7 @@ impl GridConfig {
         self.formatting.lookup(entity)
     }
 
-    /// Set a vertical alignment to a given cells.
+    /// Set a vertical alignment to a given [`Entity`].
     pub fn set_alignment_vertical(&mut self, entity: Entity, alignment: AlignmentVertical) {
         self.alignment_v.set(entity, alignment);
     }
@@ -403,7 +504,7 @@ impl GridConfig {
         self.alignment_v.lookup(entity)
     }
 
-    /// Set a horizontal alignment to a given cells.
+    /// Set a horizontal alignment to a given [`Entity`].
     pub fn set_alignment_horizontal(&mut self, entity: Entity, alignment: AlignmentHorizontal) {
         self.alignment_h.set(entity, alignment);
     }
@@ -437,26 +538,26 @@ impl GridConfig {
 
     // todo: move to Grid as static methods
 
-    /// Checks if grid would have a vertical border with the current configuration.
+    /// Checks if [`Grid`] would have a vertical border with the current configuration.
     ///
-    /// grid: crate::Grid
+    /// [`Grid`]: crate::Grid
     pub fn has_vertical(&self, col: usize, count_columns: usize) -> bool {
         self.borders.has_vertical(col, count_columns)
     }
 
-    /// Checks if grid would have a horizontal border with the current configuration.
+    /// Checks if [`Grid`] would have a horizontal border with the current configuration.
     ///
-    /// grid: crate::Grid
+    /// [`Grid`]: crate::Grid
     pub fn has_horizontal(&self, row: usize, count_rows: usize) -> bool {
         self.borders.has_horizontal(row, count_rows)
     }
 
-    /// Set border set a border value to all cells in [`Entity`].
+    /// Set a border of a cell.
     pub fn set_border(&mut self, pos: Position, border: Border) {
         self.borders.insert_border(pos, border);
     }
 
-    /// Sets off all borders possible on the [`Entity`].
+    /// Sets off a border of a cell.
     ///
     /// It doesn't changes globaly set borders through [`GridConfig::set_borders`].
     pub fn remove_border(&mut self, pos: Position, shape: (usize, usize)) {
@@ -470,18 +571,23 @@ impl GridConfig {
         self.borders_missing_char = c;
     }
 
-    /// Calculates an amount of vertical lines would present on the grid.
+    /// Returns a character which is used in case of missconfiguration of borders.
+    pub fn get_borders_missing(&self) -> char {
+        self.borders_missing_char
+    }
+
+    /// Calculates an amount of vertical lines would present on the [`Grid`].
     ///
-    /// grid: crate::Grid
+    /// [`Grid`]: crate::Grid
     pub fn count_vertical(&self, count_columns: usize) -> usize {
         (0..=count_columns)
             .filter(|&col| self.has_vertical(col, count_columns))
             .count()
     }
 
-    /// Calculates an amount of horizontal lines would present on the grid.
+    /// Calculates an amount of horizontal lines would present on the [`Grid`].
     ///
-    /// grid: crate::Grid
+    /// [`Grid`]: crate::Grid
     pub fn count_horizontal(&self, count_rows: usize) -> usize {
         (0..=count_rows)
             .filter(|&row| self.has_horizontal(row, count_rows))
@@ -493,9 +599,9 @@ impl GridConfig {
         self.borders.get_border(pos, shape.0, shape.1).copied()
     }
 
-    /// Gets a vertical character which would be rendered on the grid.
+    /// Gets a vertical character which would be rendered on the [`Grid`].
     ///
-    /// grid: crate::Grid
+    /// [`Grid`]: crate::Grid
     pub fn get_vertical(&self, pos: Position, count_columns: usize) -> Option<&char> {
         let c = self.borders.get_vertical(pos, count_columns);
         if c.is_some() {
@@ -509,9 +615,9 @@ impl GridConfig {
         None
     }
 
-    /// Gets a horizontal character which would be rendered on the grid.
+    /// Gets a horizontal character which would be rendered on the [`Grid`].
     ///
-    /// grid: crate::Grid
+    /// [`Grid`]: crate::Grid
     pub fn get_horizontal(&self, pos: Position, count_rows: usize) -> Option<&char> {
         let c = self.borders.get_horizontal(pos, count_rows);
         if c.is_some() {
@@ -525,9 +631,9 @@ impl GridConfig {
         None
     }
 
-    /// Gets an intersection character which would be rendered on the grid.
+    /// Gets an intersection character which would be rendered on the [`Grid`].
     ///
-    /// grid: crate::Grid
+    /// [`Grid`]: crate::Grid
     pub fn get_intersection(&self, pos: Position, shape: (usize, usize)) -> Option<&char> {
         let c = self.borders.get_intersection(pos, shape.0, shape.1);
         if c.is_some() {
@@ -540,6 +646,11 @@ impl GridConfig {
 
         None
     }
+
+    /// Checks whether an intersection was set for a particular cell, e.g. by a cell border.
+    pub(crate) fn has_cell_intersection(&self, pos: Position) -> bool {
+        self.borders.has_cell_intersection(pos)
+    }
 }
 
 #[cfg(feature = "color")]
@@ -581,7 +692,13 @@ impl GridConfig {
     }
 
     /// Get colors for a [`Margin`] value.
+    ///
+    /// Empty colors are returned in case colors are disabled.
     pub fn get_margin_color(&self) -> &MarginColor<'_> {
+        if !self.colors_enabled {
+            return &NO_COLORS;
+        }
+
         &self.margin_color
     }
 
@@ -591,7 +708,13 @@ impl GridConfig {
     }
 
     /// Get a padding to a given cells.
+    ///
+    /// Empty colors are returned in case colors are disabled.
     pub fn get_padding_color(&self, entity: Entity) -> &PaddingColor<'_> {
+        if !self.colors_enabled {
+            return &NO_COLORS;
+        }
+
         self.padding_color.lookup(entity)
     }
 
@@ -601,29 +724,119 @@ impl GridConfig {
     }
 
     /// Gets a color of a cell horizontal.
+    ///
+    /// [`None`] is returned in case colors are disabled.
     pub fn get_horizontal_color(&self, pos: Position, count_rows: usize) -> Option<&AnsiColor<'_>> {
+        if !self.colors_enabled {
+            return None;
+        }
+
         self.border_colors.get_horizontal(pos, count_rows)
     }
 
     /// Gets a color of a cell vertical.
+    ///
+    /// [`None`] is returned in case colors are disabled.
     pub fn get_vertical_color(
         &self,
         pos: Position,
         count_columns: usize,
     ) -> Option<&AnsiColor<'_>> {
+        if !self.colors_enabled {
+            return None;
+        }
+
         self.border_colors.get_vertical(pos, count_columns)
     }
 
     /// Gets a color of a cell intersection.
+    ///
+    /// [`None`] is returned in case colors are disabled.
     pub fn get_intersection_color(
         &self,
         pos: Position,
         shape: (usize, usize),
     ) -> Option<&AnsiColor<'_>> {
+        if !self.colors_enabled {
+            return None;
+        }
+
         self.border_colors.get_intersection(pos, shape.0, shape.1)
     }
+
+    /// Sets a color of a text of given cells.
+    ///
+    /// The color is applied when the grid is rendered,
+    /// so unlike ANSI sequences embedded into the text it doesn't affect width estimation.
+    pub fn set_text_color(&mut self, entity: Entity, color: AnsiColor<'static>) {
+        self.text_colors.set(entity, Some(color));
+    }
+
+    /// Removes a color of a text of given cells.
+    pub fn remove_text_color(&mut self, entity: Entity) {
+        self.text_colors.set(entity, None);
+    }
+
+    /// Gets a color of a text of a cell.
+    ///
+    /// [`None`] is returned in case colors are disabled.
+    pub fn get_text_color(&self, pos: Position) -> Option<&AnsiColor<'_>> {
+        if !self.colors_enabled {
+            return None;
+        }
+
+        self.text_colors.lookup(pos.into()).as_ref()
+    }
+
+    /// Sets a color which fills a whole area of given cells.
+    ///
+    /// Unlike a text color it covers a padding and an alignment indent as well,
+    /// so it's supposed to be a background color.
+    pub fn set_fill_color(&mut self, entity: Entity, color: AnsiColor<'static>) {
+        self.fill_colors.set(entity, Some(color));
+    }
+
+    /// Removes a fill color of given cells.
+    pub fn remove_fill_color(&mut self, entity: Entity) {
+        self.fill_colors.set(entity, None);
+    }
+
+    /// Gets a fill color of a cell.
+    ///
+    /// [`None`] is returned in case colors are disabled.
+    pub fn get_fill_color(&self, pos: Position) -> Option<&AnsiColor<'_>> {
+        if !self.colors_enabled {
+            return None;
+        }
+
+        self.fill_colors.lookup(pos.into()).as_ref()
+    }
+
+    /// Turns on and off all colors set on the grid.
+    ///
+    /// It affects colors of borders, margin, padding and text set by [`GridConfig`],
+    /// but not ANSI sequences embedded into the text.
+    /// The layout stays the same either way.
+    ///
+    /// Colors are enabled by default.
+    pub fn set_colors_enabled(&mut self, enabled: bool) {
+        self.colors_enabled = enabled;
+    }
+
+    /// Checks whether colors set on the grid are rendered.
+    pub fn is_colors_enabled(&self) -> bool {
+        self.colors_enabled
+    }
 }
 
+#[cfg(feature = "color")]
+static NO_COLORS: Sides<AnsiColor<'static>> = Sides {
+    top: AnsiColor::new(Cow::Borrowed(""), Cow::Borrowed("")),
+    bottom: AnsiColor::new(Cow::Borrowed(""), Cow::Borrowed("")),
+    left: AnsiColor::new(Cow::Borrowed(""), Cow::Borrowed("")),
+    right: AnsiColor::new(Cow::Borrowed(""), Cow::Borrowed("")),
+};
+
 /// Margin represent a 4 indents of table as a whole.
 pub type Margin = Sides<Indent>;
 
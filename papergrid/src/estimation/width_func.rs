@@ -1,7 +1,7 @@
 //! A module which contains a [`WidthFunc`] trait and it's implementation [`CfgWidthFunction`]
 
 use crate::{
-    util::{string_width_multiline_tab, string_width_tab},
+    util::{count_tabs, string_width_cjk, string_width_multiline_tab, string_width_tab},
     GridConfig,
 };
 
@@ -32,28 +32,40 @@ where
 #[derive(Debug, Default, Clone)]
 pub struct CfgWidthFunction {
     tab_width: usize,
+    ambiguous_wide: bool,
 }
 
 impl CfgWidthFunction {
     /// Creates a [`CfgWidthFunction`] from [`GridConfig`].
     pub fn from_cfg(cfg: &GridConfig) -> Self {
-        Self::new(cfg.get_tab_width())
+        let mut ctrl = Self::new(cfg.get_tab_width());
+        ctrl.ambiguous_wide = cfg.is_ambiguous_wide();
+        ctrl
     }
 
     /// Creates a [`CfgWidthFunction`] with a tab size.
     pub fn new(tab_size: usize) -> Self {
         Self {
             tab_width: tab_size,
+            ambiguous_wide: false,
         }
     }
 }
 
 impl WidthFunc for CfgWidthFunction {
     fn width(&self, text: &str) -> usize {
+        if self.ambiguous_wide {
+            return string_width_cjk(text) + count_tabs(text) * self.tab_width;
+        }
+
         string_width_tab(text, self.tab_width)
     }
 
     fn width_multiline(&self, text: &str) -> usize {
+        if self.ambiguous_wide {
+            return text.lines().map(|line| self.width(line)).max().unwrap_or(0);
+        }
+
         string_width_multiline_tab(text, self.tab_width)
     }
 }
@@ -483,7 +483,7 @@ mod print_spanned {
         let mut i = 0;
         for col in 0..records.count_columns() {
             if col == 0 {
-                let left = cfg.get_intersection((row, col), shape);
+                let left = get_intersection_spanned(cfg, records, (row, col));
                 if let Some(c) = left {
                     if i >= override_text_pos && !override_text.is_empty() {
                         let (c, rest) = spplit_str_at(&override_text, 1);
@@ -603,7 +603,7 @@ mod print_spanned {
                 i += width;
             }
 
-            let right = get_intersection(cfg, records, (row, col + 1));
+            let right = get_intersection_spanned(cfg, records, (row, col + 1));
             if let Some(c) = right {
                 if i >= override_text_pos && !override_text.is_empty() {
                     let (c, rest) = spplit_str_at(&override_text, 1);
@@ -685,11 +685,35 @@ fn print_cell_line<R>(
     pos: Position,
     line: usize,
 ) -> fmt::Result
+where
+    R: Records,
+{
+    #[cfg(feature = "color")]
+    if let Some(color) = cfg.get_fill_color(pos) {
+        color.fmt_prefix(f)?;
+        print_cell_line_content(f, cfg, records, width, height, pos, line)?;
+        color.fmt_suffix(f)?;
+
+        return Ok(());
+    }
+
+    print_cell_line_content(f, cfg, records, width, height, pos, line)
+}
+
+fn print_cell_line_content<R>(
+    f: &mut fmt::Formatter<'_>,
+    cfg: &GridConfig,
+    records: &R,
+    width: usize,
+    height: usize,
+    pos: Position,
+    line: usize,
+) -> fmt::Result
 where
     R: Records,
 {
     let mut cell_height = records.count_lines(pos);
-    let formatting = *cfg.get_formatting(pos.into());
+    let formatting = cfg.get_formatting(pos.into()).resolve();
     if formatting.vertical_trim {
         cell_height -=
             count_empty_lines_at_start(records, pos) + count_empty_lines_at_end(records, pos);
@@ -702,6 +726,8 @@ where
 
     #[cfg(feature = "color")]
     let padding_color = cfg.get_padding_color(pos.into());
+    #[cfg(feature = "color")]
+    let text_color = cfg.get_text_color(pos);
 
     let padding = cfg.get_padding(pos.into());
     let alignment = cfg.get_alignment_vertical(pos.into());
@@ -765,6 +791,8 @@ where
         width,
         cfg.get_tab_width(),
         &width_ctrl,
+        #[cfg(feature = "color")]
+        text_color,
     )?;
 
     print_indent(
@@ -789,6 +817,7 @@ fn print_line_aligned<R, W>(
     available_width: usize,
     tab_width: usize,
     width_ctrl: &W,
+    #[cfg(feature = "color")] text_color: Option<&AnsiColor<'_>>,
 ) -> Result<(), fmt::Error>
 where
     R: Records,
@@ -807,7 +836,17 @@ where
 
     if formatting.allow_lines_alignement {
         let (left, right) = calculate_indent(alignment, line_width, available_width);
-        return print_text_formated(f, records, pos, &line, tab_width, left, right);
+        return print_text_formated(
+            f,
+            records,
+            pos,
+            &line,
+            tab_width,
+            left,
+            right,
+            #[cfg(feature = "color")]
+            text_color,
+        );
     }
 
     let cell_width = if formatting.horizontal_trim {
@@ -821,7 +860,17 @@ where
     };
 
     let (left, right) = calculate_indent(alignment, cell_width, available_width);
-    print_text_formated(f, records, pos, &line, tab_width, left, right)?;
+    print_text_formated(
+        f,
+        records,
+        pos,
+        &line,
+        tab_width,
+        left,
+        right,
+        #[cfg(feature = "color")]
+        text_color,
+    )?;
 
     let rest_width = cell_width - line_width;
     repeat_char(f, DEFAULT_SPACE_CHAR, rest_width)?;
@@ -830,6 +879,7 @@ where
 }
 
 #[allow(unused)]
+#[allow(clippy::too_many_arguments)]
 fn print_text_formated<R>(
     f: &mut fmt::Formatter<'_>,
     records: &R,
@@ -838,12 +888,18 @@ fn print_text_formated<R>(
     tab_width: usize,
     left: usize,
     right: usize,
+    #[cfg(feature = "color")] text_color: Option<&AnsiColor<'_>>,
 ) -> fmt::Result
 where
     R: Records,
 {
     repeat_char(f, DEFAULT_SPACE_CHAR, left)?;
 
+    #[cfg(feature = "color")]
+    if let Some(color) = text_color {
+        color.fmt_prefix(f)?;
+    }
+
     #[cfg(feature = "color")]
     records.fmt_text_prefix(f, pos)?;
 
@@ -852,6 +908,11 @@ where
     #[cfg(feature = "color")]
     records.fmt_text_suffix(f, pos)?;
 
+    #[cfg(feature = "color")]
+    if let Some(color) = text_color {
+        color.fmt_suffix(f)?;
+    }
+
     repeat_char(f, DEFAULT_SPACE_CHAR, right)?;
 
     Ok(())
@@ -1308,6 +1369,66 @@ where
     cfg.get_intersection(pos, (records.count_rows(), records.count_columns()))
 }
 
+/// Returns an intersection character considering spans.
+///
+/// A line which is cut by a span doesn't get an intersection at the place of the cut,
+/// e.g. an intersection turns into a horizontal character in case a cell above and below is spanned.
+///
+/// An intersection which was set for a particular cell is kept as it is.
+fn get_intersection_spanned<R>(cfg: &GridConfig, records: R, pos: Position) -> Option<&char>
+where
+    R: Records,
+{
+    let shape = (records.count_rows(), records.count_columns());
+    let c = cfg.get_intersection(pos, shape)?;
+    if cfg.has_cell_intersection(pos) {
+        return Some(c);
+    }
+
+    let (row, col) = pos;
+    let expected = (row > 0, row < shape.0, col > 0, col < shape.1);
+    let actual = (
+        expected.0 && has_vertical_part(cfg, (row - 1, col), shape),
+        expected.1 && has_vertical_part(cfg, (row, col), shape),
+        expected.2 && has_horizontal_part(cfg, (row, col - 1), shape),
+        expected.3 && has_horizontal_part(cfg, (row, col), shape),
+    );
+
+    if actual == expected {
+        return Some(c);
+    }
+
+    let borders = cfg.get_borders();
+    let resolved = match actual {
+        (false, false, _, true) => cfg.get_horizontal((row, col), shape.0),
+        (false, false, true, false) => cfg.get_horizontal((row, col - 1), shape.0),
+        (true, _, false, false) => cfg.get_vertical((row - 1, col), shape.1),
+        (false, true, false, false) => cfg.get_vertical((row, col), shape.1),
+        (false, true, true, true) => borders.top_intersection.as_ref(),
+        (true, false, true, true) => borders.bottom_intersection.as_ref(),
+        (true, true, false, true) => borders.horizontal_left.as_ref(),
+        (true, true, true, false) => borders.horizontal_right.as_ref(),
+        (false, true, false, true) => borders.top_left.as_ref(),
+        (false, true, true, false) => borders.top_right.as_ref(),
+        (true, false, false, true) => borders.bottom_left.as_ref(),
+        (true, false, true, false) => borders.bottom_right.as_ref(),
+        (true, true, true, true) | (false, false, false, false) => None,
+    };
+
+    resolved.or(Some(c))
+}
+
+/// Checks whether a vertical line at the left side of a cell is not hidden by a span.
+fn has_vertical_part(cfg: &GridConfig, pos: Position, shape: (usize, usize)) -> bool {
+    !cfg.is_cell_covered_by_column_span(pos, shape)
+        && !cfg.is_cell_covered_by_both_spans(pos, shape)
+}
+
+/// Checks whether a horizontal line at the top side of a cell is not hidden by a span.
+fn has_horizontal_part(cfg: &GridConfig, pos: Position, shape: (usize, usize)) -> bool {
+    !cfg.is_cell_covered_by_row_span(pos, shape) && !cfg.is_cell_covered_by_both_spans(pos, shape)
+}
+
 fn has_horizontal<R>(cfg: &GridConfig, records: R, row: usize) -> bool
 where
     R: Records,
@@ -1367,7 +1488,17 @@ mod tests {
         impl fmt::Display for F<'_> {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 let (left, right) = calculate_indent(self.1, string_width(self.0), self.2);
-                print_text_formated(f, &EmptyRecords::default(), (0, 0), self.0, 4, left, right)
+                print_text_formated(
+                    f,
+                    &EmptyRecords::default(),
+                    (0, 0),
+                    self.0,
+                    4,
+                    left,
+                    right,
+                    #[cfg(feature = "color")]
+                    None,
+                )
             }
         }
 
@@ -8,60 +8,110 @@ use std::borrow::Cow;
 
 /// Get string at
 ///
+/// A character or a grapheme cluster which is split is replaced by `\u{FFFD}` on both sides,
+/// so a width of both parts is kept.
+///
 /// BE AWARE: width is expected to be in bytes.
 pub fn spplit_str_at(text: &str, at: usize) -> (Cow<'_, str>, Cow<'_, str>) {
     #[cfg(feature = "color")]
     {
         const REPLACEMENT: char = '\u{FFFD}';
 
+        if !has_ansi(text) {
+            return spplit_str_at_basic(text, at);
+        }
+
+        if has_osc(text) {
+            let (lhs, rhs) = split_str_at_osc(text, at, false);
+            return (Cow::Owned(lhs), Cow::Owned(rhs));
+        }
+
         let stripped = ansi_str::AnsiStr::ansi_strip(text);
-        let (length, count_unknowns, _) = split_at_pos(&stripped, at);
+        let (length, count_unknowns, split_size) = split_at_pos(&stripped, at);
 
         let mut buf = ansi_str::AnsiStr::ansi_cut(text, ..length);
 
-        if count_unknowns > 0 {
-            let mut b = buf.into_owned();
-            b.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
-            buf = Cow::Owned(b);
+        if count_unknowns == 0 {
+            let rest = ansi_str::AnsiStr::ansi_cut(text, length..);
+            return (buf, rest);
         }
 
-        let rest = ansi_str::AnsiStr::ansi_cut(text, length..);
+        let mut b = buf.into_owned();
+        b.extend((0..count_unknowns).map(|_| REPLACEMENT));
+        buf = Cow::Owned(b);
+
+        let split_width = text_width(&stripped[length..length + split_size], false);
+        let mut rest = String::new();
+        rest.extend((0..split_width - count_unknowns).map(|_| REPLACEMENT));
+        rest.push_str(&ansi_str::AnsiStr::ansi_cut(text, length + split_size..));
 
-        (buf, rest)
+        (buf, Cow::Owned(rest))
     }
     #[cfg(not(feature = "color"))]
     {
-        const REPLACEMENT: char = '\u{FFFD}';
-
-        let (length, count_unknowns, _) = split_at_pos(text, at);
-        let buf = &text[..length];
-        let rest = &text[length..];
-        if count_unknowns == 0 {
-            return (Cow::Borrowed(buf), Cow::Borrowed(rest));
-        }
+        spplit_str_at_basic(text, at)
+    }
+}
 
-        let mut buf = buf.to_owned();
-        buf.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
+fn spplit_str_at_basic(text: &str, at: usize) -> (Cow<'_, str>, Cow<'_, str>) {
+    const REPLACEMENT: char = '\u{FFFD}';
 
-        return (Cow::Owned(buf), Cow::Borrowed(rest));
+    let (length, count_unknowns, split_size) = split_at_pos(text, at);
+    let buf = &text[..length];
+    if count_unknowns == 0 {
+        return (Cow::Borrowed(buf), Cow::Borrowed(&text[length..]));
     }
+
+    let mut buf = buf.to_owned();
+    buf.extend((0..count_unknowns).map(|_| REPLACEMENT));
+
+    let split = &text[length..length + split_size];
+    let split_width = text_width(split, false);
+
+    let mut rest = String::with_capacity(text.len() - length);
+    rest.extend((0..split_width - count_unknowns).map(|_| REPLACEMENT));
+    rest.push_str(&text[length + split_size..]);
+
+    (Cow::Owned(buf), Cow::Owned(rest))
 }
 
 /// The function cuts the string to a specific width.
 ///
 /// BE AWARE: width is expected to be in bytes.
 pub fn cut_str(s: &str, width: usize) -> Cow<'_, str> {
+    cut_str_with(s, width, false)
+}
+
+/// The function cuts the string to a specific width,
+/// characters of an ambiguous width are considered to be wide as it's done by CJK terminals.
+///
+/// BE AWARE: width is expected to be in bytes.
+pub fn cut_str_cjk(s: &str, width: usize) -> Cow<'_, str> {
+    cut_str_with(s, width, true)
+}
+
+fn cut_str_with(s: &str, width: usize, cjk: bool) -> Cow<'_, str> {
     #[cfg(feature = "color")]
     {
         const REPLACEMENT: char = '\u{FFFD}';
 
+        // stripping allocates so it's avoided when there's nothing to strip
+        if !has_ansi(s) {
+            return cut_str_basic_with(s, width, cjk);
+        }
+
+        // ansi_str doesn't know about hyperlinks so they're cut by hand
+        if has_osc(s) {
+            return Cow::Owned(split_str_at_osc(s, width, cjk).0);
+        }
+
         let stripped = ansi_str::AnsiStr::ansi_strip(s);
-        let (length, count_unknowns, _) = split_at_pos(&stripped, width);
+        let (length, count_unknowns, _) = split_at_pos_with(&stripped, width, cjk);
 
         let mut buf = ansi_str::AnsiStr::ansi_cut(s, ..length);
         if count_unknowns > 0 {
             let mut b = buf.into_owned();
-            b.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
+            b.extend((0..count_unknowns).map(|_| REPLACEMENT));
             buf = Cow::Owned(b);
         }
 
@@ -69,7 +119,7 @@ pub fn cut_str(s: &str, width: usize) -> Cow<'_, str> {
     }
     #[cfg(not(feature = "color"))]
     {
-        cut_str_basic(s, width)
+        cut_str_basic_with(s, width, cjk)
     }
 }
 
@@ -77,16 +127,20 @@ pub fn cut_str(s: &str, width: usize) -> Cow<'_, str> {
 ///
 /// BE AWARE: width is expected to be in bytes.
 pub fn cut_str_basic(s: &str, width: usize) -> Cow<'_, str> {
+    cut_str_basic_with(s, width, false)
+}
+
+fn cut_str_basic_with(s: &str, width: usize, cjk: bool) -> Cow<'_, str> {
     const REPLACEMENT: char = '\u{FFFD}';
 
-    let (length, count_unknowns, _) = split_at_pos(s, width);
+    let (length, count_unknowns, _) = split_at_pos_with(s, width, cjk);
     let buf = &s[..length];
     if count_unknowns == 0 {
         return Cow::Borrowed(buf);
     }
 
     let mut buf = buf.to_owned();
-    buf.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
+    buf.extend((0..count_unknowns).map(|_| REPLACEMENT));
 
     Cow::Owned(buf)
 }
@@ -97,6 +151,60 @@ pub fn cut_str_basic(s: &str, width: usize) -> Cow<'_, str> {
 ///
 /// BE AWARE: pos is expected to be in bytes.
 pub fn split_at_pos(s: &str, pos: usize) -> (usize, usize, usize) {
+    split_at_pos_with(s, pos, false)
+}
+
+/// The function splits a string in the position the same way as [`split_at_pos`],
+/// but characters of an ambiguous width are considered to be wide.
+///
+/// BE AWARE: pos is expected to be in bytes.
+pub fn split_at_pos_cjk(s: &str, pos: usize) -> (usize, usize, usize) {
+    split_at_pos_with(s, pos, true)
+}
+
+fn split_at_pos_with(s: &str, pos: usize, cjk: bool) -> (usize, usize, usize) {
+    if is_printable_ascii(s) {
+        return (std::cmp::min(pos, s.len()), 0, 0);
+    }
+
+    #[cfg(feature = "graphemes")]
+    {
+        split_graphemes_at_pos(s, pos, cjk)
+    }
+
+    #[cfg(not(feature = "graphemes"))]
+    {
+        split_chars_at_pos(s, pos, cjk)
+    }
+}
+
+/// Splits a string by grapheme clusters,
+/// so a cluster like a ZWJ emoji sequence is either kept or dropped as a whole.
+#[cfg(feature = "graphemes")]
+fn split_graphemes_at_pos(s: &str, pos: usize, cjk: bool) -> (usize, usize, usize) {
+    let mut length = 0;
+    let mut i = 0;
+    for g in unicode_segmentation::UnicodeSegmentation::graphemes(s, true) {
+        if i == pos {
+            break;
+        };
+
+        let g_width = grapheme_width(g, cjk);
+
+        if i + g_width > pos {
+            let count = pos - i;
+            return (length, count, g.len());
+        }
+
+        i += g_width;
+        length += g.len();
+    }
+
+    (length, 0, 0)
+}
+
+#[cfg(not(feature = "graphemes"))]
+fn split_chars_at_pos(s: &str, pos: usize, cjk: bool) -> (usize, usize, usize) {
     let mut length = 0;
     let mut i = 0;
     for c in s.chars() {
@@ -104,7 +212,7 @@ pub fn split_at_pos(s: &str, pos: usize) -> (usize, usize, usize) {
             break;
         };
 
-        let c_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+        let c_width = char_width(c, cjk);
 
         // We cut the chars which takes more then 1 symbol to display,
         // in order to archive the necessary width.
@@ -123,29 +231,65 @@ pub fn split_at_pos(s: &str, pos: usize) -> (usize, usize, usize) {
 /// Returns a string width.
 #[cfg(not(feature = "color"))]
 pub fn string_width(text: &str) -> usize {
-    unicode_width::UnicodeWidthStr::width(text)
+    if is_printable_ascii(text) {
+        return text.len();
+    }
+
+    text_width_skip_osc(text, false)
 }
 
 /// Returns a string width.
 #[cfg(feature = "color")]
 pub fn string_width(text: &str) -> usize {
+    if is_printable_ascii(text) {
+        return text.len();
+    }
+
+    if !has_ansi(text) {
+        return text_width(text, false);
+    }
+
     // we need to strip ansi because of terminal links
     // and they're can't be stripped by ansi_str.
 
     ansitok::parse_ansi(text)
         .filter(|e| e.kind() == ansitok::ElementKind::Text)
-        .map(|e| &text[e.start()..e.end()])
-        .map(unicode_width::UnicodeWidthStr::width)
+        .map(|e| text_width(&text[e.start()..e.end()], false))
         .sum()
 }
 
+/// Returns a string width,
+/// characters of an ambiguous width are considered to be wide as it's done by CJK terminals.
+pub fn string_width_cjk(text: &str) -> usize {
+    if is_printable_ascii(text) {
+        return text.len();
+    }
+
+    #[cfg(feature = "color")]
+    {
+        if has_ansi(text) {
+            return ansitok::parse_ansi(text)
+                .filter(|e| e.kind() == ansitok::ElementKind::Text)
+                .map(|e| text_width(&text[e.start()..e.end()], true))
+                .sum();
+        }
+    }
+
+    #[cfg(not(feature = "color"))]
+    {
+        text_width_skip_osc(text, true)
+    }
+
+    #[cfg(feature = "color")]
+    {
+        text_width(text, true)
+    }
+}
+
 /// Returns a max string width of a line.
 #[cfg(not(feature = "color"))]
 pub fn string_width_multiline(text: &str) -> usize {
-    text.lines()
-        .map(unicode_width::UnicodeWidthStr::width)
-        .max()
-        .unwrap_or(0)
+    text.lines().map(string_width).max().unwrap_or(0)
 }
 
 /// Returns a max string width of a line.
@@ -197,16 +341,42 @@ pub fn count_tabs(s: &str) -> usize {
 }
 
 /// Splits the string by lines.
+///
+/// Both `\n` and `\r\n` are considered to be a line break.
 #[cfg(not(feature = "color"))]
 pub fn get_lines(text: &str) -> impl Iterator<Item = Cow<'_, str>> {
     // we call split but not `lines()` in order to match colored implementation
-    text.split('\n').map(Cow::Borrowed)
+    text.split('\n').map(strip_cr).map(Cow::Borrowed)
 }
 
 /// Splits the string by lines.
+///
+/// Both `\n` and `\r\n` are considered to be a line break.
+/// Lines are borrowed unless the string has ANSI sequences.
 #[cfg(feature = "color")]
 pub fn get_lines(text: &str) -> impl Iterator<Item = Cow<'_, str>> {
-    ansi_str::AnsiStr::ansi_split(text, "\n")
+    let is_plain = !has_ansi(text);
+    let plain = is_plain.then(|| text.split('\n').map(strip_cr).map(Cow::Borrowed));
+    let colored = (!is_plain).then(|| {
+        ansi_str::AnsiStr::ansi_split(text, "\n").map(|line| {
+            // a carriage return may be followed by an escape sequence so it's removed completely
+            if line.contains('\r') {
+                Cow::Owned(line.replace('\r', ""))
+            } else {
+                line
+            }
+        })
+    });
+
+    plain
+        .into_iter()
+        .flatten()
+        .chain(colored.into_iter().flatten())
+}
+
+/// Removes a carriage return left by a split of `\r\n`.
+fn strip_cr(line: &str) -> &str {
+    line.strip_suffix('\r').unwrap_or(line)
 }
 
 /// Replaces tabs in a string with a given width of spaces.
@@ -216,11 +386,23 @@ pub fn replace_tab(text: &str, n: usize) -> String {
         text.replace('\t', "    ")
     } else {
         let mut text = text.to_owned();
-        replace_tab_range(&mut text, n);
+        replace_tab_in_place(&mut text, n);
         text
     }
 }
 
+/// Replaces tabs in a string with a given width of spaces,
+/// the string is borrowed in case there's no tabs.
+pub fn replace_tab_cow(text: &str, n: usize) -> Cow<'_, str> {
+    if count_tabs(text) == 0 {
+        return Cow::Borrowed(text);
+    }
+
+    let mut text = text.to_owned();
+    replace_tab_in_place(&mut text, n);
+    Cow::Owned(text)
+}
+
 /// Strip OSC codes from `s`. If `s` is a single OSC8 hyperlink, with no other text, then return
 /// (s_with_all_hyperlinks_removed, Some(url)). If `s` does not meet this description, then return
 /// (s_with_all_hyperlinks_removed, None). Any ANSI color sequences in `s` will be retained. See
@@ -284,7 +466,15 @@ pub fn strip_osc(text: &str) -> (String, Option<String>) {
     }
 }
 
-fn replace_tab_range(cell: &mut String, n: usize) -> &str {
+/// Replaces tabs in a string with a given width of spaces, reusing the string buffer.
+///
+/// A tab escaped by a backslash is kept as it is.
+pub fn replace_tab_in_place(cell: &mut String, n: usize) {
+    if count_tabs(cell) == 0 {
+        return;
+    }
+
+    let spaces = " ".repeat(n);
     let mut skip = 0;
     while let &Some(pos) = &cell[skip..].find('\t') {
         let pos = skip + pos;
@@ -296,9 +486,7 @@ fn replace_tab_range(cell: &mut String, n: usize) -> &str {
             cell.remove(pos);
             skip = pos;
         } else {
-            // I'am not sure which version is faster a loop of 'replace'
-            // or allacation of a string for replacement;
-            cell.replace_range(pos..=pos, &" ".repeat(n));
+            cell.replace_range(pos..=pos, &spaces);
             skip = pos + 1;
         }
 
@@ -306,7 +494,196 @@ fn replace_tab_range(cell: &mut String, n: usize) -> &str {
             break;
         }
     }
-    cell
+}
+
+/// Checks whether a string consists only of printable ASCII characters,
+/// so its width is the same as its length.
+///
+/// It's a common case which is a lot cheaper to check than a unicode width.
+pub fn is_printable_ascii(text: &str) -> bool {
+    text.bytes().all(|b| matches!(b, b' '..=b'~'))
+}
+
+/// Returns a width of a text without escape sequences.
+///
+/// With a `graphemes` feature it's a sum of widths of grapheme clusters,
+/// the same ones a text is cut by, so a cut part is exactly as wide as it was planned.
+fn text_width(text: &str, cjk: bool) -> usize {
+    #[cfg(feature = "graphemes")]
+    {
+        unicode_segmentation::UnicodeSegmentation::graphemes(text, true)
+            .map(|g| grapheme_width(g, cjk))
+            .sum()
+    }
+
+    #[cfg(not(feature = "graphemes"))]
+    {
+        if cjk {
+            unicode_width::UnicodeWidthStr::width_cjk(text)
+        } else {
+            unicode_width::UnicodeWidthStr::width(text)
+        }
+    }
+}
+
+/// Returns a width of a text skipping OSC sequences in it.
+///
+/// Without a `color` feature escape sequences are not recognized in general,
+/// but OSC 8 hyperlinks are an exception as they have a long invisible URL in them.
+#[cfg(not(feature = "color"))]
+fn text_width_skip_osc(mut text: &str, cjk: bool) -> usize {
+    const OSC: &str = "\u{1b}]";
+
+    let mut width = 0;
+    while let Some(start) = text.find(OSC) {
+        let rest = &text[start + OSC.len()..];
+
+        // a sequence is terminated either by ST or by BEL
+        let end = match (rest.find("\u{1b}\\"), rest.find('\u{7}')) {
+            (Some(st), Some(bel)) if bel < st => bel + 1,
+            (Some(st), _) => st + 2,
+            (None, Some(bel)) => bel + 1,
+            (None, None) => break,
+        };
+
+        width += text_width(&text[..start], cjk);
+        text = &rest[end..];
+    }
+
+    width + text_width(text, cjk)
+}
+
+#[cfg(feature = "graphemes")]
+fn grapheme_width(g: &str, cjk: bool) -> usize {
+    if cjk {
+        unicode_width::UnicodeWidthStr::width_cjk(g)
+    } else {
+        unicode_width::UnicodeWidthStr::width(g)
+    }
+}
+
+#[cfg(not(feature = "graphemes"))]
+fn char_width(c: char, cjk: bool) -> usize {
+    let width = if cjk {
+        unicode_width::UnicodeWidthChar::width_cjk(c)
+    } else {
+        unicode_width::UnicodeWidthChar::width(c)
+    };
+
+    width.unwrap_or(0)
+}
+
+#[cfg(feature = "color")]
+fn has_ansi(text: &str) -> bool {
+    text.contains('\u{1b}')
+}
+
+#[cfg(feature = "color")]
+fn has_osc(text: &str) -> bool {
+    text.contains("\u{1b}]")
+}
+
+/// Splits a string at a given width keeping escape sequences untouched.
+///
+/// A hyperlink which is active at the split is closed at the end of the left part
+/// and reopened at the beginning of the right one, the same is done for a color.
+///
+/// BE AWARE: width is expected to be in bytes.
+#[cfg(feature = "color")]
+fn split_str_at_osc(text: &str, at: usize, cjk: bool) -> (String, String) {
+    const REPLACEMENT: char = '\u{FFFD}';
+    const OSC8_END: &str = "\u{1b}]8;;\u{1b}\\";
+    const SGR_RESET: &str = "\u{1b}[0m";
+
+    let mut lhs = String::with_capacity(text.len());
+    let mut rhs = String::new();
+    // a range of an active link, ansitok parses a `\` of ST as a separate ESC element
+    let mut link: Option<(usize, usize)> = None;
+    let mut sgr = String::new();
+    let mut width = 0;
+    let mut is_split = false;
+
+    for el in ansitok::parse_ansi(text) {
+        let part = &text[el.start()..el.end()];
+        if is_split {
+            rhs.push_str(part);
+            continue;
+        }
+
+        match el.kind() {
+            ansitok::ElementKind::Text => {
+                let (length, count_unknowns, _) = split_at_pos_with(part, at - width, cjk);
+                if length == part.len() {
+                    lhs.push_str(part);
+                    width += text_width(part, cjk);
+                    continue;
+                }
+
+                lhs.push_str(&part[..length]);
+                lhs.extend((0..count_unknowns).map(|_| REPLACEMENT));
+
+                if let Some((start, end)) = link {
+                    rhs.push_str(&text[start..end]);
+                }
+                rhs.push_str(&sgr);
+                rhs.push_str(&part[length..]);
+
+                is_split = true;
+            }
+            ansitok::ElementKind::Osc => {
+                lhs.push_str(part);
+                link = if is_osc8_link_start(part) {
+                    Some((el.start(), el.end()))
+                } else {
+                    None
+                };
+            }
+            ansitok::ElementKind::Sgr => {
+                lhs.push_str(part);
+                if part == SGR_RESET || part == "\u{1b}[m" {
+                    sgr.clear();
+                } else {
+                    sgr.push_str(part);
+                }
+            }
+            ansitok::ElementKind::Esc => {
+                lhs.push_str(part);
+                if let Some((_, end)) = &mut link {
+                    if *end == el.start() && part == "\\" {
+                        *end = el.end();
+                    }
+                }
+            }
+            ansitok::ElementKind::Csi => lhs.push_str(part),
+        }
+    }
+
+    if is_split {
+        if link.is_some() {
+            lhs.push_str(OSC8_END);
+        }
+
+        if !sgr.is_empty() {
+            lhs.push_str(SGR_RESET);
+        }
+    }
+
+    (lhs, rhs)
+}
+
+/// Checks whether an OSC sequence opens a hyperlink,
+/// which is an OSC 8 sequence with a not empty URL.
+#[cfg(feature = "color")]
+fn is_osc8_link_start(osc: &str) -> bool {
+    let params = match osc.strip_prefix("\u{1b}]8;") {
+        Some(params) => params,
+        None => return false,
+    };
+
+    let url = params.split_once(';').map_or("", |(_, url)| url);
+    let url = url.trim_end_matches(&['\u{7}', '\u{1b}', '\\'][..]);
+
+    !url.is_empty()
 }
 
 #[cfg(test)]
@@ -328,6 +705,55 @@ mod tests {
         assert_eq!(replace_tab("123\\tabc", 4), "123\\tabc");
     }
 
+    #[test]
+    fn replace_tab_in_place_test() {
+        let mut text = String::from("123\t\tabc\t");
+        replace_tab_in_place(&mut text, 3);
+        assert_eq!(text, "123      abc   ");
+
+        let mut text = String::from("123\\\tabc");
+        replace_tab_in_place(&mut text, 2);
+        assert_eq!(text, "123\\\tabc");
+
+        let mut text = String::from("\t\t");
+        replace_tab_in_place(&mut text, 0);
+        assert_eq!(text, "");
+    }
+
+    #[test]
+    fn replace_tab_cow_test() {
+        assert!(matches!(replace_tab_cow("abc", 4), Cow::Borrowed("abc")));
+        assert_eq!(replace_tab_cow("a\tb", 2), "a  b");
+        assert_eq!(replace_tab_cow("a\tb", 0), "ab");
+    }
+
+    #[test]
+    fn get_lines_test() {
+        let lines = get_lines("a\n\nb").collect::<Vec<_>>();
+        assert_eq!(lines, ["a", "", "b"]);
+        assert!(lines.iter().all(|line| matches!(line, Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn printable_ascii_test() {
+        assert!(is_printable_ascii(""));
+        assert!(is_printable_ascii("Hello World ~!"));
+        assert!(!is_printable_ascii("a\tb"));
+        assert!(!is_printable_ascii("a\nb"));
+        assert!(!is_printable_ascii("\u{1b}[31ma"));
+        assert!(!is_printable_ascii("привет"));
+
+        for text in ["Hello World", "", "a\tb", "a\u{0}b", "a\u{7f}b", "Ω"] {
+            assert_eq!(
+                string_width(text),
+                unicode_width::UnicodeWidthStr::width(text)
+            );
+        }
+
+        assert_eq!(split_at_pos("Hello", 3), (3, 0, 0));
+        assert_eq!(split_at_pos("Hello", 10), (5, 0, 0));
+    }
+
     #[test]
     fn string_width_emojie_test() {
         // ...emojis such as “joy”, which normally take up two columns when printed in a terminal
@@ -337,6 +763,15 @@ mod tests {
         assert_eq!(string_width_multiline("Go 👍\nC 😎"), 5);
     }
 
+    #[test]
+    fn hyperlink_string_width_test() {
+        let link = "\u{1b}]8;;https://example.com\u{1b}\\Rust 💕\u{1b}]8;;\u{1b}\\";
+        assert_eq!(string_width(link), 7);
+        assert_eq!(string_width_cjk(link), 7);
+        assert_eq!(string_width("\u{1b}]8;;man:ls\u{7}ls\u{1b}]8;;\u{7}"), 2);
+        assert_eq!(string_width_multiline(&format!("Go 👍\n{}", link)), 7);
+    }
+
     #[cfg(feature = "color")]
     #[test]
     fn colored_string_width_test() {
@@ -363,11 +798,6 @@ mod tests {
         assert_eq!(cut_str("😳😳😳😳😳", 4), "😳😳");
         assert_eq!(cut_str("😳😳😳😳😳", 20), "😳😳😳😳😳");
 
-        assert_eq!(cut_str("🏳️🏳️", 0), "");
-        assert_eq!(cut_str("🏳️🏳️", 1), "🏳");
-        assert_eq!(cut_str("🏳️🏳️", 2), "🏳\u{fe0f}🏳");
-        assert_eq!(string_width("🏳️🏳️"), string_width("🏳\u{fe0f}🏳"));
-
         assert_eq!(cut_str("🎓", 1), "�");
         assert_eq!(cut_str("🎓", 2), "🎓");
 
@@ -377,15 +807,41 @@ mod tests {
         assert_eq!(cut_str("🩰", 1), "�");
         assert_eq!(cut_str("🩰", 2), "🩰");
 
-        assert_eq!(cut_str("👍🏿", 1), "�");
-        assert_eq!(cut_str("👍🏿", 2), "👍");
-        assert_eq!(cut_str("👍🏿", 3), "👍�");
-        assert_eq!(cut_str("👍🏿", 4), "👍🏿");
+        #[cfg(not(feature = "graphemes"))]
+        {
+            assert_eq!(cut_str("🏳️🏳️", 0), "");
+            assert_eq!(cut_str("🏳️🏳️", 1), "🏳");
+            assert_eq!(cut_str("🏳️🏳️", 2), "🏳\u{fe0f}🏳");
+            assert_eq!(string_width("🏳️🏳️"), string_width("🏳\u{fe0f}🏳"));
+
+            assert_eq!(cut_str("👍🏿", 1), "�");
+            assert_eq!(cut_str("👍🏿", 2), "👍");
+            assert_eq!(cut_str("👍🏿", 3), "👍�");
+            assert_eq!(cut_str("👍🏿", 4), "👍🏿");
+
+            assert_eq!(cut_str("🇻🇬", 1), "🇻");
+            assert_eq!(cut_str("🇻🇬", 2), "🇻🇬");
+            assert_eq!(cut_str("🇻🇬", 3), "🇻🇬");
+            assert_eq!(cut_str("🇻🇬", 4), "🇻🇬");
+        }
 
-        assert_eq!(cut_str("🇻🇬", 1), "🇻");
-        assert_eq!(cut_str("🇻🇬", 2), "🇻🇬");
-        assert_eq!(cut_str("🇻🇬", 3), "🇻🇬");
-        assert_eq!(cut_str("🇻🇬", 4), "🇻🇬");
+        // a cluster is either kept or replaced as a whole
+        #[cfg(feature = "graphemes")]
+        {
+            assert_eq!(cut_str("🏳️🏳️", 0), "");
+            assert_eq!(cut_str("🏳️🏳️", 1), "�");
+            assert_eq!(cut_str("🏳️🏳️", 2), "🏳️");
+            assert_eq!(string_width(&cut_str("🏳️🏳️", 3)), 3);
+            assert_eq!(string_width("🏳️🏳️"), 4);
+
+            assert_eq!(cut_str("👍🏿", 1), "�");
+            assert_eq!(cut_str("👍🏿", 2), "👍🏿");
+            assert_eq!(cut_str("👍🏿", 3), "👍🏿");
+
+            assert_eq!(cut_str("🇻🇬", 1), "�");
+            assert_eq!(cut_str("🇻🇬", 2), "🇻🇬");
+            assert_eq!(cut_str("🇻🇬", 3), "🇻🇬");
+        }
     }
 
     #[cfg(feature = "color")]
@@ -418,15 +874,73 @@ mod tests {
         let emojies = "🏳️🏳️".red().on_bright_black().to_string();
 
         assert_eq!(cut_str(&emojies, 0), "\u{1b}[31;100m\u{1b}[39m\u{1b}[49m");
-        assert_eq!(cut_str(&emojies, 1), "\u{1b}[31;100m🏳\u{1b}[39m\u{1b}[49m");
-        assert_eq!(
-            cut_str(&emojies, 2),
-            "\u{1b}[31;100m🏳\u{fe0f}🏳\u{1b}[39m\u{1b}[49m"
-        );
+
+        #[cfg(not(feature = "graphemes"))]
+        {
+            assert_eq!(cut_str(&emojies, 1), "\u{1b}[31;100m🏳\u{1b}[39m\u{1b}[49m");
+            assert_eq!(
+                cut_str(&emojies, 2),
+                "\u{1b}[31;100m🏳\u{fe0f}🏳\u{1b}[39m\u{1b}[49m"
+            );
+            assert_eq!(
+                string_width(&emojies),
+                string_width("\u{1b}[31;100m🏳\u{fe0f}🏳\u{1b}[39m\u{1b}[49m")
+            );
+        }
+
+        #[cfg(feature = "graphemes")]
+        {
+            assert_eq!(cut_str(&emojies, 1), "\u{1b}[31;100m\u{1b}[39m\u{1b}[49m�");
+            assert_eq!(cut_str(&emojies, 2), "\u{1b}[31;100m🏳️\u{1b}[39m\u{1b}[49m");
+            assert_eq!(string_width(&emojies), 4);
+        }
+    }
+
+    #[cfg(feature = "graphemes")]
+    #[test]
+    fn cut_str_graphemes_test() {
+        assert_eq!(cut_str("👍🏿👍🏿", 1), "\u{FFFD}");
+        assert_eq!(cut_str("👍🏿👍🏿", 2), "👍🏿");
+        assert_eq!(cut_str("👍🏿👍🏿", 3), "👍🏿\u{FFFD}");
+        assert_eq!(cut_str("👍🏿👍🏿", 4), "👍🏿👍🏿");
+
+        assert_eq!(cut_str("👨‍👩‍👧x", 2), "👨‍👩‍👧");
+        assert_eq!(cut_str("🇻🇬🇻🇬", 3), "🇻🇬\u{FFFD}");
+
+        let (lhs, rhs) = spplit_str_at("a👍🏿b", 2);
+        assert_eq!(lhs, "a\u{FFFD}");
+        assert_eq!(rhs, "\u{FFFD}b");
+    }
+
+    #[test]
+    fn string_width_cjk_test() {
+        assert_eq!(string_width_cjk("hello"), 5);
+        assert_eq!(string_width("±½→"), 3);
+        assert_eq!(string_width_cjk("±½→"), 6);
+        assert_eq!(string_width_cjk("你好"), 4);
+        assert_eq!(string_width_cjk("😳"), 2);
+    }
+
+    #[test]
+    fn cut_str_cjk_test() {
+        assert_eq!(cut_str("±½→", 2), "±½");
+        assert_eq!(cut_str_cjk("±½→", 2), "±");
+        assert_eq!(cut_str_cjk("±½→", 3), "±\u{FFFD}");
+        assert_eq!(cut_str_cjk("±½→", 6), "±½→");
+        assert_eq!(split_at_pos_cjk("a→b", 3), ("a→".len(), 0, 0));
+        assert_eq!(split_at_pos_cjk("a→b", 2), (1, 1, "→".len()));
+    }
+
+    #[test]
+    fn get_lines_crlf_test() {
         assert_eq!(
-            string_width(&emojies),
-            string_width("\u{1b}[31;100m🏳\u{fe0f}🏳\u{1b}[39m\u{1b}[49m")
+            get_lines("line 1\r\nline 2\nline 3\r\n").collect::<Vec<_>>(),
+            ["line 1", "line 2", "line 3", ""]
         );
+        assert_eq!(get_lines("\r\n").collect::<Vec<_>>(), ["", ""]);
+        assert_eq!(get_lines("a\rb").collect::<Vec<_>>(), ["a\rb"]);
+        assert_eq!(count_lines("line 1\r\nline 2"), 2);
+        assert_eq!(string_width_multiline("line 1\r\nline two\r\n"), 8);
     }
 
     #[test]
@@ -449,6 +963,51 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "color")]
+    #[test]
+    fn cut_str_for_link() {
+        let link = "\u{1b}]8;;https://example.com\u{1b}\\example\u{1b}]8;;\u{1b}\\";
+
+        assert_eq!(string_width(link), 7);
+        assert_eq!(cut_str(link, 10), link);
+        assert_eq!(cut_str(link, 7), link);
+        assert_eq!(
+            cut_str(link, 3),
+            "\u{1b}]8;;https://example.com\u{1b}\\exa\u{1b}]8;;\u{1b}\\"
+        );
+        assert_eq!(string_width(&cut_str(link, 3)), 3);
+        assert_eq!(
+            cut_str(link, 0),
+            "\u{1b}]8;;https://example.com\u{1b}\\\u{1b}]8;;\u{1b}\\"
+        );
+
+        let link = "\u{1b}]8;;https://example.com\u{7}你好\u{1b}]8;;\u{7}";
+
+        assert_eq!(string_width(link), 4);
+        assert_eq!(
+            cut_str(link, 3),
+            "\u{1b}]8;;https://example.com\u{7}你\u{fffd}\u{1b}]8;;\u{1b}\\"
+        );
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn split_str_at_for_link() {
+        let link = "id: \u{1b}]8;;https://example.com\u{1b}\\example\u{1b}]8;;\u{1b}\\!";
+
+        let (lhs, rhs) = spplit_str_at(link, 7);
+        assert_eq!(
+            lhs,
+            "id: \u{1b}]8;;https://example.com\u{1b}\\exa\u{1b}]8;;\u{1b}\\"
+        );
+        assert_eq!(
+            rhs,
+            "\u{1b}]8;;https://example.com\u{1b}\\mple\u{1b}]8;;\u{1b}\\!"
+        );
+        assert_eq!(string_width(&lhs), 7);
+        assert_eq!(string_width(&rhs), 5);
+    }
+
     #[cfg(feature = "color")]
     #[test]
     fn string_width_for_link() {
@@ -12,7 +12,7 @@ test_table!(
             cfg.set_alignment_horizontal(Entity::Cell(0, 0), AlignmentHorizontal::Center);
         })
         .build(),
-    "+---+---+"
+    "+-------+"
     "|  0-0  |"
     "+---+---+"
     "|1-0|1-1|"
@@ -28,7 +28,7 @@ test_table!(
             cfg.set_alignment_horizontal(Entity::Cell(0, 0), AlignmentHorizontal::Center);
         })
         .build(),
-    "+---+---+"
+    "+-------+"
     "|  0-0  |"
     "|  0-1  |"
     "+---+---+"
@@ -50,7 +50,7 @@ test_table!(
             cfg.set_column_span((3, 0), 3);
         })
         .build(),
-    "+-----+----+----+"
+    "+----------+----+"
     "|first line|e.g.|"
     "+-----+----+----+"
     "|0    |1   |2   |"
@@ -58,7 +58,7 @@ test_table!(
     "|0    |1   |2   |"
     "+-----+----+----+"
     "|full last line |"
-    "+-----+----+----+"
+    "+---------------+"
 );
 
 test_table!(
@@ -77,7 +77,7 @@ test_table!(
             );
         })
         .build(),
-    "+-----------+---+"
+    "+---------------+"
     "|0-0            |"
     "+-----------+---+"
     "|    1-0    |1-1|"
@@ -95,13 +95,13 @@ test_table!(
             cfg.set_column_span((2, 0), 2);
         })
         .build(),
-    "+-+-+---+"
+    "+-------+"
     "|0-0    |"
-    "+-+-+---+"
+    "+---+---+"
     "|1-0|1-2|"
-    "+-+-+---+"
+    "+---+---+"
     "|2-0|2-2|"
-    "+-+-+---+"
+    "+---+---+"
 );
 
 test_table!(
@@ -113,7 +113,7 @@ test_table!(
             cfg.set_column_span((1, 1), 2);
         })
         .build(),
-    "+-----+----+---+"
+    "+----------+---+"
     "|0-0xxxxxxx|0-2|"
     "+-----+----+---+"
     "|1-0  |1-1     |"
@@ -131,7 +131,7 @@ test_table!(
             cfg.set_column_span((1, 1), 2);
         })
         .build(),
-    "+---+-----+----+"
+    "+---------+----+"
     "|0-0      |0-2 |"
     "+---+-----+----+"
     "|1-0|1-1xxxxxxx|"
@@ -150,7 +150,7 @@ test_table!(
             cfg.set_column_span((1, 1), 2);
         })
         .build(),
-    "+----------------+-----+----+"
+    "+----------------------+----+"
     "|0-0                   |0-2 |"
     "+----------------+-----+----+"
     "|1-0             |1-1xxxxxxx|"
@@ -168,7 +168,7 @@ test_table!(
             cfg.set_column_span((1, 1), 2);
         })
         .build(),
-    "+---+----------------+---+"
+    "+--------------------+---+"
     "|0-0                 |0-2|"
     "+---+----------------+---+"
     "|1-0|1-1                 |"
@@ -186,7 +186,7 @@ test_table!(
             cfg.set_column_span((1, 1), 2);
         })
         .build(),
-    "+---+---+----------------+"
+    "+-------+----------------+"
     "|0-0    |0-2xxxxxxxxxxxxx|"
     "+---+---+----------------+"
     "|1-0|1-1                 |"
@@ -201,7 +201,7 @@ test_table!(
         .change_cell((0, 0), "0-0xxxxxxx")
         .config(|cfg| cfg.set_column_span((0, 0), 2))
         .build(),
-    "+-----+----+---+"
+    "+----------+---+"
     "|0-0xxxxxxx|0-2|"
     "+-----+----+---+"
     "|1-0  |1-1 |1-2|"
@@ -219,13 +219,13 @@ test_table!(
             cfg.set_column_span((2, 0), 2);
         })
         .build(),
-    "+---------+---------+"
+    "+-------------------+"
     "|first row          |"
     "+---------+---------+"
     "|0        |1        |"
     "+---------+---------+"
     "|a longer second row|"
-    "+---------+---------+"
+    "+-------------------+"
 );
 
 test_table!(
@@ -234,7 +234,7 @@ test_table!(
         .data([["3   ", ""], ["2", "4"]])
         .config(|cfg| cfg.set_column_span((0, 0), 2))
         .build(),
-    "+--+-+"
+    "+----+"
     "|3   |"
     "+--+-+"
     "|2 |4|"
@@ -250,13 +250,13 @@ test_table!(
             cfg.set_column_span((2, 0), 2);
         })
         .build(),
-    "+-+-+"
+    "+---+"
     "|0-0|"
-    "+-+-+"
+    "+---+"
     "|1-0|"
-    "+-+-+"
+    "+---+"
     "|2-0|"
-    "+-+-+"
+    "+---+"
 );
 
 test_table!(
@@ -265,7 +265,7 @@ test_table!(
         .data([["123", ""], ["asd", "asd"]])
         .config(|cfg| cfg.set_column_span((0, 0), 2))
         .build(),
-    "+---+---+"
+    "+-------+"
     "|123    |"
     "+---+---+"
     "|asd|asd|"
@@ -281,11 +281,11 @@ test_table!(
             cfg.set_column_span((1, 0), 2);
         })
         .build(),
-    "+--+-+"
+    "+----+"
     "|1234|"
-    "+--+-+"
+    "+----+"
     "|asdw|"
-    "+--+-+"
+    "+----+"
 );
 
 test_table!(
@@ -297,11 +297,11 @@ test_table!(
             cfg.set_column_span((1, 0), 2);
         })
         .build(),
-    "+++"
+    "+-+"
     "|1|"
-    "+++"
+    "+-+"
     "|a|"
-    "+++"
+    "+-+"
 );
 
 test_table!(
@@ -322,7 +322,7 @@ test_table!(
     grid(2, 3)
         .config(|cfg| cfg.set_column_span((0, 1), 0))
         .build(),
-    "+---+---+---+"
+    "+-------+---+"
     "|0-0    |0-2|"
     "+---+---+---+"
     "|1-0|1-1|1-2|"
@@ -337,11 +337,11 @@ test_table!(
             cfg.set_column_span((1, 1), 0);
         })
         .build(),
-    "+-+-+---+"
+    "+---+---+"
     "|0-0|0-2|"
-    "+-+-+---+"
+    "+---+---+"
     "|1-0|1-2|"
-    "+-+-+---+"
+    "+---+---+"
 );
 
 test_table!(
@@ -352,7 +352,7 @@ test_table!(
             cfg.set_column_span((0, 2), 0);
         })
         .build(),
-    "+---+---+---+"
+    "+-----------+"
     "|0-0        |"
     "+---+---+---+"
     "|1-0|1-1|1-2|"
@@ -369,11 +369,11 @@ test_table!(
             cfg.set_column_span((1, 2), 0);
         })
         .build(),
-    "+-+++"
+    "+---+"
     "|0-0|"
-    "+-+++"
+    "+---+"
     "|1-0|"
-    "+-+++"
+    "+---+"
 );
 
 // todo: determine if it's correct behaviour?
@@ -388,11 +388,11 @@ test_table!(
             cfg.set_column_span((1, 1), 0);
         })
         .build(),
-    "+-+-+"
+    "+---+"
     "|123|"
-    "+-+-+"
+    "+---+"
     "|asd|"
-    "+-+-+"
+    "+---+"
 );
 
 test_table!(
@@ -412,3 +412,37 @@ test_table!(
     // "|1-0|1-1|"
     // "+---+---+"
 );
+
+test_table!(
+    column_span_intersections_test,
+    grid(3, 3)
+        .config(|cfg| {
+            cfg.set_borders(Borders {
+                top: Some('─'),
+                top_left: Some('┌'),
+                top_right: Some('┐'),
+                top_intersection: Some('┬'),
+                bottom: Some('─'),
+                bottom_left: Some('└'),
+                bottom_right: Some('┘'),
+                bottom_intersection: Some('┴'),
+                horizontal: Some('─'),
+                horizontal_left: Some('├'),
+                horizontal_right: Some('┤'),
+                vertical: Some('│'),
+                vertical_left: Some('│'),
+                vertical_right: Some('│'),
+                intersection: Some('┼'),
+            });
+            cfg.set_column_span((0, 0), 2);
+            cfg.set_column_span((1, 1), 2);
+        })
+        .build(),
+    "┌───────┬───┐"
+    "│0-0    │0-2│"
+    "├───┬───┴───┤"
+    "│1-0│1-1    │"
+    "├───┼───┬───┤"
+    "│2-0│2-1│2-2│"
+    "└───┴───┴───┘"
+);
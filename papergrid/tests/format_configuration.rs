@@ -983,3 +983,38 @@ fn tab_size_test() {
         )
     );
 }
+
+#[test]
+fn formatting_preserve_whitespace_test() {
+    let grid = grid(2, 2)
+        .data([["diff -  \n+ line  ", "1"], ["a very long text", "  2  "]])
+        .config(|cfg| {
+            cfg.set_formatting(Entity::Global, Formatting::new(true, true, true));
+            cfg.set_alignment_horizontal(Entity::Global, AlignmentHorizontal::Right);
+        });
+
+    assert_eq!(
+        grid.clone().build(),
+        static_table!(
+            "+----------------+-----+"
+            "|          diff -|    1|"
+            "|          + line|     |"
+            "+----------------+-----+"
+            "|a very long text|    2|"
+            "+----------------+-----+"
+        ),
+    );
+
+    assert_eq!(
+        grid.config(|cfg| cfg.set_formatting(Entity::Column(0), Formatting::preserved()))
+            .build(),
+        static_table!(
+            "+----------------+-----+"
+            "|        diff -  |    1|"
+            "|        + line  |     |"
+            "+----------------+-----+"
+            "|a very long text|    2|"
+            "+----------------+-----+"
+        ),
+    );
+}
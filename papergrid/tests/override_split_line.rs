@@ -140,6 +140,7 @@ test_table!(
     "|1-0|1-1|"
 );
 
+#[cfg(not(feature = "graphemes"))]
 test_table!(
     string_which_starts_from_emojie,
     grid(2, 2).config(|cfg| {
@@ -152,6 +153,20 @@ test_table!(
     "🇻🇬🇻🇬🇻🇬🇻🇬🇻"
 );
 
+// a flag is a single cluster of width 2, so it's replaced where it's split by an intersection
+#[cfg(feature = "graphemes")]
+test_table!(
+    string_which_starts_from_emojie,
+    grid(2, 2).config(|cfg| {
+        cfg.override_split_line(2, "🇻🇬🇻🇬🇻🇬🇻🇬🇻🇬🇻🇬🇻🇬", Offset::Begin(0));
+    }).build(),
+    "+---+---+"
+    "|0-0|0-1|"
+    "+---+---+"
+    "|1-0|1-1|"
+    "\u{FFFD}\u{FFFD}🇻🇬\u{FFFD}\u{FFFD}🇻🇬\u{FFFD}"
+);
+
 test_table!(
     offset_test_1,
     grid(2, 2).config(|cfg| cfg.override_split_line(0, "T", Offset::Begin(1))).build(),
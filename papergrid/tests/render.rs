@@ -231,6 +231,17 @@ test_table!(
     "+---+--------+"
 );
 
+test_table!(
+    render_crlf,
+    grid(2, 2).change_cell((0, 1), "line 1\r\nline two").build(),
+    "+---+--------+"
+    "|0-0|line 1  |"
+    "|   |line two|"
+    "+---+--------+"
+    "|1-0|1-1     |"
+    "+---+--------+"
+);
+
 // #[test]
 // #[ignore = "I am not sure what is the right behaiviour here"]
 // fn hieroglyph_handling() {
@@ -18,7 +18,7 @@ test_table!(
         .build(),
     "+---+---+"
     "|   |0-1|"
-    "+0-0+---+"
+    "|0-0+---+"
     "|   |1-1|"
     "+---+---+"
 );
@@ -33,7 +33,7 @@ test_table!(
         .build(),
     "+---+---+"
     "|   |0-1|"
-    "+   +---+"
+    "|   +---+"
     "|0-0|1-1|"
     "+---+---+"
 );
@@ -49,7 +49,7 @@ test_table!(
         .build(),
     "+------+---+"
     "|0-0   |0-1|"
-    "+0-1xxx+---+"
+    "|0-1xxx+---+"
     "|      |1-1|"
     "+------+---+"
 );
@@ -65,7 +65,7 @@ test_table!(
         .build(),
     "+------+---+"
     "|      |0-1|"
-    "+0-0   +---+"
+    "|0-0   +---+"
     "|0-1xxx|1-1|"
     "+------+---+"
 );
@@ -86,11 +86,11 @@ test_table!(
         .build(),
     "+----------+---+--------------+"
     "|first line|0-1|full last line|"
-    "+          +---+              +"
+    "|          +---+              |"
     "|          |1  |              |"
-    "+----------+---+              +"
+    "+----------+---+              |"
     "|0         |1  |              |"
-    "+----------+---+              +"
+    "+----------+---+              |"
     "|3-0       |3-1|              |"
     "+----------+---+--------------+"
 );
@@ -105,7 +105,7 @@ test_table!(
         .build(),
     "+---+---+"
     "|0-0|0-1|"
-    "+   +---+"
+    "|   +---+"
     "|   |1-1|"
     "+---+---+"
     "|2-0|2-1|"
@@ -122,7 +122,7 @@ test_table!(
         .build(),
     "+-----------+---+"
     "|    0-0    |0-1|"
-    "+           +---+"
+    "|           +---+"
     "|           |1-1|"
     "+-----------+---+"
     "|2-0        |2-1|"
@@ -142,7 +142,7 @@ test_table!(
     "|   |   |"
     "|   |   |"
     "|   |   |"
-    "+0-0+---+"
+    "|0-0+---+"
     "|   |1-1|"
     "|   |   |"
     "|   |   |"
@@ -162,8 +162,8 @@ test_table!(
         })
         .build(),
     "+---+---+---+"
-    "+0-0+0-1+0-2+"
-    "+   +---+---+"
+    "|0-0|0-1|0-2|"
+    "|   +---+---+"
     "|   |2-1|2-2|"
     "+---+---+---+"
 );
@@ -181,9 +181,9 @@ test_table!(
     "+---+---+---+"
     "|0-0|t  |0-2|"
     "|   |e  |   |"
-    "+   +x  +   +"
+    "|   |x  |   |"
     "|   |t  |   |"
-    "+   +---+---+"
+    "|   +---+---+"
     "|   |2-1|2-2|"
     "+---+---+---+"
 );
@@ -199,7 +199,7 @@ test_table!(
         .build(),
     "+----------+---+---+"
     "|0-0xxxxxxx|0-1|0-2|"
-    "+          +---+---+"
+    "|          +---+---+"
     "|          |1-1|1-2|"
     "+----------+   +---+"
     "|2-0       |   |2-2|"
@@ -217,7 +217,7 @@ test_table!(
         .build(),
     "+---+----------+---+"
     "|0-0|0-1       |0-2|"
-    "+   +----------+---+"
+    "|   +----------+---+"
     "|   |1-1xxxxxxx|1-2|"
     "+---+          +---+"
     "|2-0|          |2-2|"
@@ -239,7 +239,7 @@ test_table!(
     "|   |     |xxx    |"
     "|   |     |xx     |"
     "|   |     |xxxxxxx|"
-    "+   +-----+-------+"
+    "|   +-----+-------+"
     "|   |1-1  |1-2    |"
     "|   |x    |       |"
     "+---+x    +-------+"
@@ -258,7 +258,7 @@ test_table!(
         .build(),
     "+---+---+------+"
     "|0-0|0-1|0-2   |"
-    "+   +---+------+"
+    "|   +---+------+"
     "|   |1-1|2-1   |"
     "|   |   |xx    |"
     "|   |   |xx    |"
@@ -281,9 +281,9 @@ test_table!(
         .build(),
     "+---+-------+---+"
     "|0-0|0-1    |0-2|"
-    "+   +-------+---+"
+    "|   +-------+---+"
     "|   |1-1    |1-2|"
-    "+---+-------+   +"
+    "+---+-------+   |"
     "|2-0|0-2    |   |"
     "|   |x      |   |"
     "|   |x      |   |"
@@ -305,7 +305,7 @@ test_table!(
     "|0-0|0-1|0-2|"
     "|xx |   |   |"
     "|x  |   |   |"
-    "+x  +---+---+"
+    "|x  +---+---+"
     "|x  |1-1|1-2|"
     "|x  |   |   |"
     "|x  |   |   |"
@@ -330,7 +330,7 @@ test_table!(
     "|f  |0-1|a     |"
     "|ir |   |      |"
     "|st |   |longer|"
-    "+ ro+---+      +"
+    "| ro+---+      |"
     "|w  |1  |second|"
     "|   |   |      |"
     "|   |   |row   |"
@@ -350,7 +350,7 @@ test_table!(
     "+-+-+"
     "|3|2|"
     "| | |"
-    "+ +-+"
+    "| +-+"
     "| |4|"
     "+-+-+"
 );
@@ -365,7 +365,7 @@ test_table!(
         })
         .build(),
     "+---+---+---+"
-    "+0-0+0-1+0-2+"
+    "|0-0|0-1|0-2|"
     "+---+---+---+"
 );
 
@@ -382,7 +382,7 @@ test_table!(
     "|  |   |"
     "|  |   |"
     "|  |   |"
-    "+  +---+"
+    "|  +---+"
     "|  |1-1|"
     "|  |   |"
     "|23|   |"
@@ -400,7 +400,7 @@ test_table!(
         .build(),
     "+--+--+"
     "|12|a |"
-    "+3 +s +"
+    "|3 |s |"
     "|4 |dw|"
     "+--+--+"
 );
@@ -415,7 +415,7 @@ test_table!(
         })
         .build(),
     "+-+-+"
-    "+1+a+"
+    "|1|a|"
     "+-+-+"
 );
 
@@ -430,7 +430,7 @@ test_table!(
         .build(),
     "+---+-+"
     "|1as|a|"
-    "+d  + +"
+    "|d  | |"
     "|   | |"
     "+---+-+"
 );
@@ -447,7 +447,7 @@ test_table!(
         .build(),
     "+---+-+"
     "|1as| |"
-    "+d  +a+"
+    "|d  |a|"
     "|   | |"
     "+---+-+"
 );
@@ -465,7 +465,7 @@ test_table!(
     "+--+-+"
     "|1a| |"
     "|s |a|"
-    "+d + +"
+    "|d | |"
     "|  | |"
     "+--+-+"
 );
@@ -482,7 +482,7 @@ test_table!(
         .build(),
     "+--+-+"
     "|1a| |"
-    "+s + +"
+    "|s | |"
     "|d |a|"
     "+--+-+"
 );
@@ -511,7 +511,7 @@ test_table!(
         .build(),
     "+---+---+"
     "|0-0|0-1|"
-    "+   +---+"
+    "|   +---+"
     "|   |1-1|"
     "+---+---+"
     "|2-0|2-1|"
@@ -527,7 +527,7 @@ test_table!(
         })
         .build(),
     "+---+---+"
-    "+0-0+0-1+"
+    "|0-0|0-1|"
     "+---+---+"
     "|2-0|2-1|"
     "+---+---+"
@@ -543,9 +543,9 @@ test_table!(
         .build(),
     "+---+---+"
     "|0-0|0-1|"
-    "+---+   +"
+    "+---+   |"
     "|1-0|   |"
-    "+---+   +"
+    "+---+   |"
     "|2-0|   |"
     "+---+---+"
 );
@@ -561,8 +561,8 @@ test_table!(
         })
         .build(),
     "+---+---+"
-    "+0-0+0-1+"
-    "+   +   +"
+    "|0-0|0-1|"
+    "|   |   |"
     "+---+---+"
 );
 
@@ -577,7 +577,7 @@ test_table!(
         })
         .build(),
     "+---+---+"
-    "+0-0+0-1+"
+    "|0-0|0-1|"
     "+---+---+"
 );
 
@@ -615,10 +615,10 @@ test_table!(
             cfg.set_alignment_vertical(Cell(0, 0), AlignmentVertical::Center);
         })
         .build(),
-    "+---+---+---+---+"
+    "+-------+---+---+"
     "|  123  |0-2|0-3|"
     "|  345  |   |   |"
-    "+  555  +---+---+"
+    "|  555  +---+---+"
     "|  333  |1-2|1-3|"
     "+---+---+---+---+"
     "|2-0|2-1|2-2|2-3|"
@@ -645,9 +645,9 @@ test_table!(
     "+---+---+---+---+"
     "|  123  |2-2|2-3|"
     "|  345  |   |   |"
-    "+  555  +---+---+"
+    "|  555  +---+---+"
     "|  333  |3-2|3-3|"
-    "+---+---+---+---+"
+    "+-------+---+---+"
 );
 
 test_table!(
@@ -668,9 +668,9 @@ test_table!(
     "+---+---+---+---+"
     "|2-0|2-1|  123  |"
     "|   |   |  345  |"
-    "+---+---+  555  +"
+    "+---+---+  555  |"
     "|3-0|3-1|  333  |"
-    "+---+---+---+---+"
+    "+---+---+-------+"
 );
 
 test_table!(
@@ -684,10 +684,10 @@ test_table!(
             cfg.set_alignment_vertical(Cell(0, 2), AlignmentVertical::Center);
         })
         .build(),
-    "+---+---+---+---+"
+    "+---+---+-------+"
     "|0-0|0-1|  123  |"
     "|   |   |  345  |"
-    "+---+---+  555  +"
+    "+---+---+  555  |"
     "|1-0|1-1|  333  |"
     "+---+---+---+---+"
     "|2-0|2-1|2-2|2-3|"
@@ -707,7 +707,7 @@ test_table!(
             cfg.set_alignment_vertical(Cell(0, 1), AlignmentVertical::Center);
         })
         .build(),
-    "+---+---+---+---+"
+    "+---+-------+---+"
     "|0-0|  123  |0-3|"
     "|   |  345  |   |"
     "+---+  555  +---+"
@@ -734,11 +734,11 @@ test_table!(
     "|0-0|0-1|0-2|0-3|"
     "+---+---+---+---+"
     "|1-0|    123    |"
-    "+---+    345    +"
+    "+---+    345    |"
     "|2-0|    555    |"
-    "+---+    333    +"
+    "+---+    333    |"
     "|3-0|           |"
-    "+---+---+---+---+"
+    "+---+-----------+"
 );
 
 test_table!(
@@ -752,11 +752,11 @@ test_table!(
             cfg.set_alignment_vertical(Cell(0, 0), AlignmentVertical::Center);
         })
         .build(),
-    "+---+---+---+---+"
+    "+-----------+---+"
     "|    123    |0-3|"
-    "+    345    +---+"
+    "|    345    +---+"
     "|    555    |1-3|"
-    "+    333    +---+"
+    "|    333    +---+"
     "|           |2-3|"
     "+---+---+---+---+"
     "|3-0|3-1|3-2|3-3|"
@@ -774,11 +774,11 @@ test_table!(
             cfg.set_alignment_vertical(Cell(0, 1), AlignmentVertical::Center);
         })
         .build(),
-    "+---+---+---+---+"
+    "+---+-----------+"
     "|0-0|    123    |"
-    "+---+    345    +"
+    "+---+    345    |"
     "|1-0|    555    |"
-    "+---+    333    +"
+    "+---+    333    |"
     "|2-0|           |"
     "+---+---+---+---+"
     "|3-0|3-1|3-2|3-3|"
@@ -800,11 +800,11 @@ test_table!(
     "|0-0|0-1|0-2|0-3|"
     "+---+---+---+---+"
     "|    123    |1-3|"
-    "+    345    +---+"
+    "|    345    +---+"
     "|    555    |2-3|"
-    "+    333    +---+"
+    "|    333    +---+"
     "|           |3-3|"
-    "+---+---+---+---+"
+    "+-----------+---+"
 );
 
 test_table!(
@@ -822,14 +822,14 @@ test_table!(
     "|hello|0-1|0-2|0-3|"
     "|world|   |   |   |"
     "|!    |   |   |   |"
-    "+!    +---+---+   +"
+    "|!    +---+---+   |"
     "|!    |1-1    |   |"
     "|!    |       |   |"
-    "+-----+---+---+   +"
+    "+-----+---+---+   |"
     "|2-0  |2-1|2-2|   |"
     "+-----+---+---+---+"
     "|3-0          |3-3|"
-    "+-----+---+---+---+"
+    "+-------------+---+"
 );
 
 test_table!(
@@ -843,13 +843,13 @@ test_table!(
             cfg.set_row_span((0, 3), 3);
         })
         .build(),
-    "+---+---+---+---+"
+    "+-------+---+---+"
     "|0-0    |q  |q1 |"
     "|       |w  |w1 |"
-    "+---+---+e  +e1 +"
+    "+---+---+e  |e1 |"
     "|1-0|1-1|r  |r1 |"
     "|   |   |t  |t1 |"
-    "+---+---+---+   +"
+    "+---+---+---+   |"
     "|2-0|2-1|2-2|   |"
     "+---+---+---+---+"
     "|3-0|3-1|3-2|3-3|"
@@ -867,13 +867,13 @@ test_table!(
             cfg.set_row_span((0, 4), 3);
         })
         .build(),
-    "+---+---+---+---+--+"
+    "+-------+---+---+--+"
     "|0-0    |0-2|q  |q1|"
     "|       |   |w  |w1|"
-    "+---+---+---+e  +e1+"
+    "+---+---+---+e  |e1|"
     "|1-0|1-1|1-2|r  |r1|"
     "|   |   |   |t  |t1|"
-    "+---+---+---+---+  +"
+    "+---+---+---+---+  |"
     "|2-0|2-1|2-2|2-3|  |"
     "+---+---+---+---+--+"
 );
@@ -889,14 +889,14 @@ test_table!(
             cfg.set_row_span((0, 4), 3);
         })
         .build(),
-    "+---+---+---+---+--+"
+    "+-------+---+---+--+"
     "|0-0    |0-2|q  |q1|"
     "|       |   |w  |w1|"
     "|       |   |e  |e1|"
-    "+---+---+---+r  +r1+"
+    "+---+---+---+r  |r1|"
     "|1-0|1-1|1-2|t  |t1|"
     "|   |   |   |   |  |"
-    "+---+---+---+---+  +"
+    "+---+---+---+---+  |"
     "|2-0|2-1|2-2|2-3|  |"
     "+---+---+---+---+--+"
 );
@@ -924,13 +924,13 @@ test_table!(
             );
         })
         .build(),
-    "+---+---+-------+-----+--+"
+    "+-------+-------+-----+--+"
     "|       |0-2    |     |q1|"
     "|       |       |     |w1|"
     "|  0-0  |       |  q  |e1|"
     "|       |       |  w  |r1|"
     "|       |       |  e  |t1|"
-    "+---+---+-------+  r  +  +"
+    "+---+---+-------+  r  |  |"
     "|1-0|1-1|       |  t  |  |"
     "|   |   |       |     |  |"
     "|   |   |       |     |  |"
@@ -938,7 +938,7 @@ test_table!(
     "|   |   |  1-2  |     |  |"
     "|   |   |       |     |  |"
     "|   |   |       |     |  |"
-    "+---+---+-------+-----+  +"
+    "+---+---+-------+-----+  |"
     "|2-0|2-1|2-2    |2-3  |  |"
     "+---+---+-------+-----+--+"
 );
@@ -953,13 +953,13 @@ test_table!(
             cfg.set_row_span((1, 0), 2);
         })
         .build(),
-    "+-+-+---+---+---+"
+    "+---+---+---+---+"
     "|0-0|0-2|0-3|0-4|"
-    "+-+-+---+---+---+"
+    "+---+---+---+---+"
     "|1-0|1-2|1-3|1-4|"
-    "+   +---+---+---+"
+    "|   +---+---+---+"
     "|   |2-2|2-3|2-4|"
-    "+-+-+---+---+---+"
+    "+---+---+---+---+"
 );
 
 test_table!(
@@ -974,11 +974,11 @@ test_table!(
     "|0-0|0-1|"
     "+---+---+"
     "|1-0|1  |"
-    "+---+2  +"
+    "+---+2  |"
     "|2-0|3  |"
-    "+---+4  +"
+    "+---+4  |"
     "|3-0|   |"
-    "+---+   +"
+    "+---+   |"
     "|4-0|   |"
     "+---+---+"
 );
@@ -992,13 +992,13 @@ test_table!(
             cfg.set_column_span((2, 0), 2);
         })
         .build(),
-    "+-+-+---+---+"
+    "+---+---+---+"
     "|0-0|0-2|0-3|"
-    "+-+-+---+---+"
+    "+---+---+---+"
     "|1-0|1-2|1-3|"
-    "+-+-+---+---+"
+    "+---+---+---+"
     "|2-0|2-2|2-3|"
-    "+-+-+---+---+"
+    "+---+---+---+"
 );
 
 test_table!(
@@ -1010,13 +1010,13 @@ test_table!(
             cfg.set_column_span((2, 0), 3);
         })
         .build(),
-    "+-+++---+"
+    "+---+---+"
     "|0-0|0-3|"
-    "+-+++---+"
+    "+---+---+"
     "|1-0|1-3|"
-    "+-+++---+"
+    "+---+---+"
     "|2-0|2-3|"
-    "+-+++---+"
+    "+---+---+"
 );
 
 test_table!(
@@ -1031,13 +1031,13 @@ test_table!(
             cfg.set_column_span((2, 0), 3);
         })
         .build(),
-    "++++---+"
+    "+--+---+"
     "|  |0-3|"
-    "++++---+"
+    "+--+---+"
     "|  |1-3|"
-    "++++---+"
+    "+--+---+"
     "|  |2-3|"
-    "++++---+"
+    "+--+---+"
 );
 
 // #[test]
@@ -1066,3 +1066,36 @@ test_table!(
 //         )
 //     );
 // }
+
+test_table!(
+    row_span_intersections_test,
+    grid(3, 2)
+        .config(|cfg| {
+            cfg.set_borders(Borders {
+                top: Some('─'),
+                top_left: Some('┌'),
+                top_right: Some('┐'),
+                top_intersection: Some('┬'),
+                bottom: Some('─'),
+                bottom_left: Some('└'),
+                bottom_right: Some('┘'),
+                bottom_intersection: Some('┴'),
+                horizontal: Some('─'),
+                horizontal_left: Some('├'),
+                horizontal_right: Some('┤'),
+                vertical: Some('│'),
+                vertical_left: Some('│'),
+                vertical_right: Some('│'),
+                intersection: Some('┼'),
+            });
+            cfg.set_row_span((0, 0), 2);
+        })
+        .build(),
+    "┌───┬───┐"
+    "│0-0│0-1│"
+    "│   ├───┤"
+    "│   │1-1│"
+    "├───┼───┤"
+    "│2-0│2-1│"
+    "└───┴───┘"
+);
@@ -145,3 +145,28 @@ test_table!(
         .build(),
     "0-00-1\n$$$###\n$$$###\n$$$###\n1-01-1\n   ###\n   ###"
 );
+
+test_table!(
+    ambiguous_width_narrow,
+    grid(2, 2)
+        .data([["±½", "→"], ["°", "×"]])
+        .build(),
+    "+--+-+"
+    "|±½|→|"
+    "+--+-+"
+    "|° |×|"
+    "+--+-+"
+);
+
+test_table!(
+    ambiguous_width_wide,
+    grid(2, 2)
+        .data([["±½", "→"], ["°", "×"]])
+        .config(|cfg| cfg.set_ambiguous_wide(true))
+        .build(),
+    "+----+--+"
+    "|±½|→|"
+    "+----+--+"
+    "|°  |×|"
+    "+----+--+"
+);
@@ -335,3 +335,65 @@ fn grid_2x2_ansi_border_none_if_string_is_not_1_char_test() {
     assert!(AnsiColor::try_from("1".on_red().blue().to_string()).is_ok());
     assert!(AnsiColor::try_from("1".truecolor(0, 1, 3).on_truecolor(1, 2, 3).to_string()).is_ok());
 }
+
+#[cfg(feature = "color")]
+test_table!(
+    grid_2x2_text_color_test,
+    grid(2, 2)
+        .config(|cfg| {
+            use papergrid::AnsiColor;
+
+            let color = AnsiColor::new("\u{1b}[31m".into(), "\u{1b}[39m".into());
+            cfg.set_text_color(Entity::Column(1), color);
+        })
+        .build(),
+    "+---+---+"
+    "|0-0|\u{1b}[31m0-1\u{1b}[39m|"
+    "+---+---+"
+    "|1-0|\u{1b}[31m1-1\u{1b}[39m|"
+    "+---+---+"
+);
+
+#[cfg(feature = "color")]
+test_table!(
+    grid_2x2_fill_color_test,
+    grid(2, 2)
+        .change_cell((0, 0), "0")
+        .change_cell((1, 0), "1-0-0")
+        .config(|cfg| {
+            use papergrid::AnsiColor;
+
+            let color = AnsiColor::new("\u{1b}[41m".into(), "\u{1b}[49m".into());
+            cfg.set_fill_color(Entity::Row(0), color);
+            cfg.set_padding(
+                Entity::Row(0),
+                Padding::new(Indent::spaced(1), Indent::spaced(1), Indent::default(), Indent::default()),
+            );
+        })
+        .build(),
+    "+-----+-----+"
+    "|\u{1b}[41m 0   \u{1b}[49m|\u{1b}[41m 0-1 \u{1b}[49m|"
+    "+-----+-----+"
+    "|1-0-0|1-1  |"
+    "+-----+-----+"
+);
+
+#[cfg(feature = "color")]
+test_table!(
+    grid_2x2_colors_disabled_test,
+    grid(2, 2)
+        .config(|cfg| {
+            use papergrid::AnsiColor;
+
+            let color = AnsiColor::new("\u{1b}[31m".into(), "\u{1b}[39m".into());
+            cfg.set_text_color(Entity::Global, color.clone());
+            cfg.set_border_color_global(color);
+            cfg.set_colors_enabled(false);
+        })
+        .build(),
+    "+---+---+"
+    "|0-0|0-1|"
+    "+---+---+"
+    "|1-0|1-1|"
+    "+---+---+"
+);
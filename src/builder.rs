@@ -118,7 +118,7 @@ use papergrid::{
         Records,
     },
     width::{CfgWidthFunction, WidthFunc},
-    AlignmentHorizontal, Entity, Formatting, GridConfig, Indent, Padding,
+    AlignmentHorizontal, Entity, Formatting, GridConfig, Indent, Padding, Position,
 };
 
 use crate::{Style, Table};
@@ -139,6 +139,41 @@ use crate::{Style, Table};
 /// println!("{}", table);
 /// ```
 ///
+/// Setters return `&mut Self`, while [`Builder::build`] consumes the builder,
+/// so keep the builder in a variable in case you need to add records in a loop,
+/// e.g. when a data comes from a CSV file or a query result.
+///
+/// ```rust
+/// use tabled::builder::Builder;
+///
+/// let csv = "name,size\nCargo.toml,2048\nsrc,4096";
+///
+/// let mut builder = Builder::default();
+/// for (i, line) in csv.lines().enumerate() {
+///     let fields = line.split(',');
+///     if i == 0 {
+///         builder.set_columns(fields);
+///     } else {
+///         builder.add_record(fields);
+///     }
+/// }
+///
+/// let table = builder.build().to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+------------+------+\n",
+///         "| name       | size |\n",
+///         "+------------+------+\n",
+///         "| Cargo.toml | 2048 |\n",
+///         "+------------+------+\n",
+///         "| src        | 4096 |\n",
+///         "+------------+------+",
+///     )
+/// );
+/// ```
+///
 /// It may be useful to use [`FromIterator`] for building.
 ///
 /// ```rust
@@ -166,6 +201,12 @@ pub struct Builder<'a> {
     different_column_sizes_used: bool,
     /// A content of cells which are created in case rows has different length.
     empty_cell_text: Option<String>,
+    /// A capacity hint of a row.
+    row_capacity: usize,
+    /// A list of column spans.
+    column_spans: Vec<(Position, usize)>,
+    /// A list of row spans.
+    row_spans: Vec<(Position, usize)>,
 }
 
 impl<'a> Builder<'a> {
@@ -174,6 +215,184 @@ impl<'a> Builder<'a> {
         Self::default()
     }
 
+    /// Creates a [`Builder`] instance with a preallocated space
+    /// for a given number of records and columns.
+    ///
+    /// It's only a hint, it doesn't limit the size of a [`Table`].
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::with_capacity(1000, 2);
+    /// for i in 0..1000 {
+    ///     builder.add_record([i.to_string(), (i * i).to_string()]);
+    /// }
+    ///
+    /// let table = builder.build();
+    /// assert_eq!(table.shape(), (1000, 2));
+    /// ```
+    pub fn with_capacity(count_records: usize, count_columns: usize) -> Self {
+        Self {
+            records: Vec::with_capacity(count_records),
+            row_capacity: count_columns,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a [`Builder`] with `key` and `value` columns out of a map.
+    ///
+    /// Rows are kept in an iteration order of the map,
+    /// so a [`BTreeMap`] is sorted by keys while a [`HashMap`] order is arbitrary.
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.insert("Minsk", 1_996_553);
+    /// map.insert("Grodno", 357_493);
+    ///
+    /// let table = Builder::from_map(&map).build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+--------+---------+\n\
+    ///      | key    | value   |\n\
+    ///      +--------+---------+\n\
+    ///      | Grodno | 357493  |\n\
+    ///      +--------+---------+\n\
+    ///      | Minsk  | 1996553 |\n\
+    ///      +--------+---------+"
+    /// );
+    /// ```
+    ///
+    /// [`BTreeMap`]: std::collections::BTreeMap
+    /// [`HashMap`]: std::collections::HashMap
+    pub fn from_map<M, K, V>(map: M) -> Self
+    where
+        M: IntoIterator<Item = (K, V)>,
+        K: std::fmt::Display,
+        V: std::fmt::Display,
+    {
+        let mut builder = Self::default();
+        builder.set_columns(["key", "value"]);
+
+        for (key, value) in map {
+            builder.add_record([key.to_string(), value.to_string()]);
+        }
+
+        builder
+    }
+
+    /// Creates a [`Builder`] out of a list of maps, where each map is a row.
+    ///
+    /// A union of the keys is used as columns.
+    /// Columns are ordered by a first appearance of a key,
+    /// iterating over the maps in order, so use [`BTreeMap`]s to get a stable order.
+    ///
+    /// A cell of a key which a map doesn't have is left empty.
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use tabled::builder::Builder;
+    ///
+    /// let rows = [
+    ///     BTreeMap::from([("name", "Grodno"), ("region", "Grodno")]),
+    ///     BTreeMap::from([("name", "Minsk"), ("capital", "yes")]),
+    /// ];
+    ///
+    /// let table = Builder::from_maps(&rows).build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+--------+--------+---------+\n\
+    ///      | name   | region | capital |\n\
+    ///      +--------+--------+---------+\n\
+    ///      | Grodno | Grodno |         |\n\
+    ///      +--------+--------+---------+\n\
+    ///      | Minsk  |        | yes     |\n\
+    ///      +--------+--------+---------+"
+    /// );
+    /// ```
+    ///
+    /// [`BTreeMap`]: std::collections::BTreeMap
+    pub fn from_maps<I, M, K, V>(maps: I) -> Self
+    where
+        I: IntoIterator<Item = M>,
+        M: IntoIterator<Item = (K, V)>,
+        K: std::fmt::Display,
+        V: std::fmt::Display,
+    {
+        let mut columns: Vec<String> = Vec::new();
+        let mut rows = Vec::new();
+        for map in maps {
+            let mut row = vec![None; columns.len()];
+            for (key, value) in map {
+                let key = key.to_string();
+                let value = Some(value.to_string());
+                match columns.iter().position(|column| *column == key) {
+                    Some(i) => row[i] = value,
+                    None => {
+                        columns.push(key);
+                        row.push(value);
+                    }
+                }
+            }
+
+            rows.push(row);
+        }
+
+        let mut builder = Self::default();
+        let count_columns = columns.len();
+        builder.set_columns(columns);
+
+        for mut row in rows {
+            row.resize(count_columns, None);
+            builder.add_record(row.into_iter().map(Option::unwrap_or_default));
+        }
+
+        builder
+    }
+
+    /// Creates a [`Builder`] out of a rendered table.
+    ///
+    /// It can be used to get a table back from an output, or to write a table by hand.
+    ///
+    /// - Borders are recognized in most of the built-in styles,
+    ///   a table without vertical borders is split by columns of spaces.
+    /// - A first row is considered to be a header if it's separated from the rest by a line.
+    /// - Cells are considered to be multiline only in case all rows are separated by lines,
+    ///   otherwise each line is a row.
+    /// - Column spans are recognized, row spans are not.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let text = "
+    /// ┌───────┬──────────┐
+    /// │ name  │ based on │
+    /// ├───────┼──────────┤
+    /// │ Debian│          │
+    /// │ Arch  │          │
+    /// └───────┴──────────┘";
+    ///
+    /// let table = Builder::from_rendered(text).build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+--------+----------+\n\
+    ///      | name   | based on |\n\
+    ///      +--------+----------+\n\
+    ///      | Debian |          |\n\
+    ///      +--------+----------+\n\
+    ///      | Arch   |          |\n\
+    ///      +--------+----------+"
+    /// );
+    /// ```
+    pub fn from_rendered(text: &str) -> Builder<'static> {
+        crate::parse::parse_table(text)
+    }
+
     /// Set a column size.
     ///
     /// If it make it lower then it was originally it is considered NOP.
@@ -197,7 +416,7 @@ impl<'a> Builder<'a> {
         T: Into<Cow<'a, str>>,
     {
         let ctrl = CfgWidthFunction::new(4);
-        let list = create_row(columns, self.size, &ctrl);
+        let list = create_row(columns, self.get_row_capacity(), &ctrl);
 
         self.update_size(list.len());
         self.columns = Some(list);
@@ -267,7 +486,7 @@ impl<'a> Builder<'a> {
         T: Into<Cow<'a, str>>,
     {
         let ctrl = CfgWidthFunction::new(4);
-        let list = create_row(row, self.size, &ctrl);
+        let list = create_row(row, self.get_row_capacity(), &ctrl);
 
         self.update_size(list.len());
         self.records.push(list);
@@ -294,6 +513,72 @@ impl<'a> Builder<'a> {
         self
     }
 
+    /// Sets a column span of a cell.
+    ///
+    /// A position is a position of a cell in a built [`Table`],
+    /// so the columns row, if set, is the row `0`.
+    ///
+    /// Spans which don't fit into a [`Table`] are ignored.
+    /// Spans are dropped by [`Builder::index`].
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_columns(["name", "value"]);
+    /// builder.add_record(["Network"]);
+    /// builder.add_record(["host", "localhost"]);
+    /// builder.add_record(["port", "8080"]);
+    /// builder.set_span(1, 0, 2);
+    ///
+    /// assert_eq!(
+    ///     builder.build().to_string(),
+    ///     "+------+-----------+\n\
+    ///      | name | value     |\n\
+    ///      +------+-----------+\n\
+    ///      | Network          |\n\
+    ///      +------+-----------+\n\
+    ///      | host | localhost |\n\
+    ///      +------+-----------+\n\
+    ///      | port | 8080      |\n\
+    ///      +------+-----------+"
+    /// );
+    /// ```
+    pub fn set_span(&mut self, row: usize, column: usize, span: usize) -> &mut Self {
+        self.column_spans.push(((row, column), span));
+        self
+    }
+
+    /// Sets a row span of a cell.
+    ///
+    /// A position is a position of a cell in a built [`Table`],
+    /// so the columns row, if set, is the row `0`.
+    ///
+    /// Spans which don't fit into a [`Table`] are ignored.
+    /// Spans are dropped by [`Builder::index`].
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.add_record(["Network", "host", "localhost"]);
+    /// builder.add_record(["", "port", "8080"]);
+    /// builder.set_row_span(0, 0, 2);
+    ///
+    /// assert_eq!(
+    ///     builder.build().to_string(),
+    ///     "+---------+------+-----------+\n\
+    ///      | Network | host | localhost |\n\
+    ///      |         +------+-----------+\n\
+    ///      |         | port | 8080      |\n\
+    ///      +---------+------+-----------+"
+    /// );
+    /// ```
+    pub fn set_row_span(&mut self, row: usize, column: usize, span: usize) -> &mut Self {
+        self.row_spans.push(((row, column), span));
+        self
+    }
+
     /// Build creates a [`Table`] instance.
     ///
     /// ```rust
@@ -310,7 +595,18 @@ impl<'a> Builder<'a> {
 
         let has_columns = self.columns.is_some();
         let records = build_grid(self.records, self.columns, self.size);
-        build_table(records, has_columns)
+        let mut table = build_table(records, has_columns);
+
+        let cfg = table.get_config_mut();
+        for (pos, span) in self.column_spans {
+            cfg.set_column_span(pos, span);
+        }
+
+        for (pos, span) in self.row_spans {
+            cfg.set_row_span(pos, span);
+        }
+
+        table
     }
 
     /// Add an index to the [`Table`].
@@ -337,7 +633,16 @@ impl<'a> Builder<'a> {
     ///      +---+-------+"
     /// )
     /// ```
-    pub fn index(self) -> IndexBuilder<'a> {
+    pub fn index(mut self) -> IndexBuilder<'a> {
+        // an index moves cells so the spans would point to wrong ones
+        self.column_spans.clear();
+        self.row_spans.clear();
+
+        if self.different_column_sizes_used {
+            self.fix_rows();
+            self.different_column_sizes_used = false;
+        }
+
         IndexBuilder::new(self)
     }
 
@@ -370,6 +675,127 @@ impl<'a> Builder<'a> {
         self
     }
 
+    /// Appends records of another [`Builder`].
+    ///
+    /// The columns of the other [`Builder`] are used only in case the [`Builder`] has none.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut chunk1 = Builder::default();
+    /// chunk1.set_columns(["id", "name"]);
+    /// chunk1.add_record(["0", "Grodno"]);
+    ///
+    /// let mut chunk2 = Builder::default();
+    /// chunk2.set_columns(["id", "name"]);
+    /// chunk2.add_record(["1", "Minsk"]);
+    ///
+    /// chunk1.append(chunk2);
+    ///
+    /// assert_eq!(
+    ///     chunk1.build().to_string(),
+    ///     "+----+--------+\n\
+    ///      | id | name   |\n\
+    ///      +----+--------+\n\
+    ///      | 0  | Grodno |\n\
+    ///      +----+--------+\n\
+    ///      | 1  | Minsk  |\n\
+    ///      +----+--------+"
+    /// );
+    /// ```
+    pub fn append(&mut self, other: Builder<'a>) -> &mut Self {
+        if self.columns.is_none() {
+            if let Some(columns) = other.columns {
+                self.update_size(columns.len());
+                self.columns = Some(columns);
+            }
+        }
+
+        for row in other.records {
+            self.update_size(row.len());
+            self.records.push(row);
+        }
+
+        self
+    }
+
+    /// Appends records of another [`Builder`] uniting their columns by name.
+    ///
+    /// Columns which are not present in the [`Builder`] are added to the end.
+    ///
+    /// If any of builders has no columns it works as [`Builder::append`].
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut chunk1 = Builder::default();
+    /// chunk1.set_columns(["id", "name"]);
+    /// chunk1.add_record(["0", "Grodno"]);
+    ///
+    /// let mut chunk2 = Builder::default();
+    /// chunk2.set_columns(["country", "id"]);
+    /// chunk2.add_record(["Belarus", "1"]);
+    ///
+    /// chunk1.merge(chunk2);
+    ///
+    /// assert_eq!(
+    ///     chunk1.build().to_string(),
+    ///     "+----+--------+---------+\n\
+    ///      | id | name   | country |\n\
+    ///      +----+--------+---------+\n\
+    ///      | 0  | Grodno |         |\n\
+    ///      +----+--------+---------+\n\
+    ///      | 1  |        | Belarus |\n\
+    ///      +----+--------+---------+"
+    /// );
+    /// ```
+    pub fn merge(&mut self, mut other: Builder<'a>) -> &mut Self {
+        let (columns, other_columns) = match (self.columns.as_mut(), other.columns.take()) {
+            (Some(columns), Some(other_columns)) => (columns, other_columns),
+            (_, other_columns) => {
+                other.columns = other_columns;
+                return self.append(other);
+            }
+        };
+
+        // records of the other builder may be longer than its columns
+        let mut other_columns = other_columns;
+        other_columns.resize(other.size, CellInfo::default());
+
+        let count_columns = columns.len();
+        let mut mapping = Vec::with_capacity(other_columns.len());
+        for column in other_columns {
+            let found = (0..count_columns)
+                .find(|&i| columns[i].as_ref() == column.as_ref() && !mapping.contains(&i));
+
+            match found {
+                Some(i) => mapping.push(i),
+                None => {
+                    mapping.push(columns.len());
+                    columns.push(column);
+                }
+            }
+        }
+
+        let size = columns.len();
+        self.update_size(size);
+
+        let ctrl = CfgWidthFunction::new(4);
+        let text = self.empty_cell_text.clone().unwrap_or_default();
+        let empty_cell_text = CellInfo::new(text, &ctrl);
+
+        for row in other.records {
+            let mut list = vec![empty_cell_text.clone(); size];
+            for (cell, &col) in row.into_iter().zip(mapping.iter()) {
+                list[col] = cell;
+            }
+
+            self.records.push(list);
+        }
+
+        self
+    }
+
     /// Creates a Builder from a built [`Records`]
     ///
     /// [`Records`]: papergrid::records::Records
@@ -382,17 +808,17 @@ impl<'a> Builder<'a> {
         for col in 0..self.size {
             let col = col - i;
 
-            let mut is_empty = true;
-            for row in 0..self.records.len() {
-                if !self.records[row][col].is_empty() {
-                    is_empty = false;
-                    break;
-                }
-            }
+            // a cell may be missing in case rows have different length
+            let is_empty = self
+                .records
+                .iter()
+                .all(|row| row.len() <= col || row[col].is_empty());
 
             if is_empty {
-                for row in 0..self.records.len() {
-                    self.records[row].remove(col);
+                for row in self.records.iter_mut() {
+                    if row.len() > col {
+                        row.remove(col);
+                    }
                 }
 
                 if let Some(columns) = self.columns.as_mut() {
@@ -410,13 +836,7 @@ impl<'a> Builder<'a> {
 
     fn clean_rows(&mut self) {
         for row in (0..self.records.len()).rev() {
-            let mut is_empty = true;
-            for col in 0..self.size {
-                if !self.records[row][col].is_empty() {
-                    is_empty = false;
-                    break;
-                }
-            }
+            let is_empty = self.records[row].iter().all(CellInfo::is_empty);
 
             if is_empty {
                 self.records.remove(row);
@@ -428,6 +848,10 @@ impl<'a> Builder<'a> {
         }
     }
 
+    fn get_row_capacity(&self) -> usize {
+        std::cmp::max(self.size, self.row_capacity)
+    }
+
     fn update_size(&mut self, size: usize) {
         match size.cmp(&self.size) {
             std::cmp::Ordering::Less => {
@@ -475,6 +899,345 @@ impl<'a> Builder<'a> {
     }
 }
 
+#[cfg(feature = "csv")]
+impl Builder<'static> {
+    /// Creates a [`Builder`] from a CSV [`Reader`].
+    ///
+    /// A header record is used as columns in case the [`Reader`] is set to have headers.
+    ///
+    /// Rows of a different length are allowed only if the [`Reader`] is [`flexible`],
+    /// missing cells are filled with a default text.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let data = "name,population\nGrodno,\"357,493\"\nMinsk";
+    ///
+    /// let mut reader = csv::ReaderBuilder::new()
+    ///     .flexible(true)
+    ///     .from_reader(data.as_bytes());
+    ///
+    /// let table = Builder::from_csv(&mut reader).unwrap().build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+--------+------------+\n\
+    ///      | name   | population |\n\
+    ///      +--------+------------+\n\
+    ///      | Grodno | 357,493    |\n\
+    ///      +--------+------------+\n\
+    ///      | Minsk  |            |\n\
+    ///      +--------+------------+"
+    /// );
+    /// ```
+    ///
+    /// [`Reader`]: csv::Reader
+    /// [`flexible`]: csv::ReaderBuilder::flexible
+    #[cfg_attr(docsrs, doc(cfg(feature = "csv")))]
+    pub fn from_csv<R>(reader: &mut csv::Reader<R>) -> csv::Result<Self>
+    where
+        R: std::io::Read,
+    {
+        let headers = if reader.has_headers() {
+            Some(reader.headers()?.clone())
+        } else {
+            None
+        };
+
+        let mut builder = Self::from_csv_records(reader.records())?;
+
+        if let Some(headers) = headers {
+            builder.set_columns(headers.iter().map(ToOwned::to_owned));
+        }
+
+        Ok(builder)
+    }
+
+    /// Creates a [`Builder`] from CSV records.
+    ///
+    /// It can take [`Reader::records`] or any other iterator of [`StringRecord`]s,
+    /// so records are added one by one as they are read.
+    ///
+    /// The first error is returned as is.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let data = "Grodno,357493\nMinsk,1996553";
+    ///
+    /// let mut reader = csv::ReaderBuilder::new()
+    ///     .has_headers(false)
+    ///     .from_reader(data.as_bytes());
+    ///
+    /// let mut builder = Builder::from_csv_records(reader.records()).unwrap();
+    /// builder.set_columns(["name", "population"]);
+    ///
+    /// let table = builder.build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+--------+------------+\n\
+    ///      | name   | population |\n\
+    ///      +--------+------------+\n\
+    ///      | Grodno | 357493     |\n\
+    ///      +--------+------------+\n\
+    ///      | Minsk  | 1996553    |\n\
+    ///      +--------+------------+"
+    /// );
+    /// ```
+    ///
+    /// [`Reader::records`]: csv::Reader::records
+    /// [`StringRecord`]: csv::StringRecord
+    #[cfg_attr(docsrs, doc(cfg(feature = "csv")))]
+    pub fn from_csv_records<I>(records: I) -> csv::Result<Self>
+    where
+        I: IntoIterator<Item = csv::Result<csv::StringRecord>>,
+    {
+        let mut builder = Self::default();
+        for record in records {
+            let record = record?;
+            builder.add_record(record.iter().map(ToOwned::to_owned));
+        }
+
+        Ok(builder)
+    }
+}
+
+#[cfg(feature = "json")]
+impl Builder<'static> {
+    /// Creates a [`Builder`] from a JSON value.
+    ///
+    /// - An array of objects is turned into rows, a union of keys is used as columns.
+    /// - Nested objects are flattened into columns named as `parent.child`.
+    /// - Arrays and other values are turned into rows of cells without columns.
+    /// - Nested arrays are rendered as JSON text, and `null`s as empty cells.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let value = serde_json::json!([
+    ///     { "name": "Grodno", "location": { "lat": 53.6, "lon": 23.8 } },
+    ///     { "name": "Minsk", "tags": ["capital"] },
+    /// ]);
+    ///
+    /// let table = Builder::from_json(&value).build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+--------------+--------------+--------+-------------+\n\
+    ///      | location.lat | location.lon | name   | tags        |\n\
+    ///      +--------------+--------------+--------+-------------+\n\
+    ///      | 53.6         | 23.8         | Grodno |             |\n\
+    ///      +--------------+--------------+--------+-------------+\n\
+    ///      |              |              | Minsk  | [\"capital\"] |\n\
+    ///      +--------------+--------------+--------+-------------+"
+    /// );
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    pub fn from_json(value: &serde_json::Value) -> Self {
+        Self::from_json_with_depth(value, usize::MAX)
+    }
+
+    /// Creates a [`Builder`] from a JSON value, flattening nested objects only up to a given depth.
+    ///
+    /// Deeper objects are rendered as JSON text.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let value = serde_json::json!([{ "name": "Grodno", "location": { "lat": 53.6 } }]);
+    ///
+    /// let table = Builder::from_json_with_depth(&value, 0).build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+--------------+--------+\n\
+    ///      | location     | name   |\n\
+    ///      +--------------+--------+\n\
+    ///      | {\"lat\":53.6} | Grodno |\n\
+    ///      +--------------+--------+"
+    /// );
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    pub fn from_json_with_depth(value: &serde_json::Value, depth: usize) -> Self {
+        use serde_json::Value;
+
+        let mut builder = Self::default();
+
+        let list = match value {
+            Value::Array(list) => list.iter().collect::<Vec<_>>(),
+            value => vec![value],
+        };
+
+        let is_objects = !list.is_empty() && list.iter().all(|value| value.is_object());
+        if !is_objects {
+            for value in list {
+                match value {
+                    Value::Array(row) => builder.add_record(row.iter().map(json_to_string)),
+                    value => builder.add_record([json_to_string(value)]),
+                };
+            }
+
+            return builder;
+        }
+
+        let mut columns: Vec<String> = Vec::new();
+        let mut rows = Vec::with_capacity(list.len());
+        for value in list {
+            let mut fields = Vec::new();
+            flatten_json(value, String::new(), depth, &mut fields);
+
+            let mut row = vec![String::new(); columns.len()];
+            for (key, text) in fields {
+                match columns.iter().position(|column| *column == key) {
+                    Some(i) => row[i] = text,
+                    None => {
+                        columns.push(key);
+                        row.push(text);
+                    }
+                }
+            }
+
+            rows.push(row);
+        }
+
+        let count_columns = columns.len();
+        builder.set_columns(columns);
+        for mut row in rows {
+            row.resize(count_columns, String::new());
+            builder.add_record(row);
+        }
+
+        builder
+    }
+}
+
+#[cfg(feature = "json")]
+fn flatten_json(
+    value: &serde_json::Value,
+    prefix: String,
+    depth: usize,
+    fields: &mut Vec<(String, String)>,
+) {
+    match value {
+        serde_json::Value::Object(map) if prefix.is_empty() || depth > 0 => {
+            let depth = if prefix.is_empty() { depth } else { depth - 1 };
+            for (key, value) in map {
+                let key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+
+                flatten_json(value, key, depth, fields);
+            }
+        }
+        value => fields.push((prefix, json_to_string(value))),
+    }
+}
+
+#[cfg(feature = "json")]
+fn json_to_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl Builder<'static> {
+    /// Creates a [`Builder`] from rows of a database query.
+    ///
+    /// Names of columns of the first row are used as a header.
+    ///
+    /// Strings, integers, floats and booleans are rendered as they are,
+    /// `NULL`s are rendered as `NULL`,
+    /// and values of other types are rendered as a type name, like `<BLOB>`.
+    ///
+    #[cfg_attr(feature = "sqlx-sqlite", doc = "```rust,no_run")]
+    #[cfg_attr(not(feature = "sqlx-sqlite"), doc = "```rust,ignore")]
+    /// use tabled::builder::Builder;
+    ///
+    /// # async fn query(pool: sqlx::SqlitePool) -> Result<(), sqlx::Error> {
+    /// let rows = sqlx::query("SELECT id, name FROM users").fetch_all(&pool).await?;
+    ///
+    /// let table = Builder::from_sqlx_rows(&rows).build();
+    /// println!("{}", table);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
+    pub fn from_sqlx_rows<R>(rows: &[R]) -> Self
+    where
+        R: sqlx::Row,
+        usize: sqlx::ColumnIndex<R>,
+        for<'r> String: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+        for<'r> i64: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+        for<'r> i32: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+        for<'r> f64: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+        for<'r> bool: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    {
+        use sqlx::Column;
+
+        let mut builder = Self::with_capacity(rows.len(), 0);
+
+        if let Some(row) = rows.first() {
+            let columns = row.columns().iter().map(|c| c.name().to_owned());
+            builder.set_columns(columns);
+        }
+
+        for row in rows {
+            let record = (0..row.len()).map(|i| sqlx_value(row, i));
+            builder.add_record(record);
+        }
+
+        builder
+    }
+}
+
+#[cfg(feature = "sqlx")]
+fn sqlx_value<R>(row: &R, i: usize) -> String
+where
+    R: sqlx::Row,
+    usize: sqlx::ColumnIndex<R>,
+    for<'r> String: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    for<'r> i64: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    for<'r> i32: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    for<'r> f64: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    for<'r> bool: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+{
+    use sqlx::{TypeInfo, ValueRef};
+
+    let type_name = match row.try_get_raw(i) {
+        Ok(value) if value.is_null() => return String::from("NULL"),
+        Ok(value) => value.type_info().name().to_owned(),
+        Err(_) => return String::new(),
+    };
+
+    if let Ok(value) = row.try_get::<String, _>(i) {
+        return value;
+    }
+
+    if let Ok(value) = row.try_get::<i64, _>(i) {
+        return value.to_string();
+    }
+
+    if let Ok(value) = row.try_get::<i32, _>(i) {
+        return value.to_string();
+    }
+
+    if let Ok(value) = row.try_get::<f64, _>(i) {
+        return value.to_string();
+    }
+
+    if let Ok(value) = row.try_get::<bool, _>(i) {
+        return value.to_string();
+    }
+
+    format!("<{}>", type_name)
+}
+
 impl<'a, R, V> FromIterator<R> for Builder<'a>
 where
     R: IntoIterator<Item = V>,
@@ -508,10 +1271,23 @@ impl From<Vec<Vec<String>>> for Builder<'_> {
 
         let ctrl = CfgWidthFunction::new(4);
         let mut records = vec![vec![CellInfo::default(); size]; strings.len()];
+
+        #[cfg(not(feature = "parallel"))]
         for (row, list) in strings.into_iter().zip(records.iter_mut()) {
             create_row_exact(list, row, &ctrl);
         }
 
+        // widths of cells are calculated here, which is the most expensive part for big tables
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+
+            strings
+                .into_par_iter()
+                .zip(records.par_iter_mut())
+                .for_each(|(row, list)| create_row_exact(list, row, &ctrl));
+        }
+
         Self {
             records,
             size,
@@ -557,6 +1333,8 @@ pub struct IndexBuilder<'a> {
     print_index: bool,
     /// A flag which checks if table was transposed.
     transposed: bool,
+    /// A flag which checks if index was taken from a column.
+    column_index: bool,
     /// Original builder instance.
     b: Builder<'a>,
 }
@@ -588,10 +1366,10 @@ impl<'a> IndexBuilder<'a> {
     /// )
     /// ```
     fn new(mut b: Builder<'a>) -> Self {
-        let index = build_range_index(b.records.len());
+        let index = build_range_index(0, b.records.len());
 
         if b.columns.is_none() {
-            b.columns = Some(build_range_index(b.size));
+            b.columns = Some(build_range_index(0, b.size));
         }
 
         Self {
@@ -599,6 +1377,7 @@ impl<'a> IndexBuilder<'a> {
             name: None,
             print_index: true,
             transposed: false,
+            column_index: false,
             b,
         }
     }
@@ -647,6 +1426,46 @@ impl<'a> IndexBuilder<'a> {
         self
     }
 
+    /// Sets a number the default index starts from.
+    ///
+    /// It's ignored in case the index was set to a column by [`Self::set_index`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_columns(["name"]);
+    /// builder.add_record(["Grodno"]);
+    /// builder.add_record(["Minsk"]);
+    ///
+    /// let mut builder = builder.index();
+    /// builder.set_start(1).set_name(Some(String::from("#")));
+    ///
+    /// let table = builder.build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+---+--------+\n\
+    ///      |   | name   |\n\
+    ///      +---+--------+\n\
+    ///      | # |        |\n\
+    ///      +---+--------+\n\
+    ///      | 1 | Grodno |\n\
+    ///      +---+--------+\n\
+    ///      | 2 | Minsk  |\n\
+    ///      +---+--------+"
+    /// )
+    /// ```
+    pub fn set_start(&mut self, start: usize) -> &mut Self {
+        if !self.column_index {
+            self.index = build_range_index(start, self.b.records.len());
+        }
+
+        self
+    }
+
     /// Sets a index to the chosen column.
     ///
     /// Also sets a name of the index to the column name.
@@ -695,6 +1514,7 @@ impl<'a> IndexBuilder<'a> {
         self.name = Some(name);
 
         self.index = get_column(&mut self.b.records, column);
+        self.column_index = true;
 
         self.b.size -= 1;
 
@@ -732,6 +1552,36 @@ impl<'a> IndexBuilder<'a> {
     ///      +----------+---------+---------+---------+"
     /// )
     /// ```
+    ///
+    /// It can be used to show a single record as a vertical list of properties.
+    ///
+    /// ```
+    /// use tabled::{Table, Tabled, Disable, object::Rows};
+    ///
+    /// #[derive(Tabled)]
+    /// struct Config {
+    ///     host: &'static str,
+    ///     port: u16,
+    /// }
+    ///
+    /// let config = Config { host: "localhost", port: 8080 };
+    ///
+    /// let mut builder = Table::builder([config]).index();
+    /// builder.transpose();
+    ///
+    /// let table = builder.build()
+    ///     .with(Disable::row(Rows::first()))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+------+-----------+\n\
+    ///      | host | localhost |\n\
+    ///      +------+-----------+\n\
+    ///      | port | 8080      |\n\
+    ///      +------+-----------+"
+    /// )
+    /// ```
     pub fn transpose(&mut self) -> &mut Self {
         let columns = self.b.columns.take().unwrap_or_default();
 
@@ -765,6 +1615,283 @@ impl<'a> From<IndexBuilder<'a>> for Builder<'a> {
     }
 }
 
+/// [`DataFrameBuilder`] helps to build a table out of a polars [`DataFrame`].
+///
+/// A header is made of column names and it's followed by a row of column data types.
+///
+/// # Example
+///
+/// ```
+/// use polars::prelude::*;
+/// use tabled::builder::DataFrameBuilder;
+///
+/// let frame = df!(
+///     "city" => ["Grodno", "Minsk", "Brest"],
+///     "population" => [357_493, 1_996_553, 340_723],
+/// )
+/// .unwrap();
+///
+/// let mut builder = DataFrameBuilder::new(&frame);
+/// builder.truncate(1, 1);
+///
+/// let table = builder.build();
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+--------+------------+\n\
+///      | city   | population |\n\
+///      +--------+------------+\n\
+///      | str    | i32        |\n\
+///      +--------+------------+\n\
+///      | Grodno | 357493     |\n\
+///      +--------+------------+\n\
+///      | …      | …          |\n\
+///      +--------+------------+\n\
+///      | Brest  | 340723     |\n\
+///      +--------+------------+"
+/// );
+/// ```
+///
+/// A [`Series`] can be shown by turning it into a frame by [`Series::into_frame`].
+///
+/// [`DataFrame`]: polars::frame::DataFrame
+/// [`Series`]: polars::series::Series
+/// [`Series::into_frame`]: polars::series::Series::into_frame
+#[cfg(feature = "polars")]
+#[cfg_attr(docsrs, doc(cfg(feature = "polars")))]
+#[derive(Debug, Clone)]
+pub struct DataFrameBuilder<'a> {
+    frame: &'a polars::frame::DataFrame,
+    show_dtypes: bool,
+    truncate: Option<(usize, usize)>,
+}
+
+#[cfg(feature = "polars")]
+impl<'a> DataFrameBuilder<'a> {
+    /// Creates a new [`DataFrameBuilder`] instance.
+    pub fn new(frame: &'a polars::frame::DataFrame) -> Self {
+        Self {
+            frame,
+            show_dtypes: true,
+            truncate: None,
+        }
+    }
+
+    /// No data types row will be added.
+    pub fn hide_dtypes(&mut self) -> &mut Self {
+        self.show_dtypes = false;
+        self
+    }
+
+    /// Limits a number of rows to a given number of first and last rows.
+    ///
+    /// The rows in between are replaced by a single row of `…`.
+    /// Nothing is changed if a frame is not bigger than `head + tail` rows.
+    pub fn truncate(&mut self, head: usize, tail: usize) -> &mut Self {
+        self.truncate = Some((head, tail));
+        self
+    }
+
+    /// Builds a table.
+    pub fn build(self) -> Table<VecRecords<CellInfo<'static>>> {
+        Builder::from(self).build()
+    }
+}
+
+#[cfg(feature = "polars")]
+impl From<DataFrameBuilder<'_>> for Builder<'static> {
+    fn from(b: DataFrameBuilder<'_>) -> Self {
+        let columns = b.frame.get_columns();
+        let count_rows = b.frame.height();
+
+        let mut builder = Builder::with_capacity(count_rows + 1, columns.len());
+        builder.set_columns(columns.iter().map(|c| c.name().to_string()));
+
+        if b.show_dtypes {
+            builder.add_record(columns.iter().map(|c| c.dtype().to_string()));
+        }
+
+        let add_rows = |builder: &mut Builder<'static>, rows: std::ops::Range<usize>| {
+            for row in rows {
+                builder.add_record(columns.iter().map(|c| polars_value(c, row)));
+            }
+        };
+
+        match b.truncate {
+            Some((head, tail)) if head + tail < count_rows => {
+                add_rows(&mut builder, 0..head);
+                builder.add_record(columns.iter().map(|_| "…"));
+                add_rows(&mut builder, count_rows - tail..count_rows);
+            }
+            _ => add_rows(&mut builder, 0..count_rows),
+        }
+
+        builder
+    }
+}
+
+#[cfg(feature = "polars")]
+fn polars_value(column: &polars::prelude::Column, row: usize) -> String {
+    match column.get(row) {
+        Ok(value) => match value.get_str() {
+            Some(s) => s.to_owned(),
+            None => value.to_string(),
+        },
+        Err(_) => String::new(),
+    }
+}
+
+/// [`ArrayBuilder`] helps to build a table out of a 2-dimensional [`ndarray`] array.
+///
+/// Values are aligned to the right, and optional row labels to the left.
+///
+/// # Example
+///
+/// ```
+/// use ndarray::arr2;
+/// use tabled::builder::ArrayBuilder;
+///
+/// let array = arr2(&[[1.5, -20.0], [100.0, 0.25]]);
+///
+/// let mut builder = ArrayBuilder::new(&array);
+/// builder
+///     .column_labels(["x", "y"])
+///     .row_labels(["first", "second"]);
+///
+/// let table = builder.build();
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+--------+-----+------+\n\
+///      |        |   x |    y |\n\
+///      +--------+-----+------+\n\
+///      | first  | 1.5 |  -20 |\n\
+///      +--------+-----+------+\n\
+///      | second | 100 | 0.25 |\n\
+///      +--------+-----+------+"
+/// );
+/// ```
+///
+/// [`ndarray`]: ndarray::ArrayBase
+#[cfg(feature = "ndarray")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+#[derive(Debug, Clone)]
+pub struct ArrayBuilder<'a, T> {
+    array: ndarray::ArrayView2<'a, T>,
+    row_labels: Option<Vec<String>>,
+    column_labels: Option<Vec<String>>,
+}
+
+#[cfg(feature = "ndarray")]
+impl<'a, T> ArrayBuilder<'a, T>
+where
+    T: std::fmt::Display,
+{
+    /// Creates a new [`ArrayBuilder`] instance.
+    pub fn new<S>(array: &'a ndarray::ArrayBase<S, ndarray::Ix2>) -> Self
+    where
+        S: ndarray::Data<Elem = T>,
+    {
+        Self {
+            array: array.view(),
+            row_labels: None,
+            column_labels: None,
+        }
+    }
+
+    /// Sets labels of rows, which are shown as a first column.
+    ///
+    /// Missing labels are left empty and extra ones are ignored.
+    pub fn row_labels<I, L>(&mut self, labels: I) -> &mut Self
+    where
+        I: IntoIterator<Item = L>,
+        L: std::fmt::Display,
+    {
+        let labels = labels.into_iter().take(self.array.nrows());
+        self.row_labels = Some(labels.map(|l| l.to_string()).collect());
+        self
+    }
+
+    /// Sets labels of columns, which are shown as a header.
+    ///
+    /// Missing labels are left empty and extra ones are ignored.
+    pub fn column_labels<I, L>(&mut self, labels: I) -> &mut Self
+    where
+        I: IntoIterator<Item = L>,
+        L: std::fmt::Display,
+    {
+        let labels = labels.into_iter().take(self.array.ncols());
+        self.column_labels = Some(labels.map(|l| l.to_string()).collect());
+        self
+    }
+
+    /// Builds a table.
+    pub fn build(self) -> Table<VecRecords<CellInfo<'static>>> {
+        let has_row_labels = self.row_labels.is_some();
+
+        let mut table = Builder::from(self).build();
+
+        let cfg = table.get_config_mut();
+        cfg.set_alignment_horizontal(Entity::Global, AlignmentHorizontal::Right);
+        if has_row_labels {
+            cfg.set_alignment_horizontal(Entity::Column(0), AlignmentHorizontal::Left);
+        }
+
+        table
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T> From<ArrayBuilder<'_, T>> for Builder<'static>
+where
+    T: std::fmt::Display,
+{
+    fn from(b: ArrayBuilder<'_, T>) -> Self {
+        let (count_rows, count_columns) = b.array.dim();
+        let label = |labels: &Option<Vec<String>>, i: usize| {
+            labels
+                .as_ref()
+                .and_then(|labels| labels.get(i).cloned())
+                .unwrap_or_default()
+        };
+
+        let mut builder = Builder::with_capacity(count_rows, count_columns + 1);
+
+        if b.column_labels.is_some() {
+            let mut columns = Vec::with_capacity(count_columns + 1);
+            if b.row_labels.is_some() {
+                columns.push(String::new());
+            }
+
+            columns.extend((0..count_columns).map(|i| label(&b.column_labels, i)));
+            builder.set_columns(columns);
+        }
+
+        for (i, row) in b.array.outer_iter().enumerate() {
+            let mut record = Vec::with_capacity(count_columns + 1);
+            if b.row_labels.is_some() {
+                record.push(label(&b.row_labels, i));
+            }
+
+            record.extend(row.iter().map(|value| value.to_string()));
+            builder.add_record(record);
+        }
+
+        builder
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<S> From<&ndarray::ArrayBase<S, ndarray::Ix2>> for Table<VecRecords<CellInfo<'static>>>
+where
+    S: ndarray::Data,
+    S::Elem: std::fmt::Display,
+{
+    fn from(array: &ndarray::ArrayBase<S, ndarray::Ix2>) -> Self {
+        ArrayBuilder::new(array).build()
+    }
+}
+
 /// A builder which wraps [`Records`] and builds [`Table`] out of it.
 ///
 /// [`Records`]: papergrid::records::Records
@@ -869,9 +1996,9 @@ fn remove_or_default<T: Default>(v: &mut Vec<T>, i: usize) -> T {
     }
 }
 
-fn build_range_index(n: usize) -> Vec<CellInfo<'static>> {
+fn build_range_index(start: usize, n: usize) -> Vec<CellInfo<'static>> {
     let ctrl = CfgWidthFunction::new(4);
-    (0..n)
+    (start..start + n)
         .map(|i| CellInfo::new(i.to_string(), &ctrl))
         .collect()
 }
@@ -928,7 +2055,7 @@ fn build_grid<'a>(
     VecRecords::with_hint(records, count_columns)
 }
 
-fn configure_grid(cfg: &mut GridConfig) {
+pub(crate) fn configure_grid(cfg: &mut GridConfig) {
     cfg.set_tab_width(4);
     cfg.set_padding(
         Entity::Global,
@@ -946,6 +2073,7 @@ fn configure_grid(cfg: &mut GridConfig) {
             horizontal_trim: false,
             allow_lines_alignement: false,
             vertical_trim: false,
+            preserve_whitespace: false,
         },
     );
 }
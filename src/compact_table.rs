@@ -0,0 +1,362 @@
+//! This module contains a [`CompactTable`] structure which renders a table with a const number of columns
+//! without any allocations.
+
+use core::fmt::{self, Display, Write};
+
+use papergrid::{AlignmentHorizontal, Borders, Indent};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::{style::HorizontalLine, Padding, Style};
+
+/// [`CompactTable`] is a minimal table with a const number of columns and fixed column widths,
+/// which is rendered via [`Display`] without any heap allocations.
+///
+/// It's supposed to be used for logging small tables
+/// in an environment where allocations are undesirable.
+///
+/// The records are iterated by reference on each render,
+/// so they must be a collection, like an array or a [`Vec`] of rows,
+/// where a reference to a row iterates over cells which can be referenced as `&str`.
+/// An iterator can't be used directly and must be collected first.
+///
+/// A cell which doesn't fit a column is truncated.
+/// ANSI sequences, spans and vertical lines of a style aren't supported,
+/// and a horizontal line is kept only after the first row.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{CompactTable, Style};
+///
+/// let data = [["FreeBSD", "1993"], ["OpenBSD", "1995"]];
+///
+/// let table = CompactTable::from(data).style(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     " FreeBSD | 1993 \n\
+///      ---------+------\n \
+///      OpenBSD | 1995 "
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct CompactTable<I, const COLS: usize> {
+    records: I,
+    widths: [usize; COLS],
+    borders: Borders<char>,
+    first_line: Option<papergrid::HorizontalLine<char>>,
+    padding: papergrid::Padding,
+    alignment: AlignmentHorizontal,
+}
+
+impl<I, const COLS: usize> CompactTable<I, COLS> {
+    /// Creates a new [`CompactTable`] instance.
+    ///
+    /// Column widths are 0 by default, so they must be set by [`CompactTable::widths`].
+    pub fn new(records: I) -> Self {
+        let indent = Indent::spaced(1);
+        let padding = papergrid::Padding {
+            left: indent,
+            right: indent,
+            top: Indent::default(),
+            bottom: Indent::default(),
+        };
+
+        Self {
+            records,
+            widths: [0; COLS],
+            borders: Style::ascii().borders,
+            first_line: None,
+            padding,
+            alignment: AlignmentHorizontal::Left,
+        }
+    }
+
+    /// Sets widths of columns.
+    ///
+    /// A width doesn't include padding.
+    pub fn widths(mut self, widths: [usize; COLS]) -> Self {
+        self.widths = widths;
+        self
+    }
+
+    /// Sets a style of the table.
+    pub fn style<T, B, L, R, H, V, HLines, VLines>(
+        mut self,
+        style: Style<T, B, L, R, H, V, HLines, VLines>,
+    ) -> Self
+    where
+        HLines: IntoIterator<Item = HorizontalLine>,
+    {
+        self.borders = style.borders;
+        self.first_line = style
+            .horizontals
+            .into_iter()
+            .filter(|hr| hr.index == 1)
+            .flat_map(|hr| hr.line)
+            .last()
+            .map(papergrid::HorizontalLine::from);
+
+        self
+    }
+
+    /// Sets a padding of all cells.
+    pub fn padding(mut self, padding: Padding) -> Self {
+        self.padding = padding.0;
+        self
+    }
+
+    /// Sets a horizontal alignment of all cells.
+    pub fn alignment(mut self, alignment: AlignmentHorizontal) -> Self {
+        self.alignment = alignment;
+        self
+    }
+}
+
+impl<T, const COLS: usize, const ROWS: usize> From<[[T; COLS]; ROWS]>
+    for CompactTable<[[T; COLS]; ROWS], COLS>
+where
+    T: AsRef<str>,
+{
+    /// Creates a [`CompactTable`] with widths which fit the data.
+    fn from(records: [[T; COLS]; ROWS]) -> Self {
+        let mut widths = [0; COLS];
+        for row in &records {
+            for (text, width) in row.iter().zip(widths.iter_mut()) {
+                let text_width = text.as_ref().split('\n').map(|l| l.width()).max();
+                *width = std::cmp::max(*width, text_width.unwrap_or(0));
+            }
+        }
+
+        Self::new(records).widths(widths)
+    }
+}
+
+impl<I, const COLS: usize> Display for CompactTable<I, COLS>
+where
+    for<'a> &'a I: IntoIterator,
+    for<'a> <&'a I as IntoIterator>::Item: IntoIterator,
+    for<'a> <<&'a I as IntoIterator>::Item as IntoIterator>::Item: AsRef<str>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if COLS == 0 {
+            return Ok(());
+        }
+
+        let b = &self.borders;
+        let mut is_first_line = true;
+
+        let mut rows = (&self.records).into_iter().enumerate().peekable();
+        if rows.peek().is_none() {
+            return Ok(());
+        }
+
+        if b.has_top() {
+            let line = (b.top_left, b.top, b.top_intersection, b.top_right);
+            self.print_split_line(f, line, &mut is_first_line)?;
+        }
+
+        while let Some((i, row)) = rows.next() {
+            if i > 0 {
+                let line = match (i, self.first_line) {
+                    (1, Some(l)) => Some((
+                        l.left.or(b.horizontal_left),
+                        l.main.or(b.horizontal),
+                        l.intersection.or(b.intersection),
+                        l.right.or(b.horizontal_right),
+                    )),
+                    _ if b.has_horizontal() => Some((
+                        b.horizontal_left,
+                        b.horizontal,
+                        b.intersection,
+                        b.horizontal_right,
+                    )),
+                    _ => None,
+                };
+
+                if let Some(line) = line {
+                    self.print_split_line(f, line, &mut is_first_line)?;
+                }
+            }
+
+            let mut cells = row.into_iter();
+            let cells = [(); COLS].map(|_| cells.next());
+
+            self.print_row(f, &cells, &mut is_first_line)?;
+
+            if rows.peek().is_none() && b.has_bottom() {
+                let line = (
+                    b.bottom_left,
+                    b.bottom,
+                    b.bottom_intersection,
+                    b.bottom_right,
+                );
+                self.print_split_line(f, line, &mut is_first_line)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+type Line = (Option<char>, Option<char>, Option<char>, Option<char>);
+
+impl<I, const COLS: usize> CompactTable<I, COLS> {
+    fn print_split_line(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        (left, main, intersection, right): Line,
+        is_first_line: &mut bool,
+    ) -> fmt::Result {
+        new_line(f, is_first_line)?;
+
+        let b = &self.borders;
+        if b.has_left() {
+            f.write_char(left.unwrap_or(' '))?;
+        }
+
+        for (col, width) in self.widths.iter().enumerate() {
+            if col > 0 && b.has_vertical() {
+                f.write_char(intersection.unwrap_or(' '))?;
+            }
+
+            let width = width + self.padding.left.size + self.padding.right.size;
+            repeat_char(f, main.unwrap_or(' '), width)?;
+        }
+
+        if b.has_right() {
+            f.write_char(right.unwrap_or(' '))?;
+        }
+
+        Ok(())
+    }
+
+    fn print_row<S>(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        cells: &[Option<S>; COLS],
+        is_first_line: &mut bool,
+    ) -> fmt::Result
+    where
+        S: AsRef<str>,
+    {
+        let count_lines = cells
+            .iter()
+            .map(|cell| match cell {
+                Some(text) => text.as_ref().split('\n').count(),
+                None => 1,
+            })
+            .max()
+            .unwrap_or(1);
+
+        let top = self.padding.top;
+        let bottom = self.padding.bottom;
+
+        for _ in 0..top.size {
+            self.print_line(f, cells, None, top.fill, is_first_line)?;
+        }
+
+        for i in 0..count_lines {
+            self.print_line(f, cells, Some(i), ' ', is_first_line)?;
+        }
+
+        for _ in 0..bottom.size {
+            self.print_line(f, cells, None, bottom.fill, is_first_line)?;
+        }
+
+        Ok(())
+    }
+
+    fn print_line<S>(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        cells: &[Option<S>; COLS],
+        line: Option<usize>,
+        fill: char,
+        is_first_line: &mut bool,
+    ) -> fmt::Result
+    where
+        S: AsRef<str>,
+    {
+        new_line(f, is_first_line)?;
+
+        let b = &self.borders;
+        if b.has_left() {
+            f.write_char(b.vertical_left.unwrap_or(' '))?;
+        }
+
+        for (col, (cell, &width)) in cells.iter().zip(&self.widths).enumerate() {
+            if col > 0 && b.has_vertical() {
+                f.write_char(b.vertical.unwrap_or(' '))?;
+            }
+
+            let text = match (line, cell) {
+                (Some(line), Some(text)) => text.as_ref().split('\n').nth(line).unwrap_or(""),
+                (Some(_), None) => "",
+                (None, _) => {
+                    let width = width + self.padding.left.size + self.padding.right.size;
+                    repeat_char(f, fill, width)?;
+                    continue;
+                }
+            };
+
+            repeat_char(f, self.padding.left.fill, self.padding.left.size)?;
+            print_text(f, text, width, self.alignment)?;
+            repeat_char(f, self.padding.right.fill, self.padding.right.size)?;
+        }
+
+        if b.has_right() {
+            f.write_char(b.vertical_right.unwrap_or(' '))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn print_text(
+    f: &mut fmt::Formatter<'_>,
+    text: &str,
+    width: usize,
+    alignment: AlignmentHorizontal,
+) -> fmt::Result {
+    let text_width = std::cmp::min(text.width(), width);
+    let rest = width - text_width;
+    let (left, right) = match alignment {
+        AlignmentHorizontal::Left => (0, rest),
+        AlignmentHorizontal::Right => (rest, 0),
+        AlignmentHorizontal::Center => (rest / 2, rest - rest / 2),
+    };
+
+    repeat_char(f, ' ', left)?;
+
+    let mut used = 0;
+    for c in text.chars() {
+        let c_width = c.width().unwrap_or(0);
+        if used + c_width > text_width {
+            break;
+        }
+
+        used += c_width;
+        f.write_char(c)?;
+    }
+
+    // a wide character which doesn't fit is replaced by spaces
+    repeat_char(f, ' ', text_width - used + right)
+}
+
+fn new_line(f: &mut fmt::Formatter<'_>, is_first_line: &mut bool) -> fmt::Result {
+    if *is_first_line {
+        *is_first_line = false;
+        return Ok(());
+    }
+
+    f.write_char('\n')
+}
+
+fn repeat_char(f: &mut fmt::Formatter<'_>, c: char, n: usize) -> fmt::Result {
+    for _ in 0..n {
+        f.write_char(c)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,172 @@
+//! This module contains a [`DeferredTable`] structure which keeps settings until a table is built.
+
+use std::fmt;
+
+use crate::{Table, TableOption};
+
+/// [`DeferredTable`] records settings instead of applying them right away,
+/// and applies all of them at once when the [`Table`] is built.
+///
+/// It's handy when a set of settings is gathered in different places,
+/// as the table is changed only once the settings are known.
+///
+/// Settings are applied one by one in order they were added, the same way [`Table::with`] does it.
+/// Though a setting which is overridden by a later setting of the same [`SettingKind`]
+/// is merged into it, so it's not applied at all.
+/// Only settings of other kinds are allowed in between,
+/// so a built table is the same as if the settings were applied to the [`Table`] eagerly.
+///
+/// It's created by [`Table::deferred`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{width::Width, Style, Table};
+///
+/// let data = [["FreeBSD", "1993"], ["OpenBSD", "1995"]];
+///
+/// let table = Table::new(data)
+///     .deferred()
+///     .with(Style::psql())
+///     .with(Width::truncate(14))
+///     .build();
+///
+/// assert_eq!(
+///     table.to_string(),
+///     " 0      | 1   \n\
+///      --------+-----\n \
+///      FreeBS | 199 \n \
+///      OpenBS | 199 "
+/// );
+/// ```
+pub struct DeferredTable<'a, R> {
+    table: Table<R>,
+    settings: Vec<Box<dyn TableOption<R> + 'a>>,
+}
+
+impl<'a, R> DeferredTable<'a, R> {
+    pub(crate) fn new(table: Table<R>) -> Self {
+        Self {
+            table,
+            settings: Vec::new(),
+        }
+    }
+
+    /// Records a setting, which is applied when the table is built.
+    ///
+    /// ```
+    /// use tabled::{Alignment, Style, Table};
+    ///
+    /// let table = Table::new([1, 22])
+    ///     .deferred()
+    ///     .with(Alignment::left())
+    ///     .with(Alignment::top())
+    ///     .with(Alignment::right())
+    ///     .with(Style::psql());
+    ///
+    /// // the left alignment is overridden by the right one
+    /// assert_eq!(table.count_settings(), 3);
+    ///
+    /// assert_eq!(table.build().to_string(), " i32 \n-----\n   1 \n  22 ");
+    /// ```
+    pub fn with<O>(mut self, option: O) -> Self
+    where
+        O: TableOption<R> + 'a,
+    {
+        if let Some(kind) = option.kind() {
+            self.remove_overridden(kind);
+        }
+
+        self.settings.push(Box::new(option));
+        self
+    }
+
+    /// Returns an amount of settings which are applied when the table is built.
+    ///
+    /// Settings overridden by later ones are not counted.
+    pub fn count_settings(&self) -> usize {
+        self.settings.len()
+    }
+
+    /// Returns a table with all the recorded settings applied.
+    pub fn build(self) -> Table<R> {
+        let mut table = self.table;
+        for mut option in self.settings {
+            option.change(&mut table);
+        }
+
+        table
+    }
+
+    /// Removes a setting overridden by a setting of a given kind,
+    /// in case there's only settings of other kinds after it.
+    fn remove_overridden(&mut self, kind: SettingKind) {
+        for i in (0..self.settings.len()).rev() {
+            match self.settings[i].kind() {
+                Some(recorded) if kind.overrides(recorded) => {
+                    self.settings.remove(i);
+                    return;
+                }
+                Some(recorded) if recorded.is_same(kind) => return,
+                Some(_) => (),
+                None => return,
+            }
+        }
+    }
+}
+
+impl<R> fmt::Debug for DeferredTable<'_, R>
+where
+    R: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeferredTable")
+            .field("table", &self.table)
+            .field("settings", &self.settings.len())
+            .finish()
+    }
+}
+
+impl<'a, R> From<DeferredTable<'a, R>> for Table<R> {
+    fn from(table: DeferredTable<'a, R>) -> Self {
+        table.build()
+    }
+}
+
+/// A kind of a [`TableOption`], which is used by [`DeferredTable`] to merge settings.
+///
+/// A setting doesn't depend on settings of other kinds applied before it,
+/// so a setting overridden by a later one of the same kind can be dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingKind {
+    /// A horizontal alignment of a whole table.
+    HorizontalAlignment,
+    /// A vertical alignment of a whole table.
+    VerticalAlignment,
+    /// A list of widths of a given amount of columns.
+    ///
+    /// It's not applied to a table which has more columns than that.
+    Widths(usize),
+    /// A list of heights of a given amount of rows.
+    ///
+    /// It's not applied to a table which has more rows than that.
+    Heights(usize),
+}
+
+impl SettingKind {
+    /// Checks whether a setting of this kind overrides a setting of an `other` kind applied before.
+    fn overrides(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::HorizontalAlignment, Self::HorizontalAlignment)
+            | (Self::VerticalAlignment, Self::VerticalAlignment) => true,
+            // a shorter list might be not applied while an earlier one would
+            (Self::Widths(count), Self::Widths(other))
+            | (Self::Heights(count), Self::Heights(other)) => count >= other,
+            _ => false,
+        }
+    }
+
+    fn is_same(self, other: Self) -> bool {
+        std::mem::discriminant(&self) == std::mem::discriminant(&other)
+    }
+}
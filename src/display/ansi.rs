@@ -0,0 +1,138 @@
+//! This module contains a parser of ANSI SGR sequences which is used by views
+//! which can't render ANSI sequences themselves.
+
+/// A style of a text set by ANSI sequences.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct TextStyle {
+    pub(crate) foreground: Option<Color>,
+    pub(crate) background: Option<Color>,
+    pub(crate) bold: bool,
+    pub(crate) italic: bool,
+    pub(crate) underline: bool,
+}
+
+/// An ANSI color.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Color {
+    /// A color of a 256 color palette, the first 16 are basic colors.
+    Indexed(u8),
+    /// A true color.
+    Rgb(u8, u8, u8),
+}
+
+/// A text with the same style.
+#[derive(Debug)]
+pub(crate) struct Segment {
+    pub(crate) text: String,
+    pub(crate) style: TextStyle,
+}
+
+/// Splits a line into segments of a text with the same style.
+///
+/// Sequences other than SGR are dropped.
+pub(crate) fn parse_line(line: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut style = TextStyle::default();
+    let mut text = String::new();
+
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            text.push(c);
+            continue;
+        }
+
+        // a control sequence is ESC [ params final-byte
+        if chars.peek() != Some(&'[') {
+            continue;
+        }
+
+        chars.next();
+
+        let mut params = String::new();
+        let mut end = None;
+        for c in chars.by_ref() {
+            if ('\u{40}'..='\u{7e}').contains(&c) {
+                end = Some(c);
+                break;
+            }
+
+            params.push(c);
+        }
+
+        if end != Some('m') {
+            continue;
+        }
+
+        let mut new_style = style.clone();
+        apply_sgr(&mut new_style, &params);
+
+        if new_style != style {
+            if !text.is_empty() {
+                let text = std::mem::take(&mut text);
+                segments.push(Segment {
+                    text,
+                    style: style.clone(),
+                });
+            }
+
+            style = new_style;
+        }
+    }
+
+    if !text.is_empty() {
+        segments.push(Segment { text, style });
+    }
+
+    segments
+}
+
+fn apply_sgr(style: &mut TextStyle, params: &str) {
+    if params.is_empty() {
+        *style = TextStyle::default();
+        return;
+    }
+
+    let mut codes = params.split(';').map(|p| p.parse::<u8>().unwrap_or(0));
+    while let Some(code) = codes.next() {
+        match code {
+            0 => *style = TextStyle::default(),
+            1 => style.bold = true,
+            3 => style.italic = true,
+            4 => style.underline = true,
+            22 => style.bold = false,
+            23 => style.italic = false,
+            24 => style.underline = false,
+            30..=37 => style.foreground = Some(Color::Indexed(code - 30)),
+            90..=97 => style.foreground = Some(Color::Indexed(code - 90 + 8)),
+            39 => style.foreground = None,
+            40..=47 => style.background = Some(Color::Indexed(code - 40)),
+            100..=107 => style.background = Some(Color::Indexed(code - 100 + 8)),
+            49 => style.background = None,
+            38 => {
+                if let Some(color) = parse_color(&mut codes) {
+                    style.foreground = Some(color);
+                }
+            }
+            48 => {
+                if let Some(color) = parse_color(&mut codes) {
+                    style.background = Some(color);
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+fn parse_color(codes: &mut impl Iterator<Item = u8>) -> Option<Color> {
+    match codes.next() {
+        Some(5) => Some(Color::Indexed(codes.next().unwrap_or(0))),
+        Some(2) => {
+            let r = codes.next().unwrap_or(0);
+            let g = codes.next().unwrap_or(0);
+            let b = codes.next().unwrap_or(0);
+            Some(Color::Rgb(r, g, b))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,147 @@
+//! This module contains a [`AsciiDocTable`] structure which renders a [`Table`] as an AsciiDoc table.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, display::AsciiDocTable};
+//!
+//! let data = [("Grodno", 1), ("Minsk", 2)];
+//! let table = Table::new(data);
+//!
+//! let asciidoc = AsciiDocTable::new(&table).to_string();
+//!
+//! assert_eq!(
+//!     asciidoc,
+//!     concat!(
+//!         "[cols=\"<,<\",options=\"header\"]\n",
+//!         "|===\n",
+//!         "|&str |i32\n",
+//!         "|Grodno |1\n",
+//!         "|Minsk |2\n",
+//!         "|===",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::fmt::{self, Display, Write};
+
+use papergrid::{records::Records, AlignmentHorizontal, Entity};
+
+use crate::Table;
+
+/// [`AsciiDocTable`] renders a [`Table`] as an AsciiDoc table.
+///
+/// It uses a text of the [`Table`] records so any changes made to the records,
+/// like sorting or removing of rows, are reflected.
+///
+/// - A `cols` specification is taken from a horizontal alignment of columns.
+/// - A cell with a different alignment or a span gets a cell specifier, e.g. `2.3+^|`.
+/// - Pipes are escaped.
+/// - Lines of multiline cells are separated by hard line breaks.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone)]
+pub struct AsciiDocTable<'a, R> {
+    table: &'a Table<R>,
+}
+
+impl<'a, R> AsciiDocTable<'a, R> {
+    /// Creates a new [`AsciiDocTable`] instance.
+    pub fn new(table: &'a Table<R>) -> Self {
+        Self { table }
+    }
+}
+
+impl<R> Display for AsciiDocTable<'_, R>
+where
+    R: Records,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let records = self.table.get_records();
+        let cfg = self.table.get_config();
+        let shape = self.table.shape();
+        let (count_rows, count_columns) = shape;
+
+        f.write_str("[cols=\"")?;
+        for col in 0..count_columns {
+            if col > 0 {
+                f.write_char(',')?;
+            }
+
+            let alignment = cfg.get_alignment_horizontal(Entity::Column(col));
+            f.write_char(alignment_spec(alignment))?;
+        }
+        f.write_char('"')?;
+
+        if self.table.has_header() {
+            f.write_str(",options=\"header\"")?;
+        }
+
+        f.write_str("]\n|===\n")?;
+
+        for row in 0..count_rows {
+            let mut is_first = true;
+            for col in 0..count_columns {
+                if !cfg.is_cell_visible((row, col), shape) {
+                    continue;
+                }
+
+                if !is_first {
+                    f.write_char(' ')?;
+                }
+
+                is_first = false;
+
+                let column_span = cfg.get_column_span((row, col), shape).unwrap_or(1);
+                let row_span = cfg.get_row_span((row, col), shape).unwrap_or(1);
+                match (column_span > 1, row_span > 1) {
+                    (true, true) => write!(f, "{}.{}+", column_span, row_span)?,
+                    (true, false) => write!(f, "{}+", column_span)?,
+                    (false, true) => write!(f, ".{}+", row_span)?,
+                    (false, false) => (),
+                }
+
+                let alignment = cfg.get_alignment_horizontal(Entity::Cell(row, col));
+                let column_alignment = cfg.get_alignment_horizontal(Entity::Column(col));
+                if alignment != column_alignment {
+                    f.write_char(alignment_spec(alignment))?;
+                }
+
+                f.write_char('|')?;
+                write_cell(f, records.get_text((row, col)))?;
+            }
+
+            f.write_char('\n')?;
+        }
+
+        f.write_str("|===")
+    }
+}
+
+fn alignment_spec(alignment: &AlignmentHorizontal) -> char {
+    match alignment {
+        AlignmentHorizontal::Left => '<',
+        AlignmentHorizontal::Center => '^',
+        AlignmentHorizontal::Right => '>',
+    }
+}
+
+fn write_cell(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    for (i, line) in text.lines().enumerate() {
+        if i > 0 {
+            f.write_str(" +\n")?;
+        }
+
+        for c in line.chars() {
+            if c == '|' {
+                f.write_char('\\')?;
+            }
+
+            f.write_char(c)?;
+        }
+    }
+
+    Ok(())
+}
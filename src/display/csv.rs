@@ -0,0 +1,127 @@
+//! This module contains a [`CsvTable`] structure which renders a [`Table`] as CSV.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, display::CsvTable};
+//!
+//! let data = [("Grodno", "357,493"), ("Minsk", "1,996,553")];
+//! let table = Table::new(data);
+//!
+//! let csv = CsvTable::new(&table).to_string();
+//!
+//! assert_eq!(
+//!     csv,
+//!     concat!(
+//!         "&str,&str\n",
+//!         "Grodno,\"357,493\"\n",
+//!         "Minsk,\"1,996,553\"",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::fmt::{self, Display, Write};
+
+use papergrid::records::Records;
+
+use crate::Table;
+
+/// [`CsvTable`] renders a [`Table`] as CSV.
+///
+/// It uses a text of the [`Table`] records so any changes made to the records,
+/// like sorting or removing of rows, are reflected.
+///
+/// Fields are quoted according to RFC 4180,
+/// which means only fields which contain a delimiter, a quote or a line break are quoted.
+///
+/// Cells covered by a span are rendered as empty fields.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone)]
+pub struct CsvTable<'a, R> {
+    table: &'a Table<R>,
+    delimiter: char,
+}
+
+impl<'a, R> CsvTable<'a, R> {
+    /// Creates a new [`CsvTable`] instance.
+    ///
+    /// A default delimiter is a comma.
+    pub fn new(table: &'a Table<R>) -> Self {
+        Self {
+            table,
+            delimiter: ',',
+        }
+    }
+
+    /// Sets a delimiter of fields.
+    ///
+    /// ```
+    /// use tabled::{Table, display::CsvTable};
+    ///
+    /// let table = Table::new([("a b", 1)]);
+    ///
+    /// assert_eq!(
+    ///     CsvTable::new(&table).delimiter('\t').to_string(),
+    ///     "&str\ti32\na b\t1",
+    /// );
+    /// ```
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+}
+
+impl<R> Display for CsvTable<'_, R>
+where
+    R: Records,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let records = self.table.get_records();
+        let cfg = self.table.get_config();
+        let shape = self.table.shape();
+        let (count_rows, count_columns) = shape;
+
+        for row in 0..count_rows {
+            if row > 0 {
+                f.write_char('\n')?;
+            }
+
+            for col in 0..count_columns {
+                if col > 0 {
+                    f.write_char(self.delimiter)?;
+                }
+
+                if !cfg.is_cell_visible((row, col), shape) {
+                    continue;
+                }
+
+                write_field(f, records.get_text((row, col)), self.delimiter)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn write_field(f: &mut fmt::Formatter<'_>, text: &str, delimiter: char) -> fmt::Result {
+    let need_quotes = text
+        .chars()
+        .any(|c| c == delimiter || c == '"' || c == '\n' || c == '\r');
+
+    if !need_quotes {
+        return f.write_str(text);
+    }
+
+    f.write_char('"')?;
+    for c in text.chars() {
+        if c == '"' {
+            f.write_char('"')?;
+        }
+
+        f.write_char(c)?;
+    }
+    f.write_char('"')
+}
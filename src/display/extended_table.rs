@@ -1,9 +1,9 @@
-//! This module contains an [`ExpandedDisplay`] structure which is useful in cases where
+//! This module contains an [`ExtendedTable`] structure which is useful in cases where
 //! a structure has a lot of fields.
 //!
 #![cfg_attr(feature = "derive", doc = "```")]
 #![cfg_attr(not(feature = "derive"), doc = "```ignore")]
-//! use tabled::{Tabled, display::ExpandedDisplay};
+//! use tabled::{Tabled, display::ExtendedTable};
 //!
 //! #[derive(Tabled)]
 //! struct Language {
@@ -30,7 +30,7 @@
 //!     },
 //! ];
 //!
-//! let table = ExpandedDisplay::new(languages).to_string();
+//! let table = ExtendedTable::new(languages).to_string();
 //!
 //! let expected = "-[ RECORD 0 ]-+---------------\n\
 //!                 name          | C\n\
@@ -48,11 +48,14 @@
 //! assert_eq!(table, expected);
 //! ```
 
-use papergrid::util::{cut_str_basic, string_width};
+use papergrid::{
+    records::Records,
+    util::{cut_str_basic, string_width},
+};
 
-use crate::Tabled;
+use crate::{Table, Tabled};
 
-/// `ExpandedDisplay` display data in a 'expanded display mode' from postgresql.
+/// `ExtendedTable` display data in a 'expanded display mode' from postgresql.
 /// It may be useful for a large data sets with a lot of fields.
 ///
 /// See 'Examples' in <https://www.postgresql.org/docs/current/app-psql.html.>.
@@ -61,10 +64,10 @@ use crate::Tabled;
 /// Because of that ANSI sequences will be not be rendered too so colores will not be showed.
 ///
 /// ```
-/// use tabled::{display::ExpandedDisplay};
+/// use tabled::{display::ExtendedTable};
 ///
 /// let data = vec!["Hello", "2021"];
-/// let table = ExpandedDisplay::new(&data);
+/// let table = ExtendedTable::new(&data);
 ///
 /// assert_eq!(
 ///     table.to_string(),
@@ -77,13 +80,13 @@ use crate::Tabled;
 /// );
 /// ```
 #[derive(Debug, Clone)]
-pub struct ExpandedDisplay {
+pub struct ExtendedTable {
     fields: Vec<String>,
     records: Vec<Vec<String>>,
 }
 
-impl ExpandedDisplay {
-    /// Creates a new instance of `ExpandedDisplay`
+impl ExtendedTable {
+    /// Creates a new instance of `ExtendedTable`
     pub fn new<T>(iter: impl IntoIterator<Item = T>) -> Self
     where
         T: Tabled,
@@ -163,7 +166,49 @@ impl ExpandedDisplay {
     }
 }
 
-impl std::fmt::Display for ExpandedDisplay {
+impl<R> From<Table<R>> for ExtendedTable
+where
+    R: Records,
+{
+    /// Creates an [`ExtendedTable`] from a [`Table`], its first row is used as fields.
+    ///
+    /// ```
+    /// use tabled::{builder::Builder, display::ExtendedTable};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_columns(["host", "port"]);
+    /// builder.add_record(["localhost", "8080"]);
+    ///
+    /// assert_eq!(
+    ///     ExtendedTable::from(builder.build()).to_string(),
+    ///     concat!(
+    ///         "-[ RECORD 0 ]---\n",
+    ///         "host | localhost\n",
+    ///         "port | 8080",
+    ///     )
+    /// );
+    /// ```
+    fn from(table: Table<R>) -> Self {
+        let (count_rows, count_cols) = table.shape();
+        let records = table.get_records();
+        let mut rows = (0..count_rows).map(|row| {
+            (0..count_cols)
+                .map(|col| records.get_text((row, col)).escape_debug().to_string())
+                .collect::<Vec<_>>()
+        });
+
+        let fields = rows.next().unwrap_or_default();
+        let records = rows.collect();
+
+        Self { fields, records }
+    }
+}
+
+/// A former name of [`ExtendedTable`].
+#[deprecated(note = "use ExtendedTable instead")]
+pub type ExpandedDisplay = ExtendedTable;
+
+impl std::fmt::Display for ExtendedTable {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if self.records.is_empty() {
             return Ok(());
@@ -239,13 +284,13 @@ fn write_header_template(
             let part2 = rest_to_print - part1 - 1;
 
             template.extend(
-                std::iter::repeat('-')
-                    .take(part1)
+                (0..part1)
+                    .map(|_| '-')
                     .chain(std::iter::once('+'))
-                    .chain(std::iter::repeat('-').take(part2)),
+                    .chain((0..part2).map(|_| '-')),
             );
         } else {
-            template.extend(std::iter::repeat('-').take(rest_to_print));
+            template.extend((0..rest_to_print).map(|_| '-'));
         }
     }
 
@@ -0,0 +1,300 @@
+//! This module contains a [`HtmlTable`] structure which renders a [`Table`] as an HTML `<table>`,
+//! and a [`HtmlAttribute`] setting of cells which is used by it.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, display::HtmlTable};
+//!
+//! let data = [("Grodno", 1), ("Minsk", 2)];
+//! let table = Table::new(data);
+//!
+//! let html = HtmlTable::new(&table).to_string();
+//!
+//! assert_eq!(
+//!     html,
+//!     concat!(
+//!         "<table>\n",
+//!         "  <thead>\n",
+//!         "    <tr>\n",
+//!         "      <th>&amp;str</th>\n",
+//!         "      <th>i32</th>\n",
+//!         "    </tr>\n",
+//!         "  </thead>\n",
+//!         "  <tbody>\n",
+//!         "    <tr>\n",
+//!         "      <td>Grodno</td>\n",
+//!         "      <td>1</td>\n",
+//!         "    </tr>\n",
+//!         "    <tr>\n",
+//!         "      <td>Minsk</td>\n",
+//!         "      <td>2</td>\n",
+//!         "    </tr>\n",
+//!         "  </tbody>\n",
+//!         "</table>",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::fmt::{self, Display, Write};
+
+use papergrid::{records::Records, AlignmentHorizontal, Entity};
+
+use crate::{format::split_hyperlinks, CellOption, Table};
+
+/// [`HtmlTable`] renders a [`Table`] as an HTML `<table>`.
+///
+/// It uses a text of the [`Table`] records so any changes made to the records,
+/// like sorting or removing of rows, are reflected.
+///
+/// - A header row is rendered in `<thead>` in case the [`Table`] has one.
+/// - Spans are rendered as `colspan` and `rowspan` attributes.
+/// - Center and right alignment are rendered as a `text-align` style.
+/// - Hyperlinks, see [`Hyperlink`], are rendered as `<a>` elements.
+/// - Attributes set by [`HtmlAttribute`] are added to cells.
+/// - A text is escaped and line breaks are rendered as `<br>`.
+///
+/// [`Table`]: crate::Table
+/// [`Hyperlink`]: crate::format::Hyperlink
+#[derive(Debug, Clone)]
+pub struct HtmlTable<'a, R> {
+    table: &'a Table<R>,
+    class: String,
+}
+
+impl<'a, R> HtmlTable<'a, R> {
+    /// Creates a new [`HtmlTable`] instance.
+    pub fn new(table: &'a Table<R>) -> Self {
+        Self {
+            table,
+            class: String::new(),
+        }
+    }
+
+    /// Sets a class of the table.
+    ///
+    /// An empty class is not rendered.
+    pub fn class<S>(mut self, class: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<R> Display for HtmlTable<'_, R>
+where
+    R: Records,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let records = self.table.get_records();
+        let cfg = self.table.get_config();
+        let shape = self.table.shape();
+        let (count_rows, count_columns) = shape;
+
+        f.write_str("<table")?;
+        if !self.class.is_empty() {
+            f.write_str(" class=\"")?;
+            write_escaped(f, &self.class)?;
+            f.write_char('"')?;
+        }
+        f.write_str(">\n")?;
+
+        let has_header = self.table.has_header() && count_rows > 0;
+        for row in 0..count_rows {
+            let is_header = row == 0 && has_header;
+            if is_header {
+                f.write_str("  <thead>\n")?;
+            } else if row == 0 || (row == 1 && has_header) {
+                f.write_str("  <tbody>\n")?;
+            }
+
+            f.write_str("    <tr>\n")?;
+
+            let tag = if is_header { "th" } else { "td" };
+            for col in 0..count_columns {
+                if !cfg.is_cell_visible((row, col), shape) {
+                    continue;
+                }
+
+                write!(f, "      <{}", tag)?;
+
+                if let Some(span) = cfg.get_column_span((row, col), shape) {
+                    write!(f, " colspan=\"{}\"", span)?;
+                }
+
+                if let Some(span) = cfg.get_row_span((row, col), shape) {
+                    write!(f, " rowspan=\"{}\"", span)?;
+                }
+
+                let attributes = self.table.get_html_attributes((row, col));
+                let style = attributes.iter().find(|(name, _)| name == "style");
+
+                let alignment = match cfg.get_alignment_horizontal(Entity::Cell(row, col)) {
+                    AlignmentHorizontal::Left => None,
+                    AlignmentHorizontal::Center => Some("text-align: center;"),
+                    AlignmentHorizontal::Right => Some("text-align: right;"),
+                };
+
+                // an alignment is merged with a style set by a user
+                match (alignment, style) {
+                    (Some(alignment), Some((_, style))) => {
+                        write!(f, " style=\"{} ", alignment)?;
+                        write_escaped(f, style)?;
+                        f.write_char('"')?;
+                    }
+                    (Some(alignment), None) => write!(f, " style=\"{}\"", alignment)?,
+                    (None, Some((_, style))) => {
+                        f.write_str(" style=\"")?;
+                        write_escaped(f, style)?;
+                        f.write_char('"')?;
+                    }
+                    (None, None) => (),
+                }
+
+                for (name, value) in attributes.iter().filter(|(name, _)| name != "style") {
+                    f.write_char(' ')?;
+                    write_escaped(f, name)?;
+                    f.write_str("=\"")?;
+                    write_escaped(f, value)?;
+                    f.write_char('"')?;
+                }
+
+                f.write_char('>')?;
+                write_cell(f, records.get_text((row, col)))?;
+                writeln!(f, "</{}>", tag)?;
+            }
+
+            f.write_str("    </tr>\n")?;
+
+            if is_header {
+                f.write_str("  </thead>\n")?;
+            }
+        }
+
+        if count_rows > 1 || (count_rows == 1 && !has_header) {
+            f.write_str("  </tbody>\n")?;
+        }
+
+        f.write_str("</table>")
+    }
+}
+
+/// [`HtmlAttribute`] sets an attribute of cells, like a tooltip or a CSS class.
+///
+/// It's rendered only by [`HtmlTable`] and it's ignored by other outputs,
+/// so the same [`Table`] can be printed in a terminal and published on a web page.
+///
+/// Setting an attribute of a cell again replaces its value.
+/// Attributes follow their rows when rows are sorted, filtered, reversed, split into pages or shifted by panels,
+/// but they're bound to positions of columns, so they need to be set after columns are rearranged.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{display::HtmlAttribute, object::Cell, Modify, Table};
+///
+/// let mut table = Table::new([("tabled", "0.10.0")]);
+/// table.with(Modify::new(Cell(1, 1)).with(HtmlAttribute::title("released in 2022")));
+///
+/// assert!(table.to_html().contains(r#"<td title="released in 2022">0.10.0</td>"#));
+/// assert!(!table.to_string().contains("2022"));
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HtmlAttribute {
+    name: String,
+    value: String,
+}
+
+impl HtmlAttribute {
+    /// Creates an attribute with a name and a value.
+    pub fn new<N, V>(name: N, value: V) -> Self
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Creates a `title` attribute, which is shown as a tooltip.
+    pub fn title<V>(value: V) -> Self
+    where
+        V: Into<String>,
+    {
+        Self::new("title", value)
+    }
+
+    /// Creates a `class` attribute.
+    pub fn class<V>(value: V) -> Self
+    where
+        V: Into<String>,
+    {
+        Self::new("class", value)
+    }
+
+    /// Creates a `data-*` attribute.
+    pub fn data<N, V>(name: N, value: V) -> Self
+    where
+        N: AsRef<str>,
+        V: Into<String>,
+    {
+        Self::new(format!("data-{}", name.as_ref()), value)
+    }
+}
+
+impl<R> CellOption<R> for HtmlAttribute
+where
+    R: Records,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            table.set_html_attribute(pos, &self.name, &self.value);
+        }
+    }
+}
+
+fn write_cell(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    for (i, line) in text.lines().enumerate() {
+        if i > 0 {
+            f.write_str("<br>")?;
+        }
+
+        for (url, text) in split_hyperlinks(line) {
+            match url {
+                Some(url) => {
+                    f.write_str("<a href=\"")?;
+                    write_escaped(f, url)?;
+                    f.write_str("\">")?;
+                    write_escaped(f, text)?;
+                    f.write_str("</a>")?;
+                }
+                None => write_escaped(f, text)?,
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn write_escaped(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    for c in text.chars() {
+        match c {
+            '&' => f.write_str("&amp;")?,
+            '<' => f.write_str("&lt;")?,
+            '>' => f.write_str("&gt;")?,
+            '"' => f.write_str("&quot;")?,
+            c => f.write_char(c)?,
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+//! This module contains a [`JiraTable`] structure which renders a [`Table`] as a Jira/Confluence wiki markup.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, display::JiraTable};
+//!
+//! let data = [("Grodno", 1), ("Minsk", 2)];
+//! let table = Table::new(data);
+//!
+//! let jira = JiraTable::new(&table).to_string();
+//!
+//! assert_eq!(
+//!     jira,
+//!     concat!(
+//!         "||&str||i32||\n",
+//!         "|Grodno|1|\n",
+//!         "|Minsk|2|",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::fmt::{self, Display, Write};
+
+use papergrid::records::Records;
+
+use crate::Table;
+
+/// [`JiraTable`] renders a [`Table`] as a Jira/Confluence wiki markup.
+///
+/// It uses a text of the [`Table`] records so any changes made to the records,
+/// like sorting or removing of rows, are reflected.
+///
+/// - A header row is rendered with `||` separators in case the [`Table`] has one.
+/// - Pipes are escaped.
+/// - Lines of multiline cells are separated by `\\` line breaks.
+/// - Empty cells and cells covered by a span are rendered as a single space,
+///   as the markup doesn't support spans.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone)]
+pub struct JiraTable<'a, R> {
+    table: &'a Table<R>,
+}
+
+impl<'a, R> JiraTable<'a, R> {
+    /// Creates a new [`JiraTable`] instance.
+    pub fn new(table: &'a Table<R>) -> Self {
+        Self { table }
+    }
+}
+
+impl<R> Display for JiraTable<'_, R>
+where
+    R: Records,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let records = self.table.get_records();
+        let cfg = self.table.get_config();
+        let shape = self.table.shape();
+        let (count_rows, count_columns) = shape;
+
+        for row in 0..count_rows {
+            if row > 0 {
+                f.write_char('\n')?;
+            }
+
+            let separator = if row == 0 && self.table.has_header() {
+                "||"
+            } else {
+                "|"
+            };
+
+            f.write_str(separator)?;
+            for col in 0..count_columns {
+                let text = if cfg.is_cell_visible((row, col), shape) {
+                    records.get_text((row, col))
+                } else {
+                    ""
+                };
+
+                write_cell(f, text)?;
+                f.write_str(separator)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn write_cell(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    if text.is_empty() {
+        return f.write_char(' ');
+    }
+
+    for (i, line) in text.lines().enumerate() {
+        if i > 0 {
+            f.write_str(" \\\\ ")?;
+        }
+
+        for c in line.chars() {
+            if c == '|' {
+                f.write_char('\\')?;
+            }
+
+            f.write_char(c)?;
+        }
+    }
+
+    Ok(())
+}
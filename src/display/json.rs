@@ -0,0 +1,188 @@
+//! This module contains a [`JsonTable`] structure which renders a [`Table`] as JSON.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, display::JsonTable};
+//!
+//! let data = [("Grodno", 1), ("Minsk", 2)];
+//! let table = Table::new(data);
+//!
+//! let json = JsonTable::new(&table).to_string();
+//!
+//! assert_eq!(
+//!     json,
+//!     r#"[{"&str":"Grodno","i32":"1"},{"&str":"Minsk","i32":"2"}]"#,
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::fmt::{self, Display, Write};
+
+use papergrid::records::Records;
+
+use crate::Table;
+
+/// [`JsonTable`] renders a [`Table`] as JSON.
+///
+/// It uses a text of the [`Table`] records so any changes made to the records,
+/// like sorting or removing of rows, are reflected.
+///
+/// By default rows are rendered as an array of objects keyed by a header
+/// in case the [`Table`] has one, and as an array of arrays otherwise.
+///
+/// All values are rendered as strings.
+/// Cells covered by a span are rendered as empty strings.
+///
+/// Keys of objects are made unique, a repeated or an empty name is given a `_1`, `_2`, etc. suffix.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone)]
+pub struct JsonTable<'a, R> {
+    table: &'a Table<R>,
+    layout: Option<Layout>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Layout {
+    Objects,
+    Arrays,
+}
+
+impl<'a, R> JsonTable<'a, R> {
+    /// Creates a new [`JsonTable`] instance.
+    pub fn new(table: &'a Table<R>) -> Self {
+        Self {
+            table,
+            layout: None,
+        }
+    }
+
+    /// Render rows as objects keyed by a first row.
+    pub fn objects(mut self) -> Self {
+        self.layout = Some(Layout::Objects);
+        self
+    }
+
+    /// Render all rows, including a header, as arrays.
+    ///
+    /// ```
+    /// use tabled::{Table, display::JsonTable};
+    ///
+    /// let table = Table::new([("Grodno", 1)]);
+    ///
+    /// assert_eq!(
+    ///     JsonTable::new(&table).arrays().to_string(),
+    ///     r#"[["&str","i32"],["Grodno","1"]]"#,
+    /// );
+    /// ```
+    pub fn arrays(mut self) -> Self {
+        self.layout = Some(Layout::Arrays);
+        self
+    }
+}
+
+impl<R> Display for JsonTable<'_, R>
+where
+    R: Records,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let layout = match self.layout {
+            Some(layout) => layout,
+            None if self.table.has_header() => Layout::Objects,
+            None => Layout::Arrays,
+        };
+
+        let (count_rows, count_columns) = self.table.shape();
+        let (start, keys) = match layout {
+            Layout::Objects => (1, self.build_keys()),
+            Layout::Arrays => (0, Vec::new()),
+        };
+
+        f.write_char('[')?;
+        for row in start..count_rows {
+            if row > start {
+                f.write_char(',')?;
+            }
+
+            match layout {
+                Layout::Objects => f.write_char('{')?,
+                Layout::Arrays => f.write_char('[')?,
+            }
+
+            for col in 0..count_columns {
+                if col > 0 {
+                    f.write_char(',')?;
+                }
+
+                if let Some(key) = keys.get(col) {
+                    write_string(f, key)?;
+                    f.write_char(':')?;
+                }
+
+                write_string(f, self.get_text((row, col)))?;
+            }
+
+            match layout {
+                Layout::Objects => f.write_char('}')?,
+                Layout::Arrays => f.write_char(']')?,
+            }
+        }
+
+        f.write_char(']')
+    }
+}
+
+impl<R> JsonTable<'_, R>
+where
+    R: Records,
+{
+    fn build_keys(&self) -> Vec<String> {
+        let (count_rows, count_columns) = self.table.shape();
+        if count_rows == 0 {
+            return Vec::new();
+        }
+
+        let mut keys: Vec<String> = Vec::with_capacity(count_columns);
+        for col in 0..count_columns {
+            let name = self.get_text((0, col));
+
+            let mut key = name.to_owned();
+            let mut suffix = 0;
+            while key.is_empty() || keys.contains(&key) {
+                suffix += 1;
+                key = format!("{}_{}", name, suffix);
+            }
+
+            keys.push(key);
+        }
+
+        keys
+    }
+
+    fn get_text(&self, pos: (usize, usize)) -> &str {
+        let shape = self.table.shape();
+        if self.table.get_config().is_cell_visible(pos, shape) {
+            self.table.get_records().get_text(pos)
+        } else {
+            ""
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in text.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
@@ -0,0 +1,180 @@
+//! This module contains a [`LatexTable`] structure which renders a [`Table`] as a LaTeX `tabular` environment.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, display::LatexTable};
+//!
+//! let data = [("Grodno", "25%"), ("Minsk", "50%")];
+//! let table = Table::new(data);
+//!
+//! let latex = LatexTable::new(&table).booktabs().to_string();
+//!
+//! assert_eq!(
+//!     latex,
+//!     concat!(
+//!         "\\begin{tabular}{ll}\n",
+//!         "\\toprule\n",
+//!         "\\&str & \\&str \\\\\n",
+//!         "\\midrule\n",
+//!         "Grodno & 25\\% \\\\\n",
+//!         "Minsk & 50\\% \\\\\n",
+//!         "\\bottomrule\n",
+//!         "\\end{tabular}",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::fmt::{self, Display, Write};
+
+use papergrid::{records::Records, AlignmentHorizontal, Entity};
+
+use crate::Table;
+
+/// [`LatexTable`] renders a [`Table`] as a LaTeX `tabular` environment.
+///
+/// It uses a text of the [`Table`] records so any changes made to the records,
+/// like sorting or removing of rows, are reflected.
+///
+/// - A column specification is taken from a horizontal alignment of a column.
+/// - A cell with a different alignment and a column span are put into `\multicolumn`.
+/// - Special characters are escaped.
+/// - Multiline cells are put into `\shortstack`.
+///
+/// By default `\hline` rules are used, [`LatexTable::booktabs`] switches them to the `booktabs` ones.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone)]
+pub struct LatexTable<'a, R> {
+    table: &'a Table<R>,
+    booktabs: bool,
+}
+
+impl<'a, R> LatexTable<'a, R> {
+    /// Creates a new [`LatexTable`] instance.
+    pub fn new(table: &'a Table<R>) -> Self {
+        Self {
+            table,
+            booktabs: false,
+        }
+    }
+
+    /// Use `\toprule`, `\midrule` and `\bottomrule` rules from the `booktabs` package.
+    pub fn booktabs(mut self) -> Self {
+        self.booktabs = true;
+        self
+    }
+}
+
+impl<R> Display for LatexTable<'_, R>
+where
+    R: Records,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (top, mid, bottom) = if self.booktabs {
+            ("\\toprule", "\\midrule", "\\bottomrule")
+        } else {
+            ("\\hline", "\\hline", "\\hline")
+        };
+
+        let records = self.table.get_records();
+        let cfg = self.table.get_config();
+        let shape = self.table.shape();
+        let (count_rows, count_columns) = shape;
+
+        f.write_str("\\begin{tabular}{")?;
+        for col in 0..count_columns {
+            let alignment = cfg.get_alignment_horizontal(Entity::Column(col));
+            f.write_char(column_spec(alignment))?;
+        }
+        f.write_str("}\n")?;
+
+        writeln!(f, "{}", top)?;
+
+        for row in 0..count_rows {
+            let mut is_first = true;
+            for col in 0..count_columns {
+                if cfg.is_cell_covered_by_column_span((row, col), shape) {
+                    continue;
+                }
+
+                if !is_first {
+                    f.write_str(" & ")?;
+                }
+
+                is_first = false;
+
+                let text = if cfg.is_cell_covered_by_row_span((row, col), shape) {
+                    ""
+                } else {
+                    records.get_text((row, col))
+                };
+
+                let span = cfg.get_column_span((row, col), shape).unwrap_or(1);
+                let alignment = cfg.get_alignment_horizontal(Entity::Cell(row, col));
+                let column_alignment = cfg.get_alignment_horizontal(Entity::Column(col));
+
+                if span > 1 || alignment != column_alignment {
+                    let spec = column_spec(alignment);
+                    write!(f, "\\multicolumn{{{}}}{{{}}}{{", span, spec)?;
+                    write_cell(f, text, spec)?;
+                    f.write_char('}')?;
+                } else {
+                    write_cell(f, text, column_spec(alignment))?;
+                }
+            }
+
+            f.write_str(" \\\\\n")?;
+
+            if row == 0 && self.table.has_header() && count_rows > 1 {
+                writeln!(f, "{}", mid)?;
+            }
+        }
+
+        writeln!(f, "{}", bottom)?;
+        f.write_str("\\end{tabular}")
+    }
+}
+
+fn column_spec(alignment: &AlignmentHorizontal) -> char {
+    match alignment {
+        AlignmentHorizontal::Left => 'l',
+        AlignmentHorizontal::Center => 'c',
+        AlignmentHorizontal::Right => 'r',
+    }
+}
+
+fn write_cell(f: &mut fmt::Formatter<'_>, text: &str, spec: char) -> fmt::Result {
+    if !text.contains('\n') {
+        return write_escaped(f, text);
+    }
+
+    write!(f, "\\shortstack[{}]{{", spec)?;
+    for (i, line) in text.lines().enumerate() {
+        if i > 0 {
+            f.write_str(" \\\\ ")?;
+        }
+
+        write_escaped(f, line)?;
+    }
+    f.write_char('}')
+}
+
+fn write_escaped(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    for c in text.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                f.write_char('\\')?;
+                f.write_char(c)?;
+            }
+            '~' => f.write_str("\\textasciitilde{}")?,
+            '^' => f.write_str("\\textasciicircum{}")?,
+            '\\' => f.write_str("\\textbackslash{}")?,
+            c => f.write_char(c)?,
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,166 @@
+//! This module contains a [`MediaWikiTable`] structure which renders a [`Table`] as a MediaWiki table markup.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, display::MediaWikiTable};
+//!
+//! let data = [("Grodno", 1), ("Minsk", 2)];
+//! let table = Table::new(data);
+//!
+//! let wiki = MediaWikiTable::new(&table).to_string();
+//!
+//! assert_eq!(
+//!     wiki,
+//!     concat!(
+//!         "{| class=\"wikitable\"\n",
+//!         "|-\n",
+//!         "! &str\n",
+//!         "! i32\n",
+//!         "|-\n",
+//!         "| Grodno\n",
+//!         "| 1\n",
+//!         "|-\n",
+//!         "| Minsk\n",
+//!         "| 2\n",
+//!         "|}",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::fmt::{self, Display, Write};
+
+use papergrid::{records::Records, AlignmentHorizontal, Entity};
+
+use crate::Table;
+
+/// [`MediaWikiTable`] renders a [`Table`] as a MediaWiki table markup.
+///
+/// It uses a text of the [`Table`] records so any changes made to the records,
+/// like sorting or removing of rows, are reflected.
+///
+/// - A header row is rendered as header cells (`!`) in case the [`Table`] has one.
+/// - Spans are rendered as `colspan` and `rowspan` attributes.
+/// - Center and right alignment are rendered as a `text-align` style.
+/// - Pipes are escaped and line breaks are rendered as `<br />`.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone)]
+pub struct MediaWikiTable<'a, R> {
+    table: &'a Table<R>,
+    class: String,
+}
+
+impl<'a, R> MediaWikiTable<'a, R> {
+    /// Creates a new [`MediaWikiTable`] instance.
+    ///
+    /// A default class of the table is `wikitable`.
+    pub fn new(table: &'a Table<R>) -> Self {
+        Self {
+            table,
+            class: String::from("wikitable"),
+        }
+    }
+
+    /// Sets a class of the table.
+    ///
+    /// An empty class is not rendered.
+    pub fn class<S>(mut self, class: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<R> Display for MediaWikiTable<'_, R>
+where
+    R: Records,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let records = self.table.get_records();
+        let cfg = self.table.get_config();
+        let shape = self.table.shape();
+        let (count_rows, count_columns) = shape;
+
+        f.write_str("{|")?;
+        if !self.class.is_empty() {
+            f.write_str(" class=\"")?;
+            write_escaped(f, &self.class)?;
+            f.write_char('"')?;
+        }
+        f.write_char('\n')?;
+
+        for row in 0..count_rows {
+            f.write_str("|-\n")?;
+
+            let is_header = row == 0 && self.table.has_header();
+            for col in 0..count_columns {
+                if !cfg.is_cell_visible((row, col), shape) {
+                    continue;
+                }
+
+                f.write_str(if is_header { "! " } else { "| " })?;
+
+                let mut has_attributes = false;
+                if let Some(span) = cfg.get_column_span((row, col), shape) {
+                    write!(f, "colspan=\"{}\" ", span)?;
+                    has_attributes = true;
+                }
+
+                if let Some(span) = cfg.get_row_span((row, col), shape) {
+                    write!(f, "rowspan=\"{}\" ", span)?;
+                    has_attributes = true;
+                }
+
+                match cfg.get_alignment_horizontal(Entity::Cell(row, col)) {
+                    AlignmentHorizontal::Left => (),
+                    AlignmentHorizontal::Center => {
+                        f.write_str("style=\"text-align: center;\" ")?;
+                        has_attributes = true;
+                    }
+                    AlignmentHorizontal::Right => {
+                        f.write_str("style=\"text-align: right;\" ")?;
+                        has_attributes = true;
+                    }
+                }
+
+                if has_attributes {
+                    f.write_str("| ")?;
+                }
+
+                write_cell(f, records.get_text((row, col)))?;
+                f.write_char('\n')?;
+            }
+        }
+
+        f.write_str("|}")
+    }
+}
+
+fn write_cell(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    for (i, line) in text.lines().enumerate() {
+        if i > 0 {
+            f.write_str("<br />")?;
+        }
+
+        write_escaped(f, line)?;
+    }
+
+    Ok(())
+}
+
+fn write_escaped(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    for c in text.chars() {
+        match c {
+            '|' => f.write_str("&#124;")?,
+            '"' => f.write_str("&quot;")?,
+            c => f.write_char(c)?,
+        }
+    }
+
+    Ok(())
+}
@@ -2,6 +2,34 @@
 //!
 //! [`Table`]: crate::Table
 
-mod expanded_display;
+mod asciidoc;
+mod csv;
+mod extended_table;
+mod html;
+mod jira;
+mod json;
+mod latex;
+mod mediawiki;
 
-pub use expanded_display::*;
+#[cfg(any(feature = "image", feature = "ratatui"))]
+mod ansi;
+#[cfg(feature = "image")]
+mod svg;
+#[cfg(feature = "ratatui")]
+mod tui;
+#[cfg(feature = "xlsx")]
+pub(crate) mod xlsx;
+
+pub use self::csv::*;
+pub use asciidoc::*;
+pub use extended_table::*;
+pub use html::*;
+pub use jira::*;
+pub use json::*;
+pub use latex::*;
+pub use mediawiki::*;
+
+#[cfg(feature = "image")]
+pub use svg::*;
+#[cfg(feature = "ratatui")]
+pub use tui::*;
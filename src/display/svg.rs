@@ -0,0 +1,209 @@
+//! This module contains a [`SvgTable`] structure which renders a [`Table`] as an SVG image.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, display::SvgTable};
+//!
+//! let table = Table::new([1]);
+//!
+//! let svg = SvgTable::new(&table).to_string();
+//!
+//! assert!(svg.starts_with("<svg"));
+//! assert!(svg.contains(">| i32 |</text>"));
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::fmt::{self, Display, Write};
+
+use papergrid::records::Records;
+
+use crate::Table;
+
+use super::ansi::{parse_line, Color, Segment};
+
+/// [`SvgTable`] renders a [`Table`] as an SVG image.
+///
+/// The table is rendered as it's shown in a terminal, line by line, using a monospace font.
+///
+/// ANSI foreground colors and bold text are kept, other ANSI sequences are dropped.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+pub struct SvgTable<'a, R> {
+    table: &'a Table<R>,
+    font_size: usize,
+    font_family: String,
+    foreground: String,
+    background: Option<String>,
+}
+
+impl<'a, R> SvgTable<'a, R> {
+    /// Creates a new [`SvgTable`] instance.
+    ///
+    /// By default a black text of 14px size is used with no background.
+    pub fn new(table: &'a Table<R>) -> Self {
+        Self {
+            table,
+            font_size: 14,
+            font_family: String::from("monospace"),
+            foreground: String::from("#000000"),
+            background: None,
+        }
+    }
+
+    /// Sets a font size in pixels.
+    pub fn font_size(mut self, size: usize) -> Self {
+        self.font_size = size;
+        self
+    }
+
+    /// Sets a font family.
+    ///
+    /// It's expected to be a monospace font.
+    pub fn font_family<S>(mut self, family: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.font_family = family.into();
+        self
+    }
+
+    /// Sets a default color of a text.
+    ///
+    /// It can be any color SVG supports e.g. `#ff0000` or `red`.
+    pub fn foreground<S>(mut self, color: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.foreground = color.into();
+        self
+    }
+
+    /// Sets a background color.
+    ///
+    /// It can be any color SVG supports e.g. `#ff0000` or `red`.
+    pub fn background<S>(mut self, color: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.background = Some(color.into());
+        self
+    }
+}
+
+impl<R> Display for SvgTable<'_, R>
+where
+    R: Records,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = self.table.to_string();
+        let lines = text.lines().map(parse_line).collect::<Vec<_>>();
+
+        let count_chars = lines.iter().map(|line| line_width(line)).max().unwrap_or(0);
+
+        // a usual width of a monospace glyph is 0.6 of a font size
+        let char_width = self.font_size as f64 * 0.6;
+        let line_height = self.font_size as f64 * 1.2;
+        let width = char_width * count_chars as f64;
+        let height = line_height * lines.len() as f64;
+
+        writeln!(
+            f,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w:.1}\" height=\"{h:.1}\" viewBox=\"0 0 {w:.1} {h:.1}\">",
+            w = width,
+            h = height,
+        )?;
+
+        if let Some(background) = &self.background {
+            f.write_str("<rect width=\"100%\" height=\"100%\" fill=\"")?;
+            write_escaped(f, background)?;
+            f.write_str("\"/>\n")?;
+        }
+
+        f.write_str("<g font-family=\"")?;
+        write_escaped(f, &self.font_family)?;
+        write!(f, "\" font-size=\"{}\" fill=\"", self.font_size)?;
+        write_escaped(f, &self.foreground)?;
+        f.write_str("\">\n")?;
+
+        for (i, line) in lines.iter().enumerate() {
+            let count_chars = line_width(line);
+            if count_chars == 0 {
+                continue;
+            }
+
+            // put a baseline a bit above a bottom of a line so descenders fit
+            let y = line_height * i as f64 + self.font_size as f64;
+            write!(
+                f,
+                "<text x=\"0\" y=\"{:.1}\" xml:space=\"preserve\" textLength=\"{:.1}\" lengthAdjust=\"spacingAndGlyphs\">",
+                y,
+                char_width * count_chars as f64,
+            )?;
+
+            for segment in line {
+                write_segment(f, segment)?;
+            }
+
+            f.write_str("</text>\n")?;
+        }
+
+        f.write_str("</g>\n</svg>")
+    }
+}
+
+fn line_width(line: &[Segment]) -> usize {
+    line.iter()
+        .map(|s| unicode_width::UnicodeWidthStr::width(s.text.as_str()))
+        .sum()
+}
+
+fn write_segment(f: &mut fmt::Formatter<'_>, segment: &Segment) -> fmt::Result {
+    let color = segment.style.foreground.and_then(svg_color);
+    if color.is_none() && !segment.style.bold {
+        return write_escaped(f, &segment.text);
+    }
+
+    f.write_str("<tspan")?;
+    if let Some(color) = color {
+        write!(f, " fill=\"{}\"", color)?;
+    }
+
+    if segment.style.bold {
+        f.write_str(" font-weight=\"bold\"")?;
+    }
+
+    f.write_char('>')?;
+    write_escaped(f, &segment.text)?;
+    f.write_str("</tspan>")
+}
+
+fn write_escaped(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    for c in text.chars() {
+        match c {
+            '&' => f.write_str("&amp;")?,
+            '<' => f.write_str("&lt;")?,
+            '>' => f.write_str("&gt;")?,
+            '"' => f.write_str("&quot;")?,
+            c => f.write_char(c)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn svg_color(color: Color) -> Option<String> {
+    match color {
+        Color::Indexed(n) if n < 16 => Some(ANSI_COLORS[usize::from(n)].to_owned()),
+        Color::Indexed(_) => None,
+        Color::Rgb(r, g, b) => Some(format!("#{:02x}{:02x}{:02x}", r, g, b)),
+    }
+}
+
+const ANSI_COLORS: [&str; 16] = [
+    "#000000", "#cd0000", "#00cd00", "#cdcd00", "#0000ee", "#cd00cd", "#00cdcd", "#e5e5e5",
+    "#7f7f7f", "#ff0000", "#00ff00", "#ffff00", "#5c5cff", "#ff00ff", "#00ffff", "#ffffff",
+];
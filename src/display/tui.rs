@@ -0,0 +1,163 @@
+//! This module contains a [`TableWidget`] structure which renders a [`Table`] as a [`ratatui`] widget.
+//!
+//! # Example
+//!
+//! ```
+//! use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+//! use tabled::{Table, display::TableWidget};
+//!
+//! let table = Table::new([1]);
+//!
+//! let area = Rect::new(0, 0, 7, 5);
+//! let mut buf = Buffer::empty(area);
+//! TableWidget::new(&table).render(area, &mut buf);
+//!
+//! assert_eq!(
+//!     buf,
+//!     Buffer::with_lines([
+//!         "+-----+",
+//!         "| i32 |",
+//!         "+-----+",
+//!         "| 1   |",
+//!         "+-----+",
+//!     ])
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::records::Records;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color as TuiColor, Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Paragraph, Widget},
+};
+
+use crate::Table;
+
+use super::ansi::{parse_line, Color, TextStyle};
+
+/// [`TableWidget`] renders a [`Table`] as a [`ratatui`] widget.
+///
+/// The table is rendered the same way as it's printed to a terminal,
+/// so all borders and settings are kept.
+///
+/// ANSI colors and bold, italic and underlined text are mapped to [`ratatui`] styles,
+/// other ANSI sequences are dropped.
+///
+/// A table which doesn't fit into an area is clipped.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+pub struct TableWidget<'a, R> {
+    table: &'a Table<R>,
+    style: Style,
+}
+
+impl<'a, R> TableWidget<'a, R> {
+    /// Creates a new [`TableWidget`] instance.
+    pub fn new(table: &'a Table<R>) -> Self {
+        Self {
+            table,
+            style: Style::default(),
+        }
+    }
+
+    /// Sets a base style of the widget,
+    /// which is patched by styles set by ANSI sequences.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<R> TableWidget<'_, R>
+where
+    R: Records,
+{
+    /// Converts the table into a [`Text`].
+    ///
+    /// It can be used to put the table into other widgets, e.g. a scrollable [`Paragraph`].
+    pub fn to_text(&self) -> Text<'static> {
+        let text = self.table.to_string();
+        let lines = text.lines().map(|line| {
+            let spans = parse_line(line)
+                .into_iter()
+                .map(|segment| Span::styled(segment.text, tui_style(&segment.style)));
+
+            Line::from(spans.collect::<Vec<_>>())
+        });
+
+        Text::from(lines.collect::<Vec<_>>()).style(self.style)
+    }
+}
+
+impl<R> Widget for TableWidget<'_, R>
+where
+    R: Records,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Paragraph::new(self.to_text()).render(area, buf);
+    }
+}
+
+impl<R> Widget for &Table<R>
+where
+    R: Records,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        TableWidget::new(self).render(area, buf);
+    }
+}
+
+fn tui_style(style: &TextStyle) -> Style {
+    let mut tui = Style::default();
+
+    if let Some(color) = style.foreground {
+        tui = tui.fg(tui_color(color));
+    }
+
+    if let Some(color) = style.background {
+        tui = tui.bg(tui_color(color));
+    }
+
+    if style.bold {
+        tui = tui.add_modifier(Modifier::BOLD);
+    }
+
+    if style.italic {
+        tui = tui.add_modifier(Modifier::ITALIC);
+    }
+
+    if style.underline {
+        tui = tui.add_modifier(Modifier::UNDERLINED);
+    }
+
+    tui
+}
+
+fn tui_color(color: Color) -> TuiColor {
+    match color {
+        Color::Indexed(0) => TuiColor::Black,
+        Color::Indexed(1) => TuiColor::Red,
+        Color::Indexed(2) => TuiColor::Green,
+        Color::Indexed(3) => TuiColor::Yellow,
+        Color::Indexed(4) => TuiColor::Blue,
+        Color::Indexed(5) => TuiColor::Magenta,
+        Color::Indexed(6) => TuiColor::Cyan,
+        Color::Indexed(7) => TuiColor::Gray,
+        Color::Indexed(8) => TuiColor::DarkGray,
+        Color::Indexed(9) => TuiColor::LightRed,
+        Color::Indexed(10) => TuiColor::LightGreen,
+        Color::Indexed(11) => TuiColor::LightYellow,
+        Color::Indexed(12) => TuiColor::LightBlue,
+        Color::Indexed(13) => TuiColor::LightMagenta,
+        Color::Indexed(14) => TuiColor::LightCyan,
+        Color::Indexed(15) => TuiColor::White,
+        Color::Indexed(n) => TuiColor::Indexed(n),
+        Color::Rgb(r, g, b) => TuiColor::Rgb(r, g, b),
+    }
+}
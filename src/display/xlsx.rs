@@ -0,0 +1,90 @@
+//! This module contains a logic of writing a [`Table`] into a XLSX spreadsheet.
+//!
+//! [`Table`]: crate::Table
+
+use std::convert::TryFrom;
+
+use papergrid::{records::Records, AlignmentHorizontal, AlignmentVertical, Entity};
+use rust_xlsxwriter::{ColNum, Format, FormatAlign, RowNum, Worksheet, XlsxError};
+
+use crate::Table;
+
+/// Writes records of a [`Table`] into a [`Worksheet`] starting from its first cell.
+pub(crate) fn write_worksheet<R>(
+    table: &Table<R>,
+    worksheet: &mut Worksheet,
+) -> Result<(), XlsxError>
+where
+    R: Records,
+{
+    let records = table.get_records();
+    let cfg = table.get_config();
+    let shape = table.shape();
+    let (count_rows, count_columns) = shape;
+
+    for row in 0..count_rows {
+        for col in 0..count_columns {
+            if !cfg.is_cell_visible((row, col), shape) {
+                continue;
+            }
+
+            let text = records.get_text((row, col));
+
+            let mut format = Format::new()
+                .set_align(horizontal_alignment(
+                    cfg.get_alignment_horizontal(Entity::Cell(row, col)),
+                ))
+                .set_align(vertical_alignment(
+                    cfg.get_alignment_vertical(Entity::Cell(row, col)),
+                ));
+
+            if row == 0 && table.has_header() {
+                format = format.set_bold();
+            }
+
+            if text.contains('\n') {
+                format = format.set_text_wrap();
+            }
+
+            let column_span = cfg.get_column_span((row, col), shape).unwrap_or(1);
+            let row_span = cfg.get_row_span((row, col), shape).unwrap_or(1);
+
+            let (first_row, first_col) = (row_num(row)?, col_num(col)?);
+            if column_span > 1 || row_span > 1 {
+                let last_row = row_num(row + row_span - 1)?;
+                let last_col = col_num(col + column_span - 1)?;
+                worksheet.merge_range(first_row, first_col, last_row, last_col, text, &format)?;
+            } else {
+                worksheet.write_string_with_format(first_row, first_col, text, &format)?;
+            }
+        }
+    }
+
+    worksheet.autofit();
+
+    Ok(())
+}
+
+fn row_num(row: usize) -> Result<RowNum, XlsxError> {
+    RowNum::try_from(row).map_err(|_| XlsxError::RowColumnLimitError)
+}
+
+fn col_num(col: usize) -> Result<ColNum, XlsxError> {
+    ColNum::try_from(col).map_err(|_| XlsxError::RowColumnLimitError)
+}
+
+fn horizontal_alignment(alignment: &AlignmentHorizontal) -> FormatAlign {
+    match alignment {
+        AlignmentHorizontal::Left => FormatAlign::Left,
+        AlignmentHorizontal::Center => FormatAlign::Center,
+        AlignmentHorizontal::Right => FormatAlign::Right,
+    }
+}
+
+fn vertical_alignment(alignment: &AlignmentVertical) -> FormatAlign {
+    match alignment {
+        AlignmentVertical::Top => FormatAlign::Top,
+        AlignmentVertical::Center => FormatAlign::VerticalCenter,
+        AlignmentVertical::Bottom => FormatAlign::Bottom,
+    }
+}
@@ -0,0 +1,263 @@
+//! This module contains an [`Aggregate`] setting which appends a row with totals of columns.
+//!
+//! It's created by [`Footer::sum`], [`Footer::avg`], [`Footer::min`], [`Footer::max`] and [`Footer::count`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{locator::ByColumnName, Footer, Style, TableIteratorExt};
+//!
+//! let data = [("main.rs", 1200), ("lib.rs", 300), ("util.rs", 45)];
+//!
+//! let table = data.table()
+//!     .with(Style::psql())
+//!     .with(Footer::sum(ByColumnName::new("i32")))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         " &str    | i32  \n",
+//!         "---------+------\n",
+//!         " main.rs | 1200 \n",
+//!         " lib.rs  | 300  \n",
+//!         " util.rs | 45   \n",
+//!         " Total   | 1545 ",
+//!     )
+//! );
+//! ```
+//!
+//! [`Footer::sum`]: crate::Footer::sum
+//! [`Footer::avg`]: crate::Footer::avg
+//! [`Footer::min`]: crate::Footer::min
+//! [`Footer::max`]: crate::Footer::max
+//! [`Footer::count`]: crate::Footer::count
+
+use papergrid::{
+    records::{Records, RecordsMut, Resizable},
+    width::CfgWidthFunction,
+};
+
+use crate::{features::panel::Footer, locator::Locator, Table, TableOption};
+
+/// Aggregate appends a row with a result of a function over located columns.
+///
+/// The first row is considered to be a header, so it's not aggregated.
+/// Rows appended by [`Footer`] settings, including other aggregates, are not aggregated as well.
+/// Cells which can't be parsed as a number are skipped.
+///
+/// A label is put into the first column in case it's not aggregated itself.
+///
+/// An integer result is printed as it is and a fractional one is rounded to 2 decimals.
+#[derive(Debug)]
+pub struct Aggregate<L> {
+    locator: L,
+    function: Function,
+    label: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Function {
+    Sum,
+    Avg,
+    Min,
+    Max,
+    Count,
+}
+
+impl Footer<&'static str> {
+    /// Appends a row with sums of located columns, labeled as `Total`.
+    ///
+    /// See [`Aggregate`].
+    pub fn sum<L>(locator: L) -> Aggregate<L>
+    where
+        L: Locator<Coordinate = usize>,
+    {
+        Aggregate::new(locator, Function::Sum)
+    }
+
+    /// Appends a row with averages of located columns, labeled as `Average`.
+    ///
+    /// See [`Aggregate`].
+    pub fn avg<L>(locator: L) -> Aggregate<L>
+    where
+        L: Locator<Coordinate = usize>,
+    {
+        Aggregate::new(locator, Function::Avg)
+    }
+
+    /// Appends a row with minimums of located columns, labeled as `Min`.
+    ///
+    /// See [`Aggregate`].
+    pub fn min<L>(locator: L) -> Aggregate<L>
+    where
+        L: Locator<Coordinate = usize>,
+    {
+        Aggregate::new(locator, Function::Min)
+    }
+
+    /// Appends a row with maximums of located columns, labeled as `Max`.
+    ///
+    /// See [`Aggregate`].
+    pub fn max<L>(locator: L) -> Aggregate<L>
+    where
+        L: Locator<Coordinate = usize>,
+    {
+        Aggregate::new(locator, Function::Max)
+    }
+
+    /// Appends a row with a number of not empty cells of located columns, labeled as `Count`.
+    ///
+    /// Unlike other functions it counts any values, not only numbers.
+    ///
+    /// See [`Aggregate`].
+    pub fn count<L>(locator: L) -> Aggregate<L>
+    where
+        L: Locator<Coordinate = usize>,
+    {
+        Aggregate::new(locator, Function::Count)
+    }
+}
+
+impl<L> Aggregate<L> {
+    fn new(locator: L, function: Function) -> Self {
+        Self {
+            locator,
+            function,
+            label: None,
+        }
+    }
+
+    /// Sets a label of the row.
+    pub fn label<S>(mut self, label: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+impl<L, R> TableOption<R> for Aggregate<L>
+where
+    L: Locator<Coordinate = usize>,
+    R: Records + RecordsMut<String> + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let (count_rows, count_cols) = table.shape();
+        if count_rows == 0 || count_cols == 0 {
+            return;
+        }
+
+        let mut columns = self
+            .locator
+            .locate(table.get_records())
+            .into_iter()
+            .filter(|&col| col < count_cols)
+            .collect::<Vec<_>>();
+        columns.sort_unstable();
+        columns.dedup();
+
+        let last_row = count_rows.saturating_sub(table.count_footer_rows());
+        let values = columns
+            .iter()
+            .map(|&col| {
+                let records = table.get_records();
+                let cells = (1..last_row).map(|row| records.get_text((row, col)));
+                (col, aggregate(self.function, cells))
+            })
+            .collect::<Vec<_>>();
+
+        table.get_records_mut().push_row();
+
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        if !columns.contains(&0) {
+            let label = match &self.label {
+                Some(label) => label.clone(),
+                None => default_label(self.function).to_owned(),
+            };
+
+            table
+                .get_records_mut()
+                .set((count_rows, 0), label, &width_fn);
+        }
+
+        for (col, value) in values {
+            table
+                .get_records_mut()
+                .set((count_rows, col), value, &width_fn);
+        }
+
+        table.set_footer_row(count_rows);
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+fn default_label(function: Function) -> &'static str {
+    match function {
+        Function::Sum => "Total",
+        Function::Avg => "Average",
+        Function::Min => "Min",
+        Function::Max => "Max",
+        Function::Count => "Count",
+    }
+}
+
+pub(crate) fn aggregate<'a>(function: Function, cells: impl Iterator<Item = &'a str>) -> String {
+    let cells = cells.map(str::trim).filter(|text| !text.is_empty());
+
+    if function == Function::Count {
+        return cells.count().to_string();
+    }
+
+    let numbers = cells
+        .filter_map(|text| text.parse::<f64>().ok().map(|value| (text, value)))
+        .filter(|(_, value)| value.is_finite())
+        .collect::<Vec<_>>();
+
+    match function {
+        Function::Sum => {
+            let integers = numbers
+                .iter()
+                .map(|(text, _)| text.parse::<i128>().ok())
+                .collect::<Option<Vec<_>>>();
+
+            // an integer sum may overflow, in which case a float one is used
+            let sum = integers.and_then(|integers| {
+                integers
+                    .iter()
+                    .try_fold(0i128, |sum, &value| sum.checked_add(value))
+            });
+
+            match sum {
+                Some(sum) => sum.to_string(),
+                None => format_float(numbers.iter().map(|(_, value)| value).sum()),
+            }
+        }
+        Function::Avg if numbers.is_empty() => String::new(),
+        Function::Avg => {
+            let sum = numbers.iter().map(|(_, value)| value).sum::<f64>();
+            format_float(sum / numbers.len() as f64)
+        }
+        Function::Min => numbers
+            .iter()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(text, _)| text.to_string())
+            .unwrap_or_default(),
+        Function::Max => numbers
+            .iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(text, _)| text.to_string())
+            .unwrap_or_default(),
+        Function::Count => unreachable!(),
+    }
+}
+
+fn format_float(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.2}", value)
+    }
+}
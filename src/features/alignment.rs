@@ -48,9 +48,9 @@
 //! [`Table`]: crate::Table
 //! [`AlignmentStrategy`]: crate::formatting::AlignmentStrategy
 
-use papergrid::Entity;
+use papergrid::{records::Records, Entity};
 
-use crate::{CellOption, Table, TableOption};
+use crate::{CellOption, SettingKind, Table, TableOption};
 
 pub use papergrid::{AlignmentHorizontal, AlignmentVertical};
 
@@ -119,6 +119,11 @@ impl Alignment {
         Self::vertical(AlignmentVertical::Center)
     }
 
+    /// Auto constructs an [`AutoAlignment`] which aligns columns by their content.
+    pub fn auto() -> AutoAlignment {
+        AutoAlignment
+    }
+
     /// Returns an alignment with the given horizontal alignment.
     fn horizontal(alignment: AlignmentHorizontal) -> Self {
         Self::Horizontal(alignment)
@@ -133,18 +138,137 @@ impl Alignment {
 impl<R> CellOption<R> for Alignment {
     fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
         match *self {
-            Self::Horizontal(a) => table.get_config_mut().set_alignment_horizontal(entity, a),
-            Self::Vertical(a) => table.get_config_mut().set_alignment_vertical(entity, a),
+            Self::Horizontal(a) => table
+                .get_config_mut_keep_dimension()
+                .set_alignment_horizontal(entity, a),
+            Self::Vertical(a) => table
+                .get_config_mut_keep_dimension()
+                .set_alignment_vertical(entity, a),
         };
     }
 }
 
 impl<R> TableOption<R> for Alignment {
     fn change(&mut self, table: &mut Table<R>) {
-        let cfg = table.get_config_mut();
+        let cfg = table.get_config_mut_keep_dimension();
         match self {
             Alignment::Horizontal(a) => cfg.set_alignment_horizontal(Entity::Global, *a),
             Alignment::Vertical(a) => cfg.set_alignment_vertical(Entity::Global, *a),
         }
     }
+
+    fn kind(&self) -> Option<SettingKind> {
+        match self {
+            Alignment::Horizontal(_) => Some(SettingKind::HorizontalAlignment),
+            Alignment::Vertical(_) => Some(SettingKind::VerticalAlignment),
+        }
+    }
+}
+
+/// AutoAlignment aligns columns by a kind of their content, the way database shells do.
+///
+/// Columns of numbers and dates are aligned to the right and columns of text to the left.
+/// A column is considered numeric in case all its not empty cells are numbers.
+///
+/// The first row is considered to be a header, so it's left as it is.
+///
+/// ```
+/// use tabled::{Alignment, Style, TableIteratorExt};
+///
+/// let data = [("apple", 3, "2023-01-02"), ("watermelon", 12, "2023-01-10")];
+///
+/// let table = data.table()
+///     .with(Style::psql())
+///     .with(Alignment::auto())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str       | i32 | &str       \n",
+///         "------------+-----+------------\n",
+///         " apple      |   3 | 2023-01-02 \n",
+///         " watermelon |  12 | 2023-01-10 ",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AutoAlignment;
+
+impl<R> TableOption<R> for AutoAlignment
+where
+    R: Records,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let (count_rows, count_cols) = table.shape();
+        for col in 0..count_cols {
+            let mut kind = None;
+            for row in 1..count_rows {
+                let text = table.get_records().get_text((row, col)).trim();
+                if text.is_empty() {
+                    continue;
+                }
+
+                let cell_kind = content_kind(text);
+                kind = match kind {
+                    None => Some(cell_kind),
+                    Some(kind) if kind == cell_kind => Some(kind),
+                    Some(_) => Some(ContentKind::Text),
+                };
+
+                if kind == Some(ContentKind::Text) {
+                    break;
+                }
+            }
+
+            let alignment = match kind {
+                Some(ContentKind::Number) | Some(ContentKind::Date) => AlignmentHorizontal::Right,
+                Some(ContentKind::Text) => AlignmentHorizontal::Left,
+                None => continue,
+            };
+
+            let cfg = table.get_config_mut_keep_dimension();
+            for row in 1..count_rows {
+                cfg.set_alignment_horizontal(Entity::Cell(row, col), alignment);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ContentKind {
+    Number,
+    Date,
+    Text,
+}
+
+fn content_kind(text: &str) -> ContentKind {
+    if is_number(text) {
+        ContentKind::Number
+    } else if is_date(text) {
+        ContentKind::Date
+    } else {
+        ContentKind::Text
+    }
+}
+
+fn is_number(text: &str) -> bool {
+    let text = text.trim_end_matches('%');
+    let text = text.replace([',', '_', '\u{a0}', '\u{202f}'], "");
+    text.parse::<f64>().is_ok()
+}
+
+/// Checks whether a text starts with a date like `2023-01-02` or a time like `10:30`.
+fn is_date(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    let digits = |range: std::ops::Range<usize>| matches!(bytes.get(range), Some(b) if b.iter().all(u8::is_ascii_digit));
+
+    let is_date = digits(0..4)
+        && bytes.get(4) == Some(&b'-')
+        && digits(5..7)
+        && bytes.get(7) == Some(&b'-')
+        && digits(8..10);
+    let is_time = digits(0..2) && bytes.get(2) == Some(&b':') && digits(3..5);
+
+    is_date || is_time
 }
@@ -0,0 +1,245 @@
+//! This module contains a render time safeguard which closes ANSI sequences
+//! left open by a content of cells.
+//!
+//! A cell like `"\u{1b}[31mred"` doesn't reset its color,
+//! so the color would bleed into borders and other cells of a table.
+//! Each line of such a cell which leaves a style open is closed by a reset sequence.
+//! Lines of a multiline cell already restore a style of a previous line, so it's not done here.
+
+use std::collections::HashMap;
+
+use papergrid::{records::Records, width::WidthFunc, GridConfig, Position};
+
+const RESET: &str = "\u{1b}[0m";
+
+/// A [`Records`] adapter which closes ANSI SGR sequences at a cell boundary.
+///
+/// Only cells which need it are copied, other ones are read from the original records.
+/// Widths are taken from the original records as the sequences don't have a width.
+pub(crate) struct ClosedAnsiRecords<'a, R> {
+    records: &'a R,
+    cells: HashMap<Position, ClosedCell>,
+}
+
+struct ClosedCell {
+    text: String,
+    lines: Vec<String>,
+}
+
+impl<'a, R> ClosedAnsiRecords<'a, R>
+where
+    R: Records,
+{
+    /// Returns [`None`] in case there's no cell with an unclosed sequence.
+    pub(crate) fn new(records: &'a R, cfg: &GridConfig) -> Option<Self> {
+        let shape = (records.count_rows(), records.count_columns());
+
+        let mut cells = HashMap::new();
+        for row in 0..shape.0 {
+            for col in 0..shape.1 {
+                // cells covered by a span are not rendered
+                if !cfg.is_cell_visible((row, col), shape) {
+                    continue;
+                }
+
+                if let Some(lines) = close_lines(records, (row, col)) {
+                    let text = lines.join("\n");
+                    cells.insert((row, col), ClosedCell { text, lines });
+                }
+            }
+        }
+
+        if cells.is_empty() {
+            return None;
+        }
+
+        Some(Self { records, cells })
+    }
+}
+
+impl<R> Records for ClosedAnsiRecords<'_, R>
+where
+    R: Records,
+{
+    fn count_rows(&self) -> usize {
+        self.records.count_rows()
+    }
+
+    fn count_columns(&self) -> usize {
+        self.records.count_columns()
+    }
+
+    fn get_text(&self, pos: Position) -> &str {
+        match self.cells.get(&pos) {
+            Some(cell) => &cell.text,
+            None => self.records.get_text(pos),
+        }
+    }
+
+    fn get_line(&self, pos: Position, i: usize) -> &str {
+        match self.cells.get(&pos) {
+            Some(cell) => cell.lines.get(i).map_or("", String::as_str),
+            None => self.records.get_line(pos, i),
+        }
+    }
+
+    fn count_lines(&self, pos: Position) -> usize {
+        self.records.count_lines(pos)
+    }
+
+    fn get_width<W>(&self, pos: Position, width_ctrl: W) -> usize
+    where
+        W: WidthFunc,
+    {
+        self.records.get_width(pos, width_ctrl)
+    }
+
+    fn get_line_width<W>(&self, pos: Position, i: usize, width_ctrl: W) -> usize
+    where
+        W: WidthFunc,
+    {
+        self.records.get_line_width(pos, i, width_ctrl)
+    }
+
+    fn fmt_text_prefix(&self, f: &mut std::fmt::Formatter<'_>, pos: Position) -> std::fmt::Result {
+        self.records.fmt_text_prefix(f, pos)
+    }
+
+    fn fmt_text_suffix(&self, f: &mut std::fmt::Formatter<'_>, pos: Position) -> std::fmt::Result {
+        self.records.fmt_text_suffix(f, pos)
+    }
+}
+
+/// Closes a style which is left active at the end of a line of a cell.
+///
+/// Returns [`None`] in case no line needs to be changed.
+fn close_lines<R>(records: &R, pos: Position) -> Option<Vec<String>>
+where
+    R: Records,
+{
+    if !records.get_text(pos).contains('\u{1b}') {
+        return None;
+    }
+
+    let mut changed = false;
+    let lines = (0..records.count_lines(pos))
+        .map(|i| {
+            let line = records.get_line(pos, i);
+
+            let mut state = SgrState::default();
+            state.read(line);
+
+            if !state.is_active() {
+                return line.to_owned();
+            }
+
+            changed = true;
+
+            let mut buf = String::with_capacity(line.len() + RESET.len());
+            buf.push_str(line);
+            buf.push_str(RESET);
+            buf
+        })
+        .collect::<Vec<_>>();
+
+    if !changed {
+        return None;
+    }
+
+    Some(lines)
+}
+
+/// A state of a text style set by SGR sequences.
+#[derive(Debug, Default)]
+struct SgrState {
+    /// Set attributes like bold or underline by their codes.
+    attributes: u32,
+    foreground: bool,
+    background: bool,
+    underline_color: bool,
+}
+
+impl SgrState {
+    fn is_active(&self) -> bool {
+        self.attributes != 0 || self.foreground || self.background || self.underline_color
+    }
+
+    fn read(&mut self, line: &str) {
+        let mut rest = line;
+        while let Some(start) = rest.find("\u{1b}[") {
+            rest = &rest[start + 2..];
+
+            // a control sequence is ESC [ params final-byte
+            let end = match rest.find(|c| ('\u{40}'..='\u{7e}').contains(&c)) {
+                Some(end) => end,
+                None => return,
+            };
+
+            let params = &rest[..end];
+            if rest[end..].starts_with('m') {
+                self.apply(params);
+            }
+
+            rest = &rest[end + 1..];
+        }
+    }
+
+    fn apply(&mut self, params: &str) {
+        let mut codes = params
+            .split([';', ':'])
+            .map(|p| p.parse::<u16>().unwrap_or(0));
+
+        while let Some(code) = codes.next() {
+            match code {
+                0 => self.reset(),
+                1..=9 | 21 | 53 => self.attributes |= attribute(code),
+                22 => self.attributes &= !(attribute(1) | attribute(2)),
+                23 => self.attributes &= !attribute(3),
+                24 => self.attributes &= !(attribute(4) | attribute(21)),
+                25 => self.attributes &= !(attribute(5) | attribute(6)),
+                27 => self.attributes &= !attribute(7),
+                28 => self.attributes &= !attribute(8),
+                29 => self.attributes &= !attribute(9),
+                55 => self.attributes &= !attribute(53),
+                30..=37 | 90..=97 => self.foreground = true,
+                39 => self.foreground = false,
+                40..=47 | 100..=107 => self.background = true,
+                49 => self.background = false,
+                59 => self.underline_color = false,
+                38 | 48 | 58 => {
+                    match code {
+                        38 => self.foreground = true,
+                        48 => self.background = true,
+                        _ => self.underline_color = true,
+                    }
+
+                    // skip arguments of an extended color
+                    match codes.next() {
+                        Some(5) => {
+                            codes.next();
+                        }
+                        Some(2) => {
+                            codes.next();
+                            codes.next();
+                            codes.next();
+                        }
+                        _ => (),
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+fn attribute(code: u16) -> u32 {
+    match code {
+        21 => 1 << 10,
+        53 => 1 << 11,
+        code => 1 << code,
+    }
+}
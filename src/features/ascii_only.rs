@@ -0,0 +1,249 @@
+//! This module contains an [`AsciiOnly`] setting which guarantees a pure ASCII output of a [`Table`].
+//!
+//! [`Table`]: crate::Table
+
+use std::fmt;
+
+use papergrid::{
+    records::{Records, RecordsMut},
+    width::CfgWidthFunction,
+};
+use unicode_width::UnicodeWidthChar;
+
+use crate::{Table, TableOption};
+
+/// [`AsciiOnly`] guarantees that a [`Table`] is rendered with ASCII characters only,
+/// which is needed for legacy systems, serial consoles and plain text emails.
+///
+/// - Non ASCII characters of cells are transliterated, like `é` to `e` or `…` to `...`,
+///   and the ones which can't be are replaced by `?`.
+/// - Borders are drawn by `-`, `|` and `+` in place of box drawing characters.
+/// - Any other non ASCII character is replaced by `?` while a table is rendered,
+///   as many times as its width, so the table stays aligned.
+///
+/// Cells are transliterated once the setting is applied,
+/// borders are replaced at rendering so it's not important when a [`Style`] is set.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{AsciiOnly, Style, Table};
+///
+/// let data = [("Zürich", "12°C"), ("Kraków", "9°C")];
+///
+/// let table = Table::new(data)
+///     .with(Style::modern())
+///     .with(AsciiOnly)
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+--------+------+\n\
+///      | &str   | &str |\n\
+///      +--------+------+\n\
+///      | Zurich | 12?C |\n\
+///      +--------+------+\n\
+///      | Krakow | 9?C  |\n\
+///      +--------+------+"
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Style`]: crate::Style
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AsciiOnly;
+
+impl<R> TableOption<R> for AsciiOnly
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        for row in 0..count_rows {
+            for col in 0..count_cols {
+                let text = table.get_records().get_text((row, col));
+                if text.is_ascii() {
+                    continue;
+                }
+
+                let text = transliterate(text);
+                table.get_records_mut().set((row, col), text, &width_fn);
+            }
+        }
+
+        table.set_ascii_only(true);
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+/// Replaces non ASCII characters of a text by their ASCII look-alikes or `?`.
+fn transliterate(text: &str) -> String {
+    let mut buf = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_ascii() {
+            buf.push(c);
+            continue;
+        }
+
+        match transliterate_char(c) {
+            Some(s) => buf.push_str(s),
+            None if c.width() == Some(0) => (),
+            None => buf.push('?'),
+        }
+    }
+
+    buf
+}
+
+/// A [`fmt::Write`] which replaces non ASCII characters of a rendered table,
+/// keeping a width of a line.
+pub(crate) struct AsciiWriter<W> {
+    writer: W,
+}
+
+impl<W> AsciiWriter<W> {
+    pub(crate) fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl<W> fmt::Write for AsciiWriter<W>
+where
+    W: fmt::Write,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if s.is_ascii() {
+            return self.writer.write_str(s);
+        }
+
+        for c in s.chars() {
+            if c.is_ascii() {
+                self.writer.write_char(c)?;
+                continue;
+            }
+
+            if let Some(border) = box_drawing_char(c) {
+                self.writer.write_char(border)?;
+                continue;
+            }
+
+            let width = c.width().unwrap_or(0);
+            match transliterate_char(c) {
+                Some(s) if s.len() == width => self.writer.write_str(s)?,
+                _ => {
+                    for _ in 0..width {
+                        self.writer.write_char('?')?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn box_drawing_char(c: char) -> Option<char> {
+    let c = match c {
+        '─' | '━' | '┄' | '┅' | '┈' | '┉' | '╌' | '╍' | '═' | '╴' | '╶' | '╸' | '╺' | '╼' | '╾'
+        | '▔' => '-',
+        '│' | '┃' | '┆' | '┇' | '┊' | '┋' | '╎' | '╏' | '║' | '╵' | '╷' | '╹' | '╻' | '╽' | '╿'
+        | '▏' | '▕' => '|',
+        '▁' => '_',
+        '╱' => '/',
+        '╲' => '\\',
+        '╳' => 'X',
+        '\u{2500}'..='\u{257F}' => '+',
+        '\u{2580}'..='\u{259F}' => '#',
+        _ => return None,
+    };
+
+    Some(c)
+}
+
+fn transliterate_char(c: char) -> Option<&'static str> {
+    let s = match c {
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'Ā' | 'Ă' | 'Ą' => "A",
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'Æ' => "AE",
+        'æ' => "ae",
+        'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' => "C",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'Ð' | 'Ď' | 'Đ' => "D",
+        'ð' | 'ď' | 'đ' => "d",
+        'È' | 'É' | 'Ê' | 'Ë' | 'Ē' | 'Ĕ' | 'Ė' | 'Ę' | 'Ě' => "E",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'Ĝ' | 'Ğ' | 'Ġ' | 'Ģ' => "G",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'Ĥ' | 'Ħ' => "H",
+        'ĥ' | 'ħ' => "h",
+        'Ì' | 'Í' | 'Î' | 'Ï' | 'Ĩ' | 'Ī' | 'Ĭ' | 'Į' | 'İ' => "I",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'Ĵ' => "J",
+        'ĵ' => "j",
+        'Ķ' => "K",
+        'ķ' => "k",
+        'Ĺ' | 'Ļ' | 'Ľ' | 'Ŀ' | 'Ł' => "L",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'Ñ' | 'Ń' | 'Ņ' | 'Ň' => "N",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' | 'Ō' | 'Ŏ' | 'Ő' => "O",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'Œ' => "OE",
+        'œ' => "oe",
+        'Ŕ' | 'Ŗ' | 'Ř' => "R",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'Ś' | 'Ŝ' | 'Ş' | 'Š' => "S",
+        'ś' | 'ŝ' | 'ş' | 'š' => "s",
+        'ß' => "ss",
+        'Ţ' | 'Ť' | 'Ŧ' => "T",
+        'ţ' | 'ť' | 'ŧ' => "t",
+        'Þ' => "TH",
+        'þ' => "th",
+        'Ù' | 'Ú' | 'Û' | 'Ü' | 'Ũ' | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' => "U",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'Ŵ' => "W",
+        'ŵ' => "w",
+        'Ý' | 'Ŷ' | 'Ÿ' => "Y",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'Ź' | 'Ż' | 'Ž' => "Z",
+        'ź' | 'ż' | 'ž' => "z",
+        '\u{a0}' | '\u{2000}'..='\u{200a}' | '\u{202f}' | '\u{205f}' | '\u{3000}' => " ",
+        '‘' | '’' | '‚' | '′' => "'",
+        '“' | '”' | '„' | '″' => "\"",
+        '«' => "<<",
+        '»' => ">>",
+        '‹' => "<",
+        '›' => ">",
+        '‐' | '‑' | '‒' | '–' | '—' | '―' | '−' => "-",
+        '…' => "...",
+        '•' | '∙' => "*",
+        '·' => ".",
+        '×' => "x",
+        '÷' => "/",
+        '±' => "+/-",
+        '≤' => "<=",
+        '≥' => ">=",
+        '≠' => "!=",
+        '←' => "<-",
+        '→' => "->",
+        '↔' => "<->",
+        '⇐' => "<=",
+        '⇒' => "=>",
+        '©' => "(c)",
+        '®' => "(R)",
+        '™' => "TM",
+        '€' => "EUR",
+        '£' => "GBP",
+        '¥' => "JPY",
+        '¢' => "c",
+        '¡' => "!",
+        '¿' => "?",
+        '✓' | '✔' => "v",
+        '✗' | '✘' => "x",
+        _ => return None,
+    };
+
+    Some(s)
+}
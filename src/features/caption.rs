@@ -0,0 +1,152 @@
+//! This module contains a [`Caption`] setting which prints a text above or below a [`Table`].
+//!
+//! [`Table`]: crate::Table
+
+use std::fmt::{self, Write};
+
+use papergrid::{records::Records, util::string_width, AlignmentHorizontal};
+
+use crate::{Table, TableOption};
+
+/// [`Caption`] prints a text above or below a [`Table`], outside of its borders.
+///
+/// Unlike [`Panel`] it's not a part of a grid,
+/// so it doesn't change widths of columns and it's not affected by a [`Style`].
+///
+/// A caption is aligned relative to a table width, it's centered by default.
+/// A caption which is wider than the table is printed as it is.
+///
+/// Setting a caption on the same side again replaces it.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Caption, Style, TableIteratorExt};
+///
+/// let data = [("ls", "list files"), ("rm", "remove files")];
+///
+/// let table = data.table()
+///     .with(Style::modern())
+///     .with(Caption::top("Commands"))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "       Commands        \n",
+///         "┌──────┬──────────────┐\n",
+///         "│ &str │ &str         │\n",
+///         "├──────┼──────────────┤\n",
+///         "│ ls   │ list files   │\n",
+///         "├──────┼──────────────┤\n",
+///         "│ rm   │ remove files │\n",
+///         "└──────┴──────────────┘",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Panel`]: crate::Panel
+/// [`Style`]: crate::Style
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Caption {
+    text: String,
+    position: CaptionPosition,
+    alignment: AlignmentHorizontal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CaptionPosition {
+    Top,
+    Bottom,
+}
+
+impl Caption {
+    /// Creates a [`Caption`] printed above a table.
+    pub fn top<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(text.into(), CaptionPosition::Top)
+    }
+
+    /// Creates a [`Caption`] printed below a table.
+    pub fn bottom<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(text.into(), CaptionPosition::Bottom)
+    }
+
+    /// Sets an alignment of a caption relative to a table width.
+    pub fn alignment(mut self, alignment: AlignmentHorizontal) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    fn new(text: String, position: CaptionPosition) -> Self {
+        Self {
+            text,
+            position,
+            alignment: AlignmentHorizontal::Center,
+        }
+    }
+
+    pub(crate) fn is_top(&self) -> bool {
+        self.position == CaptionPosition::Top
+    }
+
+    /// Returns a size of a caption in bytes, including a new line which separates it from a table.
+    ///
+    /// It's exact in case the caption consists of ASCII characters only.
+    pub(crate) fn estimate_size(&self, width: usize) -> usize {
+        let size = self
+            .text
+            .lines()
+            .map(|line| std::cmp::max(line.len(), width))
+            .sum::<usize>();
+        let count_lines = std::cmp::max(self.text.lines().count(), 1);
+
+        size + count_lines
+    }
+
+    /// Writes lines of a caption aligned in a given width, without a trailing new line.
+    pub(crate) fn write<W>(&self, mut writer: W, width: usize) -> fmt::Result
+    where
+        W: Write,
+    {
+        for (i, line) in self.text.lines().enumerate() {
+            if i > 0 {
+                writer.write_char('\n')?;
+            }
+
+            let rest = width.saturating_sub(string_width(line));
+            let (left, right) = match self.alignment {
+                AlignmentHorizontal::Left => (0, rest),
+                AlignmentHorizontal::Right => (rest, 0),
+                AlignmentHorizontal::Center => (rest / 2, rest - rest / 2),
+            };
+
+            write!(
+                writer,
+                "{:left$}{}{:right$}",
+                "",
+                line,
+                "",
+                left = left,
+                right = right
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<R> TableOption<R> for Caption
+where
+    R: Records,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        table.set_caption(self.clone());
+    }
+}
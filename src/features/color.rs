@@ -1,15 +1,23 @@
-//! This module contains a configuration of a [`Border`] or a [`Table`] to set its borders color via [`Color`].
-//!
-//! [`Border`]: crate::Border
+//! This module contains a [`Color`] to set a color of [`Table`] borders or of a cell text.
 
-use std::{borrow::Cow, convert::TryFrom};
+use std::{borrow::Cow, convert::TryFrom, ops::BitOr};
 
-use papergrid::{records::Records, AnsiColor, Entity};
+use papergrid::{
+    records::{Records, RecordsMut},
+    width::CfgWidthFunction,
+    AnsiColor, Entity, Position,
+};
 
-use crate::{CellOption, Table, TableOption};
+use crate::{CellOption, StripColors, Table, TableOption};
 
 /// Color represents a color which can be set to things like [`Border`], [`Padding`] and [`Margin`].
 ///
+/// Being used as a [`TableOption`] it colors all borders of a [`Table`].
+/// Being used as a [`CellOption`] it colors borders of given cells.
+/// To color a text of cells use [`Color::text`].
+///
+/// Colors can be combined using `|`.
+///
 /// # Example
 ///
 /// ```
@@ -29,6 +37,16 @@ use crate::{CellOption, Table, TableOption};
 /// println!("{}", table);
 /// ```
 ///
+/// ```
+/// use tabled::{color::Color, object::Columns, Modify, TableIteratorExt};
+///
+/// let table = [(0u8, "Hello")].table()
+///     .with(Modify::new(Columns::single(1)).with((Color::FG_RED | Color::BOLD).text()))
+///     .to_string();
+///
+/// assert!(table.contains("\u{1b}[31m\u{1b}[1mHello\u{1b}[22m\u{1b}[39m"));
+/// ```
+///
 /// [`Padding`]: crate::Padding
 /// [`Margin`]: crate::Margin
 /// [`Border`]: crate::Border
@@ -40,133 +58,150 @@ pub struct Color(AnsiColor<'static>);
 impl Color {
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const FG_BLACK:          Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[30m"), Cow::Borrowed("\u{1b}[39m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const FG_BLUE:           Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[34m"), Cow::Borrowed("\u{1b}[39m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const FG_BRIGHT_BLACK:   Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[90m"), Cow::Borrowed("\u{1b}[39m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const FG_BRIGHT_BLUE:    Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[94m"), Cow::Borrowed("\u{1b}[39m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const FG_BRIGHT_CYAN:    Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[96m"), Cow::Borrowed("\u{1b}[39m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const FG_BRIGHT_GREEN:   Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[92m"), Cow::Borrowed("\u{1b}[39m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const FG_BRIGHT_MAGENTA: Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[95m"), Cow::Borrowed("\u{1b}[39m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const FG_BRIGHT_RED:     Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[91m"), Cow::Borrowed("\u{1b}[39m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const FG_BRIGHT_WHITE:   Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[97m"), Cow::Borrowed("\u{1b}[39m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const FG_BRIGHT_YELLOW:  Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[93m"), Cow::Borrowed("\u{1b}[39m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const FG_CYAN:           Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[36m"), Cow::Borrowed("\u{1b}[39m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const FG_GREEN:          Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[32m"), Cow::Borrowed("\u{1b}[39m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const FG_MAGENTA:        Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[35m"), Cow::Borrowed("\u{1b}[39m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const FG_RED:            Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[31m"), Cow::Borrowed("\u{1b}[39m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const FG_WHITE:          Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[37m"), Cow::Borrowed("\u{1b}[39m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const FG_YELLOW:         Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[33m"), Cow::Borrowed("\u{1b}[39m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
 
     pub const BG_BLACK:          Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[40m"),  Cow::Borrowed("\u{1b}[49m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const BG_BLUE:           Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[44m"),  Cow::Borrowed("\u{1b}[49m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const BG_BRIGHT_BLACK:   Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[100m"), Cow::Borrowed("\u{1b}[49m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const BG_BRIGHT_BLUE:    Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[104m"), Cow::Borrowed("\u{1b}[49m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const BG_BRIGHT_CYAN:    Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[106m"), Cow::Borrowed("\u{1b}[49m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const BG_BRIGHT_GREEN:   Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[102m"), Cow::Borrowed("\u{1b}[49m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const BG_BRIGHT_MAGENTA: Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[105m"), Cow::Borrowed("\u{1b}[49m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const BG_BRIGHT_RED:     Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[101m"), Cow::Borrowed("\u{1b}[49m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const BG_BRIGHT_WHITE:   Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[107m"), Cow::Borrowed("\u{1b}[49m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const BG_BRIGHT_YELLOW:  Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[103m"), Cow::Borrowed("\u{1b}[49m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const BG_CYAN:           Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[46m"),  Cow::Borrowed("\u{1b}[49m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const BG_GREEN:          Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[42m"),  Cow::Borrowed("\u{1b}[49m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const BG_MAGENTA:        Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[45m"),  Cow::Borrowed("\u{1b}[49m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const BG_RED:            Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[41m"),  Cow::Borrowed("\u{1b}[49m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const BG_WHITE:          Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[47m"),  Cow::Borrowed("\u{1b}[49m")));
     /// A color representation.
     /// 
-    /// Notice that the colors are constants so you can't combine them.
+    /// It can be combined with other colors using `|`.
     pub const BG_YELLOW:         Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[43m"),  Cow::Borrowed("\u{1b}[49m")));
+
+    /// A bold text style.
+    /// 
+    /// It can be combined with other colors using `|`.
+    pub const BOLD:              Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[1m"),   Cow::Borrowed("\u{1b}[22m")));
+    /// A dimmed text style.
+    /// 
+    /// It can be combined with other colors using `|`.
+    pub const DIM:               Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[2m"),   Cow::Borrowed("\u{1b}[22m")));
+    /// An italic text style.
+    /// 
+    /// It can be combined with other colors using `|`.
+    pub const ITALIC:            Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[3m"),   Cow::Borrowed("\u{1b}[23m")));
+    /// An underlined text style.
+    /// 
+    /// It can be combined with other colors using `|`.
+    pub const UNDERLINE:         Self = Self(AnsiColor::new(Cow::Borrowed("\u{1b}[4m"),   Cow::Borrowed("\u{1b}[24m")));
 }
 
 impl Color {
@@ -175,6 +210,108 @@ impl Color {
     pub fn new(prefix: String, suffix: String) -> Self {
         Self(AnsiColor::new(prefix.into(), suffix.into()))
     }
+
+    /// Creates a truecolor foreground [`Color`].
+    ///
+    /// ```
+    /// use tabled::color::Color;
+    ///
+    /// let color = Color::rgb(255, 128, 0);
+    /// assert_eq!(color, Color::new("\u{1b}[38;2;255;128;0m".into(), "\u{1b}[39m".into()));
+    /// ```
+    pub fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::new(
+            format!("\u{1b}[38;2;{};{};{}m", r, g, b),
+            "\u{1b}[39m".into(),
+        )
+    }
+
+    /// Creates a truecolor background [`Color`].
+    pub fn bg_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::new(
+            format!("\u{1b}[48;2;{};{};{}m", r, g, b),
+            "\u{1b}[49m".into(),
+        )
+    }
+
+    /// Creates a foreground [`Color`] from the 256 colors palette.
+    pub fn ansi256(index: u8) -> Self {
+        Self::new(format!("\u{1b}[38;5;{}m", index), "\u{1b}[39m".into())
+    }
+
+    /// Creates a background [`Color`] from the 256 colors palette.
+    pub fn bg_ansi256(index: u8) -> Self {
+        Self::new(format!("\u{1b}[48;5;{}m", index), "\u{1b}[49m".into())
+    }
+
+    /// Replaces truecolor and 256 palette colors by the closest ones of the basic 16 colors.
+    ///
+    /// It's handy for terminals which don't support extended colors.
+    /// Other parts of the color are kept as they are.
+    ///
+    /// ```
+    /// use tabled::color::Color;
+    ///
+    /// assert_eq!(Color::rgb(250, 10, 10).to_ansi16(), Color::FG_BRIGHT_RED);
+    /// assert_eq!(Color::bg_ansi256(4).to_ansi16(), Color::BG_BLUE);
+    /// assert_eq!((Color::BOLD | Color::ansi256(16)).to_ansi16(), Color::BOLD | Color::FG_BLACK);
+    /// ```
+    pub fn to_ansi16(&self) -> Self {
+        let prefix = downgrade_sequences(self.0.get_prefix());
+        Self::new(prefix, self.0.get_suffix().to_owned())
+    }
+
+    /// Creates a [`TextColor`] which colors a text of cells instead of their borders.
+    ///
+    /// ```
+    /// use tabled::{color::Color, object::Rows, Modify, TableIteratorExt};
+    ///
+    /// let table = [("cpu", 95)].table()
+    ///     .with(Modify::new(Rows::first()).with(Color::FG_BLUE.text()))
+    ///     .to_string();
+    ///
+    /// assert!(table.contains("\u{1b}[34m&str\u{1b}[39m"));
+    /// ```
+    pub fn text(self) -> TextColor {
+        TextColor(self)
+    }
+
+    /// Creates a [`ColorBy`] which colors a text of cells by a color returned by a closure.
+    ///
+    /// The closure gets a text of a cell and its position,
+    /// the cell is left as it is in case [`None`] is returned.
+    ///
+    /// ```
+    /// use tabled::{color::Color, object::Columns, Modify, TableIteratorExt};
+    ///
+    /// let table = [("cpu", 95), ("mem", 40)].table()
+    ///     .with(Modify::new(Columns::single(1)).with(Color::by(|text, _| {
+    ///         match text.parse::<u8>() {
+    ///             Ok(value) if value > 90 => Some(Color::FG_RED),
+    ///             _ => None,
+    ///         }
+    ///     })))
+    ///     .to_string();
+    ///
+    /// assert!(table.contains("\u{1b}[31m95\u{1b}[39m"));
+    /// assert!(!table.contains("\u{1b}[31m40\u{1b}[39m"));
+    /// ```
+    pub fn by<F>(f: F) -> ColorBy<F>
+    where
+        F: FnMut(&str, Position) -> Option<Color>,
+    {
+        ColorBy { f }
+    }
+}
+
+impl BitOr for Color {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        let prefix = format!("{}{}", self.0.get_prefix(), rhs.0.get_prefix());
+        let suffix = format!("{}{}", rhs.0.get_suffix(), self.0.get_suffix());
+        Self::new(prefix, suffix)
+    }
 }
 
 impl From<Color> for AnsiColor<'static> {
@@ -189,6 +326,38 @@ impl From<AnsiColor<'static>> for Color {
     }
 }
 
+#[cfg(feature = "owo-colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "owo-colors")))]
+impl From<owo_colors::Style> for Color {
+    fn from(style: owo_colors::Style) -> Self {
+        struct Prefix(owo_colors::Style);
+
+        impl std::fmt::Display for Prefix {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt_prefix(f)
+            }
+        }
+
+        struct Suffix(owo_colors::Style);
+
+        impl std::fmt::Display for Suffix {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt_suffix(f)
+            }
+        }
+
+        Self::new(Prefix(style).to_string(), Suffix(style).to_string())
+    }
+}
+
+#[cfg(feature = "anstyle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "anstyle")))]
+impl From<anstyle::Style> for Color {
+    fn from(style: anstyle::Style) -> Self {
+        Self::new(style.render().to_string(), style.render_reset().to_string())
+    }
+}
+
 impl TryFrom<&str> for Color {
     type Error = ();
 
@@ -208,7 +377,9 @@ impl TryFrom<String> for Color {
 impl<R> TableOption<R> for Color {
     fn change(&mut self, table: &mut Table<R>) {
         let color = self.0.clone();
-        table.get_config_mut().set_border_color_global(color);
+        table
+            .get_config_mut_keep_dimension()
+            .set_border_color_global(color);
     }
 }
 
@@ -221,7 +392,9 @@ where
 
         let (count_rows, count_cols) = table.shape();
         for pos in entity.iter(count_rows, count_cols) {
-            table.get_config_mut().set_border_color(pos, border.clone());
+            table
+                .get_config_mut_keep_dimension()
+                .set_border_color(pos, border.clone());
         }
     }
 }
@@ -235,7 +408,9 @@ where
 
         let (count_rows, count_cols) = table.shape();
         for pos in entity.iter(count_rows, count_cols) {
-            table.get_config_mut().set_border_color(pos, border.clone());
+            table
+                .get_config_mut_keep_dimension()
+                .set_border_color(pos, border.clone());
         }
     }
 }
@@ -252,3 +427,353 @@ fn border_color(color: &Color) -> papergrid::Border<AnsiColor<'static>> {
         color.0.clone(),
     )
 }
+
+/// A coloring of a text of cells.
+///
+/// It's created by [`Color::text`].
+#[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct TextColor(Color);
+
+impl From<Color> for TextColor {
+    fn from(color: Color) -> Self {
+        Self(color)
+    }
+}
+
+impl<R> CellOption<R> for TextColor
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        colorize_cells(table, &self.0, entity);
+    }
+}
+
+/// A coloring of cells by a closure over their content.
+///
+/// It's created by [`Color::by`].
+#[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+#[derive(Debug)]
+pub struct ColorBy<F> {
+    f: F,
+}
+
+impl<F, R> CellOption<R> for ColorBy<F>
+where
+    F: FnMut(&str, Position) -> Option<Color>,
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        if !table.is_colors_enabled() {
+            return;
+        }
+
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            let text = table.get_records().get_text(pos);
+            if let Some(color) = (self.f)(text, pos) {
+                let text = colorize(&color.0, text);
+                table.get_records_mut().set(pos, text, &width_fn);
+            }
+        }
+    }
+}
+
+/// Colorization colors a [`Table`] by a scheme declared as data.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{builder::Builder, color::{Color, Colorization}};
+///
+/// let mut builder = Builder::default();
+/// builder.set_columns(["name", "status"]);
+/// builder.add_record(["nginx", "running"]);
+///
+/// let table = builder
+///     .build()
+///     .with(Colorization::columns([("status", Color::FG_GREEN)]))
+///     .to_string();
+///
+/// assert!(table.contains("\u{1b}[32mrunning\u{1b}[39m"));
+/// ```
+///
+/// It also can turn colors off.
+///
+/// ```
+/// use tabled::{color::{Color, Colorization}, object::Segment, Modify, Table};
+///
+/// let table = Table::new(["text"])
+///     .with(Modify::new(Segment::all()).with(Color::FG_BLUE.text()))
+///     .with(Colorization::never())
+///     .with(Color::FG_RED)
+///     .with(Modify::new(Segment::all()).with(Color::FG_RED.text()))
+///     .to_string();
+///
+/// assert!(!table.contains('\u{1b}'));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+#[derive(Debug, Clone)]
+pub struct Colorization(Scheme);
+
+#[derive(Debug, Clone)]
+enum Scheme {
+    Columns(Vec<(String, Color)>),
+    Enabled(bool),
+}
+
+impl Colorization {
+    /// Colors columns by their names.
+    ///
+    /// A name is considered to be a value in a first row, the same way as [`ByColumnName`] does.
+    /// The whole column is colored including the first row.
+    ///
+    /// [`ByColumnName`]: crate::locator::ByColumnName
+    pub fn columns<I, S>(columns: I) -> Self
+    where
+        I: IntoIterator<Item = (S, Color)>,
+        S: Into<String>,
+    {
+        let columns = columns
+            .into_iter()
+            .map(|(name, color)| (name.into(), color))
+            .collect();
+
+        Self(Scheme::Columns(columns))
+    }
+
+    /// Turns colors off in case a `NO_COLOR` environment variable is set
+    /// or [`stdout`] is not a terminal.
+    ///
+    /// [`stdout`]: std::io::stdout
+    pub fn auto() -> Self {
+        Self(Scheme::Enabled(is_color_supported()))
+    }
+
+    /// Turns colors on, which is the default.
+    pub fn always() -> Self {
+        Self(Scheme::Enabled(true))
+    }
+
+    /// Turns colors off.
+    ///
+    /// Colors of borders, padding and margin are not rendered,
+    /// and [`TextColor`] settings applied afterwards don't color a text.
+    /// ANSI sequences which are already a part of a text are removed the same way [`StripColors`] does,
+    /// so it doesn't matter whether it's applied before or after other colors.
+    /// The layout of the table stays the same.
+    ///
+    /// [`StripColors`]: crate::StripColors
+    pub fn never() -> Self {
+        Self(Scheme::Enabled(false))
+    }
+}
+
+impl<R> TableOption<R> for Colorization
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let columns = match &self.0 {
+            Scheme::Columns(columns) => columns,
+            Scheme::Enabled(enabled) => {
+                // colors set before are already a part of a content
+                if !*enabled {
+                    table.with(StripColors);
+                }
+
+                table.set_colors_enabled(*enabled);
+                return;
+            }
+        };
+
+        if table.count_rows() == 0 || !table.is_colors_enabled() {
+            return;
+        }
+
+        // columns are located before any coloring as it changes the names
+        let mut targets = Vec::new();
+        for (name, color) in columns {
+            for col in 0..table.count_columns() {
+                if table.get_records().get_text((0, col)) == name.as_str() {
+                    targets.push((col, color));
+                }
+            }
+        }
+
+        for (col, color) in targets {
+            colorize_cells(table, color, Entity::Column(col));
+        }
+    }
+}
+
+fn colorize_cells<R>(table: &mut Table<R>, color: &Color, entity: Entity)
+where
+    R: Records + RecordsMut<String>,
+{
+    if !table.is_colors_enabled() {
+        return;
+    }
+
+    let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+    let (count_rows, count_cols) = table.shape();
+    for pos in entity.iter(count_rows, count_cols) {
+        let text = colorize(&color.0, table.get_records().get_text(pos));
+        table.get_records_mut().set(pos, text, &width_fn);
+    }
+}
+
+/// Wraps each line of a text in a color,
+/// so the color is kept in case a cell is split into lines.
+pub(crate) fn colorize(color: &AnsiColor<'_>, text: &str) -> String {
+    let mut buf = String::with_capacity(text.len());
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            buf.push('\n');
+        }
+
+        if !line.is_empty() {
+            buf.push_str(color.get_prefix());
+            buf.push_str(line);
+            buf.push_str(color.get_suffix());
+        }
+    }
+
+    buf
+}
+
+/// The basic 16 colors as they're rendered by xterm.
+const ANSI16_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+fn downgrade_sequences(text: &str) -> String {
+    let mut buf = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("\u{1b}[") {
+        buf.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let end = match rest.find('m') {
+            Some(end) => end,
+            None => break,
+        };
+
+        let params = &rest[2..end];
+        buf.push_str("\u{1b}[");
+        buf.push_str(&downgrade_params(params));
+        buf.push('m');
+
+        rest = &rest[end + 1..];
+    }
+
+    buf.push_str(rest);
+    buf
+}
+
+fn downgrade_params(params: &str) -> String {
+    let params = params.split(';').collect::<Vec<_>>();
+    let mut codes = Vec::with_capacity(params.len());
+
+    let mut i = 0;
+    while i < params.len() {
+        let is_extended = params[i] == "38" || params[i] == "48";
+        if !is_extended {
+            codes.push(params[i].to_owned());
+            i += 1;
+            continue;
+        }
+
+        let background = params[i] == "48";
+        let parse = |i: usize| params.get(i).and_then(|p| p.parse::<u8>().ok());
+        let (rgb, size) = match params.get(i + 1) {
+            Some(&"2") => match (parse(i + 2), parse(i + 3), parse(i + 4)) {
+                (Some(r), Some(g), Some(b)) => (Some((r, g, b)), 5),
+                _ => (None, 0),
+            },
+            Some(&"5") => (parse(i + 2).map(ansi256_to_rgb), 3),
+            _ => (None, 0),
+        };
+
+        match rgb {
+            Some(rgb) => {
+                codes.push(ansi16_code(closest_ansi16(rgb), background).to_string());
+                i += size;
+            }
+            None => {
+                codes.push(params[i].to_owned());
+                i += 1;
+            }
+        }
+    }
+
+    codes.join(";")
+}
+
+fn ansi256_to_rgb(index: u8) -> (u8, u8, u8) {
+    const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+    match index {
+        0..=15 => ANSI16_PALETTE[index as usize],
+        16..=231 => {
+            let index = index - 16;
+            (
+                CUBE_LEVELS[(index / 36) as usize],
+                CUBE_LEVELS[(index / 6 % 6) as usize],
+                CUBE_LEVELS[(index % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+fn closest_ansi16((r, g, b): (u8, u8, u8)) -> usize {
+    let distance = |(r1, g1, b1): (u8, u8, u8)| {
+        let diff = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        diff(r, r1) + diff(g, g1) + diff(b, b1)
+    };
+
+    (0..ANSI16_PALETTE.len())
+        .min_by_key(|&i| distance(ANSI16_PALETTE[i]))
+        .unwrap_or(0)
+}
+
+fn ansi16_code(index: usize, background: bool) -> usize {
+    let base = match (index < 8, background) {
+        (true, false) => 30,
+        (true, true) => 40,
+        (false, false) => 90 - 8,
+        (false, true) => 100 - 8,
+    };
+
+    base + index
+}
+
+fn is_color_supported() -> bool {
+    use std::io::IsTerminal;
+
+    // https://no-color.org
+    let no_color = std::env::var_os("NO_COLOR").map_or(false, |value| !value.is_empty());
+
+    !no_color && std::io::stdout().is_terminal()
+}
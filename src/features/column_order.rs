@@ -0,0 +1,137 @@
+//! This module contains a [`ColumnOrder`] setting which rearranges columns of a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{builder::Builder, ColumnOrder};
+//!
+//! let mut builder = Builder::default();
+//! builder.set_columns(["modified", "name", "size"]);
+//! builder.add_record(["2022-10-18", "Cargo.toml", "2048"]);
+//!
+//! let table = builder.build()
+//!     .with(ColumnOrder::new(["name", "size", "modified"]))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+------------+------+------------+\n",
+//!         "| name       | size | modified   |\n",
+//!         "+------------+------+------------+\n",
+//!         "| Cargo.toml | 2048 | 2022-10-18 |\n",
+//!         "+------------+------+------------+",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::records::{Records, Resizable};
+
+use crate::{features::sort::reorder_columns, Table, TableOption};
+
+/// [`ColumnOrder`] rearranges columns of a [`Table`].
+///
+/// Listed columns are put first in a given order,
+/// the rest of columns follow them in their original order.
+///
+/// Unknown columns are ignored.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone)]
+pub struct ColumnOrder {
+    order: Order,
+}
+
+#[derive(Debug, Clone)]
+enum Order {
+    Names(Vec<String>),
+    Indexes(Vec<usize>),
+}
+
+impl ColumnOrder {
+    /// Rearranges columns by their names.
+    ///
+    /// A name is considered to be a value in the first row.
+    pub fn new<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            order: Order::Names(names.into_iter().map(Into::into).collect()),
+        }
+    }
+
+    /// Rearranges columns by their indexes.
+    ///
+    /// ```
+    /// use tabled::{Table, ColumnOrder};
+    ///
+    /// let data = [("a", 'b', 3)];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(ColumnOrder::indexes([2, 0]))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+-----+------+------+\n",
+    ///         "| i32 | &str | char |\n",
+    ///         "+-----+------+------+\n",
+    ///         "| 3   | a    | b    |\n",
+    ///         "+-----+------+------+",
+    ///     )
+    /// );
+    /// ```
+    pub fn indexes<I>(indexes: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        Self {
+            order: Order::Indexes(indexes.into_iter().collect()),
+        }
+    }
+}
+
+impl<R> TableOption<R> for ColumnOrder
+where
+    R: Records + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let (count_rows, count_columns) = table.shape();
+        if count_rows == 0 || count_columns == 0 {
+            return;
+        }
+
+        let columns = match &self.order {
+            Order::Indexes(indexes) => indexes.clone(),
+            Order::Names(names) => {
+                let records = table.get_records();
+                names
+                    .iter()
+                    .filter_map(|name| {
+                        (0..count_columns).find(|&col| records.get_text((0, col)) == name)
+                    })
+                    .collect()
+            }
+        };
+
+        let mut order = Vec::with_capacity(count_columns);
+        let mut used = vec![false; count_columns];
+        for col in columns {
+            if col < count_columns && !used[col] {
+                used[col] = true;
+                order.push(col);
+            }
+        }
+
+        order.extend((0..count_columns).filter(|&col| !used[col]));
+
+        reorder_columns(table.get_records_mut(), 0, &order);
+
+        table.destroy_width_cache();
+    }
+}
@@ -0,0 +1,126 @@
+//! This module contains a [`Dedup`] setting which removes duplicate rows of a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, Dedup};
+//!
+//! let data = [("GET", 200), ("GET", 200), ("POST", 500), ("GET", 200)];
+//!
+//! let table = Table::new(data)
+//!     .with(Dedup::consecutive())
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+------+-----+\n",
+//!         "| &str | i32 |\n",
+//!         "+------+-----+\n",
+//!         "| GET  | 200 |\n",
+//!         "+------+-----+\n",
+//!         "| POST | 500 |\n",
+//!         "+------+-----+\n",
+//!         "| GET  | 200 |\n",
+//!         "+------+-----+",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::collections::HashSet;
+
+use papergrid::records::{Records, Resizable};
+
+use crate::{features::filter::retain_rows, Table, TableOption};
+
+/// [`Dedup`] removes data rows which are equal to other ones.
+///
+/// Rows are compared by the text of all their cells.
+/// A header row is never removed in case the [`Table`] has one,
+/// neither are footer rows added by [`Footer`] and [`Panel::footer`].
+///
+/// [`Table`]: crate::Table
+/// [`Footer`]: crate::Footer
+/// [`Panel::footer`]: crate::Panel::footer
+#[derive(Debug)]
+pub enum Dedup {
+    /// Removes a row if it's equal to a row right above it.
+    Consecutive,
+    /// Removes a row if it's equal to any row above it.
+    All,
+}
+
+impl Dedup {
+    /// Removes a row if it's equal to a row right above it.
+    pub fn consecutive() -> Self {
+        Self::Consecutive
+    }
+
+    /// Removes a row if it's equal to any row above it.
+    ///
+    /// ```
+    /// use tabled::{Table, Dedup};
+    ///
+    /// let data = [("GET", 200), ("POST", 500), ("GET", 200)];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Dedup::all())
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+------+-----+\n",
+    ///         "| &str | i32 |\n",
+    ///         "+------+-----+\n",
+    ///         "| GET  | 200 |\n",
+    ///         "+------+-----+\n",
+    ///         "| POST | 500 |\n",
+    ///         "+------+-----+",
+    ///     )
+    /// );
+    /// ```
+    pub fn all() -> Self {
+        Self::All
+    }
+}
+
+impl<R> TableOption<R> for Dedup
+where
+    R: Records + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let count_columns = table.count_columns();
+        let last_row = table.count_rows().saturating_sub(table.count_footer_rows());
+        let get_row = |records: &R, row: usize| {
+            (0..count_columns)
+                .map(|col| records.get_text((row, col)).to_owned())
+                .collect::<Vec<_>>()
+        };
+
+        match self {
+            Self::Consecutive => {
+                let mut last = None;
+                retain_rows(table, |records, row| {
+                    if row >= last_row {
+                        return true;
+                    }
+
+                    let row = get_row(records, row);
+                    let is_duplicate = last.as_ref() == Some(&row);
+                    last = Some(row);
+
+                    !is_duplicate
+                });
+            }
+            Self::All => {
+                let mut seen = HashSet::new();
+                retain_rows(table, |records, row| {
+                    row >= last_row || seen.insert(get_row(records, row))
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! This module contains a [`TableDiff`] structure which compares 2 tables.
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+};
+
+use papergrid::records::Records;
+
+use crate::{builder::Builder, Table};
+
+/// [`TableDiff`] renders a combined table of 2 versions of a [`Table`].
+///
+/// An extra first column marks a kind of a change of a row:
+///
+/// - `+` a row was added
+/// - `-` a row was removed
+/// - `~` a row was changed, changed cells are printed as `old → new`
+///
+/// Rows are compared by their position,
+/// but they can be matched by a value of a column instead, see [`TableDiff::key`].
+///
+/// In case a new table has a header it's used as a header of the result.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{TableDiff, TableIteratorExt};
+///
+/// let old = [("port", "80"), ("host", "localhost")].table();
+/// let new = [("port", "8080"), ("host", "localhost"), ("tls", "on")].table();
+///
+/// let diff = TableDiff::new(&old, &new).to_string();
+///
+/// assert_eq!(
+///     diff,
+///     "+---+------+-----------+\n\
+///      |   | &str | &str      |\n\
+///      +---+------+-----------+\n\
+///      | ~ | port | 80 → 8080 |\n\
+///      +---+------+-----------+\n\
+///      |   | host | localhost |\n\
+///      +---+------+-----------+\n\
+///      | + | tls  | on        |\n\
+///      +---+------+-----------+"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct TableDiff {
+    old: Vec<Vec<String>>,
+    new: Vec<Vec<String>>,
+    header: Option<Vec<String>>,
+    key: Option<usize>,
+    #[cfg(feature = "color")]
+    colored: bool,
+}
+
+#[derive(Debug)]
+enum Change<'a> {
+    Same(&'a [String]),
+    Changed(&'a [String], &'a [String]),
+    Added(&'a [String]),
+    Removed(&'a [String]),
+}
+
+impl TableDiff {
+    /// Creates a [`TableDiff`] of an old and a new table.
+    pub fn new<R1, R2>(old: &Table<R1>, new: &Table<R2>) -> Self
+    where
+        R1: Records,
+        R2: Records,
+    {
+        let mut old_rows = collect_rows(old);
+        if old.has_header() && !old_rows.is_empty() {
+            old_rows.remove(0);
+        }
+
+        let mut new_rows = collect_rows(new);
+        let header = if new.has_header() && !new_rows.is_empty() {
+            Some(new_rows.remove(0))
+        } else {
+            None
+        };
+
+        Self {
+            old: old_rows,
+            new: new_rows,
+            header,
+            key: None,
+            #[cfg(feature = "color")]
+            colored: false,
+        }
+    }
+
+    /// Matches rows by a value of a column instead of their position.
+    ///
+    /// Rows of a new table are printed in their order,
+    /// removed rows are printed at the end.
+    pub fn key(mut self, column: usize) -> Self {
+        self.key = Some(column);
+        self
+    }
+
+    /// Colors added rows in green, removed ones in red and changed cells in yellow.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn colored(mut self) -> Self {
+        self.colored = true;
+        self
+    }
+
+    /// Builds a combined [`Table`].
+    pub fn build(&self) -> Table {
+        let mut builder = Builder::default();
+        if let Some(header) = &self.header {
+            builder.set_columns(std::iter::once(String::new()).chain(header.iter().cloned()));
+        }
+
+        for change in self.changes() {
+            let row = match change {
+                Change::Same(row) => mark_row("", row),
+                Change::Added(row) => self.paint_row(mark_row("+", row), Paint::Added),
+                Change::Removed(row) => self.paint_row(mark_row("-", row), Paint::Removed),
+                Change::Changed(old, new) => {
+                    let count = old.len().max(new.len());
+                    let mut row = Vec::with_capacity(count + 1);
+                    row.push(self.paint("~".to_owned(), Paint::Changed));
+                    for i in 0..count {
+                        let before = old.get(i).map(String::as_str).unwrap_or_default();
+                        let after = new.get(i).map(String::as_str).unwrap_or_default();
+                        if before == after {
+                            row.push(after.to_owned());
+                        } else {
+                            let text = format!("{} → {}", before, after);
+                            row.push(self.paint(text, Paint::Changed));
+                        }
+                    }
+
+                    row
+                }
+            };
+
+            builder.add_record(row);
+        }
+
+        builder.build()
+    }
+
+    fn changes(&self) -> Vec<Change<'_>> {
+        let key = match self.key {
+            Some(key) => key,
+            None => {
+                let count = self.old.len().max(self.new.len());
+                return (0..count)
+                    .map(|i| match (self.old.get(i), self.new.get(i)) {
+                        (Some(old), Some(new)) => compare(old, new),
+                        (Some(old), None) => Change::Removed(old),
+                        (None, Some(new)) => Change::Added(new),
+                        (None, None) => unreachable!(),
+                    })
+                    .collect();
+            }
+        };
+
+        // old rows by a key, in reverse order so a first one is popped first
+        let mut index: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (i, old) in self.old.iter().enumerate().rev() {
+            if let Some(value) = old.get(key) {
+                index.entry(value.as_str()).or_default().push(i);
+            }
+        }
+
+        let mut matched = vec![false; self.old.len()];
+        let mut changes = Vec::with_capacity(self.new.len());
+        for new in &self.new {
+            let found = new
+                .get(key)
+                .and_then(|value| index.get_mut(value.as_str()))
+                .and_then(Vec::pop);
+
+            match found {
+                Some(i) => {
+                    matched[i] = true;
+                    changes.push(compare(&self.old[i], new));
+                }
+                None => changes.push(Change::Added(new)),
+            }
+        }
+
+        for (old, matched) in self.old.iter().zip(matched) {
+            if !matched {
+                changes.push(Change::Removed(old));
+            }
+        }
+
+        changes
+    }
+
+    #[cfg(feature = "color")]
+    fn paint(&self, text: String, paint: Paint) -> String {
+        use crate::color::{colorize, Color};
+
+        if !self.colored {
+            return text;
+        }
+
+        let color = match paint {
+            Paint::Added => Color::FG_GREEN,
+            Paint::Removed => Color::FG_RED,
+            Paint::Changed => Color::FG_YELLOW,
+        };
+
+        colorize(&color.into(), &text)
+    }
+
+    #[cfg(not(feature = "color"))]
+    fn paint(&self, text: String, _: Paint) -> String {
+        text
+    }
+
+    fn paint_row(&self, row: Vec<String>, paint: Paint) -> Vec<String> {
+        row.into_iter()
+            .map(|text| self.paint(text, paint))
+            .collect()
+    }
+}
+
+impl Display for TableDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.build().fmt(f)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Paint {
+    Added,
+    Removed,
+    Changed,
+}
+
+fn compare<'a>(old: &'a [String], new: &'a [String]) -> Change<'a> {
+    if old == new {
+        Change::Same(new)
+    } else {
+        Change::Changed(old, new)
+    }
+}
+
+fn mark_row(mark: &str, row: &[String]) -> Vec<String> {
+    std::iter::once(mark.to_owned())
+        .chain(row.iter().cloned())
+        .collect()
+}
+
+fn collect_rows<R>(table: &Table<R>) -> Vec<Vec<String>>
+where
+    R: Records,
+{
+    let (count_rows, count_cols) = table.shape();
+    let records = table.get_records();
+    (0..count_rows)
+        .map(|row| {
+            (0..count_cols)
+                .map(|col| records.get_text((row, col)).to_owned())
+                .collect()
+        })
+        .collect()
+}
@@ -188,14 +188,14 @@ where
 {
     fn change(&mut self, table: &mut Table<D>) {
         let rows = self.locator.locate(table.get_records());
-        let records = table.get_records_mut();
         let mut shift = 0;
         for row in rows.into_iter() {
-            if row - shift > records.count_rows() {
+            if row - shift > table.count_rows() {
                 continue;
             }
 
-            records.remove_row(row - shift);
+            table.get_records_mut().remove_row(row - shift);
+            table.remove_row_aside(row - shift);
             shift += 1;
         }
 
@@ -167,7 +167,7 @@ where
         rows.0 = std::cmp::min(rows.0, shape.0);
         cols.0 = std::cmp::min(cols.0, shape.1);
 
-        extract(table.get_records_mut(), shape, rows, cols);
+        extract(table, shape, rows, cols);
     }
 }
 
@@ -199,25 +199,29 @@ where
 /// +---+
 /// ```
 fn extract<R>(
-    records: &mut R,
+    table: &mut Table<R>,
     (count_rows, count_cols): (usize, usize),
     (start_row, end_row): (usize, usize),
     (start_col, end_col): (usize, usize),
 ) where
-    R: Resizable,
+    R: Records + Resizable,
 {
     for (i, row) in (0..start_row).enumerate() {
         let row = row - i;
-        records.remove_row(row);
+        table.get_records_mut().remove_row(row);
+        table.remove_row_aside(row);
     }
 
     let count_rows = count_rows - start_row;
     let end_row = end_row - start_row;
     for (i, row) in (end_row..count_rows).enumerate() {
         let row = row - i;
-        records.remove_row(row);
+        table.get_records_mut().remove_row(row);
+        table.remove_row_aside(row);
     }
 
+    let records = table.get_records_mut();
+
     for (i, col) in (0..start_col).enumerate() {
         let col = col - i;
         records.remove_column(col);
@@ -0,0 +1,172 @@
+//! This module contains a [`Filter`] setting which hides rows of a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{builder::Builder, locator::ByColumnName, Filter};
+//!
+//! let mut builder = Builder::default();
+//! builder.set_columns(["service", "status"]);
+//! builder.add_record(["web", "OK"]);
+//! builder.add_record(["db", "DOWN"]);
+//! builder.add_record(["cache", "OK"]);
+//!
+//! let table = builder.build()
+//!     .with(Filter::by_column(ByColumnName::new("status"), |v| v != "OK"))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+---------+--------+\n",
+//!         "| service | status |\n",
+//!         "+---------+--------+\n",
+//!         "| db      | DOWN   |\n",
+//!         "+---------+--------+",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::records::{Records, Resizable};
+
+use crate::{locator::Locator, Table, TableOption};
+
+/// [`Filter`] retains only rows of a [`Table`] which satisfy a predicate.
+///
+/// A header row is never removed in case the [`Table`] has one.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug)]
+pub struct Filter<F, L = ()> {
+    f: F,
+    locator: L,
+}
+
+impl Filter<(), ()> {
+    /// Creates a [`Filter`] with a predicate over all cells of a row.
+    ///
+    /// ```
+    /// use tabled::{Table, Filter};
+    ///
+    /// let data = [(1, 2), (3, 3), (5, 6)];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Filter::rows(|row: &[&str]| row[0] != row[1]))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+-----+-----+\n",
+    ///         "| i32 | i32 |\n",
+    ///         "+-----+-----+\n",
+    ///         "| 1   | 2   |\n",
+    ///         "+-----+-----+\n",
+    ///         "| 5   | 6   |\n",
+    ///         "+-----+-----+",
+    ///     )
+    /// );
+    /// ```
+    pub fn rows<F>(f: F) -> Filter<F>
+    where
+        F: FnMut(&[&str]) -> bool,
+    {
+        Filter { f, locator: () }
+    }
+
+    /// Creates a [`Filter`] with a predicate over a value of a located column.
+    ///
+    /// In case several columns are located a row is retained if any of the values satisfies the predicate.
+    ///
+    /// Available locators are:
+    ///
+    /// - [`Columns`]
+    /// - [`Column`]
+    /// - [`FirstColumn`]
+    /// - [`LastColumn`]
+    /// - [`ByColumnName`]
+    ///
+    /// [`Columns`]: crate::object::Columns
+    /// [`Column`]: crate::object::Column
+    /// [`FirstColumn`]: crate::object::FirstColumn
+    /// [`LastColumn`]: crate::object::LastColumn
+    /// [`ByColumnName`]: crate::locator::ByColumnName
+    pub fn by_column<L, F>(locator: L, f: F) -> Filter<F, L>
+    where
+        L: Locator<Coordinate = usize>,
+        F: FnMut(&str) -> bool,
+    {
+        Filter { f, locator }
+    }
+}
+
+impl<F, R> TableOption<R> for Filter<F>
+where
+    F: FnMut(&[&str]) -> bool,
+    R: Records + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let count_columns = table.count_columns();
+        let f = &mut self.f;
+        retain_rows(table, |records, row| {
+            let cells = (0..count_columns)
+                .map(|col| records.get_text((row, col)))
+                .collect::<Vec<_>>();
+
+            f(&cells)
+        });
+    }
+}
+
+impl<F, L, R> TableOption<R> for Filter<F, L>
+where
+    F: FnMut(&str) -> bool,
+    L: Locator<Coordinate = usize>,
+    R: Records + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let count_columns = table.count_columns();
+        let columns = self
+            .locator
+            .locate(table.get_records())
+            .into_iter()
+            .filter(|&col| col < count_columns)
+            .collect::<Vec<_>>();
+
+        if columns.is_empty() {
+            return;
+        }
+
+        let f = &mut self.f;
+        retain_rows(table, |records, row| {
+            columns.iter().any(|&col| f(records.get_text((row, col))))
+        });
+    }
+}
+
+pub(crate) fn retain_rows<R, F>(table: &mut Table<R>, mut f: F)
+where
+    R: Records + Resizable,
+    F: FnMut(&R, usize) -> bool,
+{
+    let start = if table.has_header() { 1 } else { 0 };
+    let count_rows = table.count_rows();
+
+    let removed = (start..count_rows)
+        .filter(|&row| !f(table.get_records(), row))
+        .collect::<Vec<_>>();
+
+    if removed.is_empty() {
+        return;
+    }
+
+    for &row in removed.iter().rev() {
+        table.get_records_mut().remove_row(row);
+        table.remove_row_aside(row);
+    }
+
+    table.destroy_width_cache();
+    table.destroy_height_cache();
+}
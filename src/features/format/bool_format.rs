@@ -0,0 +1,95 @@
+use papergrid::{
+    records::{Records, RecordsMut},
+    width::CfgWidthFunction,
+    Entity,
+};
+
+use crate::{CellOption, Table, TableOption};
+
+/// [`BoolFormat`] replaces `true` and `false` cells by custom texts.
+///
+/// Being used as a [`TableOption`] it affects all cells of a [`Table`],
+/// and as a [`CellOption`] only a given selection.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{format::BoolFormat, Style, TableIteratorExt};
+///
+/// let data = [("serde", true), ("rand", false)];
+///
+/// let table = data.table()
+///     .with(Style::psql())
+///     .with(BoolFormat::check_marks())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str  | bool \n",
+///         "-------+------\n",
+///         " serde | ✓    \n",
+///         " rand  | ✗    ",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoolFormat {
+    yes: String,
+    no: String,
+}
+
+impl BoolFormat {
+    /// Creates a [`BoolFormat`] with texts for `true` and `false`.
+    pub fn new<T, F>(yes: T, no: F) -> Self
+    where
+        T: Into<String>,
+        F: Into<String>,
+    {
+        Self {
+            yes: yes.into(),
+            no: no.into(),
+        }
+    }
+
+    /// Uses `✓` and `✗`.
+    pub fn check_marks() -> Self {
+        Self::new("✓", "✗")
+    }
+
+    /// Uses `yes` and `no`.
+    pub fn yes_no() -> Self {
+        Self::new("yes", "no")
+    }
+}
+
+impl<R> TableOption<R> for BoolFormat
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        self.change_cell(table, Entity::Global);
+    }
+}
+
+impl<R> CellOption<R> for BoolFormat
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            let text = match table.get_records().get_text(pos).trim() {
+                "true" => self.yes.clone(),
+                "false" => self.no.clone(),
+                _ => continue,
+            };
+
+            table.get_records_mut().set(pos, text, &width_fn);
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
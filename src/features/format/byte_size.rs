@@ -0,0 +1,121 @@
+use papergrid::{
+    records::{Records, RecordsMut},
+    width::CfgWidthFunction,
+    Entity,
+};
+
+use crate::{CellOption, Table};
+
+/// [`ByteSize`] renders a number of bytes in human units, like `1.4 GiB`.
+///
+/// It uses binary units by default, [`ByteSize::si`] can be used to switch to decimal ones.
+/// Cells which aren't a non negative integer are left as they are.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{format::ByteSize, object::Columns, Modify, Style, TableIteratorExt};
+///
+/// let data = [("Cargo.lock", 51234), ("target", 1503238554)];
+///
+/// let table = data.table()
+///     .with(Style::psql())
+///     .with(Modify::new(Columns::single(1)).with(ByteSize::binary()))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str       | i32      \n",
+///         "------------+----------\n",
+///         " Cargo.lock | 50.0 KiB \n",
+///         " target     | 1.4 GiB  ",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteSize {
+    base: u64,
+    units: [&'static str; 7],
+    precision: usize,
+}
+
+impl ByteSize {
+    /// Uses binary units which are powers of 1024, like `KiB` and `MiB`.
+    pub fn binary() -> Self {
+        Self {
+            base: 1024,
+            units: ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"],
+            precision: 1,
+        }
+    }
+
+    /// Uses SI units which are powers of 1000, like `kB` and `MB`.
+    pub fn si() -> Self {
+        Self {
+            base: 1000,
+            units: ["B", "kB", "MB", "GB", "TB", "PB", "EB"],
+            precision: 1,
+        }
+    }
+
+    /// Sets a number of decimals.
+    ///
+    /// By default it's 1.
+    pub fn precision(mut self, decimals: usize) -> Self {
+        self.precision = decimals;
+        self
+    }
+
+    /// Formats a number of bytes in case a text is one.
+    pub fn format(&self, text: &str) -> Option<String> {
+        let bytes = text.trim().parse::<u64>().ok()?;
+        if bytes < self.base {
+            return Some(format!("{} {}", bytes, self.units[0]));
+        }
+
+        let mut value = bytes as f64;
+        let mut unit = 0;
+        while value >= self.base as f64 && unit + 1 < self.units.len() {
+            value /= self.base as f64;
+            unit += 1;
+        }
+
+        let mut number = format!("{:.*}", self.precision, value);
+
+        // a value like 999.99 can be rounded up to a base, so a next unit is used
+        let is_rounded_up = matches!(number.parse::<f64>(), Ok(n) if n >= self.base as f64);
+        if is_rounded_up && unit + 1 < self.units.len() {
+            value /= self.base as f64;
+            unit += 1;
+            number = format!("{:.*}", self.precision, value);
+        }
+
+        Some(format!("{} {}", number, self.units[unit]))
+    }
+}
+
+impl Default for ByteSize {
+    fn default() -> Self {
+        Self::binary()
+    }
+}
+
+impl<R> CellOption<R> for ByteSize
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            let records = table.get_records();
+            if let Some(text) = self.format(records.get_text(pos)) {
+                table.get_records_mut().set(pos, text, &width_fn);
+            }
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
@@ -0,0 +1,198 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use papergrid::{
+    records::{Records, RecordsMut},
+    width::CfgWidthFunction,
+    Entity,
+};
+
+use crate::{format::Locale, CellOption, Table};
+
+/// [`DateTimeFormat`] reformats dates and times with a `strftime` like pattern.
+///
+/// A content of a cell can be
+///
+/// - an RFC 3339 date time, like `2023-02-01T12:30:00+02:00`
+/// - a date time as it's printed by [`chrono`], like `2023-02-01 10:30:00 UTC`
+/// - a date time without an offset, like `2023-02-01 10:30:00`, which is considered to be in UTC
+/// - a date, like `2023-02-01`
+/// - a number of seconds since the Unix epoch
+///
+/// Other cells are left as they are.
+///
+/// # Example
+///
+/// ```
+/// use chrono::FixedOffset;
+/// use tabled::{format::DateTimeFormat, object::Columns, Modify, Style, TableIteratorExt};
+///
+/// let data = [("v0.1.0", "2022-08-01T09:15:00Z"), ("v0.2.0", "1675243800")];
+///
+/// let table = data.table()
+///     .with(Style::psql())
+///     .with(
+///         Modify::new(Columns::single(1)).with(
+///             DateTimeFormat::new("%d %b %Y %H:%M")
+///                 .timezone(FixedOffset::east_opt(3600).unwrap())
+///         ),
+///     )
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str   | &str              \n",
+///         "--------+-------------------\n",
+///         " v0.1.0 | 01 Aug 2022 10:15 \n",
+///         " v0.2.0 | 01 Feb 2023 10:30 ",
+///     )
+/// );
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+#[derive(Debug, Clone)]
+pub struct DateTimeFormat<Tz = Utc> {
+    pattern: String,
+    timezone: Tz,
+    locale: Option<Locale>,
+}
+
+impl DateTimeFormat<Utc> {
+    /// Creates a [`DateTimeFormat`] with a pattern.
+    ///
+    /// See [`chrono::format::strftime`] for a list of specifiers.
+    pub fn new<S>(pattern: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            pattern: pattern.into(),
+            timezone: Utc,
+            locale: None,
+        }
+    }
+}
+
+impl<Tz> DateTimeFormat<Tz> {
+    /// Converts dates and times into a timezone before printing them.
+    ///
+    /// By default it's UTC.
+    pub fn timezone<T>(self, timezone: T) -> DateTimeFormat<T>
+    where
+        T: TimeZone,
+    {
+        DateTimeFormat {
+            pattern: self.pattern,
+            timezone,
+            locale: self.locale,
+        }
+    }
+
+    /// Uses month names of a [`Locale`] for `%B`, `%b` and `%h` specifiers.
+    pub fn locale(mut self, locale: &Locale) -> Self {
+        self.locale = Some(*locale);
+        self
+    }
+}
+
+impl<Tz> DateTimeFormat<Tz>
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    /// Formats a date time in case a text is one.
+    pub fn format(&self, text: &str) -> Option<String> {
+        let datetime = parse_datetime(text.trim())?.with_timezone(&self.timezone);
+        let pattern = match &self.locale {
+            Some(locale) => localize_pattern(&self.pattern, locale, &datetime),
+            None => self.pattern.clone(),
+        };
+
+        let mut buf = String::new();
+        std::fmt::write(&mut buf, format_args!("{}", datetime.format(&pattern))).ok()?;
+
+        Some(buf)
+    }
+}
+
+impl<Tz, R> CellOption<R> for DateTimeFormat<Tz>
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            let records = table.get_records();
+            if let Some(text) = self.format(records.get_text(pos)) {
+                table.get_records_mut().set(pos, text, &width_fn);
+            }
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+fn parse_datetime(text: &str) -> Option<DateTime<FixedOffset>> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(text) {
+        return Some(datetime);
+    }
+
+    if let Ok(datetime) = DateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S%.f %:z") {
+        return Some(datetime);
+    }
+
+    let naive = text.strip_suffix(" UTC").unwrap_or(text);
+    if let Ok(datetime) = NaiveDateTime::parse_from_str(naive, "%Y-%m-%d %H:%M:%S%.f") {
+        return Some(Utc.from_utc_datetime(&datetime).fixed_offset());
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+        return Some(
+            Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0)?)
+                .fixed_offset(),
+        );
+    }
+
+    let seconds = text.parse::<i64>().ok()?;
+    DateTime::from_timestamp(seconds, 0).map(|datetime| datetime.fixed_offset())
+}
+
+fn localize_pattern<Tz>(pattern: &str, locale: &Locale, datetime: &DateTime<Tz>) -> String
+where
+    Tz: TimeZone,
+{
+    use chrono::Datelike;
+
+    let (month, short_month) = match (
+        locale.month(datetime.month()),
+        locale.short_month(datetime.month()),
+    ) {
+        (Some(month), Some(short_month)) => (month, short_month),
+        _ => return pattern.to_owned(),
+    };
+
+    let mut buf = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            buf.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('B') => buf.push_str(month),
+            Some('b') | Some('h') => buf.push_str(short_month),
+            Some(c) => {
+                buf.push('%');
+                buf.push(c);
+            }
+            None => buf.push('%'),
+        }
+    }
+
+    buf
+}
@@ -0,0 +1,99 @@
+use papergrid::{
+    records::{Records, RecordsMut},
+    width::CfgWidthFunction,
+    Entity,
+};
+
+use crate::{CellOption, Table};
+
+/// [`DurationFormat`] renders a duration in human units, like `2m 31s`.
+///
+/// A content of a cell is expected to be a non negative integer
+/// of milliseconds or seconds; other cells are left as they are.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{format::DurationFormat, object::Columns, Modify, Style, TableIteratorExt};
+///
+/// let data = [("build", 151_000), ("test", 250), ("deploy", 3_723_000)];
+///
+/// let table = data.table()
+///     .with(Style::psql())
+///     .with(Modify::new(Columns::single(1)).with(DurationFormat::millis()))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str   | i32      \n",
+///         "--------+----------\n",
+///         " build  | 2m 31s   \n",
+///         " test   | 250ms    \n",
+///         " deploy | 1h 2m 3s ",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DurationFormat {
+    millis_in_unit: u64,
+}
+
+impl DurationFormat {
+    /// Expects durations in milliseconds.
+    pub fn millis() -> Self {
+        Self { millis_in_unit: 1 }
+    }
+
+    /// Expects durations in seconds.
+    pub fn seconds() -> Self {
+        Self {
+            millis_in_unit: 1000,
+        }
+    }
+
+    /// Formats a duration in case a text is a number.
+    pub fn format(&self, text: &str) -> Option<String> {
+        let value = text.trim().parse::<u64>().ok()?;
+        let millis = value.checked_mul(self.millis_in_unit)?;
+        if millis < 1000 {
+            return match self.millis_in_unit {
+                1 => Some(format!("{}ms", millis)),
+                _ => Some(String::from("0s")),
+            };
+        }
+
+        const UNITS: [(&str, u64); 4] = [("d", 86_400), ("h", 3_600), ("m", 60), ("s", 1)];
+
+        let mut seconds = millis / 1000;
+        let mut parts = Vec::new();
+        for (unit, size) in UNITS {
+            let count = seconds / size;
+            if count > 0 {
+                parts.push(format!("{}{}", count, unit));
+                seconds -= count * size;
+            }
+        }
+
+        Some(parts.join(" "))
+    }
+}
+
+impl<R> CellOption<R> for DurationFormat
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            let records = table.get_records();
+            if let Some(text) = self.format(records.get_text(pos)) {
+                table.get_records_mut().set(pos, text, &width_fn);
+            }
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
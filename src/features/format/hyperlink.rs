@@ -0,0 +1,123 @@
+use papergrid::{
+    records::{Records, RecordsMut},
+    width::CfgWidthFunction,
+    Entity,
+};
+
+use crate::{CellOption, Table};
+
+/// [`Hyperlink`] turns a text of cells into hyperlinks,
+/// with a URL built by a function from the text.
+///
+/// In a terminal links are printed as [OSC 8] escape sequences,
+/// and [`HtmlTable`] renders them as `<a>` elements.
+///
+/// Each line of a cell is linked on its own, so a link never covers borders.
+/// Empty cells and cells for which the function returns an empty URL are not changed.
+///
+/// A width of links is ignored with or without a `color` feature,
+/// but be aware that [`Wrap`] and [`Truncate`] keep them intact only with it.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{format::Hyperlink, object::Columns, Modify, TableIteratorExt};
+///
+/// let data = [("ls", "list files")];
+///
+/// let table = data.table()
+///     .with(Modify::new(Columns::first()).with(Hyperlink::new(|cmd| format!("man:{}", cmd))))
+///     .to_html();
+///
+/// assert!(table.contains(r#"<td><a href="man:ls">ls</a></td>"#));
+/// ```
+///
+/// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+/// [`HtmlTable`]: crate::display::HtmlTable
+/// [`Wrap`]: crate::width::Wrap
+/// [`Truncate`]: crate::width::Truncate
+#[derive(Debug, Clone)]
+pub struct Hyperlink<F> {
+    f: F,
+}
+
+impl<F> Hyperlink<F>
+where
+    F: FnMut(&str) -> String,
+{
+    /// Creates a [`Hyperlink`] with a function which returns a URL for a text of a cell.
+    pub fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+impl<F, R> CellOption<R> for Hyperlink<F>
+where
+    F: FnMut(&str) -> String,
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            let text = table.get_records().get_text(pos);
+            if text.trim().is_empty() {
+                continue;
+            }
+
+            let url = (self.f)(text);
+            if url.is_empty() {
+                continue;
+            }
+
+            let text = text
+                .lines()
+                .map(|line| format_hyperlink(&url, line))
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            table.get_records_mut().set(pos, text, &width_fn);
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+const OSC8: &str = "\u{1b}]8;;";
+const ST: &str = "\u{1b}\\";
+
+fn format_hyperlink(url: &str, text: &str) -> String {
+    if text.is_empty() {
+        return String::new();
+    }
+
+    format!("{}{}{}{}{}{}", OSC8, url, ST, text, OSC8, ST)
+}
+
+/// Splits a text into parts with a URL of a hyperlink they belong to.
+pub(crate) fn split_hyperlinks(mut text: &str) -> Vec<(Option<&str>, &str)> {
+    let mut parts = Vec::new();
+    let mut url = None;
+    while let Some(start) = text.find(OSC8) {
+        let rest = &text[start + OSC8.len()..];
+        let end = match rest.find(ST) {
+            Some(end) => end,
+            None => break,
+        };
+
+        if start > 0 {
+            parts.push((url, &text[..start]));
+        }
+
+        // an empty URL closes a link
+        url = Some(&rest[..end]).filter(|url| !url.is_empty());
+        text = &rest[end + ST.len()..];
+    }
+
+    if !text.is_empty() {
+        parts.push((url, text));
+    }
+
+    parts
+}
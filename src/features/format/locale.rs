@@ -0,0 +1,284 @@
+/// [`Locale`] holds conventions of formatting numbers and dates of a particular language.
+///
+/// It can be used with [`NumberFormat`] to use a native decimal point and digit grouping.
+///
+/// ```
+/// use tabled::format::{Locale, NumberFormat};
+///
+/// let format = NumberFormat::new().precision(2).locale(&Locale::DE);
+///
+/// assert_eq!(format.format("1234567.891").as_deref(), Some("1.234.567,89"));
+/// ```
+///
+/// Only a few locales are predefined.
+/// With a `num-format` feature any locale known by the [`num_format`] crate can be converted into a [`Locale`].
+///
+/// [`NumberFormat`]: crate::format::NumberFormat
+/// [`num_format`]: https://docs.rs/num-format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    decimal: char,
+    grouping: Option<char>,
+    group_sizes: GroupSizes,
+    months: [&'static str; 12],
+    short_months: [&'static str; 12],
+}
+
+/// Sizes of digit groups of an integer part,
+/// the first one is the rightmost group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GroupSizes {
+    /// Groups of 3 digits, like `10,000,000`.
+    Thousands,
+    /// The first group of 3 digits and groups of 2 digits after it, like `1,00,00,000`.
+    #[cfg_attr(not(feature = "num-format"), allow(dead_code))]
+    Indian,
+}
+
+impl GroupSizes {
+    pub(crate) fn first(&self) -> usize {
+        3
+    }
+
+    pub(crate) fn rest(&self) -> usize {
+        match self {
+            GroupSizes::Thousands => 3,
+            GroupSizes::Indian => 2,
+        }
+    }
+}
+
+impl Locale {
+    /// English.
+    pub const EN: Self = Self::new(
+        '.',
+        Some(','),
+        [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ],
+    );
+
+    /// German.
+    pub const DE: Self = Self::new(
+        ',',
+        Some('.'),
+        [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        [
+            "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+            "Dez.",
+        ],
+    );
+
+    /// French.
+    pub const FR: Self = Self::new(
+        ',',
+        Some('\u{202f}'),
+        [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+    );
+
+    /// Spanish.
+    pub const ES: Self = Self::new(
+        ',',
+        Some('.'),
+        [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+        ],
+    );
+
+    /// Russian.
+    pub const RU: Self = Self::new(
+        ',',
+        Some('\u{a0}'),
+        [
+            "января",
+            "февраля",
+            "марта",
+            "апреля",
+            "мая",
+            "июня",
+            "июля",
+            "августа",
+            "сентября",
+            "октября",
+            "ноября",
+            "декабря",
+        ],
+        [
+            "янв.",
+            "февр.",
+            "мар.",
+            "апр.",
+            "мая",
+            "июн.",
+            "июл.",
+            "авг.",
+            "сент.",
+            "окт.",
+            "нояб.",
+            "дек.",
+        ],
+    );
+
+    /// Creates a custom [`Locale`].
+    ///
+    /// Months are given by full and abbreviated names.
+    pub const fn new(
+        decimal: char,
+        grouping: Option<char>,
+        months: [&'static str; 12],
+        short_months: [&'static str; 12],
+    ) -> Self {
+        Self {
+            decimal,
+            grouping,
+            group_sizes: GroupSizes::Thousands,
+            months,
+            short_months,
+        }
+    }
+
+    /// Looks up one of predefined locales by a language tag, like `de` or `de-AT`.
+    ///
+    /// Only the language part of the tag is considered.
+    pub fn find(tag: &str) -> Option<Self> {
+        let language = tag.split(['-', '_']).next()?;
+        let locale = match language.to_ascii_lowercase().as_str() {
+            "en" => Self::EN,
+            "de" => Self::DE,
+            "fr" => Self::FR,
+            "es" => Self::ES,
+            "ru" => Self::RU,
+            _ => return None,
+        };
+
+        Some(locale)
+    }
+
+    /// Returns a decimal point character.
+    pub fn decimal_separator(&self) -> char {
+        self.decimal
+    }
+
+    /// Returns a separator of groups of digits.
+    pub fn grouping_separator(&self) -> Option<char> {
+        self.grouping
+    }
+
+    pub(crate) fn group_sizes(&self) -> GroupSizes {
+        self.group_sizes
+    }
+
+    /// Returns a name of a month, starting from 1.
+    pub fn month(&self, month: u32) -> Option<&'static str> {
+        let index = (month as usize).checked_sub(1)?;
+        self.months.get(index).copied()
+    }
+
+    /// Returns an abbreviated name of a month, starting from 1.
+    pub fn short_month(&self, month: u32) -> Option<&'static str> {
+        let index = (month as usize).checked_sub(1)?;
+        self.short_months.get(index).copied()
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::EN
+    }
+}
+
+/// Converts a locale of the [`num_format`] crate.
+///
+/// Names of months are taken from a predefined locale of the same language,
+/// English ones are used in case there's no such.
+///
+/// ```
+/// use tabled::format::{Locale, NumberFormat};
+///
+/// let format = NumberFormat::new().locale(&Locale::from(num_format::Locale::en_IN));
+///
+/// assert_eq!(format.format("12345678").as_deref(), Some("1,23,45,678"));
+/// ```
+///
+/// [`num_format`]: https://docs.rs/num-format
+#[cfg(feature = "num-format")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-format")))]
+impl From<num_format::Locale> for Locale {
+    fn from(locale: num_format::Locale) -> Self {
+        let language = Self::find(locale.name()).unwrap_or_default();
+        let decimal = locale.decimal().chars().next().unwrap_or('.');
+        let separator = locale.separator().chars().next();
+
+        let (grouping, group_sizes) = match locale.grouping() {
+            num_format::Grouping::Standard => (separator, GroupSizes::Thousands),
+            num_format::Grouping::Indian => (separator, GroupSizes::Indian),
+            num_format::Grouping::Posix => (None, GroupSizes::Thousands),
+        };
+
+        Self {
+            decimal,
+            grouping,
+            group_sizes,
+            months: language.months,
+            short_months: language.short_months,
+        }
+    }
+}
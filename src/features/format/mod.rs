@@ -2,6 +2,27 @@
 //!
 //! [`Table`]: crate::Table
 
+mod bool_format;
+mod byte_size;
+#[cfg(feature = "chrono")]
+mod datetime;
+mod duration;
+mod hyperlink;
+mod locale;
+mod number_format;
+mod placeholder;
+
+pub use self::{
+    bool_format::BoolFormat, byte_size::ByteSize, duration::DurationFormat, hyperlink::Hyperlink,
+    locale::Locale, number_format::NumberFormat, placeholder::Placeholder,
+};
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+pub use self::datetime::DateTimeFormat;
+
+pub(crate) use self::hyperlink::split_hyperlinks;
+
 use papergrid::{
     records::{Records, RecordsMut},
     width::CfgWidthFunction,
@@ -209,3 +230,23 @@ where
         table.destroy_height_cache();
     }
 }
+
+impl<'a, R> CellOption<R> for &'a str
+where
+    R: Records + RecordsMut<&'a str>,
+{
+    /// Sets a text to cells.
+    ///
+    /// Unlike [`String`] the text isn't copied into each cell
+    /// in case the table is able to keep a borrowed text, e.g. it's a `&'static str`.
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            table.get_records_mut().set(pos, *self, &width_fn);
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
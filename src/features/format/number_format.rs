@@ -0,0 +1,221 @@
+use papergrid::{
+    records::{Records, RecordsMut},
+    width::CfgWidthFunction,
+    Entity,
+};
+
+use crate::{
+    format::{locale::GroupSizes, Locale},
+    CellOption, Table,
+};
+
+/// [`NumberFormat`] reformats numeric cells.
+///
+/// It parses a content of a cell as a number and prints it back
+/// with grouped digits, a fixed number of decimals or in a scientific notation.
+/// Cells which can't be parsed as a number are left as they are.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{format::NumberFormat, object::Columns, Modify, Style, TableIteratorExt};
+///
+/// let data = [("disk", 1048576.0), ("memory", -2500.5)];
+///
+/// let table = data.table()
+///     .with(Style::psql())
+///     .with(Modify::new(Columns::single(1)).with(NumberFormat::new().thousands(',').precision(2)))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str   | f64          \n",
+///         "--------+--------------\n",
+///         " disk   | 1,048,576.00 \n",
+///         " memory | -2,500.50    ",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberFormat {
+    thousands: Option<char>,
+    group_sizes: GroupSizes,
+    decimal: char,
+    precision: Option<usize>,
+    scientific: bool,
+    sign: Sign,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sign {
+    Negative,
+    Plus,
+    Space,
+}
+
+impl NumberFormat {
+    /// Creates a [`NumberFormat`] which keeps numbers as they are.
+    pub fn new() -> Self {
+        Self {
+            thousands: None,
+            group_sizes: GroupSizes::Thousands,
+            decimal: '.',
+            precision: None,
+            scientific: false,
+            sign: Sign::Negative,
+        }
+    }
+
+    /// Sets a separator of groups of 3 digits in an integer part.
+    pub fn thousands(mut self, separator: char) -> Self {
+        self.thousands = Some(separator);
+        self.group_sizes = GroupSizes::Thousands;
+        self
+    }
+
+    /// Sets a decimal point character.
+    ///
+    /// By default it's `.`.
+    pub fn decimal(mut self, point: char) -> Self {
+        self.decimal = point;
+        self
+    }
+
+    /// Sets a fixed number of decimals.
+    ///
+    /// In a scientific notation it's a number of decimals of a mantissa.
+    pub fn precision(mut self, decimals: usize) -> Self {
+        self.precision = Some(decimals);
+        self
+    }
+
+    /// Uses a decimal point and a digit grouping of a [`Locale`].
+    pub fn locale(mut self, locale: &Locale) -> Self {
+        self.decimal = locale.decimal_separator();
+        self.thousands = locale.grouping_separator();
+        self.group_sizes = locale.group_sizes();
+        self
+    }
+
+    /// Prints numbers in a scientific notation, like `1.5e3`.
+    pub fn scientific(mut self) -> Self {
+        self.scientific = true;
+        self
+    }
+
+    /// Prints a `+` sign for positive numbers.
+    pub fn plus_sign(mut self) -> Self {
+        self.sign = Sign::Plus;
+        self
+    }
+
+    /// Prints a space in place of a sign for positive numbers,
+    /// so they stay aligned with negative ones.
+    pub fn align_sign(mut self) -> Self {
+        self.sign = Sign::Space;
+        self
+    }
+
+    /// Formats a number in case a text is one.
+    ///
+    /// ```
+    /// use tabled::format::NumberFormat;
+    ///
+    /// let format = NumberFormat::new().thousands(' ').decimal(',');
+    ///
+    /// assert_eq!(format.format("1234567.25").as_deref(), Some("1 234 567,25"));
+    /// assert_eq!(format.format("N/A"), None);
+    /// ```
+    pub fn format(&self, text: &str) -> Option<String> {
+        let text = text.trim();
+        let value = text.parse::<f64>().ok().filter(|value| value.is_finite())?;
+
+        let digits = if self.scientific {
+            match self.precision {
+                Some(precision) => format!("{:.*e}", precision, value.abs()),
+                None => format!("{:e}", value.abs()),
+            }
+        } else if let Some(precision) = self.precision {
+            format!("{:.*}", precision, value.abs())
+        } else if text.contains(['e', 'E']) {
+            value.abs().to_string()
+        } else {
+            text.trim_start_matches(['-', '+']).to_owned()
+        };
+
+        let (int, fraction) = match digits.find(['.', 'e']) {
+            Some(i) => digits.split_at(i),
+            None => (digits.as_str(), ""),
+        };
+
+        let mut buf = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+
+        let negative = value.is_sign_negative() && value != 0.0;
+        match (negative, self.sign) {
+            (true, _) => buf.push('-'),
+            (false, Sign::Plus) => buf.push('+'),
+            (false, Sign::Space) => buf.push(' '),
+            (false, Sign::Negative) => (),
+        }
+
+        match self.thousands {
+            Some(separator) if !self.scientific => {
+                group_digits(&mut buf, int, separator, self.group_sizes)
+            }
+            _ => buf.push_str(int),
+        }
+
+        buf.extend(
+            fraction
+                .chars()
+                .map(|c| if c == '.' { self.decimal } else { c }),
+        );
+
+        Some(buf)
+    }
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R> CellOption<R> for NumberFormat
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            let records = table.get_records();
+            if let Some(text) = self.format(records.get_text(pos)) {
+                table.get_records_mut().set(pos, text, &width_fn);
+            }
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+fn group_digits(buf: &mut String, digits: &str, separator: char, sizes: GroupSizes) {
+    let count = digits.chars().count();
+
+    // groups are counted from the right end, so the leftmost one may be shorter
+    let mut group_end = match count.saturating_sub(sizes.first()) % sizes.rest() {
+        0 => sizes.rest(),
+        n => n,
+    };
+
+    for (i, c) in digits.chars().enumerate() {
+        if i == group_end && count - i >= sizes.first() {
+            buf.push(separator);
+            group_end += sizes.rest();
+        }
+
+        buf.push(c);
+    }
+}
@@ -0,0 +1,87 @@
+use papergrid::{
+    records::{Records, RecordsMut},
+    width::CfgWidthFunction,
+    Entity,
+};
+
+use crate::{CellOption, Table, TableOption};
+
+/// [`Placeholder`] puts a text into empty cells.
+///
+/// A cell is considered empty in case it has nothing but whitespaces.
+/// Cells hidden by a span are not changed.
+///
+/// Being used as a [`TableOption`] it affects all cells of a [`Table`],
+/// and as a [`CellOption`] only a given selection.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{format::Placeholder, Style, TableIteratorExt};
+///
+/// let data = [("ls", "list files"), ("rmdir", " ")];
+///
+/// let table = data.table()
+///     .with(Style::psql())
+///     .with(Placeholder::new("N/A"))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str  | &str       \n",
+///         "-------+------------\n",
+///         " ls    | list files \n",
+///         " rmdir | N/A        ",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placeholder {
+    text: String,
+}
+
+impl Placeholder {
+    /// Creates a [`Placeholder`] with a text.
+    pub fn new<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self { text: text.into() }
+    }
+}
+
+impl<R> TableOption<R> for Placeholder
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        self.change_cell(table, Entity::Global);
+    }
+}
+
+impl<R> CellOption<R> for Placeholder
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            let is_visible = table
+                .get_config()
+                .is_cell_visible(pos, (count_rows, count_cols));
+            let is_empty = table.get_records().get_text(pos).trim().is_empty();
+            if !is_visible || !is_empty {
+                continue;
+            }
+
+            table
+                .get_records_mut()
+                .set(pos, self.text.clone(), &width_fn);
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
@@ -4,6 +4,8 @@
 //!
 //! [`TabSize`] sets a default tab size.
 //!
+//! [`AmbiguousWide`] sets a width of East Asian ambiguous characters.
+//!
 //! [`Alignment`]: crate::Alignment
 
 use papergrid::{
@@ -35,6 +37,46 @@ where
     }
 }
 
+/// Set whether characters of an East Asian ambiguous width (like `±`, `½` or `→`)
+/// are considered to be wide, as it's done by CJK terminals.
+///
+/// It affects the width estimation as well as [`Wrap`] and [`Truncate`].
+///
+/// By default they're considered to be narrow.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{formatting::AmbiguousWide, Table};
+///
+/// let table = Table::new(&["±½→"]).with(AmbiguousWide(true)).to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+--------+\n\
+///      | &str   |\n\
+///      +--------+\n\
+///      | ±½→ |\n\
+///      +--------+"
+/// );
+/// ```
+///
+/// [`Wrap`]: crate::width::Wrap
+/// [`Truncate`]: crate::width::Truncate
+#[derive(Debug, Default, Clone)]
+pub struct AmbiguousWide(pub bool);
+
+impl<R> TableOption<R> for AmbiguousWide
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        table.get_config_mut().set_ambiguous_wide(self.0);
+        table.update_records();
+        table.destroy_width_cache();
+    }
+}
+
 /// `AlignmentStrategy` is a responsible for a flow how we apply an alignment.
 /// It mostly matters for multiline strings.
 ///
@@ -0,0 +1,128 @@
+//! This module contains a [`Heatmap`] setting to color numeric cells of a [`Table`] by a gradient.
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::{
+    records::{Records, RecordsMut},
+    width::CfgWidthFunction,
+};
+
+use crate::{
+    color::{colorize, Color},
+    object::Object,
+    Table, TableOption,
+};
+
+/// Heatmap colors numeric cells of a target by a gradient,
+/// the lowest value gets the first color and the highest one gets the last.
+///
+/// Cells which can't be parsed as a number are left as they are.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{heatmap::Heatmap, object::Columns, TableIteratorExt};
+///
+/// let data = [("ls", 0.12), ("grep", 1.5), ("find", 3.0)];
+///
+/// let table = data.table()
+///     .with(Heatmap::new(Columns::single(1)).gradient((0, 255, 0), (255, 0, 0)))
+///     .to_string();
+///
+/// assert!(table.contains("\u{1b}[38;2;0;255;0m0.12\u{1b}[39m"));
+/// assert!(table.contains("\u{1b}[38;2;255;0;0m3\u{1b}[39m"));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+#[derive(Debug)]
+pub struct Heatmap<O> {
+    target: O,
+    from: (u8, u8, u8),
+    to: (u8, u8, u8),
+    background: bool,
+}
+
+impl<O> Heatmap<O>
+where
+    O: Object,
+{
+    /// Creates a new [`Heatmap`] of a target with a gradient from green to red.
+    pub fn new(target: O) -> Self {
+        Self {
+            target,
+            from: (0, 255, 0),
+            to: (255, 0, 0),
+            background: false,
+        }
+    }
+
+    /// Sets RGB colors of the lowest and the highest values.
+    pub fn gradient(mut self, from: (u8, u8, u8), to: (u8, u8, u8)) -> Self {
+        self.from = from;
+        self.to = to;
+        self
+    }
+
+    /// Colors a background of cells instead of a text.
+    pub fn background(mut self) -> Self {
+        self.background = true;
+        self
+    }
+}
+
+impl<O, R> TableOption<R> for Heatmap<O>
+where
+    O: Object,
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        if !table.is_colors_enabled() {
+            return;
+        }
+
+        let (count_rows, count_cols) = table.shape();
+        let mut cells = Vec::new();
+        for entity in self.target.cells(table) {
+            for pos in entity.iter(count_rows, count_cols) {
+                if let Ok(value) = table.get_records().get_text(pos).trim().parse::<f64>() {
+                    if value.is_finite() {
+                        cells.push((pos, value));
+                    }
+                }
+            }
+        }
+
+        let min = cells.iter().map(|(_, v)| *v).fold(f64::INFINITY, f64::min);
+        let max = cells
+            .iter()
+            .map(|(_, v)| *v)
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        for (pos, value) in cells {
+            let ratio = if max > min {
+                (value - min) / (max - min)
+            } else {
+                0.0
+            };
+
+            let (r, g, b) = interpolate(self.from, self.to, ratio);
+            let color = if self.background {
+                Color::bg_rgb(r, g, b)
+            } else {
+                Color::rgb(r, g, b)
+            };
+
+            let text = colorize(&color.into(), table.get_records().get_text(pos));
+            table.get_records_mut().set(pos, text, &width_fn);
+        }
+    }
+}
+
+fn interpolate(from: (u8, u8, u8), to: (u8, u8, u8), ratio: f64) -> (u8, u8, u8) {
+    let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * ratio).round() as u8;
+    (
+        channel(from.0, to.0),
+        channel(from.1, to.1),
+        channel(from.2, to.2),
+    )
+}
@@ -2,7 +2,7 @@ use std::iter::FromIterator;
 
 use papergrid::records::Records;
 
-use crate::{Table, TableOption};
+use crate::{SettingKind, Table, TableOption};
 
 /// A structure used to set [`Table`] height via a list of rows heights.
 #[derive(Debug)]
@@ -44,4 +44,8 @@ where
         table.cache_height(self.list.clone());
         table.destroy_width_cache();
     }
+
+    fn kind(&self) -> Option<SettingKind> {
+        Some(SettingKind::Heights(self.list.len()))
+    }
 }
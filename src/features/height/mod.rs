@@ -249,6 +249,39 @@ where
         + cfg.get_margin().bottom.size
 }
 
+/// Estimates heights of rows in parallel.
+///
+/// Spans are rare in big tables so in case of them a sequential estimation is used.
+#[cfg(feature = "parallel")]
+pub(crate) fn get_table_heights_parallel<R>(records: &R, cfg: &GridConfig) -> Vec<usize>
+where
+    R: Records + Sync,
+{
+    use rayon::prelude::*;
+
+    if cfg.has_row_spans() {
+        let mut ctrl = HeightEstimator::default();
+        ctrl.estimate(records, cfg);
+        return ctrl.into();
+    }
+
+    let shape = (records.count_rows(), records.count_columns());
+    (0..shape.0)
+        .into_par_iter()
+        .map(|row| {
+            (0..shape.1)
+                .filter(|&col| cfg.is_cell_visible((row, col), shape))
+                .map(|col| {
+                    let padding = cfg.get_padding((row, col).into());
+                    let count_lines = std::cmp::max(1, records.count_lines((row, col)));
+                    count_lines + padding.top.size + padding.bottom.size
+                })
+                .max()
+                .unwrap_or(0)
+        })
+        .collect()
+}
+
 pub(crate) fn get_table_total_height2<R>(records: &R, cfg: &GridConfig) -> (usize, Vec<usize>)
 where
     R: Records,
@@ -6,8 +6,6 @@ use crate::{
     Height, Table, TableOption,
 };
 
-use super::get_table_total_height2;
-
 /// A modification of a table to increase the table height.
 #[derive(Debug, Clone)]
 pub struct TableHeightIncrease<W = usize, P = PriorityNone> {
@@ -51,7 +49,7 @@ where
         }
 
         let height = self.height.measure(table.get_records(), table.get_config());
-        let (total, heights) = get_table_total_height2(table.get_records(), table.get_config());
+        let (total, heights) = table.estimate_heights_with_total();
         if total >= height {
             return;
         }
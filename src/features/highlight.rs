@@ -10,7 +10,10 @@ use papergrid::{records::Records, Entity, GridConfig, Position};
 use crate::{object::Object, Border, Table, TableOption};
 
 #[cfg(feature = "color")]
-use crate::style::BorderColored;
+use crate::{
+    color::Color,
+    style::{BorderColored, Symbol},
+};
 
 /// Highlight modifies a table style by changing a border of a target [`Table`] segment.
 ///
@@ -108,6 +111,43 @@ impl<O> Highlight<O> {
     pub fn colored(target: O, border: BorderColored) -> HighlightColored<O> {
         HighlightColored { target, border }
     }
+
+    /// Sets a color of a highlighted border.
+    ///
+    /// The color is applied only to the border of the target,
+    /// so it stays independent of a color of the rest of the borders.
+    ///
+    /// ```
+    /// use tabled::{color::Color, object::Cell, Border, Highlight, TableIteratorExt};
+    ///
+    /// let table = [1, 2, 3]
+    ///     .table()
+    ///     .with(Highlight::new(Cell(1, 0), Border::filled('*')).color(Color::FG_RED))
+    ///     .to_string();
+    ///
+    /// assert!(table.contains("\u{1b}[31m*\u{1b}[39m 1   \u{1b}[31m*\u{1b}[39m"));
+    /// ```
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn color(self, color: Color) -> HighlightColored<O> {
+        let border = Option::<papergrid::Border>::from(self.border).unwrap_or_default();
+        let symbol = |c: Option<char>| c.map(|c| Symbol::new(c, Some(color.clone())));
+        let border = BorderColored::new_raw(papergrid::Border {
+            top: symbol(border.top),
+            bottom: symbol(border.bottom),
+            left: symbol(border.left),
+            right: symbol(border.right),
+            left_top_corner: symbol(border.left_top_corner),
+            right_top_corner: symbol(border.right_top_corner),
+            left_bottom_corner: symbol(border.left_bottom_corner),
+            right_bottom_corner: symbol(border.right_bottom_corner),
+        });
+
+        HighlightColored {
+            target: self.target,
+            border,
+        }
+    }
 }
 
 impl<O, R> TableOption<R> for Highlight<O>
@@ -11,19 +11,42 @@ pub mod shadow;
 pub mod style;
 pub mod width;
 
+#[cfg(feature = "color")]
+pub(crate) mod ansi_closing;
 #[cfg(feature = "color")]
 pub mod color;
 #[cfg(feature = "color")]
+pub mod heatmap;
+#[cfg(feature = "color")]
 pub mod margin_color;
 #[cfg(feature = "color")]
 pub mod padding_color;
 
+#[cfg(feature = "terminal")]
+pub(crate) mod terminal;
+
+pub(crate) mod aggregate;
+pub(crate) mod ascii_only;
+pub(crate) mod caption;
+pub(crate) mod column_order;
 pub(crate) mod concat;
+pub(crate) mod dedup;
+pub(crate) mod diff;
 pub(crate) mod disable;
 pub(crate) mod extract;
+pub(crate) mod filter;
 pub(crate) mod margin;
 pub mod merge;
+pub(crate) mod multi_header;
 pub(crate) mod padding;
 pub(crate) mod panel;
+pub(crate) mod pivot;
+pub(crate) mod reverse;
 pub(crate) mod rotate;
+pub(crate) mod rows_aside;
+pub(crate) mod selection;
+pub(crate) mod sort;
 pub(crate) mod span;
+pub(crate) mod split;
+pub(crate) mod strip_colors;
+pub(crate) mod viewport;
@@ -0,0 +1,240 @@
+//! This module contains a [`MultiHeader`] setting which builds a hierarchical header.
+//!
+//! A top level header cell spans a group of columns below it.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{builder::Builder, MultiHeader};
+//!
+//! let mut builder = Builder::default();
+//! builder.set_columns(["name", "Q1.Jan", "Q1.Feb", "Q2.Apr"]);
+//! builder.add_record(["apples", "1", "2", "3"]);
+//!
+//! let table = builder.build()
+//!     .with(MultiHeader::split("."))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+--------+-----------+-----+\n",
+//!         "| name   | Q1        | Q2  |\n",
+//!         "|        +-----+-----+-----+\n",
+//!         "|        | Jan | Feb | Apr |\n",
+//!         "+--------+-----+-----+-----+\n",
+//!         "| apples | 1   | 2   | 3   |\n",
+//!         "+--------+-----+-----+-----+",
+//!     )
+//! );
+//! ```
+
+use papergrid::{
+    records::{Records, RecordsMut, Resizable},
+    width::CfgWidthFunction,
+};
+
+use crate::{
+    features::panel::{move_row_spans, move_rows_aside},
+    Table, TableOption,
+};
+
+/// [`MultiHeader`] turns a first row of a [`Table`] into a multi level header.
+///
+/// Cells on upper levels which have the same path are merged together via column [`Span`].
+/// Cells which have less levels than others are spread down via row [`Span`].
+///
+/// [`Table`]: crate::Table
+/// [`Span`]: crate::Span
+#[derive(Debug)]
+pub struct MultiHeader {
+    kind: MultiHeaderKind,
+}
+
+#[derive(Debug)]
+enum MultiHeaderKind {
+    Split(String),
+    Groups(Vec<(String, usize)>),
+}
+
+impl MultiHeader {
+    /// Splits each column name by a delimiter,
+    /// so each part becomes a header cell on its own level.
+    ///
+    /// ```
+    /// use tabled::{builder::Builder, MultiHeader};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_columns(["size::min", "size::max"]);
+    /// builder.add_record(["1", "10"]);
+    ///
+    /// let table = builder.build()
+    ///     .with(MultiHeader::split("::"))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+-----------+\n",
+    ///         "| size      |\n",
+    ///         "+-----+-----+\n",
+    ///         "| min | max |\n",
+    ///         "+-----+-----+\n",
+    ///         "| 1   | 10  |\n",
+    ///         "+-----+-----+",
+    ///     )
+    /// );
+    /// ```
+    pub fn split<S>(delimiter: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            kind: MultiHeaderKind::Split(delimiter.into()),
+        }
+    }
+
+    /// Adds a level above a header where each group covers a given number of columns.
+    ///
+    /// A group with an empty name is not rendered,
+    /// the header cells below it are spread to the top instead.
+    ///
+    /// ```
+    /// use tabled::{builder::Builder, MultiHeader};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_columns(["name", "Jan", "Feb", "Mar"]);
+    /// builder.add_record(["apples", "1", "2", "3"]);
+    ///
+    /// let table = builder.build()
+    ///     .with(MultiHeader::groups([("", 1), ("Q1", 3)]))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+--------+-----------------+\n",
+    ///         "| name   | Q1              |\n",
+    ///         "|        +-----+-----+-----+\n",
+    ///         "|        | Jan | Feb | Mar |\n",
+    ///         "+--------+-----+-----+-----+\n",
+    ///         "| apples | 1   | 2   | 3   |\n",
+    ///         "+--------+-----+-----+-----+",
+    ///     )
+    /// );
+    /// ```
+    pub fn groups<I, S>(groups: I) -> Self
+    where
+        I: IntoIterator<Item = (S, usize)>,
+        S: Into<String>,
+    {
+        let groups = groups
+            .into_iter()
+            .map(|(name, count)| (name.into(), count))
+            .collect();
+
+        Self {
+            kind: MultiHeaderKind::Groups(groups),
+        }
+    }
+
+    fn build_paths<R>(&self, records: &R) -> Vec<Vec<String>>
+    where
+        R: Records,
+    {
+        let count_columns = records.count_columns();
+        let header = (0..count_columns).map(|col| records.get_text((0, col)));
+
+        match &self.kind {
+            MultiHeaderKind::Split(delimiter) => {
+                if delimiter.is_empty() {
+                    return header.map(|text| vec![text.to_owned()]).collect();
+                }
+
+                header
+                    .map(|text| text.split(delimiter.as_str()).map(String::from).collect())
+                    .collect()
+            }
+            MultiHeaderKind::Groups(groups) => {
+                let mut group_names = groups
+                    .iter()
+                    .flat_map(|(name, count)| (0..*count).map(move |_| name));
+
+                header
+                    .map(|text| match group_names.next() {
+                        Some(group) if !group.is_empty() => vec![group.clone(), text.to_owned()],
+                        _ => vec![text.to_owned()],
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+impl<R> TableOption<R> for MultiHeader
+where
+    R: Records + RecordsMut<String> + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        if table.is_empty() {
+            return;
+        }
+
+        let paths = self.build_paths(table.get_records());
+        let count_levels = paths.iter().map(Vec::len).max().unwrap_or(0);
+        if count_levels < 2 {
+            return;
+        }
+
+        for _ in 1..count_levels {
+            move_rows_aside(table, 0);
+            move_row_spans(table, 0);
+        }
+
+        let ctrl = CfgWidthFunction::from_cfg(table.get_config());
+
+        for (col, path) in paths.iter().enumerate() {
+            for level in 0..count_levels {
+                let text = path.get(level).cloned().unwrap_or_default();
+                table.get_records_mut().set((level, col), text, &ctrl);
+            }
+
+            let last_level = path.len() - 1;
+            let span = count_levels - last_level;
+            if span > 1 {
+                table.get_config_mut().set_row_span((last_level, col), span);
+            }
+        }
+
+        for level in 0..count_levels - 1 {
+            let mut col = 0;
+            while col < paths.len() {
+                if paths[col].len() <= level + 1 {
+                    col += 1;
+                    continue;
+                }
+
+                let prefix = &paths[col][..=level];
+                let span = paths[col..]
+                    .iter()
+                    .take_while(|path| path.len() > level + 1 && &path[..=level] == prefix)
+                    .count();
+
+                if span > 1 {
+                    table.get_config_mut().set_column_span((level, col), span);
+
+                    for covered in col + 1..col + span {
+                        table
+                            .get_records_mut()
+                            .set((level, covered), String::new(), &ctrl);
+                    }
+                }
+
+                col += span;
+            }
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
@@ -53,8 +53,8 @@ use crate::{
 /// # let data: Vec<&'static str> = Vec::new();
 /// let table = Table::new(&data).with(Modify::new(Rows::single(0)).with(Padding::new(0, 0, 1, 1).set_fill('>', '<', '^', 'V')));
 /// ```
-#[derive(Debug)]
-pub struct Padding(papergrid::Padding);
+#[derive(Debug, Clone)]
+pub struct Padding(pub(crate) papergrid::Padding);
 
 impl Padding {
     /// Construct's an Padding object.
@@ -1,6 +1,6 @@
-//! This module contains a [`PaddingColor`] setting of a cell on a [`Table`].
+//! This module contains a [`PaddingColor`] and a [`FillColor`] settings of a cell on a [`Table`].
 
-use papergrid::Entity;
+use papergrid::{AnsiColor, Entity};
 
 use crate::{color::Color, CellOption, Table};
 
@@ -39,12 +39,45 @@ impl PaddingColor {
             },
         }
     }
+
+    /// Creates a [`FillColor`] which colors a whole area of a cell,
+    /// a text, a padding and an alignment indent.
+    ///
+    /// It's supposed to be used with a background color.
+    ///
+    /// ```
+    /// use tabled::{padding_color::PaddingColor, color::Color, object::Rows, Modify, TableIteratorExt};
+    ///
+    /// let table = [(0u8, "Hello")].table()
+    ///     .with(Modify::new(Rows::first()).with(PaddingColor::fill(Color::BG_BLUE)))
+    ///     .to_string();
+    ///
+    /// assert!(table.contains("|\u{1b}[44m u8 \u{1b}[49m|"));
+    /// ```
+    pub fn fill(color: Color) -> FillColor {
+        FillColor(color.into())
+    }
 }
 
 impl<R> CellOption<R> for PaddingColor {
     fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
         table
-            .get_config_mut()
+            .get_config_mut_keep_dimension()
             .set_padding_color(entity, self.inner.clone());
     }
 }
+
+/// A color which fills a whole area of a cell.
+///
+/// It's created by [`PaddingColor::fill`].
+#[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+#[derive(Debug, Clone, Default)]
+pub struct FillColor(AnsiColor<'static>);
+
+impl<R> CellOption<R> for FillColor {
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        table
+            .get_config_mut_keep_dimension()
+            .set_fill_color(entity, self.0.clone());
+    }
+}
@@ -20,7 +20,7 @@
 //! assert_eq!(
 //!     table,
 //!     concat!(
-//!         "+---+---+---+---+\n",
+//!         "+---------------+\n",
 //!         "| Numbers       |\n",
 //!         "+---+---+---+---+\n",
 //!         "| 0 | S | 1 | 2 |\n",
@@ -165,12 +165,12 @@ impl<S> VerticalPanel<S> {
         self
     }
 
-    fn get_text(&self) -> String
+    fn get_text(&self, replacement: Option<char>, cjk: bool) -> String
     where
         S: AsRef<str>,
     {
         let text = if self.text_width > 0 {
-            wrap_text(self.text.as_ref(), self.text_width, false)
+            wrap_text(self.text.as_ref(), self.text_width, false, replacement, cjk)
         } else {
             self.text.as_ref().to_owned()
         };
@@ -195,7 +195,8 @@ where
         // #[cfg(feature = "color")]
         // move_right_border_colors(table, self.pos.0, self.pos.1);
 
-        let text = self.get_text();
+        let cjk = table.get_config().is_ambiguous_wide();
+        let text = self.get_text(table.get_char_replacement(), cjk);
         set_text(table, self.pos, text);
 
         let length = count_rows.checked_sub(self.pos.0).unwrap_or(1);
@@ -290,15 +291,18 @@ where
     R: Records + RecordsMut<String> + Resizable,
 {
     fn change(&mut self, table: &mut Table<R>) {
+        let row = table.shape().0;
         HorizontalPanel {
-            pos: (table.shape().0, 0),
+            pos: (row, 0),
             text: self.0.as_ref(),
         }
         .change(table);
+
+        table.set_footer_row(row);
     }
 }
 
-fn move_rows_aside<R>(table: &mut Table<R>, row: usize)
+pub(crate) fn move_rows_aside<R>(table: &mut Table<R>, row: usize)
 where
     R: Records + Resizable,
 {
@@ -310,9 +314,11 @@ where
         let row = count_rows - i;
         table.get_records_mut().swap_row(row, row - 1);
     }
+
+    table.insert_row_aside(row);
 }
 
-fn move_columns_aside<R>(table: &mut Table<R>, column: usize)
+pub(crate) fn move_columns_aside<R>(table: &mut Table<R>, column: usize)
 where
     R: Records + Resizable,
 {
@@ -326,7 +332,7 @@ where
     }
 }
 
-fn move_row_spans<R>(table: &mut Table<R>, target_row: usize)
+pub(crate) fn move_row_spans<R>(table: &mut Table<R>, target_row: usize)
 where
     R: Records,
 {
@@ -358,7 +364,7 @@ where
     }
 }
 
-fn move_column_spans<R>(table: &mut Table<R>, target_column: usize)
+pub(crate) fn move_column_spans<R>(table: &mut Table<R>, target_column: usize)
 where
     R: Records,
 {
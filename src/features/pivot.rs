@@ -0,0 +1,208 @@
+//! This module contains a [`Pivot`] setting which groups rows of a [`Table`] by a column.
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::{
+    records::{Records, RecordsMut, Resizable},
+    width::CfgWidthFunction,
+};
+
+use crate::{
+    features::aggregate::{aggregate, Function},
+    locator::Locator,
+    object::Column,
+    Table, TableOption,
+};
+
+/// [`Pivot`] replaces a content of a [`Table`] by groups of rows which have the same value in a column.
+///
+/// The result has a row per group, in order of their first appearance,
+/// and a column with a number of rows in a group or an aggregate of another column.
+///
+/// The first row is considered to be a header.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{locator::ByColumnName, Pivot, Style, TableIteratorExt};
+///
+/// let data = [("rust", 120), ("go", 40), ("rust", 30)];
+///
+/// let table = data.table()
+///     .with(Pivot::by(ByColumnName::new("&str")).sum(ByColumnName::new("i32")))
+///     .with(Style::psql())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str | sum(i32) \n",
+///         "------+----------\n",
+///         " rust | 150      \n",
+///         " go   | 40       ",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug)]
+pub struct Pivot<G, V = Column> {
+    group: G,
+    value: Option<V>,
+    function: Function,
+}
+
+impl<G> Pivot<G> {
+    /// Groups rows by a located column and counts rows of each group.
+    ///
+    /// In case several columns are located the first one is used.
+    pub fn by(group: G) -> Self
+    where
+        G: Locator<Coordinate = usize>,
+    {
+        Self {
+            group,
+            value: None,
+            function: Function::Count,
+        }
+    }
+}
+
+impl<G, V> Pivot<G, V> {
+    /// Counts rows of each group.
+    pub fn count(self) -> Pivot<G> {
+        Pivot {
+            group: self.group,
+            value: None,
+            function: Function::Count,
+        }
+    }
+
+    /// Sums up numbers of a located column in each group.
+    pub fn sum<T>(self, value: T) -> Pivot<G, T>
+    where
+        T: Locator<Coordinate = usize>,
+    {
+        self.aggregate(value, Function::Sum)
+    }
+
+    /// Averages numbers of a located column in each group.
+    pub fn avg<T>(self, value: T) -> Pivot<G, T>
+    where
+        T: Locator<Coordinate = usize>,
+    {
+        self.aggregate(value, Function::Avg)
+    }
+
+    fn aggregate<T>(self, value: T, function: Function) -> Pivot<G, T> {
+        Pivot {
+            group: self.group,
+            value: Some(value),
+            function,
+        }
+    }
+}
+
+impl<G, V, R> TableOption<R> for Pivot<G, V>
+where
+    G: Locator<Coordinate = usize>,
+    V: Locator<Coordinate = usize>,
+    R: Records + RecordsMut<String> + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let (count_rows, count_cols) = table.shape();
+        if count_rows == 0 {
+            return;
+        }
+
+        let group_col = first_column(&mut self.group, table, count_cols);
+        let group_col = match group_col {
+            Some(col) => col,
+            None => return,
+        };
+
+        let value_col = match &mut self.value {
+            Some(value) => match first_column(value, table, count_cols) {
+                Some(col) => Some(col),
+                None => return,
+            },
+            None => None,
+        };
+
+        let records = table.get_records();
+
+        let mut groups: Vec<(&str, Vec<usize>)> = Vec::new();
+        for row in 1..count_rows {
+            let key = records.get_text((row, group_col));
+            match groups.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, rows)) => rows.push(row),
+                None => groups.push((key, vec![row])),
+            }
+        }
+
+        let label = match value_col {
+            Some(col) => {
+                let name = match self.function {
+                    Function::Avg => "avg",
+                    _ => "sum",
+                };
+
+                format!("{}({})", name, records.get_text((0, col)))
+            }
+            None => String::from("count"),
+        };
+
+        let mut rows = Vec::with_capacity(groups.len() + 1);
+        rows.push([records.get_text((0, group_col)).to_owned(), label]);
+        for (key, group) in groups {
+            let value = match value_col {
+                Some(col) => {
+                    let cells = group.iter().map(|&row| records.get_text((row, col)));
+                    aggregate(self.function, cells)
+                }
+                None => group.len().to_string(),
+            };
+
+            rows.push([key.to_owned(), value]);
+        }
+
+        for _ in rows.len()..count_rows {
+            table.get_records_mut().remove_row(rows.len());
+        }
+
+        // rows are made of groups, so none of them is an original row
+        for row in (1..count_rows).rev() {
+            table.remove_row_aside(row);
+        }
+
+        let records = table.get_records_mut();
+
+        for _ in count_cols..2 {
+            records.push_column();
+        }
+
+        for _ in 2..count_cols {
+            records.remove_column(2);
+        }
+
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        for (row, [key, value]) in rows.into_iter().enumerate() {
+            table.get_records_mut().set((row, 0), key, &width_fn);
+            table.get_records_mut().set((row, 1), value, &width_fn);
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+fn first_column<L, R>(locator: &mut L, table: &Table<R>, count_cols: usize) -> Option<usize>
+where
+    L: Locator<Coordinate = usize>,
+    R: Records,
+{
+    locator
+        .locate(table.get_records())
+        .into_iter()
+        .find(|&col| col < count_cols)
+}
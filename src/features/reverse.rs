@@ -0,0 +1,122 @@
+//! This module contains a [`Reverse`] setting which flips an order of rows or columns of a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, Reverse};
+//!
+//! let data = [("first", 1), ("second", 2), ("third", 3)];
+//!
+//! let table = Table::new(data)
+//!     .with(Reverse::rows())
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+--------+-----+\n",
+//!         "| &str   | i32 |\n",
+//!         "+--------+-----+\n",
+//!         "| third  | 3   |\n",
+//!         "+--------+-----+\n",
+//!         "| second | 2   |\n",
+//!         "+--------+-----+\n",
+//!         "| first  | 1   |\n",
+//!         "+--------+-----+",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::records::{Records, Resizable};
+
+use crate::{Table, TableOption};
+
+/// [`Reverse`] flips an order of rows or columns of a [`Table`].
+///
+/// A header row is left in place while reversing rows in case the [`Table`] has one,
+/// so are footer rows added by [`Footer`] and [`Panel::footer`].
+///
+/// [`Table`]: crate::Table
+/// [`Footer`]: crate::Footer
+/// [`Panel::footer`]: crate::Panel::footer
+#[derive(Debug)]
+pub enum Reverse {
+    /// Reverse an order of rows.
+    Rows,
+    /// Reverse an order of columns.
+    Columns,
+}
+
+impl Reverse {
+    /// Reverse an order of rows.
+    pub fn rows() -> Self {
+        Self::Rows
+    }
+
+    /// Reverse an order of columns.
+    ///
+    /// ```
+    /// use tabled::{Table, Reverse};
+    ///
+    /// let data = [("first", 1)];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Reverse::columns())
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+-----+-------+\n",
+    ///         "| i32 | &str  |\n",
+    ///         "+-----+-------+\n",
+    ///         "| 1   | first |\n",
+    ///         "+-----+-------+",
+    ///     )
+    /// );
+    /// ```
+    pub fn columns() -> Self {
+        Self::Columns
+    }
+}
+
+impl<R> TableOption<R> for Reverse
+where
+    R: Records + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let (count_rows, count_cols) = table.shape();
+
+        match self {
+            Self::Rows => {
+                let start = if table.has_header() { 1 } else { 0 };
+                let count_rows = count_rows.saturating_sub(table.count_footer_rows());
+                if count_rows <= start {
+                    return;
+                }
+
+                let count = count_rows - start;
+                let records = table.get_records_mut();
+                for i in 0..count / 2 {
+                    records.swap_row(start + i, count_rows - i - 1);
+                }
+
+                for i in 0..count / 2 {
+                    table.swap_rows_aside(start + i, count_rows - i - 1);
+                }
+
+                table.destroy_height_cache();
+            }
+            Self::Columns => {
+                let records = table.get_records_mut();
+                for i in 0..count_cols / 2 {
+                    records.swap_column(i, count_cols - i - 1);
+                }
+
+                table.destroy_width_cache();
+            }
+        }
+    }
+}
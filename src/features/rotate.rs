@@ -100,6 +100,11 @@ where
                         records.remove_row(col);
                     }
                 }
+
+                // rows are made of columns, so none of them is an original row
+                for row in (0..count_rows).rev() {
+                    table.remove_row_aside(row);
+                }
             }
             Self::Right => {
                 let size = std::cmp::max(count_rows, count_cols);
@@ -135,6 +140,11 @@ where
                         records.remove_row(col);
                     }
                 }
+
+                // rows are made of columns, so none of them is an original row
+                for row in (0..count_rows).rev() {
+                    table.remove_row_aside(row);
+                }
             }
             Self::Bottom => {
                 for row in 0..count_rows / 2 {
@@ -143,6 +153,10 @@ where
                         records.swap((last_row, col), (row, col));
                     }
                 }
+
+                for row in 0..count_rows / 2 {
+                    table.swap_rows_aside(row, count_rows - row - 1);
+                }
             }
             Self::Top => Self::Bottom.change(table),
         }
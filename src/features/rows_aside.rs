@@ -0,0 +1,53 @@
+//! This module contains a [`RowsAside`] structure which keeps data of rows aside of a [`Table`] content.
+//!
+//! [`Table`]: crate::Table
+
+/// [`RowsAside`] keeps a value per row of a [`Table`], which follows its row
+/// while rows are sorted, filtered, reversed, split into pages or shifted by panels.
+///
+/// It's tolerant to rows which were added at the end of the table without notice,
+/// they're given a default value.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct RowsAside<T>(Vec<T>);
+
+impl<T> RowsAside<T>
+where
+    T: Default + Clone,
+{
+    pub(crate) fn new(rows: Vec<T>) -> Self {
+        Self(rows)
+    }
+
+    pub(crate) fn get(&self, row: usize) -> Option<&T> {
+        self.0.get(row)
+    }
+
+    pub(crate) fn get_mut(&mut self, row: usize) -> &mut T {
+        self.fit(row + 1);
+        &mut self.0[row]
+    }
+
+    pub(crate) fn swap(&mut self, lhs: usize, rhs: usize) {
+        self.fit(std::cmp::max(lhs, rhs) + 1);
+        self.0.swap(lhs, rhs);
+    }
+
+    pub(crate) fn insert(&mut self, row: usize) {
+        self.fit(row);
+        self.0.insert(row, T::default());
+    }
+
+    pub(crate) fn remove(&mut self, row: usize) {
+        if row < self.0.len() {
+            self.0.remove(row);
+        }
+    }
+
+    fn fit(&mut self, count_rows: usize) {
+        if self.0.len() < count_rows {
+            self.0.resize(count_rows, T::default());
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! This module contains a [`Selection`] setting which numbers rows of a [`Table`]
+//! so a user can pick one of them, and a [`SelectionMap`] to find a picked record.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Selection, Sort, Style, Table};
+//!
+//! let data = [("cargo", 3), ("rustc", 1), ("rustup", 2)];
+//!
+//! let mut table = Table::new(data);
+//! table
+//!     .with(Selection)
+//!     .with(Sort::by_column(1).numeric())
+//!     .with(Style::psql());
+//!
+//! let selection = Selection::number(&mut table, "#");
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " # | &str   | i32 \n",
+//!         "---+--------+-----\n",
+//!         " 1 | rustc  | 1   \n",
+//!         " 2 | rustup | 2   \n",
+//!         " 3 | cargo  | 3   ",
+//!     )
+//! );
+//!
+//! // a user picked the 1st row
+//! assert_eq!(selection.get(1), Some(1));
+//! assert_eq!(data[1].0, "rustc");
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::records::{Records, RecordsMut, Resizable};
+
+use crate::{features::rows_aside::RowsAside, Table, TableOption};
+
+/// [`Selection`] keeps track of original indexes of records of a [`Table`],
+/// so rows can be numbered for a user to pick one by [`Selection::number`].
+///
+/// Being applied it remembers an index of each record aside of the table content,
+/// and the index follows its row while the rows are sorted, filtered, removed, reversed or split into pages.
+/// So it must be applied before rows are rearranged.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Selection;
+
+impl Selection {
+    /// Inserts a column with numbers of rows starting from 1,
+    /// and returns a map from a number to an index of an original record.
+    ///
+    /// A header row is given a `header` text in case the table has one.
+    /// Rows which were added after [`Selection`] was applied, like panels, are not numbered.
+    ///
+    /// In case [`Selection`] wasn't applied, rows are mapped to their current indexes.
+    pub fn number<R>(table: &mut Table<R>, header: &str) -> SelectionMap
+    where
+        R: Records + RecordsMut<String> + Resizable,
+    {
+        let count_rows = table.count_rows();
+        let start = if table.has_header() { 1 } else { 0 };
+        let tracked = table.take_selection();
+
+        let mut indexes = Vec::new();
+        let mut numbers = Vec::with_capacity(count_rows);
+        for row in start..count_rows {
+            let index = match &tracked {
+                Some(tracked) => tracked.get(row).copied().flatten(),
+                None => Some(row - start),
+            };
+
+            match index {
+                Some(index) => {
+                    indexes.push(index);
+                    numbers.push(indexes.len().to_string());
+                }
+                None => numbers.push(String::new()),
+            }
+        }
+
+        table.insert_column(0, header, numbers);
+
+        SelectionMap { indexes }
+    }
+}
+
+impl<R> TableOption<R> for Selection
+where
+    R: Records,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        if table.get_selection().is_some() {
+            return;
+        }
+
+        let start = if table.has_header() { 1 } else { 0 };
+        let indexes = new_indexes(table.count_rows(), start);
+        table.set_selection(Some(indexes));
+    }
+}
+
+/// Indexes of original records of rows of a [`Table`] tracked by [`Selection`].
+///
+/// Rows which are not records, like a header, have no index.
+///
+/// [`Table`]: crate::Table
+pub(crate) type RowIndexes = RowsAside<Option<usize>>;
+
+fn new_indexes(count_rows: usize, start: usize) -> RowIndexes {
+    let indexes = (0..count_rows).map(|row| row.checked_sub(start)).collect();
+
+    RowsAside::new(indexes)
+}
+
+/// A map from a number of a row given by [`Selection::number`] to an index of an original record.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SelectionMap {
+    indexes: Vec<usize>,
+}
+
+impl SelectionMap {
+    /// Returns an index of an original record by a number of a row, starting from 1.
+    pub fn get(&self, number: usize) -> Option<usize> {
+        number
+            .checked_sub(1)
+            .and_then(|i| self.indexes.get(i))
+            .copied()
+    }
+
+    /// Returns an amount of numbered rows.
+    pub fn len(&self) -> usize {
+        self.indexes.len()
+    }
+
+    /// Checks whether there's no numbered rows.
+    pub fn is_empty(&self) -> bool {
+        self.indexes.is_empty()
+    }
+
+    /// Returns indexes of original records in the order of numbered rows.
+    pub fn indexes(&self) -> &[usize] {
+        &self.indexes
+    }
+}
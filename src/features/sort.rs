@@ -0,0 +1,276 @@
+//! This module contains a [`Sort`] setting which reorders rows of a [`Table`].
+//!
+//! The data itself is not touched, only the way it's displayed.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, Sort};
+//!
+//! let data = [("Grodno", 3), ("Minsk", 1), ("Brest", 2)];
+//!
+//! let table = Table::new(data)
+//!     .with(Sort::by_column(1).numeric())
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+--------+-----+\n",
+//!         "| &str   | i32 |\n",
+//!         "+--------+-----+\n",
+//!         "| Minsk  | 1   |\n",
+//!         "+--------+-----+\n",
+//!         "| Brest  | 2   |\n",
+//!         "+--------+-----+\n",
+//!         "| Grodno | 3   |\n",
+//!         "+--------+-----+",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::cmp::Ordering;
+
+use papergrid::records::{Records, Resizable};
+
+use crate::{Table, TableOption};
+
+/// [`Sort`] orders rows of a [`Table`] by a values of a given column.
+///
+/// A header row is left in place in case the [`Table`] has one,
+/// so are footer rows added by [`Footer`] and [`Panel::footer`].
+///
+/// The sort is stable so rows with equal values keep their relative order.
+///
+/// By default values are compared lexicographically in ascending order.
+///
+/// [`Table`]: crate::Table
+/// [`Footer`]: crate::Footer
+/// [`Panel::footer`]: crate::Panel::footer
+#[derive(Debug)]
+pub struct Sort<F = fn(&str, &str) -> Ordering> {
+    column: usize,
+    desc: bool,
+    cmp: F,
+    /// Values which are put at the end regardless of the order.
+    trailing: fn(&str) -> bool,
+}
+
+impl Sort {
+    /// Creates a [`Sort`] by a given column index.
+    pub fn by_column(column: usize) -> Self {
+        Self {
+            column,
+            desc: false,
+            cmp: compare_text,
+            trailing: |_| false,
+        }
+    }
+
+    /// Compares values as numbers.
+    ///
+    /// Values which can't be parsed as a finite number are put after the numeric ones
+    /// in both orders and compared lexicographically.
+    ///
+    /// ```
+    /// use tabled::{Table, Sort};
+    ///
+    /// let data = ["10", "9", "x", "100"];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Sort::by_column(0).numeric())
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+------+\n",
+    ///         "| &str |\n",
+    ///         "+------+\n",
+    ///         "| 9    |\n",
+    ///         "+------+\n",
+    ///         "| 10   |\n",
+    ///         "+------+\n",
+    ///         "| 100  |\n",
+    ///         "+------+\n",
+    ///         "| x    |\n",
+    ///         "+------+",
+    ///     )
+    /// );
+    /// ```
+    pub fn numeric(mut self) -> Self {
+        self.cmp = compare_numbers;
+        self.trailing = |text| parse_number(text).is_none();
+        self
+    }
+
+    /// Compares values lexicographically.
+    ///
+    /// It's the default comparator.
+    pub fn lexicographic(mut self) -> Self {
+        self.cmp = compare_text;
+        self.trailing = |_| false;
+        self
+    }
+}
+
+impl Sort<()> {
+    /// Creates a [`Sort`] by a given column index with a custom comparator.
+    ///
+    /// ```
+    /// use tabled::{Table, Sort};
+    ///
+    /// let data = ["bbb", "a", "cc"];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Sort::by_column_with(0, |a: &str, b: &str| a.len().cmp(&b.len())))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+------+\n",
+    ///         "| &str |\n",
+    ///         "+------+\n",
+    ///         "| a    |\n",
+    ///         "+------+\n",
+    ///         "| cc   |\n",
+    ///         "+------+\n",
+    ///         "| bbb  |\n",
+    ///         "+------+",
+    ///     )
+    /// );
+    /// ```
+    pub fn by_column_with<F>(column: usize, cmp: F) -> Sort<F>
+    where
+        F: FnMut(&str, &str) -> Ordering,
+    {
+        Sort {
+            column,
+            desc: false,
+            cmp,
+            trailing: |_| false,
+        }
+    }
+}
+
+impl<F> Sort<F> {
+    /// Sets a descending order.
+    pub fn desc(mut self) -> Self {
+        self.desc = true;
+        self
+    }
+
+    /// Sets an ascending order.
+    ///
+    /// It's the default order.
+    pub fn asc(mut self) -> Self {
+        self.desc = false;
+        self
+    }
+}
+
+impl<F, R> TableOption<R> for Sort<F>
+where
+    F: FnMut(&str, &str) -> Ordering,
+    R: Records + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let (count_rows, count_cols) = table.shape();
+        if self.column >= count_cols {
+            return;
+        }
+
+        let start = if table.has_header() { 1 } else { 0 };
+        let count_rows = count_rows.saturating_sub(table.count_footer_rows());
+        if count_rows <= start + 1 {
+            return;
+        }
+
+        let records = table.get_records();
+        let mut order = (start..count_rows).collect::<Vec<_>>();
+        order.sort_by(|&lhs, &rhs| {
+            let lhs = records.get_text((lhs, self.column));
+            let rhs = records.get_text((rhs, self.column));
+
+            let trailing = (self.trailing)(lhs).cmp(&(self.trailing)(rhs));
+            if trailing != Ordering::Equal {
+                return trailing;
+            }
+
+            let ordering = (self.cmp)(lhs, rhs);
+            if self.desc {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+
+        reorder_rows(table.get_records_mut(), start, &order);
+
+        reorder(start, &order, |lhs, rhs| table.swap_rows_aside(lhs, rhs));
+
+        table.destroy_height_cache();
+    }
+}
+
+/// Moves rows so a row `order[i]` ends up at `start + i` position.
+pub(crate) fn reorder_rows<R>(records: &mut R, start: usize, order: &[usize])
+where
+    R: Resizable,
+{
+    reorder(start, order, |lhs, rhs| records.swap_row(lhs, rhs));
+}
+
+/// Moves columns so a column `order[i]` ends up at `start + i` position.
+pub(crate) fn reorder_columns<R>(records: &mut R, start: usize, order: &[usize])
+where
+    R: Resizable,
+{
+    reorder(start, order, |lhs, rhs| records.swap_column(lhs, rhs));
+}
+
+fn reorder<F>(start: usize, order: &[usize], mut swap: F)
+where
+    F: FnMut(usize, usize),
+{
+    // position of an original line and an original line at a position
+    let mut positions = (start..start + order.len()).collect::<Vec<_>>();
+    let mut lines = positions.clone();
+
+    for (i, &line) in order.iter().enumerate() {
+        let target = start + i;
+        let current = positions[line - start];
+        if current == target {
+            continue;
+        }
+
+        swap(target, current);
+
+        let replaced = lines[i];
+        lines[i] = line;
+        lines[current - start] = replaced;
+        positions[replaced - start] = current;
+        positions[line - start] = target;
+    }
+}
+
+fn compare_text(lhs: &str, rhs: &str) -> Ordering {
+    lhs.cmp(rhs)
+}
+
+fn compare_numbers(lhs: &str, rhs: &str) -> Ordering {
+    match (parse_number(lhs), parse_number(rhs)) {
+        (Some(lhs), Some(rhs)) => lhs.partial_cmp(&rhs).unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => lhs.cmp(rhs),
+    }
+}
+
+/// Parses a finite number, so "NaN" and "inf" are treated as text.
+fn parse_number(text: &str) -> Option<f64> {
+    text.trim().parse::<f64>().ok().filter(|n| n.is_finite())
+}
@@ -16,13 +16,13 @@
 //! assert_eq!(
 //!     table,
 //!     concat!(
-//!         "+---+---+---+\n",
+//!         "+---+-------+\n",
 //!         "| 0 | 1     |\n",
 //!         "+---+---+---+\n",
 //!         "| 1 | 2 | 3 |\n",
 //!         "+---+---+---+\n",
 //!         "| 4     | 6 |\n",
-//!         "+---+---+---+",
+//!         "+-------+---+",
 //!     )
 //! )
 //! ```
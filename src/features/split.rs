@@ -0,0 +1,395 @@
+//! This module contains a [`Split`] setting which splits a [`Table`] into chunks.
+//!
+//! It can be used to show a wide table on a narrow terminal.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{builder::Builder, Split};
+//!
+//! let mut builder = Builder::default();
+//! builder.add_record(["a", "b", "c", "d", "e"]);
+//! builder.add_record(["1", "2", "3", "4", "5"]);
+//!
+//! let table = builder.build()
+//!     .with(Split::columns(2))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+---+---+\n",
+//!         "| a | b |\n",
+//!         "+---+---+\n",
+//!         "| 1 | 2 |\n",
+//!         "+---+---+\n",
+//!         "| c | d |\n",
+//!         "+---+---+\n",
+//!         "| 3 | 4 |\n",
+//!         "+---+---+\n",
+//!         "| e |   |\n",
+//!         "+---+---+\n",
+//!         "| 5 |   |\n",
+//!         "+---+---+",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::ops::Range;
+
+use papergrid::{
+    records::{Records, RecordsMut, Resizable},
+    width::CfgWidthFunction,
+};
+
+use crate::{height::get_table_total_height2, ColumnOrder, Extract, Table, TableOption};
+
+/// [`Split`] splits a [`Table`] into chunks of a limited size.
+///
+/// Being used as a [`TableOption`] it re-stitches the chunks back into a single [`Table`].
+/// Chunks of columns are stacked one below another,
+/// and chunks of rows are put one next to another.
+///
+/// The chunks can be retrieved as separate tables by [`Split::chunks`].
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone, Copy)]
+pub struct Split {
+    kind: SplitKind,
+    behavior: Behavior,
+    key_column: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum SplitKind {
+    Columns(usize),
+    Rows(usize),
+    Lines(usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Behavior {
+    Concat,
+    Zip,
+}
+
+impl Split {
+    /// Splits a table into chunks of a given number of columns.
+    pub fn columns(n: usize) -> Self {
+        Self::new(SplitKind::Columns(n))
+    }
+
+    /// Splits a table into chunks of a given number of rows.
+    pub fn rows(n: usize) -> Self {
+        Self::new(SplitKind::Rows(n))
+    }
+
+    /// Splits a table into chunks of rows so each chunk being rendered takes no more than a given number of lines.
+    ///
+    /// A chunk always contains at least 1 row.
+    pub fn lines(n: usize) -> Self {
+        Self::new(SplitKind::Lines(n))
+    }
+
+    /// Put chunks one after another while stitching them back.
+    ///
+    /// It's a default behaviour.
+    pub fn concat(mut self) -> Self {
+        self.behavior = Behavior::Concat;
+        self
+    }
+
+    /// Interleave rows (or columns) of chunks in a zig-zag manner while stitching them back.
+    ///
+    /// ```
+    /// use tabled::{builder::Builder, Split};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.add_record(["a", "b", "c", "d"]);
+    /// builder.add_record(["1", "2", "3", "4"]);
+    ///
+    /// let table = builder.build()
+    ///     .with(Split::columns(2).zip())
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+---+---+\n",
+    ///         "| a | b |\n",
+    ///         "+---+---+\n",
+    ///         "| c | d |\n",
+    ///         "+---+---+\n",
+    ///         "| 1 | 2 |\n",
+    ///         "+---+---+\n",
+    ///         "| 3 | 4 |\n",
+    ///         "+---+---+",
+    ///     )
+    /// );
+    /// ```
+    pub fn zip(mut self) -> Self {
+        self.behavior = Behavior::Zip;
+        self
+    }
+
+    /// Repeats a given column at the left of every chunk of columns,
+    /// so rows can be identified in each of them.
+    ///
+    /// The key column is not counted in a number of columns of a chunk.
+    /// It affects only [`Split::columns`], and an unknown column is ignored.
+    ///
+    /// ```
+    /// use tabled::{builder::Builder, Split};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.add_record(["id", "b", "c", "d"]);
+    /// builder.add_record(["1", "2", "3", "4"]);
+    ///
+    /// let table = builder.build()
+    ///     .with(Split::columns(2).key_column(0))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+----+---+---+\n",
+    ///         "| id | b | c |\n",
+    ///         "+----+---+---+\n",
+    ///         "| 1  | 2 | 3 |\n",
+    ///         "+----+---+---+\n",
+    ///         "| id | d |   |\n",
+    ///         "+----+---+---+\n",
+    ///         "| 1  | 4 |   |\n",
+    ///         "+----+---+---+",
+    ///     )
+    /// );
+    /// ```
+    pub fn key_column(mut self, column: usize) -> Self {
+        self.key_column = Some(column);
+        self
+    }
+
+    /// Returns the chunks as separate tables.
+    ///
+    /// ```
+    /// use tabled::{Table, Split};
+    ///
+    /// let data = [(0, 1, 2), (3, 4, 5)];
+    ///
+    /// let table = Table::new(data);
+    /// let chunks = Split::columns(2).chunks(&table);
+    ///
+    /// assert_eq!(chunks.len(), 2);
+    /// assert_eq!(
+    ///     chunks[1].to_string(),
+    ///     concat!(
+    ///         "+-----+\n",
+    ///         "| i32 |\n",
+    ///         "+-----+\n",
+    ///         "| 2   |\n",
+    ///         "+-----+\n",
+    ///         "| 5   |\n",
+    ///         "+-----+",
+    ///     )
+    /// );
+    /// ```
+    pub fn chunks<R>(&self, table: &Table<R>) -> Vec<Table<R>>
+    where
+        R: Records + Resizable + Clone,
+    {
+        self.ranges(table)
+            .into_iter()
+            .map(|(rows, columns)| {
+                let mut chunk = table.clone();
+                let count_columns = columns.len();
+                chunk.with(ColumnOrder::indexes(columns));
+                chunk.with(Extract::segment(rows, 0..count_columns));
+                chunk
+            })
+            .collect()
+    }
+
+    fn new(kind: SplitKind) -> Self {
+        Self {
+            kind,
+            behavior: Behavior::Concat,
+            key_column: None,
+        }
+    }
+
+    /// Returns rows and columns of each chunk, columns are listed in the order of the chunk.
+    fn ranges<R>(&self, table: &Table<R>) -> Vec<(Range<usize>, Vec<usize>)>
+    where
+        R: Records,
+    {
+        let (count_rows, count_cols) = table.shape();
+        if table.is_empty() {
+            return Vec::new();
+        }
+
+        match self.kind {
+            SplitKind::Columns(n) => {
+                let key = self.key_column.filter(|&col| col < count_cols);
+                let columns = (0..count_cols)
+                    .filter(|&col| Some(col) != key)
+                    .collect::<Vec<_>>();
+
+                let ranges = split_range(columns.len(), n);
+                if ranges.is_empty() {
+                    return vec![(0..count_rows, key.into_iter().collect())];
+                }
+
+                ranges
+                    .into_iter()
+                    .map(|range| {
+                        let chunk = key.into_iter().chain(columns[range].iter().copied());
+                        (0..count_rows, chunk.collect())
+                    })
+                    .collect()
+            }
+            SplitKind::Rows(n) => split_range(count_rows, n)
+                .into_iter()
+                .map(|rows| (rows, (0..count_cols).collect()))
+                .collect(),
+            SplitKind::Lines(n) => split_lines(table, n)
+                .into_iter()
+                .map(|rows| (rows, (0..count_cols).collect()))
+                .collect(),
+        }
+    }
+}
+
+impl<R> TableOption<R> for Split
+where
+    R: Records + RecordsMut<String> + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let ranges = self.ranges(table);
+        // a key column is moved to the left even if there's a single chunk
+        if ranges.is_empty() || (ranges.len() == 1 && self.key_column.is_none()) {
+            return;
+        }
+
+        let (count_rows, count_cols) = table.shape();
+        let count_chunks = ranges.len();
+        let is_vertical = matches!(self.kind, SplitKind::Columns(_));
+
+        let (new_rows, new_cols) = if is_vertical {
+            let width = ranges.iter().map(|(_, cols)| cols.len()).max().unwrap_or(0);
+            (count_rows * count_chunks, width)
+        } else {
+            let height = ranges.iter().map(|(rows, _)| rows.len()).max().unwrap_or(0);
+            (height, count_cols * count_chunks)
+        };
+
+        let mut cells = Vec::with_capacity(new_rows * new_cols);
+        for (chunk, (rows, cols)) in ranges.into_iter().enumerate() {
+            for (r, row) in rows.enumerate() {
+                for (c, &col) in cols.iter().enumerate() {
+                    let pos = match (is_vertical, self.behavior) {
+                        (true, Behavior::Concat) => (chunk * count_rows + r, c),
+                        (true, Behavior::Zip) => (r * count_chunks + chunk, c),
+                        (false, Behavior::Concat) => (r, chunk * count_cols + c),
+                        (false, Behavior::Zip) => (r, c * count_chunks + chunk),
+                    };
+
+                    let text = table.get_records().get_text((row, col)).to_owned();
+                    cells.push((pos, text));
+                }
+            }
+        }
+
+        resize(table, (count_rows, count_cols), (new_rows, new_cols));
+
+        // rows of a first chunk keep their place, while the rest are not records
+        if is_vertical && matches!(self.behavior, Behavior::Zip) {
+            for row in (0..count_rows).rev() {
+                for _ in 1..count_chunks {
+                    table.insert_row_aside(row + 1);
+                }
+            }
+        }
+
+        let ctrl = CfgWidthFunction::from_cfg(table.get_config());
+        let records = table.get_records_mut();
+        for row in 0..new_rows {
+            for col in 0..new_cols {
+                records.set((row, col), String::new(), &ctrl);
+            }
+        }
+
+        for (pos, text) in cells {
+            records.set(pos, text, &ctrl);
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+fn split_range(count: usize, n: usize) -> Vec<Range<usize>> {
+    if count == 0 {
+        return Vec::new();
+    }
+
+    let n = if n == 0 { count } else { n };
+
+    (0..count)
+        .step_by(n)
+        .map(|start| start..std::cmp::min(start + n, count))
+        .collect()
+}
+
+fn split_lines<R>(table: &Table<R>, n: usize) -> Vec<Range<usize>>
+where
+    R: Records,
+{
+    let cfg = table.get_config();
+    let (_, heights) = get_table_total_height2(table.get_records(), cfg);
+    let margin = cfg.get_margin().top.size + cfg.get_margin().bottom.size;
+    let chunk_height = |rows: &Range<usize>| {
+        heights[rows.clone()].iter().sum::<usize>() + cfg.count_horizontal(rows.len()) + margin
+    };
+
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    while start < heights.len() {
+        let mut end = start + 1;
+        while end < heights.len() && chunk_height(&(start..end + 1)) <= n {
+            end += 1;
+        }
+
+        ranges.push(start..end);
+        start = end;
+    }
+
+    ranges
+}
+
+fn resize<R>(
+    table: &mut Table<R>,
+    (count_rows, count_cols): (usize, usize),
+    (rows, cols): (usize, usize),
+) where
+    R: Records + Resizable,
+{
+    for row in (rows..count_rows).rev() {
+        table.get_records_mut().remove_row(row);
+        table.remove_row_aside(row);
+    }
+
+    let records = table.get_records_mut();
+    for _ in count_rows..rows {
+        records.push_row();
+    }
+
+    for _ in count_cols..cols {
+        records.push_column();
+    }
+
+    for col in (cols..count_cols).rev() {
+        records.remove_column(col);
+    }
+}
@@ -0,0 +1,117 @@
+//! This module contains a [`StripColors`] setting which removes ANSI sequences from a [`Table`] content.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, StripColors};
+//!
+//! let data = ["\u{1b}[31mred\u{1b}[39m"];
+//!
+//! let table = Table::new(data)
+//!     .with(StripColors)
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+------+\n",
+//!         "| &str |\n",
+//!         "+------+\n",
+//!         "| red  |\n",
+//!         "+------+",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::{
+    records::{Records, RecordsMut},
+    width::CfgWidthFunction,
+    Entity,
+};
+
+use crate::{CellOption, Table, TableOption};
+
+/// [`StripColors`] removes ANSI escape sequences from a content of cells.
+///
+/// It's handy to produce a plain output of a colored table,
+/// for example when it's written to a file.
+/// Being used as a [`TableOption`] it affects all cells,
+/// so it's supposed to be applied after all other settings which may color a content.
+///
+/// It can be also used as a [`CellOption`] to affect only some cells.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StripColors;
+
+impl<R> TableOption<R> for StripColors
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        self.change_cell(table, Entity::Global);
+    }
+}
+
+impl<R> CellOption<R> for StripColors
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
+        let width_fn = CfgWidthFunction::from_cfg(table.get_config());
+        let (count_rows, count_cols) = table.shape();
+        for pos in entity.iter(count_rows, count_cols) {
+            let text = table.get_records().get_text(pos);
+            if !text.contains('\u{1b}') {
+                continue;
+            }
+
+            let text = strip_ansi(text);
+            table.get_records_mut().set(pos, text, &width_fn);
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+/// Removes control sequences, OSC sequences (e.g. hyperlinks) and other escapes from a text.
+fn strip_ansi(text: &str) -> String {
+    let mut buf = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            buf.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            // a control sequence is ESC [ params final-byte
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('\u{40}'..='\u{7e}').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // an operating system command is ESC ] ... terminated by BEL or ESC \
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\u{7}' {
+                        break;
+                    }
+
+                    if c == '\u{1b}' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    buf
+}
@@ -25,7 +25,7 @@ impl Border {
 impl Border {
     /// This function constructs a cell borders with all sides set.
     #[allow(clippy::too_many_arguments)]
-    pub fn full(
+    pub const fn full(
         top: char,
         bottom: char,
         left: char,
@@ -35,83 +35,99 @@ impl Border {
         bottom_left: char,
         bottom_right: char,
     ) -> Self {
-        Self::from(papergrid::Border::full(
-            top,
-            bottom,
-            left,
-            right,
-            top_left,
-            top_right,
-            bottom_left,
-            bottom_right,
-        ))
+        Self::new_raw(Some(papergrid::Border {
+            top: Some(top),
+            bottom: Some(bottom),
+            left: Some(left),
+            right: Some(right),
+            left_top_corner: Some(top_left),
+            left_bottom_corner: Some(bottom_left),
+            right_top_corner: Some(top_right),
+            right_bottom_corner: Some(bottom_right),
+        }))
     }
 
     /// Using this function you deconstruct the existing borders.
-    pub fn empty() -> Self {
+    pub const fn empty() -> Self {
         Self { border: None }
     }
 
     /// This function constructs a cell borders with all sides's char set to a given character.
     /// It behaives like [`Border::full`] with the same character set to each side.
-    pub fn filled(c: char) -> Self {
+    pub const fn filled(c: char) -> Self {
         Self::full(c, c, c, c, c, c, c, c)
     }
 
     /// Set a top border character.
-    pub fn top(self, c: char) -> Self {
-        let mut b = self.border.unwrap_or_default();
+    pub const fn top(self, c: char) -> Self {
+        let mut b = self.into_raw();
         b.top = Some(c);
-        Self::from(b)
+        Self::new_raw(Some(b))
     }
 
     /// Set a bottom border character.
-    pub fn bottom(self, c: char) -> Self {
-        let mut b = self.border.unwrap_or_default();
+    pub const fn bottom(self, c: char) -> Self {
+        let mut b = self.into_raw();
         b.bottom = Some(c);
-        Self::from(b)
+        Self::new_raw(Some(b))
     }
 
     /// Set a left border character.
-    pub fn left(self, c: char) -> Self {
-        let mut b = self.border.unwrap_or_default();
+    pub const fn left(self, c: char) -> Self {
+        let mut b = self.into_raw();
         b.left = Some(c);
-        Self::from(b)
+        Self::new_raw(Some(b))
     }
 
     /// Set a right border character.
-    pub fn right(self, c: char) -> Self {
-        let mut b = self.border.unwrap_or_default();
+    pub const fn right(self, c: char) -> Self {
+        let mut b = self.into_raw();
         b.right = Some(c);
-        Self::from(b)
+        Self::new_raw(Some(b))
     }
 
     /// Set a top left intersection character.
-    pub fn top_left_corner(self, c: char) -> Self {
-        let mut b = self.border.unwrap_or_default();
+    pub const fn top_left_corner(self, c: char) -> Self {
+        let mut b = self.into_raw();
         b.left_top_corner = Some(c);
-        Self::from(b)
+        Self::new_raw(Some(b))
     }
 
     /// Set a top right intersection character.
-    pub fn top_right_corner(self, c: char) -> Self {
-        let mut b = self.border.unwrap_or_default();
+    pub const fn top_right_corner(self, c: char) -> Self {
+        let mut b = self.into_raw();
         b.right_top_corner = Some(c);
-        Self::from(b)
+        Self::new_raw(Some(b))
     }
 
     /// Set a bottom left intersection character.
-    pub fn bottom_left_corner(self, c: char) -> Self {
-        let mut b = self.border.unwrap_or_default();
+    pub const fn bottom_left_corner(self, c: char) -> Self {
+        let mut b = self.into_raw();
         b.left_bottom_corner = Some(c);
-        Self::from(b)
+        Self::new_raw(Some(b))
     }
 
     /// Set a bottom right intersection character.
-    pub fn bottom_right_corner(self, c: char) -> Self {
-        let mut b = self.border.unwrap_or_default();
+    pub const fn bottom_right_corner(self, c: char) -> Self {
+        let mut b = self.into_raw();
         b.right_bottom_corner = Some(c);
-        Self::from(b)
+        Self::new_raw(Some(b))
+    }
+
+    const fn into_raw(self) -> papergrid::Border<char> {
+        match self.border {
+            Some(border) => border,
+            None => papergrid::Border {
+                top: None,
+                bottom: None,
+                left: None,
+                right: None,
+                left_top_corner: None,
+                left_bottom_corner: None,
+                right_top_corner: None,
+                right_bottom_corner: None,
+            },
+        }
     }
 }
 
@@ -21,6 +21,12 @@ use crate::{style::Symbol, CellOption, Table};
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
 pub struct BorderColored(papergrid::Border<Symbol>);
 
+impl BorderColored {
+    pub(crate) const fn new_raw(border: papergrid::Border<Symbol>) -> Self {
+        Self(border)
+    }
+}
+
 impl BorderColored {
     /// Set a top border character.
     pub fn top(mut self, c: Symbol) -> Self {
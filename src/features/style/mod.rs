@@ -22,9 +22,16 @@ mod raw_style_colored;
 mod symbol;
 
 pub use self::{
-    border::Border, border_char::BorderChar, border_text::BorderText,
-    horizontal_line::HorizontalLine, line::Line, offset::Offset, raw_style::RawStyle,
-    span_border_correction::StyleCorrectSpan, style::Style, vertical_line::VerticalLine,
+    border::Border,
+    border_char::BorderChar,
+    border_text::BorderText,
+    horizontal_line::HorizontalLine,
+    line::Line,
+    offset::Offset,
+    raw_style::RawStyle,
+    span_border_correction::StyleCorrectSpan,
+    style::{On, Style},
+    vertical_line::VerticalLine,
 };
 
 #[cfg(feature = "color")]
@@ -3,7 +3,7 @@
 
 use std::collections::HashMap;
 
-use papergrid::{records::Records, Borders};
+use papergrid::{records::Records, Borders, GridConfig};
 
 use crate::{
     style::{HorizontalLine, Line, VerticalLine},
@@ -269,6 +269,38 @@ impl RawStyle {
     }
 }
 
+impl RawStyle {
+    /// Sets the style to a [`GridConfig`] of a grid with a given shape.
+    pub(crate) fn set_config(
+        &self,
+        cfg: &mut GridConfig,
+        (count_rows, count_cols): (usize, usize),
+    ) {
+        cfg.clear_theme();
+        cfg.set_borders(self.borders.clone());
+
+        if count_rows > 1 {
+            for (&row, line) in &self.horizontals {
+                if line.is_empty() {
+                    cfg.remove_horizontal_line(row);
+                } else {
+                    cfg.set_horizontal_line(row, papergrid::HorizontalLine::from(*line));
+                }
+            }
+        }
+
+        if count_cols > 1 {
+            for (&col, line) in &self.verticals {
+                if line.is_empty() {
+                    cfg.remove_vertical_line(col);
+                } else {
+                    cfg.set_vertical_line(col, papergrid::VerticalLine::from(*line));
+                }
+            }
+        }
+    }
+}
+
 impl From<Borders<char>> for RawStyle {
     fn from(borders: Borders<char>) -> Self {
         Self {
@@ -297,31 +329,8 @@ where
             return;
         }
 
-        let (count_rows, count_cols) = table.shape();
-
-        let cfg = table.get_config_mut();
-        cfg.clear_theme();
-        cfg.set_borders(self.borders.clone());
-
-        if count_rows > 1 {
-            for (&row, line) in &self.horizontals {
-                if line.is_empty() {
-                    cfg.remove_horizontal_line(row);
-                } else {
-                    cfg.set_horizontal_line(row, papergrid::HorizontalLine::from(*line));
-                }
-            }
-        }
-
-        if count_cols > 1 {
-            for (&col, line) in &self.verticals {
-                if line.is_empty() {
-                    cfg.remove_vertical_line(col);
-                } else {
-                    cfg.set_vertical_line(col, papergrid::VerticalLine::from(*line));
-                }
-            }
-        }
+        let shape = table.shape();
+        self.set_config(table.get_config_mut(), shape);
 
         table.destroy_width_cache();
         table.destroy_height_cache();
@@ -1,5 +1,5 @@
 //! This module contains [`StyleCorrectSpan`] structure, which can be usefull when [`Span`] is used, and
-//! you wan't to fix the intersections symbols which are left intact by default.
+//! you wan't to keep the intersections symbols resolved around spans as borders of cells.
 //!
 //! [`Span`]: crate::Span
 
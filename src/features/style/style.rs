@@ -121,6 +121,36 @@ use super::{HorizontalLine, Line, VerticalLine};
 /// println!("{}", table);
 /// ```
 ///
+/// Presets and setters of corners and intersections are `const`,
+/// so a theme can be defined once as a `static` and applied by a reference.
+///
+/// Setters of borders and split lines, like [`Style::top`] and [`Style::vertical`],
+/// and their `off_*` counterparts are not `const`.
+/// They update custom lines set by [`Style::horizontals`] and [`Style::verticals`],
+/// which are generic iterators and can't be iterated or dropped in a `const` context.
+/// So a theme which changes borders needs to be built at runtime, e.g. by a function.
+///
+/// ```rust
+/// use tabled::{style::On, Style, Table};
+///
+/// static THEME: Style<On, On, On, On, On, On> = Style::modern()
+///     .top_left_corner('╭')
+///     .top_right_corner('╮')
+///     .bottom_left_corner('╰')
+///     .bottom_right_corner('╯');
+///
+/// let table = Table::new(["Hello"]).with(&THEME).to_string();
+///
+/// assert_eq!(
+///     table,
+///     "╭───────╮\n\
+///      │ &str  │\n\
+///      ├───────┤\n\
+///      │ Hello │\n\
+///      ╰───────╯"
+/// );
+/// ```
+///
 /// [`Table`]: crate::Table
 /// [`RawStyle`]: crate::style::RawStyle
 #[derive(Debug, Clone)]
@@ -288,6 +318,32 @@ impl Style<(), (), (), (), (), (), (), ()> {
         )
     }
 
+    /// `org` style mimics an `Emacs Org-mode` table style.
+    ///
+    /// It can be pasted into an `Org` document as it is.
+    ///
+    /// ```text
+    ///     | id | destribution |           link            |
+    ///     |----+--------------+---------------------------|
+    ///     | 0  |    Fedora    |  https://getfedora.org/   |
+    ///     | 2  |   OpenSUSE   | https://www.opensuse.org/ |
+    ///     | 3  | Endeavouros  | https://endeavouros.com/  |
+    /// ```
+    pub const fn org() -> Style<(), (), On, On, (), On, HLineArray<1>> {
+        Style::new(
+            create_borders(
+                Line::empty(),
+                Line::empty(),
+                Line::empty(),
+                Some('|'),
+                Some('|'),
+                Some('|'),
+            ),
+            [HorizontalLine::new(1, Line::full('-', '+', '|', '|'))],
+            [],
+        )
+    }
+
     /// This style is analog of [`Style::ascii`] which uses UTF-8 charset.
     ///
     /// It has vertical and horizontal split lines.
@@ -490,10 +546,9 @@ impl Style<(), (), (), (), (), (), (), ()> {
 
     /// Try to fix the style when table contains spans.
     ///
-    /// By default [`Style`] doesn't implies any logic to better render split lines when
-    /// [`Span`] is used.
-    ///
-    /// So this function can be used to set the split lines in regard of spans used.
+    /// Intersections cut by a [`Span`] are resolved when a table is rendered,
+    /// this function sets the resolved characters as borders of cells,
+    /// so they're kept as they are even if the spans are changed afterwards.
     ///
     /// # Example
     ///
@@ -516,7 +571,7 @@ impl Style<(), (), (), (), (), (), (), ()> {
     /// assert_eq!(
     ///     table.to_string(),
     ///     concat!(
-    ///         "+----+------+------+\n",
+    ///         "+------------------+\n",
     ///         "| date             |\n",
     ///         "+----+------+------+\n",
     ///         "| 09 | June | 2022 |\n",
@@ -920,83 +975,83 @@ impl<T, B, L, R, H, V, HLines, VLines> Style<T, B, L, R, H, V, HLines, VLines> {
 
 impl<B, R, H, V, HLines, VLines> Style<On, B, On, R, H, V, HLines, VLines> {
     /// Sets a top left corner.
-    pub fn top_left_corner(mut self, c: char) -> Self {
+    pub const fn top_left_corner(mut self, c: char) -> Self {
         self.borders.top_left = Some(c);
 
-        Style::new(self.borders, self.horizontals, self.verticals)
+        self
     }
 }
 
 impl<B, L, H, V, HLines, VLines> Style<On, B, L, On, H, V, HLines, VLines> {
     /// Sets a top right corner.
-    pub fn top_right_corner(mut self, c: char) -> Self {
+    pub const fn top_right_corner(mut self, c: char) -> Self {
         self.borders.top_right = Some(c);
 
-        Style::new(self.borders, self.horizontals, self.verticals)
+        self
     }
 }
 
 impl<T, L, H, V, HLines, VLines> Style<T, On, L, On, H, V, HLines, VLines> {
     /// Sets a bottom right corner.
-    pub fn bottom_right_corner(mut self, c: char) -> Self {
+    pub const fn bottom_right_corner(mut self, c: char) -> Self {
         self.borders.bottom_right = Some(c);
 
-        Style::new(self.borders, self.horizontals, self.verticals)
+        self
     }
 }
 
 impl<T, R, H, V, HLines, VLines> Style<T, On, On, R, H, V, HLines, VLines> {
     /// Sets a bottom left corner.
-    pub fn bottom_left_corner(mut self, c: char) -> Self {
+    pub const fn bottom_left_corner(mut self, c: char) -> Self {
         self.borders.bottom_left = Some(c);
 
-        Style::new(self.borders, self.horizontals, self.verticals)
+        self
     }
 }
 
 impl<T, B, R, V, HLines, VLines> Style<T, B, On, R, On, V, HLines, VLines> {
     /// Sets a left intersection char.
-    pub fn left_intersection(mut self, c: char) -> Self {
+    pub const fn left_intersection(mut self, c: char) -> Self {
         self.borders.horizontal_left = Some(c);
 
-        Style::new(self.borders, self.horizontals, self.verticals)
+        self
     }
 }
 
 impl<T, B, L, V, HLines, VLines> Style<T, B, L, On, On, V, HLines, VLines> {
     /// Sets a right intersection char.
-    pub fn right_intersection(mut self, c: char) -> Self {
+    pub const fn right_intersection(mut self, c: char) -> Self {
         self.borders.horizontal_right = Some(c);
 
-        Style::new(self.borders, self.horizontals, self.verticals)
+        self
     }
 }
 
 impl<B, L, R, H, HLines, VLines> Style<On, B, L, R, H, On, HLines, VLines> {
     /// Sets a top intersection char.
-    pub fn top_intersection(mut self, c: char) -> Self {
+    pub const fn top_intersection(mut self, c: char) -> Self {
         self.borders.top_intersection = Some(c);
 
-        Style::new(self.borders, self.horizontals, self.verticals)
+        self
     }
 }
 
 impl<T, L, R, H, HLines, VLines> Style<T, On, L, R, H, On, HLines, VLines> {
     /// Sets a bottom intersection char.
-    pub fn bottom_intersection(mut self, c: char) -> Self {
+    pub const fn bottom_intersection(mut self, c: char) -> Self {
         self.borders.bottom_intersection = Some(c);
 
-        Style::new(self.borders, self.horizontals, self.verticals)
+        self
     }
 }
 
 impl<T, B, L, R, HLines, VLines> Style<T, B, L, R, On, On, HLines, VLines> {
     /// Sets an inner intersection char.
     /// A char between horizontal and vertical split lines.
-    pub fn inner_intersection(mut self, c: char) -> Self {
+    pub const fn inner_intersection(mut self, c: char) -> Self {
         self.borders.intersection = Some(c);
 
-        Style::new(self.borders, self.horizontals, self.verticals)
+        self
     }
 }
 
@@ -1125,6 +1180,18 @@ impl<T, B, L, R, H, V, HLines, VLines> Style<T, B, L, R, H, V, HLines, VLines> {
 }
 
 impl<T, B, L, R, H, V, HLines, VLines, I> TableOption<I> for Style<T, B, L, R, H, V, HLines, VLines>
+where
+    I: Records,
+    HLines: IntoIterator<Item = HorizontalLine> + Clone,
+    VLines: IntoIterator<Item = VerticalLine> + Clone,
+{
+    fn change(&mut self, table: &mut Table<I>) {
+        (&*self).change(table)
+    }
+}
+
+impl<T, B, L, R, H, V, HLines, VLines, I> TableOption<I>
+    for &Style<T, B, L, R, H, V, HLines, VLines>
 where
     I: Records,
     HLines: IntoIterator<Item = HorizontalLine> + Clone,
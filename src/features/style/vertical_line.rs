@@ -21,7 +21,7 @@ impl VerticalLine {
     /// Removes an existing split line by index.
     ///
     /// It not present or in case of index bigger than the count of columns it has no affect.
-    pub fn empty(index: usize) -> Self {
+    pub const fn empty(index: usize) -> Self {
         Self { index, line: None }
     }
 
@@ -0,0 +1,219 @@
+//! This module contains a [`Terminal`] setting which adapts a [`Table`] to capabilities of an output.
+//!
+//! [`Table`]: crate::Table
+
+use std::io::IsTerminal;
+
+use papergrid::records::{Records, RecordsMut};
+
+use crate::{AsciiOnly, StripColors, Table, TableOption};
+
+/// [`Terminal`] downgrades a [`Table`] in case an output doesn't support Unicode or colors,
+/// so the same code produces a fancy table in a terminal and a plain one in a pipe or a CI log.
+///
+/// - In case Unicode is not supported the table is rendered as [`AsciiOnly`],
+///   so box drawing characters of any [`Style`] are replaced by `-`, `|` and `+`.
+/// - In case colors are not supported ANSI sequences are removed from cells,
+///   and with a `color` feature colors of borders, padding and margin are not rendered.
+///
+/// The capabilities are detected once [`Terminal`] is created:
+///
+/// - An output must be a terminal and `TERM` must not be `dumb`.
+/// - Unicode requires an UTF-8 locale set by `LC_ALL`, `LC_CTYPE` or `LANG`.
+/// - Colors are turned off by `NO_COLOR` and turned on by `CLICOLOR_FORCE` even for a pipe.
+/// - On Windows a console is considered capable only in a modern terminal,
+///   which is recognized by `WT_SESSION`, `TERM_PROGRAM`, `ConEmuANSI=ON` or `TERM` being set.
+///
+/// It's supposed to be applied after all other settings which may color a content.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Style, Table, Terminal};
+///
+/// let data = [("Zürich", 12)];
+///
+/// let table = Table::new(data)
+///     .with(Style::modern())
+///     .with(Terminal::new(false, false))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+--------+-----+\n",
+///         "| &str   | i32 |\n",
+///         "+--------+-----+\n",
+///         "| Zurich | 12  |\n",
+///         "+--------+-----+",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Style`]: crate::Style
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Terminal {
+    unicode: bool,
+    colors: bool,
+}
+
+impl Terminal {
+    /// Detects capabilities of [`stdout`].
+    ///
+    /// [`stdout`]: std::io::stdout
+    pub fn stdout() -> Self {
+        Self::detect(std::io::stdout().is_terminal())
+    }
+
+    /// Detects capabilities of [`stderr`].
+    ///
+    /// [`stderr`]: std::io::stderr
+    pub fn stderr() -> Self {
+        Self::detect(std::io::stderr().is_terminal())
+    }
+
+    /// Creates a [`Terminal`] with given capabilities.
+    pub fn new(unicode: bool, colors: bool) -> Self {
+        Self { unicode, colors }
+    }
+
+    /// Checks whether the output supports Unicode box drawing characters.
+    pub fn supports_unicode(&self) -> bool {
+        self.unicode
+    }
+
+    /// Checks whether the output supports colors.
+    pub fn supports_colors(&self) -> bool {
+        self.colors
+    }
+
+    fn detect(is_terminal: bool) -> Self {
+        Self::from_env(is_terminal, cfg!(windows), |name| std::env::var(name).ok())
+    }
+
+    fn from_env<F>(is_terminal: bool, is_windows: bool, var: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let is_set = |name| var(name).filter(|value| !value.is_empty()).is_some();
+
+        let term = var("TERM").unwrap_or_default();
+        let is_capable_console = !is_windows
+            || !term.is_empty()
+            || is_set("WT_SESSION")
+            || is_set("TERM_PROGRAM")
+            || var("ConEmuANSI").as_deref() == Some("ON");
+
+        let is_interactive = is_terminal && term != "dumb" && is_capable_console;
+
+        // Windows terminals which are recognized above support Unicode regardless of a locale
+        let unicode = is_interactive && (is_windows || is_utf8_locale(&var));
+
+        // https://no-color.org and https://bixense.com/clicolors
+        let is_forced =
+            matches!(var("CLICOLOR_FORCE").as_deref(), Some(v) if !v.is_empty() && v != "0");
+        let colors = !is_set("NO_COLOR") && (is_forced || is_interactive);
+
+        Self { unicode, colors }
+    }
+}
+
+impl<R> TableOption<R> for Terminal
+where
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        if !self.unicode {
+            table.with(AsciiOnly);
+        }
+
+        if !self.colors {
+            table.with(StripColors);
+
+            #[cfg(feature = "color")]
+            table.set_colors_enabled(false);
+        }
+    }
+}
+
+/// Checks a locale the same way as C library does, the first variable which is set is used.
+fn is_utf8_locale<F>(var: F) -> bool
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .find_map(|name| var(name).filter(|value| !value.is_empty()));
+
+    match locale {
+        Some(locale) => {
+            let locale = locale.to_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detect(is_terminal: bool, is_windows: bool, vars: &[(&str, &str)]) -> (bool, bool) {
+        let terminal = Terminal::from_env(is_terminal, is_windows, |name| {
+            vars.iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, value)| value.to_string())
+        });
+
+        (terminal.supports_unicode(), terminal.supports_colors())
+    }
+
+    #[test]
+    fn unix_terminal() {
+        let vars = [("TERM", "xterm-256color"), ("LANG", "en_US.UTF-8")];
+        assert_eq!(detect(true, false, &vars), (true, true));
+        assert_eq!(detect(false, false, &vars), (false, false));
+
+        assert_eq!(detect(true, false, &[("TERM", "xterm")]), (false, true));
+        assert_eq!(
+            detect(true, false, &[("TERM", "dumb"), ("LANG", "C.utf8")]),
+            (false, false)
+        );
+        assert_eq!(
+            detect(true, false, &[("LC_ALL", "C"), ("LANG", "en_US.UTF-8")]),
+            (false, true)
+        );
+    }
+
+    #[test]
+    fn windows_console() {
+        assert_eq!(detect(true, true, &[]), (false, false));
+        assert_eq!(detect(true, true, &[("WT_SESSION", "1")]), (true, true));
+        assert_eq!(detect(true, true, &[("ConEmuANSI", "ON")]), (true, true));
+        assert_eq!(detect(true, true, &[("ConEmuANSI", "OFF")]), (false, false));
+    }
+
+    #[test]
+    fn color_variables() {
+        let vars = [
+            ("TERM", "xterm"),
+            ("LANG", "en_US.UTF-8"),
+            ("NO_COLOR", "1"),
+        ];
+        assert_eq!(detect(true, false, &vars), (true, false));
+
+        assert_eq!(
+            detect(false, false, &[("CLICOLOR_FORCE", "1")]),
+            (false, true)
+        );
+        assert_eq!(
+            detect(false, false, &[("CLICOLOR_FORCE", "0")]),
+            (false, false)
+        );
+        assert_eq!(
+            detect(false, false, &[("CLICOLOR_FORCE", "1"), ("NO_COLOR", "1")]),
+            (false, false)
+        );
+    }
+}
@@ -0,0 +1,251 @@
+//! This module contains a [`Viewport`] setting which keeps only a window of a [`Table`].
+//!
+//! [`Table`]: crate::Table
+
+use std::ops::RangeBounds;
+
+use papergrid::{
+    records::{Records, RecordsMut, Resizable},
+    width::CfgWidthFunction,
+};
+
+use crate::{object::bounds_to_usize, Table, TableOption};
+
+/// [`Viewport`] keeps only a rectangular window of rows and columns of a [`Table`],
+/// which is handy for pagers and scrolling over big data sets.
+///
+/// In case the table has a header it's always kept,
+/// and rows of the window are counted after it like in [`Table::pages`].
+///
+/// Spans which cross an edge of the window are cut by it.
+///
+/// A marker can be set to add a row or a column of markers on a side where something was cut off.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Style, Table, Viewport};
+///
+/// let data = (0..5).map(|i| [i, i * 10, i * 100, i * 1000]);
+///
+/// let table = Table::new(data)
+///     .with(Viewport::new(1..3, 1..3).marker("…"))
+///     .with(Style::modern())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "┌───┬────┬─────┬───┐\n\
+///      │ … │ 1  │ 2   │ … │\n\
+///      ├───┼────┼─────┼───┤\n\
+///      │ … │ …  │ …   │ … │\n\
+///      ├───┼────┼─────┼───┤\n\
+///      │ … │ 10 │ 100 │ … │\n\
+///      ├───┼────┼─────┼───┤\n\
+///      │ … │ 20 │ 200 │ … │\n\
+///      ├───┼────┼─────┼───┤\n\
+///      │ … │ …  │ …   │ … │\n\
+///      └───┴────┴─────┴───┘"
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Table::pages`]: crate::Table::pages
+#[derive(Debug, Clone)]
+pub struct Viewport<R, C> {
+    rows: R,
+    columns: C,
+    marker: Option<String>,
+}
+
+impl<R, C> Viewport<R, C>
+where
+    R: RangeBounds<usize>,
+    C: RangeBounds<usize>,
+{
+    /// Creates a [`Viewport`] of a range of rows and a range of columns.
+    ///
+    /// Ranges which are out of bounds are cut by the table shape.
+    pub fn new(rows: R, columns: C) -> Self {
+        Self {
+            rows,
+            columns,
+            marker: None,
+        }
+    }
+
+    /// Sets a text of cells which mark rows and columns out of the window.
+    pub fn marker<S>(mut self, marker: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.marker = Some(marker.into());
+        self
+    }
+}
+
+impl<R, C, RR> TableOption<RR> for Viewport<R, C>
+where
+    R: RangeBounds<usize>,
+    C: RangeBounds<usize>,
+    RR: Records + RecordsMut<String> + Resizable,
+{
+    fn change(&mut self, table: &mut Table<RR>) {
+        let (count_rows, count_cols) = table.shape();
+        if count_rows == 0 || count_cols == 0 {
+            return;
+        }
+
+        let count_header = if table.has_header() { 1 } else { 0 };
+        let count_data = count_rows - count_header;
+
+        let rows = bounds_to_usize(self.rows.start_bound(), self.rows.end_bound(), count_data);
+        let rows = clamp_range(rows, count_data);
+        let cols = bounds_to_usize(
+            self.columns.start_bound(),
+            self.columns.end_bound(),
+            count_cols,
+        );
+        let cols = clamp_range(cols, count_cols);
+
+        let kept_rows = (0..count_header)
+            .chain(rows.0 + count_header..rows.1 + count_header)
+            .collect::<Vec<_>>();
+        let kept_cols = (cols.0..cols.1).collect::<Vec<_>>();
+
+        let spans = cut_spans(table, &kept_rows, &kept_cols);
+
+        for row in (0..count_rows).rev().filter(|row| !kept_rows.contains(row)) {
+            table.get_records_mut().remove_row(row);
+            table.remove_row_aside(row);
+        }
+
+        let records = table.get_records_mut();
+        for col in (0..count_cols).rev().filter(|col| !kept_cols.contains(col)) {
+            records.remove_column(col);
+        }
+
+        let ctrl = CfgWidthFunction::from_cfg(table.get_config());
+        for span in spans {
+            if let Some(text) = span.text {
+                table.get_records_mut().set(span.pos, text, &ctrl);
+            }
+
+            match span.kind {
+                SpanKind::Column => table.get_config_mut().set_column_span(span.pos, span.size),
+                SpanKind::Row => table.get_config_mut().set_row_span(span.pos, span.size),
+            }
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+
+        let marker = match &self.marker {
+            Some(marker) => marker,
+            None => return,
+        };
+
+        let original_count_cols = count_cols;
+        let (count_rows, count_cols) = table.shape();
+        if rows.1 < count_data {
+            table.insert_row(count_rows, vec![marker.as_str(); count_cols]);
+        }
+
+        if rows.0 > 0 {
+            table.insert_row(count_header, vec![marker.as_str(); count_cols]);
+        }
+
+        let count_rows = table.count_rows();
+        if cols.1 < original_count_cols {
+            let values = vec![marker.as_str(); count_rows];
+            table.insert_column(count_cols, marker.as_str(), values);
+        }
+
+        if cols.0 > 0 {
+            let values = vec![marker.as_str(); count_rows];
+            table.insert_column(0, marker.as_str(), values);
+        }
+    }
+}
+
+#[derive(Debug)]
+enum SpanKind {
+    Column,
+    Row,
+}
+
+#[derive(Debug)]
+struct CutSpan {
+    kind: SpanKind,
+    pos: (usize, usize),
+    size: usize,
+    text: Option<String>,
+}
+
+fn clamp_range((start, end): (usize, usize), count: usize) -> (usize, usize) {
+    let end = end.min(count);
+    (start.min(end), end)
+}
+
+/// Resets all spans and returns the ones which are visible in the window,
+/// with positions mapped into it.
+fn cut_spans<R>(table: &mut Table<R>, kept_rows: &[usize], kept_cols: &[usize]) -> Vec<CutSpan>
+where
+    R: Records,
+{
+    let shape = table.shape();
+    let find = |list: &[usize], i: usize| list.iter().position(|&j| j == i);
+    let cut = |list: &[usize], start: usize, span: usize| {
+        let first = list.iter().position(|&i| i >= start && i < start + span)?;
+        let size = list[first..]
+            .iter()
+            .take_while(|&&i| i < start + span)
+            .count();
+        Some((first, size))
+    };
+
+    let column_spans = table
+        .get_config()
+        .iter_column_spans(shape)
+        .collect::<Vec<_>>();
+    let row_spans = table.get_config().iter_row_spans(shape).collect::<Vec<_>>();
+
+    let mut spans = Vec::new();
+    for &((row, col), span) in &column_spans {
+        table.get_config_mut().set_column_span((row, col), 1);
+
+        if let (Some(new_row), Some((new_col, size))) =
+            (find(kept_rows, row), cut(kept_cols, col, span))
+        {
+            let text = (kept_cols[new_col] != col)
+                .then(|| table.get_records().get_text((row, col)).to_owned());
+
+            spans.push(CutSpan {
+                kind: SpanKind::Column,
+                pos: (new_row, new_col),
+                size,
+                text,
+            });
+        }
+    }
+
+    for &((row, col), span) in &row_spans {
+        table.get_config_mut().set_row_span((row, col), 1);
+
+        if let (Some((new_row, size)), Some(new_col)) =
+            (cut(kept_rows, row, span), find(kept_cols, col))
+        {
+            let text = (kept_rows[new_row] != row)
+                .then(|| table.get_records().get_text((row, col)).to_owned());
+
+            spans.push(CutSpan {
+                kind: SpanKind::Row,
+                pos: (new_row, new_col),
+                size,
+                text,
+            });
+        }
+    }
+
+    spans
+}
@@ -0,0 +1,68 @@
+//! This module contains [`CharReplacement`] structure, used to set a character
+//! which takes place of a wide character split by [`Truncate`] or [`Wrap`].
+//!
+//! [`Truncate`]: crate::width::Truncate
+//! [`Wrap`]: crate::width::Wrap
+
+use crate::{Table, TableOption};
+
+/// A character which is used by default in place of a split wide character.
+pub(crate) const DEFAULT_REPLACEMENT: char = '\u{FFFD}';
+
+/// [`CharReplacement`] sets a character which is used in place of a wide character,
+/// like an emoji or a hieroglyph, which doesn't fit into a width set by [`Truncate`] or [`Wrap`].
+///
+/// The character is put as many times as many columns of a wide character are left.
+/// By default it's a replacement character `U+FFFD` (`�`).
+///
+/// It must be set before [`Truncate`] or [`Wrap`] are applied.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{object::Segment, width::CharReplacement, Modify, Style, Table, Width};
+///
+/// let table = Table::new(["你好世界"])
+///     .with(Style::markdown())
+///     .with(CharReplacement::new('.'))
+///     .with(Modify::new(Segment::all()).with(Width::truncate(3)))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "| &st |\n\
+///      |-----|\n\
+///      | 你. |"
+/// );
+/// ```
+///
+/// [`Truncate`]: crate::width::Truncate
+/// [`Wrap`]: crate::width::Wrap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CharReplacement(Option<char>);
+
+impl CharReplacement {
+    /// Creates a [`CharReplacement`] with a given character.
+    pub fn new(c: char) -> Self {
+        Self(Some(c))
+    }
+
+    /// Creates a [`CharReplacement`] which removes a split character without a replacement.
+    ///
+    /// In such case a cell may be narrower than the width it was set to.
+    pub fn none() -> Self {
+        Self(None)
+    }
+}
+
+impl Default for CharReplacement {
+    fn default() -> Self {
+        Self::new(DEFAULT_REPLACEMENT)
+    }
+}
+
+impl<R> TableOption<R> for CharReplacement {
+    fn change(&mut self, table: &mut Table<R>) {
+        table.set_char_replacement(self.0);
+    }
+}
@@ -0,0 +1,253 @@
+//! This module contains [`ColumnConstraints`] structure, used to distribute a width of a [`Table`] among columns.
+
+use papergrid::records::{empty::EmptyRecords, Records, RecordsMut};
+
+use crate::{measurment::Measurment, CellOption, Table, TableOption, Width};
+
+use super::{get_table_widths, truncate::get_decrease_cell_list, Wrap};
+
+/// [`ColumnConstraints`] sets a min and a max width and a grow weight of columns,
+/// and distributes a width of a table among them, like a layout of an HTML table.
+///
+/// Widths of columns are clamped by their limits first.
+/// In case a total width is set, a space left is given to columns proportionally to their weights,
+/// and a space which is lacking is taken from the widest columns, but not below their min width.
+/// Cells which don't fit a column are wrapped.
+///
+/// Widths include padding, but not borders.
+/// By default a column has no limits and a weight of 1,
+/// a column with a weight of 0 doesn't grow.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Style, Table, Width};
+///
+/// let data = [("tabled", "An easy to use library for pretty print tables of Rust structs and enums.")];
+///
+/// let table = Table::new(data)
+///     .with(Style::psql())
+///     .with(Width::constraints().min(0, 12).max(1, 32).total(40).keep_words())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str       | &str                      \n",
+///         "------------+---------------------------\n",
+///         " tabled     | An easy to use library    \n",
+///         "            | for pretty print tables   \n",
+///         "            | of Rust structs and       \n",
+///         "            | enums.                    ",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone)]
+pub struct ColumnConstraints<W = usize> {
+    columns: Vec<Constraint>,
+    total: Option<W>,
+    keep_words: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Constraint {
+    min: usize,
+    max: usize,
+    grow: usize,
+}
+
+impl Default for Constraint {
+    fn default() -> Self {
+        Self {
+            min: 0,
+            max: usize::MAX,
+            grow: 1,
+        }
+    }
+}
+
+impl ColumnConstraints {
+    /// Creates a [`ColumnConstraints`] without limits.
+    pub fn new() -> Self {
+        Self {
+            columns: Vec::new(),
+            total: None,
+            keep_words: false,
+        }
+    }
+}
+
+impl Default for ColumnConstraints {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W> ColumnConstraints<W> {
+    /// Sets a min width of a column.
+    pub fn min(mut self, column: usize, width: usize) -> Self {
+        self.column_mut(column).min = width;
+        self
+    }
+
+    /// Sets a max width of a column.
+    pub fn max(mut self, column: usize, width: usize) -> Self {
+        self.column_mut(column).max = width;
+        self
+    }
+
+    /// Sets a weight by which a column gets a space left.
+    pub fn grow(mut self, column: usize, weight: usize) -> Self {
+        self.column_mut(column).grow = weight;
+        self
+    }
+
+    /// Sets a total width of a table, including borders.
+    pub fn total<T>(self, width: T) -> ColumnConstraints<T>
+    where
+        T: Measurment<Width>,
+    {
+        ColumnConstraints {
+            columns: self.columns,
+            total: Some(width),
+            keep_words: self.keep_words,
+        }
+    }
+
+    /// Wraps cells keeping words, see [`Wrap::keep_words`].
+    pub fn keep_words(mut self) -> Self {
+        self.keep_words = true;
+        self
+    }
+
+    fn column_mut(&mut self, column: usize) -> &mut Constraint {
+        if column >= self.columns.len() {
+            self.columns.resize(column + 1, Constraint::default());
+        }
+
+        &mut self.columns[column]
+    }
+
+    fn column(&self, column: usize) -> Constraint {
+        self.columns.get(column).copied().unwrap_or_default()
+    }
+}
+
+impl<W, R> TableOption<R> for ColumnConstraints<W>
+where
+    W: Measurment<Width>,
+    R: Records + RecordsMut<String>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        if table.is_empty() {
+            return;
+        }
+
+        let (count_rows, count_cols) = table.shape();
+        let min_widths = get_table_widths(
+            EmptyRecords::new(count_rows, count_cols),
+            table.get_config(),
+        );
+
+        let (widths, total_width) = table.estimate_widths_with_total();
+        let borders_width = total_width - widths.iter().sum::<usize>();
+
+        let limits = (0..count_cols)
+            .map(|col| {
+                let constraint = self.column(col);
+                let min = constraint.min.max(min_widths[col]);
+                let max = constraint.max.max(min);
+                (min, max, constraint.grow)
+            })
+            .collect::<Vec<_>>();
+
+        let mut widths = widths
+            .iter()
+            .zip(&limits)
+            .map(|(&width, &(min, max, _))| width.clamp(min, max))
+            .collect::<Vec<_>>();
+
+        if let Some(total) = &self.total {
+            let total = total.measure(table.get_records(), table.get_config());
+            let width = total.saturating_sub(borders_width);
+            let current = widths.iter().sum::<usize>();
+            if current < width {
+                grow_widths(&mut widths, &limits, width - current);
+            } else {
+                shrink_widths(&mut widths, &limits, current - width);
+            }
+        }
+
+        let points = get_decrease_cell_list(
+            table.get_config(),
+            &widths,
+            &min_widths,
+            (count_rows, count_cols),
+        );
+
+        for ((row, col), width) in points {
+            let mut wrap = Wrap::new(width);
+            if self.keep_words {
+                wrap = wrap.keep_words();
+            }
+
+            wrap.change_cell(table, (row, col).into());
+        }
+
+        table.destroy_height_cache();
+        table.destroy_width_cache();
+        table.cache_width(widths);
+    }
+}
+
+fn grow_widths(widths: &mut [usize], limits: &[(usize, usize, usize)], mut space: usize) {
+    while space > 0 {
+        let columns = (0..widths.len())
+            .filter(|&col| limits[col].2 > 0 && widths[col] < limits[col].1)
+            .collect::<Vec<_>>();
+        if columns.is_empty() {
+            return;
+        }
+
+        let weights = columns.iter().map(|&col| limits[col].2).sum::<usize>();
+
+        let mut given = 0;
+        for &col in &columns {
+            let share = space * limits[col].2 / weights;
+            let share = share.min(limits[col].1 - widths[col]);
+            widths[col] += share;
+            given += share;
+        }
+
+        // a space left after rounding is given one by one
+        if given == 0 {
+            for &col in &columns {
+                if given == space {
+                    break;
+                }
+
+                widths[col] += 1;
+                given += 1;
+            }
+        }
+
+        space -= given;
+    }
+}
+
+fn shrink_widths(widths: &mut [usize], limits: &[(usize, usize, usize)], mut space: usize) {
+    while space > 0 {
+        let widest = (0..widths.len())
+            .filter(|&col| widths[col] > limits[col].0)
+            .max_by_key(|&col| (widths[col] - limits[col].0, usize::MAX - col));
+
+        match widest {
+            Some(col) => widths[col] -= 1,
+            None => return,
+        }
+
+        space -= 1;
+    }
+}
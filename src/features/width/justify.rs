@@ -38,7 +38,7 @@ use crate::{
 /// ```
 ///
 /// [`Padding`]: crate::Padding
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Justify<W> {
     width: W,
 }
@@ -14,8 +14,6 @@ use crate::{
     CellOption, Table, TableOption, Width,
 };
 
-use super::get_table_widths_with_total;
-
 /// [`MinWidth`] changes a content in case if it's length is lower then the boundary.
 ///
 /// It can be applied to a whole table.
@@ -51,7 +49,7 @@ use super::get_table_widths_with_total;
 /// ```
 ///
 /// [`Padding`]: crate::Padding
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MinWidth<W = usize, P = PriorityNone> {
     width: W,
     fill: char,
@@ -138,8 +136,7 @@ where
         }
 
         let width = self.width.measure(table.get_records(), table.get_config());
-        let (widths, total_width) =
-            get_table_widths_with_total(table.get_records(), table.get_config());
+        let (widths, total_width) = table.estimate_widths_with_total();
         if total_width >= width {
             return;
         }
@@ -159,7 +156,7 @@ fn increase_width(s: &str, width: usize, fill_with: char) -> String {
                 let remain = width - length;
                 let mut new_line = String::with_capacity(width);
                 new_line.push_str(line);
-                new_line.extend(std::iter::repeat(fill_with).take(remain));
+                new_line.extend((0..remain).map(|_| fill_with));
                 std::borrow::Cow::Owned(new_line)
             } else {
                 std::borrow::Cow::Borrowed(line)
@@ -179,7 +176,7 @@ fn increase_width(s: &str, width: usize, fill_with: char) -> String {
             if length < width {
                 let mut line = line.into_owned();
                 let remain = width - length;
-                line.extend(std::iter::repeat(fill_with).take(remain));
+                line.extend((0..remain).map(|_| fill_with));
                 std::borrow::Cow::Owned(line)
             } else {
                 line
@@ -3,6 +3,8 @@
 //! - [`Truncate`] cuts a cell content to limit width.
 //! - [`Wrap`] split the content via new lines in order to fit max width.
 //! - [`Justify`] sets columns width to the same value.
+//! - [`CharReplacement`] sets a character which takes place of a split wide character.
+//! - [`ColumnConstraints`] distributes a table width among columns by their limits.
 //!
 //! To set a a table width, a combination of [`Width::truncate`] or [`Width::wrap`] and [`Width::increase`] can be used.
 //!
@@ -32,15 +34,21 @@
 //! );
 //! ```
 
+mod char_replacement;
+mod constraints;
 mod justify;
 mod min_width;
 mod truncate;
 mod width_list;
 mod wrap;
 
+use std::borrow::Cow;
+
 use crate::measurment::Measurment;
 
 pub use self::{
+    char_replacement::CharReplacement,
+    constraints::ColumnConstraints,
     justify::Justify,
     min_width::MinWidth,
     truncate::{SuffixLimit, Truncate},
@@ -50,7 +58,7 @@ pub use self::{
 
 use papergrid::{records::Records, width::WidthEstimator, Estimate, GridConfig};
 
-pub(crate) use wrap::wrap_text;
+pub(crate) use self::{char_replacement::DEFAULT_REPLACEMENT, wrap::wrap_text};
 
 /// Width allows you to set a min and max width of an object on a [`Table`]
 /// using different strategies.
@@ -178,6 +186,11 @@ impl Width {
     {
         WidthList::new(rows.into_iter().collect())
     }
+
+    /// Returns a [`ColumnConstraints`] structure.
+    pub fn constraints() -> ColumnConstraints {
+        ColumnConstraints::new()
+    }
 }
 
 pub(crate) fn get_table_widths<R>(records: R, cfg: &GridConfig) -> Vec<usize>
@@ -189,6 +202,118 @@ where
     evaluator.into()
 }
 
+/// Estimates widths of columns in parallel.
+///
+/// Spans are rare in big tables so in case of them a sequential estimation is used.
+#[cfg(feature = "parallel")]
+pub(crate) fn get_table_widths_parallel<R>(records: &R, cfg: &GridConfig) -> Vec<usize>
+where
+    R: Records + Sync,
+{
+    use papergrid::width::CfgWidthFunction;
+    use rayon::prelude::*;
+
+    if cfg.has_column_spans() {
+        return get_table_widths(records, cfg);
+    }
+
+    let ctrl = CfgWidthFunction::from_cfg(cfg);
+    let shape = (records.count_rows(), records.count_columns());
+    (0..shape.1)
+        .into_par_iter()
+        .map(|col| {
+            (0..shape.0)
+                .into_par_iter()
+                .filter(|&row| cfg.is_cell_visible((row, col), shape))
+                .map(|row| {
+                    let padding = cfg.get_padding((row, col).into());
+                    records.get_width((row, col), &ctrl) + padding.left.size + padding.right.size
+                })
+                .max()
+                .unwrap_or(0)
+        })
+        .collect()
+}
+
+/// The function cuts the string to a specific width,
+/// a split wide character is replaced by a given character.
+pub(crate) fn cut_str(
+    text: &str,
+    width: usize,
+    replacement: Option<char>,
+    cjk: bool,
+) -> Cow<'_, str> {
+    use papergrid::util::{split_at_pos, split_at_pos_cjk};
+
+    if replacement == Some(DEFAULT_REPLACEMENT) {
+        return if cjk {
+            papergrid::util::cut_str_cjk(text, width)
+        } else {
+            papergrid::util::cut_str(text, width)
+        };
+    }
+
+    let split_at_pos = if cjk { split_at_pos_cjk } else { split_at_pos };
+
+    #[cfg(not(feature = "color"))]
+    {
+        let (length, count_unknowns, _) = split_at_pos(text, width);
+        let text = &text[..length];
+        if count_unknowns == 0 || replacement.is_none() {
+            return Cow::Borrowed(text);
+        }
+
+        let mut buf = text.to_owned();
+        push_replacement(&mut buf, replacement, count_unknowns);
+
+        Cow::Owned(buf)
+    }
+
+    #[cfg(feature = "color")]
+    {
+        let stripped = ansi_str::AnsiStr::ansi_strip(text);
+        let (length, count_unknowns, _) = split_at_pos(&stripped, width);
+
+        let mut buf = ansi_str::AnsiStr::ansi_cut(text, ..length);
+        if count_unknowns > 0 && replacement.is_some() {
+            let mut b = buf.into_owned();
+            push_replacement(&mut b, replacement, count_unknowns);
+            buf = Cow::Owned(b);
+        }
+
+        buf
+    }
+}
+
+/// Puts a replacement of a split wide character `n` times, if there's any.
+pub(crate) fn push_replacement(buf: &mut String, replacement: Option<char>, n: usize) {
+    if let Some(c) = replacement {
+        buf.extend((0..n).map(|_| c));
+    }
+}
+
+/// Returns a width of a character,
+/// an ambiguous width character is considered wide if `cjk` is set.
+pub(crate) fn char_width(c: char, cjk: bool) -> usize {
+    let width = if cjk {
+        unicode_width::UnicodeWidthChar::width_cjk(c)
+    } else {
+        unicode_width::UnicodeWidthChar::width(c)
+    };
+
+    width.unwrap_or(0)
+}
+
+/// Returns a width of a text which has no ANSI sequences,
+/// an ambiguous width character is considered wide if `cjk` is set.
+pub(crate) fn text_width(text: &str, cjk: bool) -> usize {
+    if cjk {
+        unicode_width::UnicodeWidthStr::width_cjk(text)
+    } else {
+        unicode_width::UnicodeWidthStr::width(text)
+    }
+}
+
 pub(crate) fn count_borders(
     cfg: &GridConfig,
     start: usize,
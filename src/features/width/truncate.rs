@@ -4,14 +4,13 @@ use std::{borrow::Cow, marker::PhantomData};
 
 use papergrid::{
     records::{empty::EmptyRecords, Records, RecordsMut},
-    util::cut_str,
     width::{CfgWidthFunction, WidthFunc},
     Entity, GridConfig,
 };
 
 use crate::{
     peaker::{Peaker, PriorityNone},
-    width::{count_borders, get_table_widths, get_table_widths_with_total, Measurment},
+    width::{count_borders, cut_str, get_table_widths, Measurment},
     CellOption, Table, TableOption, Width,
 };
 
@@ -33,14 +32,14 @@ use crate::{
 /// ```
 ///
 /// [`Padding`]: crate::Padding
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Truncate<'a, W = usize, P = PriorityNone> {
     width: W,
     suffix: Option<TruncateSuffix<'a>>,
     _priority: PhantomData<P>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct TruncateSuffix<'a> {
     text: Cow<'a, str>,
     limit: SuffixLimit,
@@ -156,6 +155,8 @@ where
     fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
         let width_ctrl = CfgWidthFunction::from_cfg(table.get_config());
         let set_width = self.width.measure(table.get_records(), table.get_config());
+        let replacement = table.get_char_replacement();
+        let cjk = table.get_config().is_ambiguous_wide();
 
         let mut width = set_width;
         let suffix = match self.suffix.as_ref() {
@@ -169,11 +170,11 @@ where
                         SuffixLimit::Ignore => Cow::Borrowed(""),
                         SuffixLimit::Cut => {
                             width = 0;
-                            cut_str(&suffix.text, set_width)
+                            cut_str(&suffix.text, set_width, replacement, cjk)
                         }
                         SuffixLimit::Replace(c) => {
                             width = 0;
-                            Cow::Owned(std::iter::repeat(c).take(set_width).collect())
+                            Cow::Owned((0..set_width).map(|_| c).collect())
                         }
                     }
                 }
@@ -204,8 +205,16 @@ where
             //       We could eliminate this allcation if we would be allowed to cut '\t' with unknown characters.
             //       Currently we don't do that.
             let text = papergrid::util::replace_tab(text, table.get_config().get_tab_width());
-            let text = truncate_text(&text, width, set_width, &suffix, suffix_color_try_keeping)
-                .into_owned();
+            let text = truncate_text(
+                &text,
+                width,
+                set_width,
+                &suffix,
+                suffix_color_try_keeping,
+                replacement,
+                cjk,
+            )
+            .into_owned();
 
             let records = table.get_records_mut();
             records.set(pos, text, &width_ctrl);
@@ -227,8 +236,7 @@ where
         }
 
         let width = self.width.measure(table.get_records(), table.get_config());
-        let (widths, total_width) =
-            get_table_widths_with_total(table.get_records(), table.get_config());
+        let (widths, total_width) = table.estimate_widths_with_total();
         if total_width <= width {
             return;
         }
@@ -250,6 +258,8 @@ fn truncate_text<'a>(
     original_width: usize,
     suffix: &'a str,
     _suffix_color_try_keeping: bool,
+    replacement: Option<char>,
+    cjk: bool,
 ) -> Cow<'a, str> {
     if width == 0 {
         if original_width == 0 {
@@ -258,7 +268,7 @@ fn truncate_text<'a>(
             Cow::Borrowed(suffix)
         }
     } else {
-        let content = cut_str(content, width);
+        let content = cut_str(content, width, replacement, cjk);
 
         if suffix.is_empty() {
             content
@@ -2,7 +2,7 @@ use std::iter::FromIterator;
 
 use papergrid::records::Records;
 
-use crate::{Table, TableOption};
+use crate::{SettingKind, Table, TableOption};
 
 /// A structure used to set [`Table`] width via a list of columns widths.
 #[derive(Debug)]
@@ -44,4 +44,8 @@ where
         table.cache_width(self.list.clone());
         table.destroy_height_cache();
     }
+
+    fn kind(&self) -> Option<SettingKind> {
+        Some(SettingKind::Widths(self.list.len()))
+    }
 }
@@ -17,7 +17,7 @@ use crate::{
 };
 
 use super::{
-    get_table_widths, get_table_widths_with_total,
+    char_width, get_table_widths, push_replacement, text_width,
     truncate::{decrease_widths, get_decrease_cell_list},
 };
 
@@ -99,6 +99,8 @@ where
     fn change_cell(&mut self, table: &mut Table<R>, entity: Entity) {
         let width_ctrl = CfgWidthFunction::from_cfg(table.get_config());
         let width = self.width.measure(table.get_records(), table.get_config());
+        let replacement = table.get_char_replacement();
+        let cjk = table.get_config().is_ambiguous_wide();
 
         let (count_rows, count_cols) = table.shape();
         for pos in entity.iter(count_rows, count_cols) {
@@ -113,7 +115,7 @@ where
             //       We could eliminate this allcation if we would be allowed to cut '\t' with unknown characters.
             //       Currently we don't do that.
             let text = papergrid::util::replace_tab(text, table.get_config().get_tab_width());
-            let wrapped = wrap_text(&text, width, self.keep_words);
+            let wrapped = wrap_text(&text, width, self.keep_words, replacement, cjk);
 
             debug_assert!(
                 width >= string_width_multiline(&wrapped),
@@ -143,8 +145,7 @@ where
         }
 
         let width = self.width.measure(table.get_records(), table.get_config());
-        let (widths, total_width) =
-            get_table_widths_with_total(table.get_records(), table.get_config());
+        let (widths, total_width) = table.estimate_widths_with_total();
         if width >= total_width {
             return;
         }
@@ -186,20 +187,32 @@ fn wrap_total_width<R, P>(
 }
 
 #[cfg(not(feature = "color"))]
-pub(crate) fn wrap_text(text: &str, width: usize, keep_words: bool) -> String {
+pub(crate) fn wrap_text(
+    text: &str,
+    width: usize,
+    keep_words: bool,
+    replacement: Option<char>,
+    cjk: bool,
+) -> String {
     if width == 0 {
         return String::new();
     }
 
     if keep_words {
-        split_keeping_words(text, width, "\n")
+        split_keeping_words(text, width, "\n", replacement, cjk)
     } else {
-        chunks(text, width).join("\n")
+        chunks(text, width, replacement, cjk).join("\n")
     }
 }
 
 #[cfg(feature = "color")]
-pub(crate) fn wrap_text(text: &str, width: usize, keep_words: bool) -> String {
+pub(crate) fn wrap_text(
+    text: &str,
+    width: usize,
+    keep_words: bool,
+    replacement: Option<char>,
+    cjk: bool,
+) -> String {
     use papergrid::util::strip_osc;
 
     if width == 0 {
@@ -210,9 +223,9 @@ pub(crate) fn wrap_text(text: &str, width: usize, keep_words: bool) -> String {
     let (prefix, suffix) = build_link_prefix_suffix(url);
 
     if keep_words {
-        split_keeping_words(&text, width, &prefix, &suffix)
+        split_keeping_words(&text, width, &prefix, &suffix, replacement, cjk)
     } else {
-        chunks(&text, width, &prefix, &suffix).join("\n")
+        chunks(&text, width, &prefix, &suffix, replacement, cjk).join("\n")
     }
 }
 
@@ -231,21 +244,19 @@ fn build_link_prefix_suffix(url: Option<String>) -> (String, String) {
 }
 
 #[cfg(not(feature = "color"))]
-fn chunks(s: &str, width: usize) -> Vec<String> {
+fn chunks(s: &str, width: usize, replacement: Option<char>, cjk: bool) -> Vec<String> {
     if width == 0 {
         return Vec::new();
     }
 
-    const REPLACEMENT: char = '\u{FFFD}';
-
     let mut buf = String::with_capacity(width);
     let mut list = Vec::new();
     let mut i = 0;
     for c in s.chars() {
-        let c_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+        let c_width = char_width(c, cjk);
         if i + c_width > width {
             let count_unknowns = width - i;
-            buf.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
+            push_replacement(&mut buf, replacement, count_unknowns);
             i += count_unknowns;
         } else {
             buf.push(c);
@@ -267,7 +278,14 @@ fn chunks(s: &str, width: usize) -> Vec<String> {
 }
 
 #[cfg(feature = "color")]
-fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
+fn chunks(
+    s: &str,
+    width: usize,
+    prefix: &str,
+    suffix: &str,
+    replacement: Option<char>,
+    cjk: bool,
+) -> Vec<String> {
     use std::fmt::Write;
 
     if width == 0 {
@@ -291,7 +309,7 @@ fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
         while !part.is_empty() {
             let available_space = width - line_width;
 
-            let part_width = unicode_width::UnicodeWidthStr::width(part);
+            let part_width = text_width(part, cjk);
             if part_width <= available_space {
                 line.push_str(part);
                 line_width += part_width;
@@ -309,15 +327,14 @@ fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
                 break;
             }
 
-            let (lhs, rhs, (unknowns, split_char)) = split_string_at(part, available_space);
+            let (lhs, rhs, (unknowns, split_char)) = split_string_at(part, available_space, cjk);
 
             part = &rhs[split_char..];
 
             line.push_str(lhs);
-            line_width += unicode_width::UnicodeWidthStr::width(lhs);
+            line_width += text_width(lhs, cjk);
 
-            const REPLACEMENT: char = '\u{FFFD}';
-            line.extend(std::iter::repeat(REPLACEMENT).take(unknowns));
+            push_replacement(&mut line, replacement, unknowns);
             line_width += unknowns;
 
             if line_width == width {
@@ -345,9 +362,13 @@ fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
 }
 
 #[cfg(not(feature = "color"))]
-fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
-    const REPLACEMENT: char = '\u{FFFD}';
-
+fn split_keeping_words(
+    s: &str,
+    width: usize,
+    sep: &str,
+    replacement: Option<char>,
+    cjk: bool,
+) -> String {
     let mut lines = Vec::new();
     let mut line = String::with_capacity(width);
     let mut line_width = 0;
@@ -368,7 +389,7 @@ fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
             is_first_word = false;
         }
 
-        let word_width = unicode_width::UnicodeWidthStr::width(word);
+        let word_width = text_width(word, cjk);
 
         let line_has_space = line_width + word_width <= width;
         if line_has_space {
@@ -380,7 +401,7 @@ fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
         if word_width <= width {
             // the word can be fit to 'width' so we put it on new line
 
-            line.extend(std::iter::repeat(' ').take(width - line_width));
+            line.extend((0..width - line_width).map(|_| ' '));
             lines.push(line);
 
             line = String::with_capacity(width);
@@ -396,13 +417,13 @@ fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
             while !word_part.is_empty() {
                 let available_space = width - line_width;
                 let (lhs, rhs, (unknowns, split_char)) =
-                    split_string_at(word_part, available_space);
+                    split_string_at(word_part, available_space, cjk);
 
                 word_part = &rhs[split_char..];
-                line_width += unicode_width::UnicodeWidthStr::width(lhs) + unknowns;
+                line_width += text_width(lhs, cjk) + unknowns;
 
                 line.push_str(lhs);
-                line.extend(std::iter::repeat(REPLACEMENT).take(unknowns));
+                push_replacement(&mut line, replacement, unknowns);
 
                 if line_width == width {
                     lines.push(line);
@@ -415,7 +436,7 @@ fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
     }
 
     if line_width > 0 {
-        line.extend(std::iter::repeat(' ').take(width - line_width));
+        line.extend((0..width - line_width).map(|_| ' '));
         lines.push(line);
     }
 
@@ -423,7 +444,14 @@ fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
 }
 
 #[cfg(feature = "color")]
-fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) -> String {
+fn split_keeping_words(
+    text: &str,
+    width: usize,
+    prefix: &str,
+    suffix: &str,
+    replacement: Option<char>,
+    cjk: bool,
+) -> String {
     use std::fmt::Write;
 
     use ansi_str::AnsiBlock;
@@ -458,7 +486,7 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
         let _ = write!(buf, "{}", block.start());
 
         for c in block.text().chars() {
-            let c_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+            let c_width = char_width(c, cjk);
             let is_enough_space = line_width + c_width <= width;
 
             let is_space = c == ' ';
@@ -524,8 +552,7 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
 
                     let is_big_char = c_width > width;
                     if is_big_char {
-                        const REPLACEMENT: char = '\u{FFFD}';
-                        buf.extend(std::iter::repeat(REPLACEMENT).take(width));
+                        push_replacement(&mut buf, replacement, width);
                         line_width = width;
                         word_length = width;
                     } else {
@@ -551,16 +578,20 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
     // fill the remainings in a last line if it has any.
     if line_width < width {
         let rest = width - line_width;
-        buf.extend(std::iter::repeat(' ').take(rest));
+        buf.extend((0..rest).map(|_| ' '));
     }
 
     buf
 }
 
-fn split_string_at(text: &str, at: usize) -> (&str, &str, (usize, usize)) {
-    use papergrid::util::split_at_pos;
+fn split_string_at(text: &str, at: usize, cjk: bool) -> (&str, &str, (usize, usize)) {
+    use papergrid::util::{split_at_pos, split_at_pos_cjk};
 
-    let (length, count_unknowns, split_char_size) = split_at_pos(text, at);
+    let (length, count_unknowns, split_char_size) = if cjk {
+        split_at_pos_cjk(text, at)
+    } else {
+        split_at_pos(text, at)
+    };
     let (lhs, rhs) = text.split_at(length);
 
     (lhs, rhs, (count_unknowns, split_char_size))
@@ -570,6 +601,8 @@ fn split_string_at(text: &str, at: usize) -> (&str, &str, (usize, usize)) {
 mod tests {
     use super::*;
 
+    const REPLACEMENT: Option<char> = Some('\u{FFFD}');
+
     #[cfg(feature = "color")]
     #[test]
     fn test_color_strip() {
@@ -590,10 +623,10 @@ mod tests {
     #[test]
     fn split_test() {
         #[cfg(not(feature = "color"))]
-        let split = |text, width| chunks(text, width).join("\n");
+        let split = |text, width| chunks(text, width, REPLACEMENT, false).join("\n");
 
         #[cfg(feature = "color")]
-        let split = |text, width| chunks(text, width, "", "").join("\n");
+        let split = |text, width| chunks(text, width, "", "", REPLACEMENT, false).join("\n");
 
         assert_eq!(split("123456", 0), "");
 
@@ -616,10 +649,10 @@ mod tests {
     #[test]
     fn chunks_test() {
         #[cfg(not(feature = "color"))]
-        let chunks = |text, width| chunks(text, width);
+        let chunks = |text, width| chunks(text, width, REPLACEMENT, false);
 
         #[cfg(feature = "color")]
-        let chunks = |text, width| chunks(text, width, "", "");
+        let chunks = |text, width| chunks(text, width, "", "", REPLACEMENT, false);
 
         assert_eq!(chunks("123456", 0), [""; 0]);
 
@@ -635,7 +668,8 @@ mod tests {
     #[cfg(not(feature = "color"))]
     #[test]
     fn split_by_line_keeping_words_test() {
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "\n");
+        let split_keeping_words =
+            |text, width| split_keeping_words(text, width, "\n", REPLACEMENT, false);
 
         assert_eq!(split_keeping_words("123456", 1), "1\n2\n3\n4\n5\n6");
         assert_eq!(split_keeping_words("123456", 2), "12\n34\n56");
@@ -650,7 +684,8 @@ mod tests {
     #[test]
     fn split_by_line_keeping_words_test() {
         #[cfg(feature = "color")]
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "", "");
+        let split_keeping_words =
+            |text, width| split_keeping_words(text, width, "", "", REPLACEMENT, false);
 
         assert_eq!(split_keeping_words("123456", 1), "1\n2\n3\n4\n5\n6");
         assert_eq!(split_keeping_words("123456", 2), "12\n34\n56");
@@ -665,10 +700,12 @@ mod tests {
     #[test]
     fn split_by_line_keeping_words_color_test() {
         #[cfg(feature = "color")]
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "", "");
+        let split_keeping_words =
+            |text, width| split_keeping_words(text, width, "", "", REPLACEMENT, false);
 
         #[cfg(not(feature = "color"))]
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "\n");
+        let split_keeping_words =
+            |text, width| split_keeping_words(text, width, "\n", REPLACEMENT, false);
 
         let text = "\u{1b}[36mJapanese “vacancy” button\u{1b}[0m";
 
@@ -685,10 +722,12 @@ mod tests {
         use ansi_str::AnsiStr;
 
         #[cfg(feature = "color")]
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "", "");
+        let split_keeping_words =
+            |text, width| split_keeping_words(text, width, "", "", REPLACEMENT, false);
 
         #[cfg(not(feature = "color"))]
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "\n");
+        let split_keeping_words =
+            |text, width| split_keeping_words(text, width, "\n", REPLACEMENT, false);
 
         let text = "\u{1b}[37mTigre Ecuador   OMYA Andina     3824909999      Calcium carbonate       Colombia\u{1b}[0m";
 
@@ -833,7 +872,8 @@ mod tests {
     #[cfg(feature = "color")]
     #[test]
     fn split_by_line_keeping_words_color_3_test() {
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "", "");
+        let split_keeping_words =
+            |text, width| split_keeping_words(text, width, "", "", REPLACEMENT, false);
 
         println!(
             "{}",
@@ -842,10 +882,7 @@ mod tests {
 
         println!(
             "{}",
-            split_keeping_words(
-                "\u{1b}[37m🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻\u{1b}[0m",
-                3,
-            ),
+            split_keeping_words("\u{1b}[37m🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻\u{1b}[0m", 3,),
         );
 
         assert_eq!(
@@ -876,7 +913,8 @@ mod tests {
     #[cfg(not(feature = "color"))]
     #[test]
     fn split_keeping_words_4_test() {
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "\n");
+        let split_keeping_words =
+            |text, width| split_keeping_words(text, width, "\n", REPLACEMENT, false);
 
         assert_eq!(split_keeping_words("12345678", 3,), "123\n456\n78 ");
         assert_eq!(split_keeping_words("12345678", 2,), "12\n34\n56\n78");
@@ -885,10 +923,12 @@ mod tests {
     #[cfg(feature = "color")]
     #[test]
     fn split_keeping_words_4_test() {
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "", "");
+        let split_keeping_words =
+            |text, width| split_keeping_words(text, width, "", "", REPLACEMENT, false);
 
         #[cfg(not(feature = "color"))]
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "\n");
+        let split_keeping_words =
+            |text, width| split_keeping_words(text, width, "\n", REPLACEMENT, false);
 
         assert_eq!(split_keeping_words("12345678", 3,), "123\n456\n78 ");
         assert_eq!(split_keeping_words("12345678", 2,), "12\n34\n56\n78");
@@ -897,25 +937,31 @@ mod tests {
     #[cfg(feature = "color")]
     #[test]
     fn chunks_test_with_prefix_and_suffix() {
-        assert_eq!(chunks("123456", 0, "^", "$"), ["^$"; 0]);
+        assert_eq!(chunks("123456", 0, "^", "$", REPLACEMENT, false), ["^$"; 0]);
 
         assert_eq!(
-            chunks("123456", 1, "^", "$"),
+            chunks("123456", 1, "^", "$", REPLACEMENT, false),
             ["^1$", "^2$", "^3$", "^4$", "^5$", "^6$"]
         );
-        assert_eq!(chunks("123456", 2, "^", "$"), ["^12$", "^34$", "^56$"]);
-        assert_eq!(chunks("12345", 2, "^", "$"), ["^12$", "^34$", "^5$"]);
+        assert_eq!(
+            chunks("123456", 2, "^", "$", REPLACEMENT, false),
+            ["^12$", "^34$", "^56$"]
+        );
+        assert_eq!(
+            chunks("12345", 2, "^", "$", REPLACEMENT, false),
+            ["^12$", "^34$", "^5$"]
+        );
 
         assert_eq!(
-            chunks("😳😳😳😳😳", 1, "^", "$"),
+            chunks("😳😳😳😳😳", 1, "^", "$", REPLACEMENT, false),
             ["^�$", "^�$", "^�$", "^�$", "^�$"]
         );
         assert_eq!(
-            chunks("😳😳😳😳😳", 2, "^", "$"),
+            chunks("😳😳😳😳😳", 2, "^", "$", REPLACEMENT, false),
             ["^😳$", "^😳$", "^😳$", "^😳$", "^😳$"]
         );
         assert_eq!(
-            chunks("😳😳😳😳😳", 3, "^", "$"),
+            chunks("😳😳😳😳😳", 3, "^", "$", REPLACEMENT, false),
             ["^😳�$", "^😳�$", "^😳$"]
         );
     }
@@ -924,20 +970,20 @@ mod tests {
     #[test]
     fn split_by_line_keeping_words_test_with_prefix_and_suffix() {
         assert_eq!(
-            split_keeping_words("123456", 1, "^", "$"),
+            split_keeping_words("123456", 1, "^", "$", REPLACEMENT, false),
             "^1$\n^2$\n^3$\n^4$\n^5$\n^6$"
         );
         assert_eq!(
-            split_keeping_words("123456", 2, "^", "$"),
+            split_keeping_words("123456", 2, "^", "$", REPLACEMENT, false),
             "^12$\n^34$\n^56$"
         );
         assert_eq!(
-            split_keeping_words("12345", 2, "^", "$"),
+            split_keeping_words("12345", 2, "^", "$", REPLACEMENT, false),
             "^12$\n^34$\n^5$ "
         );
 
         assert_eq!(
-            split_keeping_words("😳😳😳😳😳", 1, "^", "$"),
+            split_keeping_words("😳😳😳😳😳", 1, "^", "$", REPLACEMENT, false),
             "^�$\n^�$\n^�$\n^�$\n^�$"
         );
     }
@@ -950,7 +996,7 @@ mod tests {
         let text = "\u{1b}[37mTigre Ecuador   OMYA Andina     3824909999      Calcium carbonate       Colombia\u{1b}[0m";
 
         assert_eq!(
-            split_keeping_words(text, 2, "^", "$")
+            split_keeping_words(text, 2, "^", "$", REPLACEMENT, false)
                 .ansi_split("\n")
                 .collect::<Vec<_>>(),
             [
@@ -999,7 +1045,7 @@ mod tests {
         );
 
         assert_eq!(
-            split_keeping_words(text, 1, "^", "$")
+            split_keeping_words(text, 1, "^", "$", REPLACEMENT, false)
                 .ansi_split("\n")
                 .collect::<Vec<_>>(),
             [
@@ -1093,7 +1139,7 @@ mod tests {
         let text = "\u{1b}[30mDebian\u{1b}[0m\u{1b}[31mDebian\u{1b}[0m\u{1b}[32mDebian\u{1b}[0m\u{1b}[33mDebian\u{1b}[0m\u{1b}[34mDebian\u{1b}[0m\u{1b}[35mDebian\u{1b}[0m\u{1b}[36mDebian\u{1b}[0m\u{1b}[37mDebian\u{1b}[0m\u{1b}[40mDebian\u{1b}[0m\u{1b}[41mDebian\u{1b}[0m\u{1b}[42mDebian\u{1b}[0m\u{1b}[43mDebian\u{1b}[0m\u{1b}[44mDebian\u{1b}[0m";
 
         assert_eq!(
-            chunks(text, 30, "", ""),
+            chunks(text, 30, "", "", REPLACEMENT, false),
             [
                 "\u{1b}[30mDebian\u{1b}[39m\u{1b}[31mDebian\u{1b}[39m\u{1b}[32mDebian\u{1b}[39m\u{1b}[33mDebian\u{1b}[39m\u{1b}[34mDebian\u{1b}[39m\u{1b}[35m\u{1b}[39m", "\u{1b}[35mDebian\u{1b}[39m\u{1b}[36mDebian\u{1b}[39m\u{1b}[37mDebian\u{1b}[39m\u{1b}[40mDebian\u{1b}[49m\u{1b}[41mDebian\u{1b}[49m\u{1b}[42m\u{1b}[49m", "\u{1b}[42mDebian\u{1b}[49m\u{1b}[43mDebian\u{1b}[49m\u{1b}[44mDebian\u{1b}[49m"
             ]
@@ -0,0 +1,340 @@
+//! This module contains an [`IterTable`] structure which renders rows as they're produced by an iterator.
+
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    io,
+};
+
+use papergrid::{
+    height::HeightEstimator,
+    records::vec_records::VecRecords,
+    util::{cut_str, string_width_multiline_tab},
+    width::{CfgWidthFunction, WidthEstimator},
+    Entity, Estimate, Grid, GridConfig, Indent,
+};
+
+use crate::{builder::configure_grid, style::RawStyle, Style};
+
+/// [`IterTable`] renders rows of an iterator one by one,
+/// so all rows are never kept in memory at once.
+///
+/// As rows aren't known beforehand, column widths are either set by [`IterTable::width`]
+/// or estimated by a few first rows, see [`IterTable::sniff`].
+/// A cell which doesn't fit a column is truncated.
+///
+/// Rows are expected to have the same number of cells,
+/// extra cells are dropped and missing ones are considered empty.
+///
+/// Settings of a [`GridConfig`] are applied to each row as if it was the only row,
+/// so row and cell specific settings and spans aren't supported.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{IterTable, Style};
+///
+/// let rows = (0..3).map(|i| vec![i.to_string(), (i * i).to_string()]);
+///
+/// let mut buf = Vec::new();
+/// IterTable::new(rows)
+///     .style(Style::modern())
+///     .build(&mut buf)
+///     .unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(buf).unwrap(),
+///     "┌───┬───┐\n\
+///      │ 0 │ 0 │\n\
+///      ├───┼───┤\n\
+///      │ 1 │ 1 │\n\
+///      ├───┼───┤\n\
+///      │ 2 │ 4 │\n\
+///      └───┴───┘"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct IterTable<I> {
+    records: I,
+    cfg: GridConfig,
+    width: Option<usize>,
+    sniff: usize,
+}
+
+impl<I> IterTable<I> {
+    /// Creates a new [`IterTable`] instance.
+    ///
+    /// By default widths are estimated by the first 1000 rows,
+    /// and the same style as of [`Table`] is used.
+    ///
+    /// [`Table`]: crate::Table
+    pub fn new(records: I) -> Self {
+        let mut cfg = GridConfig::default();
+        configure_grid(&mut cfg);
+
+        Self {
+            records,
+            cfg,
+            width: None,
+            sniff: 1000,
+        }
+        .style(Style::ascii())
+    }
+
+    /// Sets a width of each column.
+    ///
+    /// The width doesn't include padding.
+    /// If it's set no rows are sniffed.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets a number of rows which are used to estimate column widths.
+    ///
+    /// The rows are kept in memory until they're rendered.
+    pub fn sniff(mut self, count_rows: usize) -> Self {
+        self.sniff = count_rows;
+        self
+    }
+
+    /// Sets a style of the table.
+    pub fn style<S>(mut self, style: S) -> Self
+    where
+        S: Into<RawStyle>,
+    {
+        // a number of rows is unknown so all lines are set
+        style
+            .into()
+            .set_config(&mut self.cfg, (usize::MAX, usize::MAX));
+        self
+    }
+
+    /// Sets a [`GridConfig`] which is used to render each row.
+    ///
+    /// It can be taken from a configured [`Table`] via [`Table::get_config`].
+    ///
+    /// [`Table`]: crate::Table
+    /// [`Table::get_config`]: crate::Table::get_config
+    pub fn config(mut self, cfg: GridConfig) -> Self {
+        self.cfg = cfg;
+        self
+    }
+
+    /// Returns a [`GridConfig`] which is used to render each row.
+    pub fn get_config(&self) -> &GridConfig {
+        &self.cfg
+    }
+}
+
+impl<I, T, S> IterTable<I>
+where
+    I: IntoIterator<Item = T>,
+    T: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    /// Renders the table into an [`io::Write`], row by row.
+    pub fn build<W>(self, mut writer: W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        print_rows(self.records, &self.cfg, self.width, self.sniff, |row| {
+            write!(writer, "{}", row)
+        })
+    }
+}
+
+impl<I, T, S> Display for IterTable<I>
+where
+    I: IntoIterator<Item = T> + Clone,
+    T: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let records = self.records.clone();
+        print_rows(records, &self.cfg, self.width, self.sniff, |row| {
+            write!(f, "{}", row)
+        })
+    }
+}
+
+fn print_rows<I, T, S, F, E>(
+    records: I,
+    cfg: &GridConfig,
+    width: Option<usize>,
+    sniff: usize,
+    mut print: F,
+) -> Result<(), E>
+where
+    I: IntoIterator<Item = T>,
+    T: IntoIterator<Item = S>,
+    S: AsRef<str>,
+    F: FnMut(&dyn Display) -> Result<(), E>,
+{
+    let mut rows = records.into_iter().map(|row| {
+        row.into_iter()
+            .map(|text| text.as_ref().to_owned())
+            .collect::<Vec<_>>()
+    });
+
+    let count_sniff = if width.is_some() { 1 } else { sniff.max(1) };
+    let mut sample = rows.by_ref().take(count_sniff).collect::<Vec<_>>();
+
+    let count_columns = sample.iter().map(Vec::len).max().unwrap_or(0);
+    if count_columns == 0 {
+        return Ok(());
+    }
+
+    for row in &mut sample {
+        row.resize(count_columns, String::new());
+    }
+
+    let widths = match width {
+        Some(width) => (0..count_columns)
+            .map(|col| width + horizontal_padding(cfg, col))
+            .collect::<Vec<_>>(),
+        None => {
+            let records = VecRecords::new(
+                &sample,
+                (sample.len(), count_columns),
+                CfgWidthFunction::from_cfg(cfg),
+            );
+
+            let mut estimator = WidthEstimator::default();
+            estimator.estimate(&records, cfg);
+            Vec::from(estimator)
+        }
+    };
+
+    let text_widths = widths
+        .iter()
+        .enumerate()
+        .map(|(col, width)| width.saturating_sub(horizontal_padding(cfg, col)))
+        .collect::<Vec<_>>();
+
+    let widths = WidthEstimator::from(widths);
+
+    // a config of a middle row is the same for most rows so it's reused
+    let mut middle_cfg: Option<(bool, GridConfig)> = None;
+
+    let mut rows = sample.into_iter().chain(rows).enumerate().peekable();
+    while let Some((i, mut row)) = rows.next() {
+        let is_last = rows.peek().is_none();
+
+        row.resize(count_columns, String::new());
+        let row = row
+            .iter()
+            .zip(&text_widths)
+            .map(|(text, &width)| truncate(text, width, cfg.get_tab_width()))
+            .collect::<Vec<_>>();
+
+        let has_line = cfg.get_horizontal_line(i + 1).is_some();
+        let own_cfg;
+        let row_cfg = if i == 0 || has_line {
+            own_cfg = row_config(cfg, i, is_last, count_columns);
+            &own_cfg
+        } else {
+            if matches!(&middle_cfg, Some((last, _)) if *last != is_last) {
+                middle_cfg = None;
+            }
+
+            let (_, row_cfg) = middle_cfg
+                .get_or_insert_with(|| (is_last, row_config(cfg, i, is_last, count_columns)));
+            &*row_cfg
+        };
+
+        let records = VecRecords::new(
+            [row],
+            (1, count_columns),
+            CfgWidthFunction::from_cfg(row_cfg),
+        );
+
+        let mut heights = HeightEstimator::default();
+        heights.estimate(&records, row_cfg);
+
+        if i > 0 {
+            print(&'\n')?;
+        }
+
+        print(&Grid::new(&records, row_cfg, &widths, &heights))?;
+    }
+
+    Ok(())
+}
+
+/// Creates a config of a single row, which is drawn as if it's a part of a table.
+fn row_config(cfg: &GridConfig, row: usize, is_last: bool, count_columns: usize) -> GridConfig {
+    let mut borders = cfg.get_borders().clone();
+
+    if row == 0 {
+        if let Some(line) = cfg.get_horizontal_line(0) {
+            borders.top = line.main.or(borders.top);
+            borders.top_left = line.left.or(borders.top_left);
+            borders.top_right = line.right.or(borders.top_right);
+            borders.top_intersection = line.intersection.or(borders.top_intersection);
+        }
+    } else {
+        borders.top = None;
+        borders.top_left = None;
+        borders.top_right = None;
+        borders.top_intersection = None;
+    }
+
+    if !is_last {
+        let line = cfg
+            .get_horizontal_line(row + 1)
+            .copied()
+            .unwrap_or_default();
+
+        borders.bottom = line.main.or(borders.horizontal);
+        borders.bottom_left = line.left.or(borders.horizontal_left);
+        borders.bottom_right = line.right.or(borders.horizontal_right);
+        borders.bottom_intersection = line.intersection.or(borders.intersection);
+    }
+
+    let mut row_cfg = cfg.clone();
+    row_cfg.clear_theme();
+    row_cfg.set_borders(borders);
+
+    for col in 0..=count_columns {
+        if let Some(line) = cfg.get_vertical_line(col) {
+            let mut line = *line;
+            if !is_last {
+                line.bottom = line.intersection;
+            }
+
+            row_cfg.set_vertical_line(col, line);
+        }
+    }
+
+    let mut margin = *cfg.get_margin();
+    if row > 0 {
+        margin.top = Indent::default();
+    }
+
+    if !is_last {
+        margin.bottom = Indent::default();
+    }
+
+    row_cfg.set_margin(margin);
+
+    row_cfg
+}
+
+fn horizontal_padding(cfg: &GridConfig, col: usize) -> usize {
+    let padding = cfg.get_padding(Entity::Column(col));
+    padding.left.size + padding.right.size
+}
+
+fn truncate(text: &str, width: usize, tab_width: usize) -> Cow<'_, str> {
+    if string_width_multiline_tab(text, tab_width) <= width {
+        return Cow::Borrowed(text);
+    }
+
+    let lines = text
+        .lines()
+        .map(|line| cut_str(line, width))
+        .collect::<Vec<_>>();
+
+    Cow::Owned(lines.join("\n"))
+}
@@ -0,0 +1,161 @@
+use papergrid::{records::Records, Estimate, GridConfig, Position};
+
+/// A resolved layout of a [`Table`].
+///
+/// It holds the final widths of columns and heights of rows,
+/// the same ones which are used when the table is rendered,
+/// and can map a cell onto its place in the output.
+///
+/// It's created by [`Table::layout`].
+///
+/// ```
+/// use tabled::{CellRect, Table};
+///
+/// let table = Table::new([("Hello", 1)]);
+/// let layout = table.layout();
+///
+/// assert_eq!(layout.column_widths(), [7, 5]);
+/// assert_eq!(layout.row_heights(), [1, 1]);
+/// assert_eq!(
+///     layout.cell((1, 0)),
+///     Some(CellRect { x: 1, y: 3, width: 7, height: 1 }),
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Table::layout`]: crate::Table::layout
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    widths: Vec<usize>,
+    heights: Vec<usize>,
+    cells: Vec<Vec<Option<CellRect>>>,
+    total_width: usize,
+    total_height: usize,
+}
+
+/// An area a cell occupies in a rendered table.
+///
+/// The coordinates are 0-based and counted in columns and lines of the output.
+/// The area includes a cell padding but not its borders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CellRect {
+    /// A column of the output where the cell starts.
+    pub x: usize,
+    /// A line of the output where the cell starts.
+    pub y: usize,
+    /// A width of the cell.
+    pub width: usize,
+    /// A height of the cell.
+    pub height: usize,
+}
+
+impl Layout {
+    pub(crate) fn new<R, W, H>(records: R, cfg: &GridConfig, width: &W, height: &H) -> Self
+    where
+        R: Records,
+        W: Estimate<R>,
+        H: Estimate<R>,
+    {
+        let shape = (records.count_rows(), records.count_columns());
+
+        let widths = (0..shape.1)
+            .map(|col| width.get(col).unwrap_or(0))
+            .collect::<Vec<_>>();
+        let heights = (0..shape.0)
+            .map(|row| height.get(row).unwrap_or(0))
+            .collect::<Vec<_>>();
+
+        let margin = cfg.get_margin();
+        let xs = line_offsets(&widths, margin.left.size, |col| {
+            cfg.has_vertical(col, shape.1)
+        });
+        let ys = line_offsets(&heights, margin.top.size, |row| {
+            cfg.has_horizontal(row, shape.0)
+        });
+
+        let total_width = xs[shape.1] + margin.right.size;
+        let total_height = ys[shape.0] + margin.bottom.size;
+
+        let mut cells = vec![vec![None; shape.1]; shape.0];
+        for (row, list) in cells.iter_mut().enumerate() {
+            for (col, cell) in list.iter_mut().enumerate() {
+                let pos = (row, col);
+                if !cfg.is_cell_visible(pos, shape) {
+                    continue;
+                }
+
+                let col_span = cfg.get_column_span(pos, shape).unwrap_or(1);
+                let row_span = cfg.get_row_span(pos, shape).unwrap_or(1);
+
+                *cell = Some(CellRect {
+                    x: xs[col] - widths[col],
+                    y: ys[row] - heights[row],
+                    width: span_size(&xs, &widths, col, col_span),
+                    height: span_size(&ys, &heights, row, row_span),
+                });
+            }
+        }
+
+        Self {
+            widths,
+            heights,
+            cells,
+            total_width,
+            total_height,
+        }
+    }
+
+    /// Returns widths of columns.
+    ///
+    /// A width includes a padding of a column.
+    pub fn column_widths(&self) -> &[usize] {
+        &self.widths
+    }
+
+    /// Returns heights of rows.
+    ///
+    /// A height includes a padding of a row.
+    pub fn row_heights(&self) -> &[usize] {
+        &self.heights
+    }
+
+    /// Returns an area of a cell in the output.
+    ///
+    /// A spanned cell covers all the columns and rows it spans.
+    /// It returns [`None`] in case the position is out of the table
+    /// or the cell is hidden by a span of another cell.
+    pub fn cell(&self, pos: Position) -> Option<CellRect> {
+        self.cells.get(pos.0)?.get(pos.1).copied().flatten()
+    }
+
+    /// Returns a total width of the table, including margin and vertical lines.
+    pub fn total_width(&self) -> usize {
+        self.total_width
+    }
+
+    /// Returns a total height of the table, including margin and horizontal lines.
+    pub fn total_height(&self) -> usize {
+        self.total_height
+    }
+}
+
+/// Returns an offset of an end of each line, and the end of the last split line.
+fn line_offsets(sizes: &[usize], start: usize, has_split: impl Fn(usize) -> bool) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(sizes.len() + 1);
+
+    let mut offset = start;
+    for (i, size) in sizes.iter().enumerate() {
+        offset += has_split(i) as usize + size;
+        offsets.push(offset);
+    }
+
+    offset += has_split(sizes.len()) as usize;
+    offsets.push(offset);
+
+    offsets
+}
+
+fn span_size(offsets: &[usize], sizes: &[usize], start: usize, span: usize) -> usize {
+    let end = std::cmp::min(start + span, sizes.len()) - 1;
+    offsets[end] - offsets[start] + sizes[start]
+}
@@ -237,52 +237,97 @@
 )]
 #![deny(unused_must_use)]
 
+mod compact_table;
+mod deferred_table;
 mod features;
+mod iter_table;
+mod layout;
 mod modify;
+mod pages;
+mod parse;
+mod pool_table;
+mod settings;
 mod table;
 mod table_iterator_ext;
+mod table_value;
 mod tabled;
 
 pub mod builder;
 pub mod display;
 pub mod object;
+pub mod records;
 
 #[cfg(feature = "macros")]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 pub mod macros;
 
+#[cfg(feature = "tracing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+pub mod trace;
+
 pub use papergrid;
 
+#[cfg(feature = "xlsx")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xlsx")))]
+pub use rust_xlsxwriter;
+
 pub use crate::{
+    compact_table::CompactTable,
+    deferred_table::{DeferredTable, SettingKind},
     features::{
+        aggregate::Aggregate,
         alignment::{self, Alignment},
+        ascii_only::AsciiOnly,
+        caption::Caption,
+        column_order::ColumnOrder,
         concat::Concat,
+        dedup::Dedup,
+        diff::TableDiff,
         disable::Disable,
         extract::Extract,
+        filter::Filter,
         format, formatting,
         height::{self, Height},
         highlight::Highlight,
         locator,
         margin::Margin,
         measurment, merge,
+        multi_header::MultiHeader,
         padding::Padding,
         panel::{Footer, Header, Panel},
         peaker,
+        pivot::Pivot,
+        reverse::Reverse,
         rotate::Rotate,
+        selection::{Selection, SelectionMap},
         shadow,
+        sort::Sort,
         span::Span,
+        split::Split,
+        strip_colors::StripColors,
         style::{self, Border, BorderText, Style},
+        viewport::Viewport,
         width::{self, Width},
     },
+    iter_table::IterTable,
+    layout::{CellRect, Layout},
     modify::{CellSettingsList, Modify, ModifyList, ModifyObject},
+    pages::Pages,
+    pool_table::PoolTable,
+    settings::{EmptySettings, Settings},
     table::{CellOption, Table, TableOption},
     table_iterator_ext::TableIteratorExt,
+    table_value::TableValue,
     tabled::Tabled,
 };
 
 #[cfg(feature = "color")]
 #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
-pub use crate::features::{color, highlight, margin_color, padding_color};
+pub use crate::features::{color, heatmap, highlight, margin_color, padding_color};
+
+#[cfg(feature = "terminal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "terminal")))]
+pub use crate::features::terminal::Terminal;
 
 /// A derive to implement a [`Tabled`] trait.
 ///
@@ -405,6 +450,31 @@ pub use crate::features::{color, highlight, margin_color, padding_color};
 /// }
 /// ```
 ///
+/// It's also possible to pass additional arguments to a function after a field,
+/// like other fields or literals, so a function can be reused for different fields.
+/// Fields are passed by a reference.
+///
+/// ```rust,no_run
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// pub struct File {
+///     pub name: String,
+///     #[tabled(display_with("format_size", self.unit, true))]
+///     pub size: u64,
+///     #[tabled(skip)]
+///     pub unit: String,
+/// }
+///
+/// fn format_size(size: &u64, unit: &str, short: bool) -> String {
+///     if short {
+///         format!("{}{}", size, &unit[..1])
+///     } else {
+///         format!("{} {}", size, unit)
+///     }
+/// }
+/// ```
+///
 /// ### Format headers
 ///
 /// Beside `#[tabled(rename = "")]` you can change a format of a column name using
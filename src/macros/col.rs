@@ -2,6 +2,9 @@
 ///
 /// The macros allows several tables to be displayed vertically.
 ///
+/// Tables of different sizes are aligned by the grid of a resulting [`Table`],
+/// so it can be styled as any other one, e.g. by `Style::blank()` to hide an outer frame.
+///
 /// Companion to [`row!`].
 ///
 /// # Examples
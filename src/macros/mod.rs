@@ -4,3 +4,4 @@
 
 mod col;
 mod row;
+mod table;
@@ -0,0 +1,49 @@
+/// Creates a [`Table`] from literal rows.
+///
+/// Rows are separated by `;` and cells by `,`.
+/// The first row is a header.
+/// Each cell must implement [`Display`].
+///
+/// # Examples
+///
+/// ```
+/// use tabled::table;
+///
+/// let table = table!(["name", "stars"; "tabled", 1500; "papergrid", 20]);
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+-----------+-------+\n\
+///      | name      | stars |\n\
+///      +-----------+-------+\n\
+///      | tabled    | 1500  |\n\
+///      +-----------+-------+\n\
+///      | papergrid | 20    |\n\
+///      +-----------+-------+"
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Display`]: std::fmt::Display
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+macro_rules! table {
+    ( [ $( $( $cell:expr ),+ $(,)? );+ $(;)? ] ) => {{
+        let mut builder = $crate::builder::Builder::default();
+
+        let mut records = ::std::vec![
+            $( ::std::vec![ $( ::std::string::ToString::to_string(&$cell), )+ ], )+
+        ]
+        .into_iter();
+
+        if let ::std::option::Option::Some(header) = records.next() {
+            builder.set_columns(header);
+        }
+
+        for record in records {
+            builder.add_record(record);
+        }
+
+        builder.build()
+    }};
+}
@@ -7,7 +7,7 @@ use crate::{object::Object, CellOption, Table, TableOption};
 ///
 /// Be aware that the settings are applied all to a cell at a time.
 /// So sometimes you may need to make a several calls of [`Modify`] in order to achieve the desired affect.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Modify<O> {
     obj: O,
 }
@@ -38,7 +38,7 @@ where
 }
 
 /// This is a container of [`CellOption`]s which are applied to a set [`Object`].
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ModifyList<O, S> {
     obj: O,
     modifiers: S,
@@ -81,7 +81,7 @@ where
 }
 
 /// This is a container of [`CellOption`]s.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CellSettingsList<S1, S2> {
     s1: S1,
     s2: S2,
@@ -61,7 +61,7 @@ pub trait Object: Sized {
 /// Combines 2 sets of cells into one.
 ///
 /// Duplicates are removed from the output set.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct UnionCombination<L, R> {
     lhs: L,
     rhs: R,
@@ -88,7 +88,7 @@ where
 /// Difference struct used for chaining [`Object`]'s.
 ///
 /// Returns cells from 1st set with removed ones from the 2nd set.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DiffCombination<L, R> {
     lhs: L,
     rhs: R,
@@ -116,7 +116,7 @@ where
 ///
 /// Returns cells which are present in 2 sets.
 /// But not in one of them
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct IntersectionCombination<L, R> {
     lhs: L,
     rhs: R,
@@ -143,7 +143,7 @@ where
 ///
 /// Returns cells which are present in 2 sets.
 /// But not in one of them
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct InversionCombination<O> {
     obj: O,
 }
@@ -167,7 +167,7 @@ where
 /// This structure represents a sub table of [`Table`].
 ///
 /// [`Table`]: crate::Table
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Segment<C, R> {
     columns: C,
     rows: R,
@@ -221,7 +221,7 @@ where
 /// This is a segment which cantains all cells on the table.
 ///
 /// Can be crated from [`Segment::all`].
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SegmentAll;
 
 impl Object for SegmentAll {
@@ -236,7 +236,7 @@ impl Object for SegmentAll {
 
 /// Frame includes cells which are on the edges of each side.
 /// Therefore it's [`Object`] implementation returns a subset of cells which are present in frame.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Frame;
 
 impl Object for Frame {
@@ -254,7 +254,7 @@ impl Object for Frame {
 /// It's often contains headers data.
 ///
 /// [`Table`]: crate::Table
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FirstRow;
 
 impl Object for FirstRow {
@@ -283,7 +283,7 @@ impl Add<usize> for FirstRow {
 /// This structure represents the last row of a [`Table`].
 ///
 /// [`Table`]: crate::Table
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LastRow;
 
 impl Object for LastRow {
@@ -343,7 +343,7 @@ impl From<Row> for usize {
 }
 
 /// A row which is located by an offset from the last row.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LastRowOffset {
     offset: usize,
 }
@@ -373,7 +373,7 @@ impl Object for LastRowOffset {
 /// Row denotes a set of cells on given rows on a [`Table`].
 ///
 /// [`Table`]: crate::Table
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Rows<R> {
     range: R,
 }
@@ -440,7 +440,7 @@ where
 /// Column denotes a set of cells on given columns on a [`Table`].
 ///
 /// [`Table`]: crate::Table
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Columns<R> {
     range: R,
 }
@@ -504,7 +504,7 @@ where
 }
 
 /// `FirstColumn` represents the first column on a grid.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FirstColumn;
 
 impl Object for FirstColumn {
@@ -531,7 +531,7 @@ impl Add<usize> for FirstColumn {
 }
 
 /// `LastColumn` represents the last column on a grid.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LastColumn;
 
 impl Object for LastColumn {
@@ -595,7 +595,7 @@ impl From<Column> for usize {
 }
 
 /// `LastColumnOffset` represents a single column on a grid indexed via offset from the last column.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LastColumnOffset {
     offset: usize,
 }
@@ -624,7 +624,7 @@ impl Object for LastColumnOffset {
 /// Cell denotes a particular cell on a [`Table`].
 ///
 /// [`Table`]: crate::Table
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Cell(pub usize, pub usize);
 
 impl Object for Cell {
@@ -0,0 +1,90 @@
+use papergrid::records::{Records, Resizable};
+
+use crate::Table;
+
+/// An iterator over pages of a [`Table`].
+///
+/// Each page is a [`Table`] on its own which repeats a header row in case the original [`Table`] has one.
+///
+/// It's created by [`Table::pages`].
+#[derive(Debug, Clone)]
+pub struct Pages<'a, R> {
+    table: &'a Table<R>,
+    rows_per_page: usize,
+    page: usize,
+    count_pages: usize,
+}
+
+impl<'a, R> Pages<'a, R>
+where
+    R: Records,
+{
+    pub(crate) fn new(table: &'a Table<R>, rows_per_page: usize) -> Self {
+        let count_pages = table.count_pages(rows_per_page);
+
+        Self {
+            table,
+            rows_per_page,
+            page: 0,
+            count_pages,
+        }
+    }
+}
+
+impl<R> Iterator for Pages<'_, R>
+where
+    R: Records + Resizable + Clone,
+{
+    type Item = Table<R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.page >= self.count_pages {
+            return None;
+        }
+
+        let page = self.table.page(self.page, self.rows_per_page);
+        self.page += 1;
+
+        page
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let rest = self.count_pages - self.page;
+        (rest, Some(rest))
+    }
+}
+
+impl<R> ExactSizeIterator for Pages<'_, R> where R: Records + Resizable + Clone {}
+
+pub(crate) fn count_data_rows<R>(table: &Table<R>) -> usize
+where
+    R: Records,
+{
+    let count_rows = table.count_rows();
+    if table.has_header() {
+        count_rows.saturating_sub(1)
+    } else {
+        count_rows
+    }
+}
+
+pub(crate) fn build_page<R>(table: &Table<R>, page: usize, rows_per_page: usize) -> Table<R>
+where
+    R: Records + Resizable + Clone,
+{
+    let start_row = if table.has_header() { 1 } else { 0 };
+    let count_rows = table.count_rows();
+    let from = std::cmp::min(start_row + page * rows_per_page, count_rows);
+    let to = std::cmp::min(from + rows_per_page, count_rows);
+
+    let mut page = table.clone();
+    for row in (to..count_rows).rev().chain((start_row..from).rev()) {
+        page.get_records_mut().remove_row(row);
+        page.remove_row_aside(row);
+    }
+
+    page.destroy_width_cache();
+    page.destroy_height_cache();
+
+    page
+}
@@ -0,0 +1,268 @@
+//! This module contains a parser of rendered tables.
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::builder::Builder;
+
+const VERTICAL_CHARS: [char; 9] = ['|', '│', '┃', '║', '╎', '┆', '┊', '┋', '╏'];
+
+const HORIZONTAL_CHARS: [char; 6] = ['-', '=', '─', '━', '═', '~'];
+
+const INTERSECTION_CHARS: &str = "+:'.`┼╋╬├┤┬┴┌┐└┘╭╮╰╯╔╗╚╝╠╣╦╩┏┓┗┛┣┫┳┻┠┨┯┷╞╡╤╧╟╢╫╪";
+
+/// Parses a rendered table into a [`Builder`].
+pub(crate) fn parse_table(text: &str) -> Builder<'static> {
+    let lines = text
+        .lines()
+        .map(|line| line.trim_end())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+
+    // content lines grouped by horizontal lines in between
+    let mut groups: Vec<Vec<Vec<Option<&str>>>> = vec![Vec::new()];
+    let mut count_splits: usize = 0;
+    for line in lines {
+        if is_horizontal_line(line) {
+            if matches!(groups.last(), Some(group) if !group.is_empty()) {
+                groups.push(Vec::new());
+                count_splits += 1;
+            }
+        } else if let Some(group) = groups.last_mut() {
+            group.push(line_slots(line));
+        }
+    }
+
+    if matches!(groups.last(), Some(group) if group.is_empty()) {
+        groups.pop();
+        count_splits = count_splits.saturating_sub(1);
+    }
+
+    let content = groups.iter().flatten().collect::<Vec<_>>();
+    if content.is_empty() {
+        return Builder::default();
+    }
+
+    let vertical = find_vertical_char(&content);
+    let columns = match vertical {
+        Some(c) => columns_by_char(&content, c),
+        None => columns_by_space(&content),
+    };
+
+    // in case every row is separated by a line cells may be multiline,
+    // otherwise each line is considered to be a row.
+    let is_multiline = count_splits > 1;
+
+    // a header is considered to be present if it's separated from the rest of the rows
+    let has_header = if is_multiline {
+        true
+    } else {
+        count_splits == 1 && groups[0].len() == 1
+    };
+
+    let rows = if is_multiline {
+        groups
+    } else {
+        groups
+            .into_iter()
+            .flatten()
+            .map(|line| vec![line])
+            .collect()
+    };
+
+    let mut builder = Builder::default();
+    for (i, row) in rows.iter().enumerate() {
+        let spans = match vertical {
+            Some(c) => cell_spans(&row[0], &columns, c),
+            None => vec![1; columns.len()],
+        };
+
+        let mut record = Vec::with_capacity(columns.len());
+        for span in spans {
+            let col = record.len();
+            let start = columns[col].0;
+            let end = columns[col + span - 1].1;
+
+            let text = row
+                .iter()
+                .map(|line| slots_text(line, start, end))
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            // a short cell of a multiline row has empty lines around
+            let text = text.trim_matches('\n').to_owned();
+
+            record.push(text);
+            record.resize(record.len() + span - 1, String::new());
+
+            if span > 1 {
+                builder.set_span(i, col, span);
+            }
+        }
+
+        if i == 0 && has_header {
+            builder.set_columns(record);
+        } else {
+            builder.add_record(record);
+        }
+    }
+
+    builder
+}
+
+fn is_horizontal_line(line: &str) -> bool {
+    let mut has_horizontal = false;
+    for c in line.chars() {
+        if HORIZONTAL_CHARS.contains(&c) {
+            has_horizontal = true;
+        } else if !(c == ' ' || VERTICAL_CHARS.contains(&c) || INTERSECTION_CHARS.contains(c)) {
+            return false;
+        }
+    }
+
+    has_horizontal
+}
+
+/// Splits a line into a list of slots of a display width 1.
+///
+/// A wide character takes a few slots, but only the first one holds it.
+/// Zero width characters, like combining ones, are kept in a slot of a previous character.
+fn line_slots(line: &str) -> Vec<Option<&str>> {
+    let mut slots = Vec::with_capacity(line.len());
+    // an index of a last taken slot and a start of its text
+    let mut last: Option<(usize, usize)> = None;
+    for (i, c) in line.char_indices() {
+        let end = i + c.len_utf8();
+        let width = c.width().unwrap_or(0);
+        if width == 0 {
+            if let Some((slot, start)) = last {
+                slots[slot] = Some(&line[start..end]);
+                continue;
+            }
+        }
+
+        last = Some((slots.len(), i));
+        slots.push(Some(&line[i..end]));
+        if width > 1 {
+            slots.resize(slots.len() + width - 1, None);
+        }
+    }
+
+    slots
+}
+
+fn slot(line: &[Option<&str>], i: usize) -> Option<char> {
+    line.get(i)
+        .copied()
+        .flatten()
+        .and_then(|text| text.chars().next())
+}
+
+fn find_vertical_char(lines: &[&Vec<Option<&str>>]) -> Option<char> {
+    VERTICAL_CHARS.iter().copied().find(|&c| {
+        let width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+        (0..width).any(|i| lines.iter().all(|line| slot(line, i) == Some(c)))
+    })
+}
+
+/// Returns ranges of columns by positions of a vertical character in most of the lines.
+fn columns_by_char(lines: &[&Vec<Option<&str>>], c: char) -> Vec<(usize, usize)> {
+    let width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+
+    let mut borders = Vec::new();
+    for i in 0..width {
+        let count = lines.iter().filter(|line| slot(line, i) == Some(c)).count();
+        if count * 2 > lines.len() {
+            borders.push(i);
+        }
+    }
+
+    let mut columns = Vec::new();
+    let mut start = 0;
+    for border in borders.into_iter().chain(std::iter::once(width)) {
+        let is_empty = lines
+            .iter()
+            .all(|line| (start..border).all(|i| matches!(slot(line, i), None | Some(' '))));
+
+        // a space out of the outer borders is not a column
+        let is_outer = start == 0 || border == width;
+        if !(is_outer && is_empty) {
+            columns.push((start, border));
+        }
+
+        start = border + 1;
+    }
+
+    columns
+}
+
+/// Returns ranges of columns separated by spaces in all of the lines.
+///
+/// A single space is considered to be a part of a text.
+fn columns_by_space(lines: &[&Vec<Option<&str>>]) -> Vec<(usize, usize)> {
+    let width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+    let is_space = (0..width)
+        .map(|i| {
+            lines
+                .iter()
+                .all(|line| matches!(line.get(i), None | Some(Some(" "))))
+        })
+        .collect::<Vec<_>>();
+
+    let mut columns = Vec::new();
+    let mut start = None;
+    let mut i = 0;
+    while i < width {
+        if !is_space[i] {
+            start = start.or(Some(i));
+            i += 1;
+            continue;
+        }
+
+        let end = (i..width).find(|&j| !is_space[j]).unwrap_or(width);
+        if end - i > 1 || end == width {
+            if let Some(start) = start.take() {
+                columns.push((start, i));
+            }
+        }
+
+        i = end;
+    }
+
+    if let Some(start) = start {
+        columns.push((start, width));
+    }
+
+    columns
+}
+
+/// Returns spans of cells in a line, where a missing border means a span.
+fn cell_spans(line: &[Option<&str>], columns: &[(usize, usize)], c: char) -> Vec<usize> {
+    let mut spans = Vec::new();
+    let mut span = 0;
+    for (i, &(_, end)) in columns.iter().enumerate() {
+        span += 1;
+
+        let is_last = i + 1 == columns.len();
+        if is_last || slot(line, end) == Some(c) {
+            spans.push(span);
+            span = 0;
+        }
+    }
+
+    spans
+}
+
+fn slots_text(line: &[Option<&str>], start: usize, end: usize) -> String {
+    let end = std::cmp::min(end, line.len());
+    if start >= end {
+        return String::new();
+    }
+
+    line[start..end]
+        .iter()
+        .flatten()
+        .copied()
+        .collect::<String>()
+        .trim()
+        .to_owned()
+}
@@ -0,0 +1,137 @@
+//! This module contains a [`PoolTable`] structure which renders rows with different numbers of cells.
+
+use std::fmt::{self, Display};
+
+use crate::{builder::Builder, style::RawStyle, Style, Table};
+
+/// [`PoolTable`] renders rows which may have a different number of cells.
+///
+/// Cells of a row share the whole width of the table,
+/// so a row of a single cell is a banner and a row of 3 cells is split in 3 parts.
+///
+/// It's built as a [`Table`] where cells are spanned over a number of columns
+/// which is the least common multiple of numbers of cells in rows,
+/// so it's supposed to be used with a few cells in a row.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{PoolTable, Style};
+///
+/// let table = PoolTable::new([
+///     vec!["Server status: all systems operational"],
+///     vec!["cpu 12%", "mem 3.1G", "disk 40G"],
+///     vec!["uptime", "12 days"],
+/// ])
+/// .style(Style::modern())
+/// .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "┌────────────────────────────────────────┐\n\
+///      │ Server status: all systems operational │\n\
+///      ├──────────────┬────────────┬────────────┤\n\
+///      │ cpu 12%      │ mem 3.1G   │ disk 40G   │\n\
+///      ├──────────────┴──────┬─────┴────────────┤\n\
+///      │ uptime              │ 12 days          │\n\
+///      └─────────────────────┴──────────────────┘"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct PoolTable {
+    rows: Vec<Vec<String>>,
+    style: RawStyle,
+}
+
+impl PoolTable {
+    /// Creates a [`PoolTable`] of rows.
+    pub fn new<I, R, T>(rows: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(Into::into).collect())
+            .collect();
+
+        Self {
+            rows,
+            style: Style::ascii().into(),
+        }
+    }
+
+    /// Sets a style of the table.
+    pub fn style<S>(mut self, style: S) -> Self
+    where
+        S: Into<RawStyle>,
+    {
+        self.style = style.into();
+        self
+    }
+
+    /// Builds a [`Table`].
+    ///
+    /// Be aware that borders of spanned cells need to be corrected by [`Style::correct_spans`]
+    /// in case another style is set to the [`Table`].
+    pub fn build(&self) -> Table {
+        let count_columns = self.rows.iter().map(|row| row.len().max(1)).fold(1, lcm);
+
+        let mut builder = Builder::default();
+        let mut spans = Vec::new();
+        for (i, row) in self.rows.iter().enumerate() {
+            // an empty row is considered to have a single empty cell
+            let count_cells = row.len().max(1);
+            let span = count_columns / count_cells;
+
+            let mut record = vec![String::new(); count_columns];
+            for j in 0..count_cells {
+                if let Some(text) = row.get(j) {
+                    record[j * span] = text.clone();
+                }
+
+                if span > 1 {
+                    spans.push((i, j * span, span));
+                }
+            }
+
+            builder.add_record(record);
+        }
+
+        for (row, col, span) in spans {
+            builder.set_span(row, col, span);
+        }
+
+        let mut table = builder.build();
+        table.with(&self.style).with(Style::correct_spans());
+
+        table
+    }
+}
+
+impl Display for PoolTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.build().fmt(f)
+    }
+}
+
+impl From<PoolTable> for Table {
+    fn from(table: PoolTable) -> Self {
+        table.build()
+    }
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    a / gcd(a, b) * b
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        let rest = a % b;
+        a = b;
+        b = rest;
+    }
+
+    a
+}
@@ -0,0 +1,244 @@
+//! This module contains an abstraction of a storage of cells which backs a [`Table`].
+//!
+//! A [`Table`] may be built from any [`Records`] implementation.
+//! [`Records`] requires a few methods to be implemented, so in most cases
+//! it's easier to implement a [`TextRecords`] and wrap it into [`ExternalRecords`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{
+//!     builder::Builder,
+//!     records::{ExternalRecords, TextRecords},
+//!     Style,
+//! };
+//!
+//! // a storage of strings, which are all kept in a single buffer
+//! struct Arena {
+//!     buf: String,
+//!     cells: Vec<(usize, usize)>,
+//!     count_columns: usize,
+//! }
+//!
+//! impl TextRecords for Arena {
+//!     fn count_rows(&self) -> usize {
+//!         self.cells.len() / self.count_columns
+//!     }
+//!
+//!     fn count_columns(&self) -> usize {
+//!         self.count_columns
+//!     }
+//!
+//!     fn get_text(&self, (row, col): (usize, usize)) -> &str {
+//!         let (start, end) = self.cells[row * self.count_columns + col];
+//!         &self.buf[start..end]
+//!     }
+//! }
+//!
+//! let arena = Arena {
+//!     buf: String::from("namecountapple1"),
+//!     cells: vec![(0, 4), (4, 9), (9, 14), (14, 15)],
+//!     count_columns: 2,
+//! };
+//!
+//! let mut table = Builder::custom(ExternalRecords::new(arena)).build();
+//! table.with(Style::psql());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     " name  | count \n\
+//!      -------+-------\n \
+//!      apple | 1     "
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::fmt;
+
+use papergrid::{width::WidthFunc, Position};
+
+pub use papergrid::records::{
+    cell_info::CellInfo, vec_records::VecRecords, Records, RecordsMut, Resizable,
+};
+
+/// A read only storage of cells.
+///
+/// It's a simplified version of [`Records`],
+/// where lines and widths of cells are calculated on demand.
+pub trait TextRecords {
+    /// Returns an amount of rows.
+    fn count_rows(&self) -> usize;
+
+    /// Returns an amount of columns.
+    fn count_columns(&self) -> usize;
+
+    /// Returns a text of a cell.
+    fn get_text(&self, pos: Position) -> &str;
+}
+
+impl<T> TextRecords for &T
+where
+    T: TextRecords + ?Sized,
+{
+    fn count_rows(&self) -> usize {
+        T::count_rows(self)
+    }
+
+    fn count_columns(&self) -> usize {
+        T::count_columns(self)
+    }
+
+    fn get_text(&self, pos: Position) -> &str {
+        T::get_text(self, pos)
+    }
+}
+
+/// A list of rows is expected to have rows of the same length as the first one.
+///
+/// Missing cells are considered to be empty.
+impl<S> TextRecords for [Vec<S>]
+where
+    S: AsRef<str>,
+{
+    fn count_rows(&self) -> usize {
+        self.len()
+    }
+
+    fn count_columns(&self) -> usize {
+        self.first().map_or(0, Vec::len)
+    }
+
+    fn get_text(&self, (row, col): Position) -> &str {
+        self[row].get(col).map_or("", AsRef::as_ref)
+    }
+}
+
+impl<S> TextRecords for Vec<Vec<S>>
+where
+    S: AsRef<str>,
+{
+    fn count_rows(&self) -> usize {
+        self.as_slice().count_rows()
+    }
+
+    fn count_columns(&self) -> usize {
+        self.as_slice().count_columns()
+    }
+
+    fn get_text(&self, pos: Position) -> &str {
+        self.as_slice().get_text(pos)
+    }
+}
+
+impl<S, const COLS: usize> TextRecords for [[S; COLS]]
+where
+    S: AsRef<str>,
+{
+    fn count_rows(&self) -> usize {
+        self.len()
+    }
+
+    fn count_columns(&self) -> usize {
+        COLS
+    }
+
+    fn get_text(&self, (row, col): Position) -> &str {
+        self[row][col].as_ref()
+    }
+}
+
+impl<S, const COLS: usize, const ROWS: usize> TextRecords for [[S; COLS]; ROWS]
+where
+    S: AsRef<str>,
+{
+    fn count_rows(&self) -> usize {
+        ROWS
+    }
+
+    fn count_columns(&self) -> usize {
+        COLS
+    }
+
+    fn get_text(&self, (row, col): Position) -> &str {
+        self[row][col].as_ref()
+    }
+}
+
+/// [`ExternalRecords`] is a [`Records`] implementation for any [`TextRecords`],
+/// so an external storage can back a [`Table`] without copying its data.
+///
+/// Widths and lines of cells are calculated each time they're requested.
+/// Settings which change a text of cells can't be applied to such a [`Table`],
+/// while the ones which change a style can.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Default, Clone)]
+pub struct ExternalRecords<T> {
+    records: T,
+}
+
+impl<T> ExternalRecords<T> {
+    /// Creates a new [`ExternalRecords`] instance.
+    pub fn new(records: T) -> Self {
+        Self { records }
+    }
+
+    /// Returns a reference to the inner storage.
+    pub fn get_ref(&self) -> &T {
+        &self.records
+    }
+
+    /// Returns the inner storage.
+    pub fn into_inner(self) -> T {
+        self.records
+    }
+}
+
+impl<T> Records for ExternalRecords<T>
+where
+    T: TextRecords,
+{
+    fn count_rows(&self) -> usize {
+        self.records.count_rows()
+    }
+
+    fn count_columns(&self) -> usize {
+        self.records.count_columns()
+    }
+
+    fn get_text(&self, pos: Position) -> &str {
+        self.records.get_text(pos)
+    }
+
+    fn get_line(&self, pos: Position, i: usize) -> &str {
+        let line = self.get_text(pos).split('\n').nth(i).unwrap_or("");
+        line.strip_suffix('\r').unwrap_or(line)
+    }
+
+    fn count_lines(&self, pos: Position) -> usize {
+        self.get_text(pos).split('\n').count()
+    }
+
+    fn get_width<W>(&self, pos: Position, width_ctrl: W) -> usize
+    where
+        W: WidthFunc,
+    {
+        width_ctrl.width_multiline(self.get_text(pos))
+    }
+
+    fn get_line_width<W>(&self, pos: Position, i: usize, width_ctrl: W) -> usize
+    where
+        W: WidthFunc,
+    {
+        width_ctrl.width(self.get_line(pos, i))
+    }
+
+    fn fmt_text_prefix(&self, _: &mut fmt::Formatter<'_>, _: Position) -> fmt::Result {
+        Ok(())
+    }
+
+    fn fmt_text_suffix(&self, _: &mut fmt::Formatter<'_>, _: Position) -> fmt::Result {
+        Ok(())
+    }
+}
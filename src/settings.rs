@@ -0,0 +1,81 @@
+//! This module contains a [`Settings`] structure which groups several [`TableOption`]s.
+
+use crate::{Table, TableOption};
+
+/// [`Settings`] is a container of [`TableOption`]s which are applied one after another.
+///
+/// It can be used to define a common look of tables once and reuse it.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{object::Rows, Alignment, Modify, Padding, Settings, Style, Table};
+///
+/// let house_style = Settings::new(Style::psql(), Padding::new(2, 2, 0, 0))
+///     .with(Modify::new(Rows::first()).with(Alignment::center()));
+///
+/// let table = Table::new([("tabled", 2020), ("papergrid", 2021)])
+///     .with(house_style.clone())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "    &str     |  i32   \n",
+///         "-------------+--------\n",
+///         "  tabled     |  2020  \n",
+///         "  papergrid  |  2021  ",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Settings<A = EmptySettings, B = EmptySettings> {
+    s1: A,
+    s2: B,
+}
+
+impl Settings {
+    /// Creates an empty [`Settings`].
+    pub fn empty() -> Self {
+        Self::new(EmptySettings, EmptySettings)
+    }
+}
+
+impl<A, B> Settings<A, B> {
+    /// Creates a [`Settings`] of 2 options.
+    pub fn new(s1: A, s2: B) -> Self {
+        Self { s1, s2 }
+    }
+
+    /// Adds an option to the end of the list.
+    pub fn with<C>(self, s: C) -> Settings<Self, C> {
+        Settings::new(self, s)
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<A, B, R> TableOption<R> for Settings<A, B>
+where
+    A: TableOption<R>,
+    B: TableOption<R>,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        self.s1.change(table);
+        self.s2.change(table);
+    }
+}
+
+/// An option which does nothing.
+///
+/// It's used by [`Settings::empty`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EmptySettings;
+
+impl<R> TableOption<R> for EmptySettings {
+    fn change(&mut self, _: &mut Table<R>) {}
+}
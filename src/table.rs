@@ -1,27 +1,57 @@
 //! This module contains a main table representation of this crate [`Table`].
 
-use std::{borrow::Cow, fmt, iter::FromIterator};
+use std::{borrow::Cow, convert::Infallible, fmt, iter::FromIterator, str::FromStr};
 
 use papergrid::{
     height::HeightEstimator,
     records::{
         cell_info::CellInfo,
         vec_records::{CellMut, VecRecords},
-        Records, RecordsMut,
+        Records, RecordsMut, Resizable,
     },
     width::{CfgWidthFunction, WidthEstimator},
-    Estimate, Grid, GridConfig,
+    Estimate, Grid, GridConfig, Position,
 };
 
 use crate::{
-    builder::Builder, height::get_table_total_height, object::Entity, width::get_table_total_width,
+    builder::Builder,
+    deferred_table::{DeferredTable, SettingKind},
+    display::{
+        AsciiDocTable, CsvTable, HtmlTable, JiraTable, JsonTable, LatexTable, MediaWikiTable,
+    },
+    features::{
+        ascii_only::AsciiWriter,
+        caption::Caption,
+        panel::{move_column_spans, move_columns_aside, move_row_spans, move_rows_aside},
+        rows_aside::RowsAside,
+        selection::RowIndexes,
+    },
+    height::get_table_total_height,
+    layout::Layout,
+    modify::Modify,
+    object::{Entity, Object},
+    pages::{build_page, count_data_rows, Pages},
+    width::{get_table_total_width, DEFAULT_REPLACEMENT},
     Tabled,
 };
 
+#[cfg(feature = "color")]
+use crate::features::ansi_closing::ClosedAnsiRecords;
+#[cfg(feature = "parallel")]
+use crate::{height::get_table_heights_parallel, width::get_table_widths_parallel};
+
 /// A trait which is responsilbe for configuration of a [`Table`].
 pub trait TableOption<R> {
     /// The function modifies a [`Grid`] object.
     fn change(&mut self, table: &mut Table<R>);
+
+    /// Returns a kind of the setting in case it can be overridden by a later setting of the same kind.
+    ///
+    /// It lets [`DeferredTable`] skip overridden settings.
+    /// By default a setting is always applied.
+    fn kind(&self) -> Option<SettingKind> {
+        None
+    }
 }
 
 impl<T, R> TableOption<R> for &mut T
@@ -31,8 +61,31 @@ where
     fn change(&mut self, table: &mut Table<R>) {
         T::change(self, table);
     }
+
+    fn kind(&self) -> Option<SettingKind> {
+        T::kind(self)
+    }
 }
 
+macro_rules! tuple_option {
+    ( $($name:ident)+ ) => {
+        impl<R, $($name: TableOption<R>),+> TableOption<R> for ($($name,)+) {
+            fn change(&mut self, table: &mut Table<R>) {
+                #![allow(non_snake_case)]
+                let ($($name,)+) = self;
+                $($name.change(table);)+
+            }
+        }
+    };
+}
+
+tuple_option! { A }
+tuple_option! { A B }
+tuple_option! { A B C }
+tuple_option! { A B C D }
+tuple_option! { A B C D E }
+tuple_option! { A B C D E F }
+
 /// A trait for configuring a single cell.
 /// Where cell represented by 'row' and 'column' indexes.
 ///
@@ -62,6 +115,31 @@ pub trait CellOption<R> {
 /// let table = Table::new(&["Year", "2021"]);
 /// ```
 ///
+/// ### From a nested iterator
+///
+/// ```rust
+/// use tabled::{builder::Builder, Table};
+/// use std::iter::FromIterator;
+///
+/// let data = vec![vec!["Year".to_owned(), "2021".to_owned()]];
+/// let table = Table::from(data);
+///
+/// let rows = (0..3).map(|i| (0..3).map(move |j| (i * j).to_string()));
+/// let table: Table = Builder::from_iter(rows).into();
+/// ```
+///
+/// ### From borrowed data
+///
+/// A table built from references keeps borrowed strings as they are, without copying them.
+///
+/// ```rust
+/// use tabled::Table;
+/// use std::iter::FromIterator;
+///
+/// let data = vec![String::from("Year"), String::from("2021")];
+/// let table = Table::from_iter(&data);
+/// ```
+///
 /// ### With settings
 ///
 /// ```rust,no_run
@@ -84,6 +162,44 @@ pub struct Table<R = VecRecords<CellInfo<'static>>> {
     has_header: bool,
     widths: Option<Vec<usize>>,
     heights: Option<Vec<usize>>,
+    estimated_widths: Option<Vec<usize>>,
+    estimated_heights: Option<Vec<usize>>,
+    settings: Settings,
+}
+
+/// Settings of a [`Table`] which are kept aside of a [`GridConfig`],
+/// as they're not a part of a grid.
+#[derive(Debug, Clone)]
+struct Settings {
+    char_replacement: Option<char>,
+    captions: Vec<Caption>,
+    ascii_only: bool,
+    /// Rows appended as footers, they're not a part of data.
+    footers: RowsAside<bool>,
+    /// Indexes of original records tracked by [`Selection`](crate::Selection).
+    selection: Option<RowIndexes>,
+    /// Attributes of cells by rows, they're used by [`HtmlTable`].
+    html_attributes: RowsAside<Vec<(usize, HtmlAttributes)>>,
+    #[cfg(feature = "color")]
+    colors_enabled: bool,
+}
+
+/// Names and values of attributes of a cell.
+type HtmlAttributes = Vec<(String, String)>;
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            char_replacement: Some(DEFAULT_REPLACEMENT),
+            captions: Vec::new(),
+            ascii_only: false,
+            footers: RowsAside::default(),
+            selection: None,
+            html_attributes: RowsAside::default(),
+            #[cfg(feature = "color")]
+            colors_enabled: true,
+        }
+    }
 }
 
 impl Table<VecRecords<CellInfo<'static>>> {
@@ -117,6 +233,153 @@ impl Table<VecRecords<CellInfo<'static>>> {
         b.with_header();
         b.build()
     }
+
+    /// Creates a [`Table`] of a single value,
+    /// where each field is a row of its name and its value.
+    ///
+    /// ```
+    /// use tabled::{Table, Tabled};
+    ///
+    /// #[derive(Tabled)]
+    /// struct Config {
+    ///     host: &'static str,
+    ///     port: u16,
+    /// }
+    ///
+    /// let table = Table::kv(&Config { host: "localhost", port: 8080 });
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+-----------+\n\
+    ///      | host | localhost |\n\
+    ///      +------+-----------+\n\
+    ///      | port | 8080      |\n\
+    ///      +------+-----------+"
+    /// );
+    /// ```
+    pub fn kv<T>(item: &T) -> Self
+    where
+        T: Tabled,
+    {
+        let ctrl = CfgWidthFunction::new(4);
+
+        let records = T::headers()
+            .into_iter()
+            .zip(item.fields())
+            .map(|(name, value)| {
+                let mut cells = vec![CellInfo::default(); 2];
+                CellMut::set(&mut cells[0], name.into_owned(), &ctrl);
+                CellMut::set(&mut cells[1], value.into_owned(), &ctrl);
+                cells
+            })
+            .collect::<Vec<_>>();
+
+        Builder::custom(VecRecords::from(records)).build()
+    }
+
+    /// Creates a [`Table`] from a CSV [`Reader`].
+    ///
+    /// It's a shortcut for [`Builder::from_csv`].
+    ///
+    /// ```rust
+    /// use tabled::Table;
+    ///
+    /// let data = "name,size\nCargo.toml,2048\nsrc,4096";
+    /// let mut reader = csv::Reader::from_reader(data.as_bytes());
+    ///
+    /// let table = Table::from_csv(&mut reader).unwrap();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------------+------+\n\
+    ///      | name       | size |\n\
+    ///      +------------+------+\n\
+    ///      | Cargo.toml | 2048 |\n\
+    ///      +------------+------+\n\
+    ///      | src        | 4096 |\n\
+    ///      +------------+------+"
+    /// );
+    /// ```
+    ///
+    /// [`Reader`]: csv::Reader
+    #[cfg(feature = "csv")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "csv")))]
+    pub fn from_csv<T>(reader: &mut csv::Reader<T>) -> csv::Result<Self>
+    where
+        T: std::io::Read,
+    {
+        Builder::from_csv(reader).map(Builder::build)
+    }
+
+    /// Creates a [`Table`] from a JSON value.
+    ///
+    /// It's a shortcut for [`Builder::from_json`].
+    ///
+    /// ```rust
+    /// use tabled::Table;
+    ///
+    /// let value = serde_json::json!([{ "name": "Grodno", "id": 1 }, { "name": "Minsk" }]);
+    ///
+    /// let table = Table::from_json(&value);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+----+--------+\n\
+    ///      | id | name   |\n\
+    ///      +----+--------+\n\
+    ///      | 1  | Grodno |\n\
+    ///      +----+--------+\n\
+    ///      |    | Minsk  |\n\
+    ///      +----+--------+"
+    /// );
+    /// ```
+    #[cfg(feature = "json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    pub fn from_json(value: &serde_json::Value) -> Self {
+        Builder::from_json(value).build()
+    }
+
+    /// Creates a [`Table`] from rows of a database query.
+    ///
+    /// It's a shortcut for [`Builder::from_sqlx_rows`].
+    ///
+    #[cfg_attr(feature = "sqlx-sqlite", doc = "```rust,no_run")]
+    #[cfg_attr(not(feature = "sqlx-sqlite"), doc = "```rust,ignore")]
+    /// use tabled::Table;
+    ///
+    /// # async fn query(pool: sqlx::SqlitePool) -> Result<(), sqlx::Error> {
+    /// let rows = sqlx::query("SELECT * FROM users").fetch_all(&pool).await?;
+    /// println!("{}", Table::from_sqlx_rows(&rows));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "sqlx")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
+    pub fn from_sqlx_rows<R>(rows: &[R]) -> Self
+    where
+        R: sqlx::Row,
+        usize: sqlx::ColumnIndex<R>,
+        for<'r> String: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+        for<'r> i64: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+        for<'r> i32: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+        for<'r> f64: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+        for<'r> bool: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    {
+        Builder::from_sqlx_rows(rows).build()
+    }
+
+    /// Creates a [`Table`] from a polars [`DataFrame`].
+    ///
+    /// It's a shortcut for [`DataFrameBuilder`],
+    /// which can be used to truncate big frames.
+    ///
+    /// [`DataFrame`]: polars::frame::DataFrame
+    /// [`DataFrameBuilder`]: crate::builder::DataFrameBuilder
+    #[cfg(feature = "polars")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "polars")))]
+    pub fn from_polars(frame: &polars::frame::DataFrame) -> Self {
+        crate::builder::DataFrameBuilder::new(frame).build()
+    }
 }
 
 impl Table<()> {
@@ -198,7 +461,17 @@ impl<R> Table<R> {
     }
 
     /// Get a reference to the table's cfg.
+    ///
+    /// As the cfg may be changed, estimated dimensions of the table are dropped.
     pub fn get_config_mut(&mut self) -> &mut GridConfig {
+        self.destroy_dimension_cache();
+        &mut self.cfg
+    }
+
+    /// Get a reference to the table's cfg, keeping estimated dimensions of the table.
+    ///
+    /// It must be used only by settings which don't affect widths and heights of cells.
+    pub(crate) fn get_config_mut_keep_dimension(&mut self) -> &mut GridConfig {
         &mut self.cfg
     }
 
@@ -208,13 +481,28 @@ impl<R> Table<R> {
     }
 
     /// Get a reference to the table's records.
+    ///
+    /// As the records may be changed, estimated dimensions of the table are dropped.
     pub fn get_records_mut(&mut self) -> &mut R {
+        self.destroy_dimension_cache();
         &mut self.records
     }
 
     /// With is a generic function which applies options to the [`Table`].
     ///
     /// It applies settings immediately.
+    ///
+    /// A tuple of options is applied in order.
+    ///
+    /// ```
+    /// use tabled::{Alignment, Padding, Style, Table};
+    ///
+    /// let table = Table::new([("tabled", 2020)])
+    ///     .with((Style::psql(), Alignment::right(), Padding::new(0, 1, 0, 0)))
+    ///     .to_string();
+    ///
+    /// assert_eq!(table, "  &str | i32 \n-------+-----\ntabled |2020 ");
+    /// ```
     pub fn with<O>(&mut self, mut option: O) -> &mut Self
     where
         O: TableOption<R>,
@@ -223,6 +511,12 @@ impl<R> Table<R> {
         self
     }
 
+    /// Turns the table into a [`DeferredTable`],
+    /// which records settings and applies them only when it's built.
+    pub fn deferred<'a>(self) -> DeferredTable<'a, R> {
+        DeferredTable::new(self)
+    }
+
     /// A verification that first row is actually a header.
     ///
     /// It's `true` when [`Table::new`] and [`Table::builder`] is used.
@@ -237,6 +531,7 @@ impl<R> Table<R> {
 
     pub(crate) fn destroy_width_cache(&mut self) {
         self.widths = None;
+        self.destroy_dimension_cache();
     }
 
     pub(crate) fn cache_height(&mut self, widths: Vec<usize>) {
@@ -245,11 +540,156 @@ impl<R> Table<R> {
 
     pub(crate) fn destroy_height_cache(&mut self) {
         self.heights = None;
+        self.destroy_dimension_cache();
+    }
+
+    /// Drops estimated widths and heights of cells,
+    /// it must be called by any setting which may change them.
+    pub(crate) fn destroy_dimension_cache(&mut self) {
+        self.estimated_widths = None;
+        self.estimated_heights = None;
     }
 
     pub(crate) fn set_header_flag(&mut self, has_header: bool) {
         self.has_header = has_header;
     }
+
+    /// Returns a character which takes place of a wide character split by [`Width`] settings,
+    /// `None` means that such a character is removed.
+    ///
+    /// It can be changed by [`CharReplacement`].
+    ///
+    /// [`CharReplacement`]: crate::width::CharReplacement
+    /// [`Width`]: crate::Width
+    pub fn get_char_replacement(&self) -> Option<char> {
+        self.settings.char_replacement
+    }
+
+    pub(crate) fn set_char_replacement(&mut self, replacement: Option<char>) {
+        self.settings.char_replacement = replacement;
+    }
+
+    /// Checks whether the table is rendered with ASCII characters only.
+    ///
+    /// It can be turned on by [`AsciiOnly`].
+    ///
+    /// [`AsciiOnly`]: crate::AsciiOnly
+    pub fn is_ascii_only(&self) -> bool {
+        self.settings.ascii_only
+    }
+
+    pub(crate) fn set_ascii_only(&mut self, ascii_only: bool) {
+        self.settings.ascii_only = ascii_only;
+    }
+
+    pub(crate) fn get_selection(&self) -> Option<&RowIndexes> {
+        self.settings.selection.as_ref()
+    }
+
+    pub(crate) fn set_selection(&mut self, indexes: Option<RowIndexes>) {
+        self.settings.selection = indexes;
+    }
+
+    pub(crate) fn take_selection(&mut self) -> Option<RowIndexes> {
+        self.settings.selection.take()
+    }
+
+    /// Swaps data kept aside of rows, it must be called when rows are swapped.
+    pub(crate) fn swap_rows_aside(&mut self, lhs: usize, rhs: usize) {
+        if let Some(indexes) = &mut self.settings.selection {
+            indexes.swap(lhs, rhs);
+        }
+
+        self.settings.html_attributes.swap(lhs, rhs);
+        self.settings.footers.swap(lhs, rhs);
+    }
+
+    /// Inserts empty data aside of a row, it must be called when a row is inserted.
+    pub(crate) fn insert_row_aside(&mut self, row: usize) {
+        if let Some(indexes) = &mut self.settings.selection {
+            indexes.insert(row);
+        }
+
+        self.settings.html_attributes.insert(row);
+        self.settings.footers.insert(row);
+    }
+
+    /// Removes data kept aside of a row, it must be called when a row is removed.
+    pub(crate) fn remove_row_aside(&mut self, row: usize) {
+        if let Some(indexes) = &mut self.settings.selection {
+            indexes.remove(row);
+        }
+
+        self.settings.html_attributes.remove(row);
+        self.settings.footers.remove(row);
+    }
+
+    /// Marks a row as a footer, so it's not a part of data.
+    pub(crate) fn set_footer_row(&mut self, row: usize) {
+        *self.settings.footers.get_mut(row) = true;
+    }
+
+    pub(crate) fn set_caption(&mut self, caption: Caption) {
+        let captions = &mut self.settings.captions;
+        captions.retain(|c| c.is_top() != caption.is_top());
+        captions.push(caption);
+    }
+
+    pub(crate) fn get_html_attributes(&self, (row, col): Position) -> &[(String, String)] {
+        self.settings
+            .html_attributes
+            .get(row)
+            .and_then(|cells| cells.iter().find(|(c, _)| *c == col))
+            .map(|(_, attributes)| attributes.as_slice())
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn set_html_attribute(&mut self, (row, col): Position, name: &str, value: &str) {
+        let cells = self.settings.html_attributes.get_mut(row);
+        let i = match cells.iter().position(|(c, _)| *c == col) {
+            Some(i) => i,
+            None => {
+                cells.push((col, Vec::new()));
+                cells.len() - 1
+            }
+        };
+
+        let attributes = &mut cells[i].1;
+        match attributes.iter_mut().find(|(n, _)| n == name) {
+            Some((_, v)) => *v = value.to_owned(),
+            None => attributes.push((name.to_owned(), value.to_owned())),
+        }
+    }
+
+    /// Checks whether colors set by the crate are rendered.
+    ///
+    /// It can be changed by [`Colorization`].
+    ///
+    /// [`Colorization`]: crate::color::Colorization
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn is_colors_enabled(&self) -> bool {
+        self.settings.colors_enabled
+    }
+
+    #[cfg(feature = "color")]
+    pub(crate) fn set_colors_enabled(&mut self, enabled: bool) {
+        self.settings.colors_enabled = enabled;
+    }
+
+    /// Returns a config which is used to render the table.
+    ///
+    /// Colors are removed from it in case they are disabled.
+    fn get_render_config(&self) -> Cow<'_, GridConfig> {
+        #[cfg(feature = "color")]
+        if !self.settings.colors_enabled {
+            let mut cfg = self.cfg.clone();
+            remove_colors(&mut cfg);
+            return Cow::Owned(cfg);
+        }
+
+        Cow::Borrowed(&self.cfg)
+    }
 }
 
 impl<R> Table<R>
@@ -262,6 +702,36 @@ where
         (records.count_rows(), records.count_columns())
     }
 
+    /// Applies a [`CellOption`] to a target immediately.
+    ///
+    /// It's a shortcut for `table.with(Modify::new(target).with(option))`.
+    ///
+    /// ```
+    /// use tabled::{object::Rows, Alignment, Style, Table};
+    ///
+    /// let mut table = Table::new([("tabled", 2020), ("papergrid", 2021)]);
+    /// table.with(Style::psql()).modify(Rows::first(), Alignment::center());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "   &str    | i32  \n",
+    ///         "-----------+------\n",
+    ///         " tabled    | 2020 \n",
+    ///         " papergrid | 2021 ",
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// [`Modify`]: crate::Modify
+    pub fn modify<O, M>(&mut self, target: O, option: M) -> &mut Self
+    where
+        O: Object,
+        M: CellOption<R>,
+    {
+        self.with(Modify::new(target).with(option))
+    }
+
     /// Returns an amount of rows in the table.
     pub fn count_rows(&self) -> usize {
         self.get_records().count_rows()
@@ -272,6 +742,15 @@ where
         self.get_records().count_columns()
     }
 
+    /// Returns a number of last rows which were appended as footers,
+    /// so they're not a part of data.
+    pub(crate) fn count_footer_rows(&self) -> usize {
+        (0..self.count_rows())
+            .rev()
+            .take_while(|&row| self.settings.footers.get(row).copied().unwrap_or(false))
+            .count()
+    }
+
     /// Returns a table shape (count rows, count columns).
     pub fn is_empty(&self) -> bool {
         let (count_rows, count_cols) = self.shape();
@@ -290,8 +769,470 @@ where
         get_table_total_height(&self.records, &self.cfg, &ctrl)
     }
 
+    /// Returns a resolved layout of the table.
+    ///
+    /// It can be used to find out where a cell ends up in the output,
+    /// for example to position a cursor on top of a printed table.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new(["Hello", "World"]);
+    /// let layout = table.layout();
+    ///
+    /// assert_eq!(layout.column_widths(), [7]);
+    /// assert_eq!(layout.row_heights(), [1, 1, 1]);
+    /// assert_eq!(layout.total_width(), table.total_width());
+    /// assert_eq!(layout.total_height(), table.total_height());
+    /// ```
+    pub fn layout(&self) -> Layout {
+        let width = self.get_width_ctrl();
+        let height = self.get_height_ctrl();
+        Layout::new(&self.records, &self.cfg, &width, &height)
+    }
+
+    /// Returns widths of columns estimated by the content.
+    ///
+    /// The widths are cached until the table is changed,
+    /// so settings applied one after another don't estimate them over and over.
+    pub(crate) fn estimate_widths(&mut self) -> &[usize] {
+        let (records, cfg) = (&self.records, &self.cfg);
+        self.estimated_widths.get_or_insert_with(|| {
+            let mut estimator = WidthEstimator::default();
+            estimator.estimate(records, cfg);
+            estimator.into()
+        })
+    }
+
+    /// Returns heights of rows estimated by the content.
+    ///
+    /// The heights are cached the same way as [`Table::estimate_widths`].
+    pub(crate) fn estimate_heights(&mut self) -> &[usize] {
+        let (records, cfg) = (&self.records, &self.cfg);
+        self.estimated_heights.get_or_insert_with(|| {
+            let mut estimator = HeightEstimator::default();
+            estimator.estimate(records, cfg);
+            estimator.into()
+        })
+    }
+
+    /// Returns estimated widths of columns and a total width of the table.
+    pub(crate) fn estimate_widths_with_total(&mut self) -> (Vec<usize>, usize) {
+        let widths = self.estimate_widths().to_vec();
+        let total = get_table_total_width(
+            &self.records,
+            &self.cfg,
+            &CachedEstimator::<WidthEstimator>::Cached(&widths),
+        );
+        (widths, total)
+    }
+
+    /// Returns a total height of the table and estimated heights of rows.
+    pub(crate) fn estimate_heights_with_total(&mut self) -> (usize, Vec<usize>) {
+        let heights = self.estimate_heights().to_vec();
+        let ctrl = CachedEstimator::<HeightEstimator>::Cached(&heights);
+        let total = get_table_total_height(&self.records, &self.cfg, &ctrl);
+        (total, heights)
+    }
+
+    /// Renders the table into a [`String`].
+    ///
+    /// It's the same as [`ToString::to_string`],
+    /// but the [`String`] is allocated once with a size estimated by dimensions of the table
+    /// instead of being grown while the table is rendered, which matters for big tables.
+    /// The size is exact in case the table consists of ASCII characters only.
+    ///
+    /// ```
+    /// use tabled::{Style, Table};
+    ///
+    /// let table = Table::new([1, 2]).with(Style::psql()).render();
+    ///
+    /// assert_eq!(table, " i32 \n-----\n 1   \n 2   ");
+    /// assert_eq!(table.capacity(), table.len());
+    /// ```
+    pub fn render(&self) -> String {
+        let cfg = self.get_render_config();
+        let width = self.get_width_ctrl();
+        let height = self.get_height_ctrl();
+
+        let total_width = get_table_total_width(&self.records, &cfg, &width);
+        let total_height = get_table_total_height(&self.records, &cfg, &height);
+        let captions = self
+            .settings
+            .captions
+            .iter()
+            .map(|caption| caption.estimate_size(total_width))
+            .sum::<usize>();
+        let size = ((total_width + 1) * total_height).saturating_sub(1) + captions;
+
+        let mut buf = String::with_capacity(size);
+        self.write_grid(&cfg, &width, &height, &mut buf)
+            .expect("a table can't fail to be written into a String");
+
+        buf
+    }
+
+    /// Renders the table into a [`io::Write`].
+    ///
+    /// The table is written piece by piece as it's rendered,
+    /// so no [`String`] of the whole output is allocated as it's done by [`ToString`].
+    /// It's a good idea to use a [`BufWriter`] in case of big tables.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([1, 2]);
+    ///
+    /// let mut buf = Vec::new();
+    /// table.build_into(&mut buf).unwrap();
+    ///
+    /// assert_eq!(buf, table.to_string().as_bytes());
+    /// ```
+    ///
+    /// [`io::Write`]: std::io::Write
+    /// [`BufWriter`]: std::io::BufWriter
+    pub fn build_into<W>(&self, mut writer: W) -> std::io::Result<()>
+    where
+        W: std::io::Write,
+    {
+        let cfg = self.get_render_config();
+        let width = self.get_width_ctrl();
+        let height = self.get_height_ctrl();
+
+        let mut writer = IoWriter {
+            writer: &mut writer,
+            result: Ok(()),
+        };
+
+        match self.write_grid(&cfg, &width, &height, &mut writer) {
+            Ok(()) => Ok(()),
+            Err(_) => writer.result,
+        }
+    }
+
+    /// Renders the table into a [`fmt::Write`].
+    ///
+    /// The table is written piece by piece as it's rendered,
+    /// so it can be appended to an existing [`String`] without an intermediate one.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([1, 2]);
+    ///
+    /// let mut text = String::from("numbers:\n");
+    /// table.build_into_fmt(&mut text).unwrap();
+    ///
+    /// assert_eq!(text, format!("numbers:\n{}", table));
+    /// ```
+    pub fn build_into_fmt<W>(&self, mut writer: W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        let cfg = self.get_render_config();
+        let width = self.get_width_ctrl();
+        let height = self.get_height_ctrl();
+        self.write_grid(&cfg, &width, &height, &mut writer)
+    }
+
+    /// Writes the grid surrounded by captions.
+    ///
+    /// Dimensions are given by a caller, so they're not estimated more than once per render.
+    fn write_grid<W>(
+        &self,
+        cfg: &GridConfig,
+        width: &CachedEstimator<'_, WidthEstimator>,
+        height: &CachedEstimator<'_, HeightEstimator>,
+        mut writer: W,
+    ) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        if self.settings.ascii_only {
+            let writer = AsciiWriter::new(&mut writer);
+            return self.write_closed_grid(cfg, width, height, writer);
+        }
+
+        self.write_closed_grid(cfg, width, height, writer)
+    }
+
+    fn write_closed_grid<W>(
+        &self,
+        cfg: &GridConfig,
+        width: &CachedEstimator<'_, WidthEstimator>,
+        height: &CachedEstimator<'_, HeightEstimator>,
+        writer: W,
+    ) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        // a style left open by a cell is closed not to bleed into borders and other cells
+        #[cfg(feature = "color")]
+        if let Some(records) = ClosedAnsiRecords::new(&self.records, cfg) {
+            return self.write_grid_with_captions(&records, cfg, width, height, writer);
+        }
+
+        self.write_grid_with_captions(&self.records, cfg, width, height, writer)
+    }
+
+    fn write_grid_with_captions<T, W>(
+        &self,
+        records: &T,
+        cfg: &GridConfig,
+        width: &CachedEstimator<'_, WidthEstimator>,
+        height: &CachedEstimator<'_, HeightEstimator>,
+        mut writer: W,
+    ) -> fmt::Result
+    where
+        T: Records,
+        W: fmt::Write,
+    {
+        let grid = Grid::new(records, cfg, width, height);
+
+        if self.settings.captions.is_empty() {
+            return write!(writer, "{}", grid);
+        }
+
+        let total_width = get_table_total_width(records, cfg, width);
+
+        for caption in self.settings.captions.iter().filter(|c| c.is_top()) {
+            caption.write(&mut writer, total_width)?;
+            writer.write_char('\n')?;
+        }
+
+        write!(writer, "{}", grid)?;
+
+        for caption in self.settings.captions.iter().filter(|c| !c.is_top()) {
+            writer.write_char('\n')?;
+            caption.write(&mut writer, total_width)?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders the table as a LaTeX `tabular` environment.
+    ///
+    /// Use [`LatexTable`] to set it up.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([("Grodno", 1)]);
+    ///
+    /// assert_eq!(
+    ///     table.to_latex(),
+    ///     concat!(
+    ///         "\\begin{tabular}{ll}\n",
+    ///         "\\hline\n",
+    ///         "\\&str & i32 \\\\\n",
+    ///         "\\hline\n",
+    ///         "Grodno & 1 \\\\\n",
+    ///         "\\hline\n",
+    ///         "\\end{tabular}",
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// [`LatexTable`]: crate::display::LatexTable
+    pub fn to_latex(&self) -> String {
+        LatexTable::new(self).to_string()
+    }
+
+    /// Renders the table as an AsciiDoc table.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([("Grodno", 1)]);
+    ///
+    /// assert_eq!(
+    ///     table.to_asciidoc(),
+    ///     concat!(
+    ///         "[cols=\"<,<\",options=\"header\"]\n",
+    ///         "|===\n",
+    ///         "|&str |i32\n",
+    ///         "|Grodno |1\n",
+    ///         "|===",
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// See [`AsciiDocTable`].
+    ///
+    /// [`AsciiDocTable`]: crate::display::AsciiDocTable
+    pub fn to_asciidoc(&self) -> String {
+        AsciiDocTable::new(self).to_string()
+    }
+
+    /// Renders the table as a Jira/Confluence wiki markup.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([("a|b", 1)]);
+    ///
+    /// assert_eq!(table.to_jira(), "||&str||i32||\n|a\\|b|1|");
+    /// ```
+    ///
+    /// See [`JiraTable`].
+    ///
+    /// [`JiraTable`]: crate::display::JiraTable
+    pub fn to_jira(&self) -> String {
+        JiraTable::new(self).to_string()
+    }
+
+    /// Renders the table as a MediaWiki table markup.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([("Grodno", 1)]);
+    ///
+    /// assert_eq!(
+    ///     table.to_mediawiki(),
+    ///     "{| class=\"wikitable\"\n|-\n! &str\n! i32\n|-\n| Grodno\n| 1\n|}",
+    /// );
+    /// ```
+    ///
+    /// Use [`MediaWikiTable`] to set it up.
+    ///
+    /// [`MediaWikiTable`]: crate::display::MediaWikiTable
+    pub fn to_mediawiki(&self) -> String {
+        MediaWikiTable::new(self).to_string()
+    }
+
+    /// Renders the table as an HTML `<table>`.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([("Grodno", 1)]);
+    ///
+    /// assert!(table.to_html().contains("<td>Grodno</td>"));
+    /// ```
+    ///
+    /// Use [`HtmlTable`] to set it up.
+    ///
+    /// [`HtmlTable`]: crate::display::HtmlTable
+    pub fn to_html(&self) -> String {
+        HtmlTable::new(self).to_string()
+    }
+
+    /// Renders the table as an SVG image.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let svg = Table::new([1]).to_svg();
+    ///
+    /// assert!(svg.starts_with("<svg"));
+    /// ```
+    ///
+    /// Use [`SvgTable`] to set it up.
+    ///
+    /// [`SvgTable`]: crate::display::SvgTable
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    pub fn to_svg(&self) -> String {
+        crate::display::SvgTable::new(self).to_string()
+    }
+
+    /// Writes the table into a XLSX spreadsheet file.
+    ///
+    /// A header row is styled bold, and alignment and spans of cells are kept.
+    /// All values are written as strings.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([("Grodno", 1), ("Minsk", 2)]);
+    ///
+    /// let path = std::env::temp_dir().join("tabled_to_xlsx.xlsx");
+    /// table.to_xlsx(&path).unwrap();
+    /// ```
+    #[cfg(feature = "xlsx")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "xlsx")))]
+    pub fn to_xlsx<P>(&self, path: P) -> Result<(), rust_xlsxwriter::XlsxError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        self.write_xlsx_worksheet(workbook.add_worksheet())?;
+        workbook.save(path)
+    }
+
+    /// Writes the table into a given worksheet of a XLSX spreadsheet.
+    ///
+    /// It can be used to put several tables into a single workbook.
+    ///
+    /// ```
+    /// use tabled::{rust_xlsxwriter::Workbook, Table};
+    ///
+    /// let cities = Table::new([("Grodno", 1), ("Minsk", 2)]);
+    /// let rivers = Table::new([("Neman", 937)]);
+    ///
+    /// let mut workbook = Workbook::new();
+    /// cities.write_xlsx_worksheet(workbook.add_worksheet().set_name("cities").unwrap()).unwrap();
+    /// rivers.write_xlsx_worksheet(workbook.add_worksheet().set_name("rivers").unwrap()).unwrap();
+    ///
+    /// let buf = workbook.save_to_buffer().unwrap();
+    /// assert!(!buf.is_empty());
+    /// ```
+    #[cfg(feature = "xlsx")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "xlsx")))]
+    pub fn write_xlsx_worksheet(
+        &self,
+        worksheet: &mut rust_xlsxwriter::Worksheet,
+    ) -> Result<(), rust_xlsxwriter::XlsxError> {
+        crate::display::xlsx::write_worksheet(self, worksheet)
+    }
+
+    /// Renders the table as CSV with a given delimiter.
+    ///
+    /// [`CsvTable`] can be used to write it without building a [`String`].
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([("Hello, World", "\"quoted\"")]);
+    ///
+    /// assert_eq!(
+    ///     table.to_csv(','),
+    ///     "&str,&str\n\"Hello, World\",\"\"\"quoted\"\"\"",
+    /// );
+    ///
+    /// assert_eq!(
+    ///     table.to_csv('\t'),
+    ///     "&str\t&str\nHello, World\t\"\"\"quoted\"\"\"",
+    /// );
+    /// ```
+    ///
+    /// [`CsvTable`]: crate::display::CsvTable
+    pub fn to_csv(&self, delimiter: char) -> String {
+        CsvTable::new(self).delimiter(delimiter).to_string()
+    }
+
+    /// Renders the table as JSON.
+    ///
+    /// Rows are rendered as an array of objects keyed by a header in case the table has one,
+    /// and as an array of arrays otherwise.
+    /// Use [`JsonTable`] to choose it explicitly.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([("Grodno", 1)]);
+    ///
+    /// assert_eq!(table.to_json(), r#"[{"&str":"Grodno","i32":"1"}]"#);
+    /// ```
+    ///
+    /// [`JsonTable`]: crate::display::JsonTable
+    pub fn to_json(&self) -> String {
+        JsonTable::new(self).to_string()
+    }
+
     fn get_width_ctrl(&self) -> CachedEstimator<'_, WidthEstimator> {
-        match &self.widths {
+        match self.widths.as_ref().or(self.estimated_widths.as_ref()) {
             Some(widths) => CachedEstimator::Cached(widths),
             None => {
                 let mut w = WidthEstimator::default();
@@ -302,7 +1243,7 @@ where
     }
 
     fn get_height_ctrl(&self) -> CachedEstimator<'_, HeightEstimator> {
-        match &self.heights {
+        match self.heights.as_ref().or(self.estimated_heights.as_ref()) {
             Some(heights) => CachedEstimator::Cached(heights),
             None => {
                 let mut w = HeightEstimator::default();
@@ -313,6 +1254,38 @@ where
     }
 }
 
+#[cfg(feature = "parallel")]
+impl<R> Table<R>
+where
+    R: Records + Sync,
+{
+    /// Estimates widths of columns and heights of rows in parallel.
+    ///
+    /// The dimensions are kept until the table is changed,
+    /// so it's supposed to be called once all the settings are applied, right before rendering.
+    /// It's worth it only for big tables.
+    ///
+    /// ```
+    /// use tabled::{Style, Table};
+    ///
+    /// let data = (0..1000).map(|i| (i, i * i));
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with(Style::psql());
+    /// table.estimate_parallel();
+    ///
+    /// assert_eq!(table.total_width(), 14);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+    pub fn estimate_parallel(&mut self) -> &mut Self {
+        let widths = get_table_widths_parallel(&self.records, &self.cfg);
+        let heights = get_table_heights_parallel(&self.records, &self.cfg);
+        self.estimated_widths = Some(widths);
+        self.estimated_heights = Some(heights);
+        self
+    }
+}
+
 impl<R> Table<R>
 where
     R: Records + RecordsMut<String>,
@@ -329,21 +1302,224 @@ where
     }
 }
 
+impl<R> Table<R>
+where
+    R: Records,
+{
+    /// Returns an amount of pages the table would be split into by [`Table::pages`].
+    ///
+    /// There's always at least 1 page.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows_per_page` is 0.
+    pub fn count_pages(&self, rows_per_page: usize) -> usize {
+        assert!(rows_per_page > 0, "rows_per_page must not be 0");
+
+        let count_rows = count_data_rows(self);
+        count_rows.saturating_sub(1) / rows_per_page + 1
+    }
+
+    /// Returns an iterator over pages of the table.
+    ///
+    /// Each page contains at most `rows_per_page` rows,
+    /// and repeats a header row in case the table has one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows_per_page` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Table, Style};
+    ///
+    /// let data = [1, 2, 3];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with(Style::psql());
+    ///
+    /// let pages = table.pages(2).map(|page| page.to_string()).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     pages,
+    ///     [
+    ///         " i32 \n-----\n 1   \n 2   ",
+    ///         " i32 \n-----\n 3   ",
+    ///     ]
+    /// );
+    /// ```
+    pub fn pages(&self, rows_per_page: usize) -> Pages<'_, R> {
+        Pages::new(self, rows_per_page)
+    }
+
+    /// Returns a page by its index, or [`None`] if there's no such page.
+    ///
+    /// See [`Table::pages`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows_per_page` is 0.
+    pub fn page(&self, page: usize, rows_per_page: usize) -> Option<Self>
+    where
+        R: Resizable + Clone,
+    {
+        if page >= self.count_pages(rows_per_page) {
+            return None;
+        }
+
+        Some(build_page(self, page, rows_per_page))
+    }
+}
+
+impl<R> Table<R>
+where
+    R: Records + RecordsMut<String> + Resizable,
+{
+    /// Inserts a row at a given index, shifting all rows after it down.
+    ///
+    /// Missing values are left empty, and values which don't fit the table are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row > count_rows`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let data = [("apple", 3), ("pear", 5)];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.insert_row(3, ["total", "8"]);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "+-------+-----+\n",
+    ///         "| &str  | i32 |\n",
+    ///         "+-------+-----+\n",
+    ///         "| apple | 3   |\n",
+    ///         "+-------+-----+\n",
+    ///         "| pear  | 5   |\n",
+    ///         "+-------+-----+\n",
+    ///         "| total | 8   |\n",
+    ///         "+-------+-----+",
+    ///     )
+    /// );
+    /// ```
+    pub fn insert_row<I, T>(&mut self, row: usize, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let count_rows = self.count_rows();
+        assert!(
+            row <= count_rows,
+            "row index (is {}) should be <= count rows (is {})",
+            row,
+            count_rows
+        );
+
+        move_rows_aside(self, row);
+        move_row_spans(self, row);
+
+        let count_columns = self.count_columns();
+        let ctrl = CfgWidthFunction::from_cfg(self.get_config());
+        for (col, text) in values.into_iter().take(count_columns).enumerate() {
+            self.records.set((row, col), text.into(), &ctrl);
+        }
+
+        self.destroy_width_cache();
+        self.destroy_height_cache();
+
+        self
+    }
+
+    /// Inserts a column at a given index, shifting all columns after it to the right.
+    ///
+    /// The header is put into the first row in case the table has a header,
+    /// otherwise it's ignored.
+    ///
+    /// Missing values are left empty, and values which don't fit the table are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `column > count_columns`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let data = [("apple", 3, 2), ("pear", 5, 4)];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.insert_column(3, "total", ["6", "20"]);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "+-------+-----+-----+-------+\n",
+    ///         "| &str  | i32 | i32 | total |\n",
+    ///         "+-------+-----+-----+-------+\n",
+    ///         "| apple | 3   | 2   | 6     |\n",
+    ///         "+-------+-----+-----+-------+\n",
+    ///         "| pear  | 5   | 4   | 20    |\n",
+    ///         "+-------+-----+-----+-------+",
+    ///     )
+    /// );
+    /// ```
+    pub fn insert_column<H, I, T>(&mut self, column: usize, header: H, values: I) -> &mut Self
+    where
+        H: Into<String>,
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let count_columns = self.count_columns();
+        assert!(
+            column <= count_columns,
+            "column index (is {}) should be <= count columns (is {})",
+            column,
+            count_columns
+        );
+
+        move_columns_aside(self, column);
+        move_column_spans(self, column);
+
+        let ctrl = CfgWidthFunction::from_cfg(self.get_config());
+        let start = if self.has_header {
+            self.records.set((0, column), header.into(), &ctrl);
+            1
+        } else {
+            0
+        };
+
+        let count_rows = self.count_rows();
+        for (row, text) in (start..count_rows).zip(values) {
+            self.records.set((row, column), text.into(), &ctrl);
+        }
+
+        self.destroy_width_cache();
+        self.destroy_height_cache();
+
+        self
+    }
+}
+
 impl<R> fmt::Display for Table<R>
 where
     R: Records,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut cfg = Cow::Borrowed(&self.cfg);
+        let mut cfg = self.get_render_config();
         set_align_table(f, &mut cfg);
         set_width_table(f, &mut cfg, self);
 
         let width = self.get_width_ctrl();
         let height = self.get_height_ctrl();
-
-        let grid = Grid::new(&self.records, &cfg, &width, &height);
-
-        write!(f, "{}", grid)
+        self.write_grid(&cfg, &width, &height, f)
     }
 }
 
@@ -358,10 +1534,36 @@ where
             has_header: false,
             widths: None,
             heights: None,
+            estimated_widths: None,
+            estimated_heights: None,
+            settings: Settings::default(),
         }
     }
 }
 
+impl FromStr for Table<VecRecords<CellInfo<'static>>> {
+    type Err = Infallible;
+
+    /// Parses a rendered table.
+    ///
+    /// It's a shortcut for [`Builder::from_rendered`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Builder::from_rendered(s).build())
+    }
+}
+
+impl<'a> From<Builder<'a>> for Table<VecRecords<CellInfo<'a>>> {
+    fn from(builder: Builder<'a>) -> Self {
+        builder.build()
+    }
+}
+
+impl From<Vec<Vec<String>>> for Table<VecRecords<CellInfo<'static>>> {
+    fn from(records: Vec<Vec<String>>) -> Self {
+        Builder::from(records).build()
+    }
+}
+
 impl<'a, T> FromIterator<&'a T> for Table<VecRecords<CellInfo<'a>>>
 where
     T: Tabled + 'a,
@@ -421,6 +1623,33 @@ where
     }
 }
 
+/// An adapter of [`std::io::Write`] to [`fmt::Write`] which keeps an IO error.
+struct IoWriter<W> {
+    writer: W,
+    result: std::io::Result<()>,
+}
+
+impl<W> fmt::Write for IoWriter<W>
+where
+    W: std::io::Write,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.writer.write_all(s.as_bytes()).map_err(|err| {
+            self.result = Err(err);
+            fmt::Error
+        })
+    }
+}
+
+#[cfg(feature = "color")]
+fn remove_colors(cfg: &mut GridConfig) {
+    use papergrid::{AnsiColor, MarginColor, PaddingColor};
+
+    cfg.set_border_color_global(AnsiColor::default());
+    cfg.set_margin_color(MarginColor::default());
+    cfg.set_padding_color(Entity::Global, PaddingColor::default());
+}
+
 fn set_align_table(f: &fmt::Formatter<'_>, cfg: &mut Cow<'_, GridConfig>) {
     if let Some(alignment) = f.align() {
         let alignment = convert_fmt_alignment(alignment);
@@ -0,0 +1,199 @@
+//! This module contains a [`TableValue`] tree which describes hierarchical data.
+
+use std::iter::FromIterator;
+
+use papergrid::records::{cell_info::CellInfo, vec_records::VecRecords};
+
+use crate::{builder::Builder, Table};
+
+/// [`TableValue`] is a tree of values which is laid out as a [`Table`].
+///
+/// A [`TableValue::Row`] puts its values next to each other,
+/// a [`TableValue::Column`] puts them one below another.
+///
+/// A value which is smaller than its neighbours is stretched by spans,
+/// so nested collections don't need to be flattened into records of the same length.
+///
+/// # Example
+///
+/// ```
+/// use std::iter::FromIterator;
+///
+/// use tabled::{Table, TableValue};
+///
+/// let value = TableValue::Column(vec![
+///     TableValue::Row(vec![
+///         TableValue::from("fruits"),
+///         TableValue::from_iter(["apple", "banana"]),
+///     ]),
+///     TableValue::Row(vec![
+///         TableValue::from("vegetables"),
+///         TableValue::from_iter(["carrot"]),
+///     ]),
+/// ]);
+///
+/// let table = Table::from(value).to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+------------+--------+\n\
+///      | fruits     | apple  |\n\
+///      |            +--------+\n\
+///      |            | banana |\n\
+///      +------------+--------+\n\
+///      | vegetables | carrot |\n\
+///      +------------+--------+"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableValue {
+    /// A single cell.
+    Cell(String),
+    /// Values placed horizontally.
+    Row(Vec<TableValue>),
+    /// Values placed vertically.
+    Column(Vec<TableValue>),
+}
+
+impl TableValue {
+    /// Returns a number of rows and columns the value occupies.
+    pub fn shape(&self) -> (usize, usize) {
+        match self {
+            Self::Cell(_) => (1, 1),
+            Self::Row(values) if values.is_empty() => (1, 1),
+            Self::Column(values) if values.is_empty() => (1, 1),
+            Self::Row(values) => values
+                .iter()
+                .map(Self::shape)
+                .fold((0, 0), |acc, shape| (acc.0.max(shape.0), acc.1 + shape.1)),
+            Self::Column(values) => values
+                .iter()
+                .map(Self::shape)
+                .fold((0, 0), |acc, shape| (acc.0 + shape.0, acc.1.max(shape.1))),
+        }
+    }
+}
+
+impl From<String> for TableValue {
+    fn from(text: String) -> Self {
+        Self::Cell(text)
+    }
+}
+
+impl From<&str> for TableValue {
+    fn from(text: &str) -> Self {
+        Self::Cell(text.to_owned())
+    }
+}
+
+impl<T> FromIterator<T> for TableValue
+where
+    T: Into<TableValue>,
+{
+    /// Creates a [`TableValue::Column`] of values.
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::Column(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl From<TableValue> for Builder<'static> {
+    fn from(value: TableValue) -> Self {
+        let (count_rows, count_cols) = value.shape();
+
+        let mut layout = Layout {
+            cells: vec![vec![String::new(); count_cols]; count_rows],
+            column_spans: Vec::new(),
+            row_spans: Vec::new(),
+        };
+        layout.place(value, (0, 0), (count_rows, count_cols));
+
+        let mut builder = Builder::from(layout.cells);
+        for ((row, col), span) in layout.column_spans {
+            builder.set_span(row, col, span);
+        }
+
+        for ((row, col), span) in layout.row_spans {
+            builder.set_row_span(row, col, span);
+        }
+
+        builder
+    }
+}
+
+impl From<TableValue> for Table<VecRecords<CellInfo<'static>>> {
+    fn from(value: TableValue) -> Self {
+        Builder::from(value).build()
+    }
+}
+
+struct Layout {
+    cells: Vec<Vec<String>>,
+    column_spans: Vec<((usize, usize), usize)>,
+    row_spans: Vec<((usize, usize), usize)>,
+}
+
+impl Layout {
+    /// Places a value into an area, the last nested value takes the space left.
+    fn place(&mut self, value: TableValue, pos: (usize, usize), size: (usize, usize)) {
+        match value {
+            TableValue::Cell(text) => self.place_cell(text, pos, size),
+            TableValue::Row(values) | TableValue::Column(values) if values.is_empty() => {
+                self.place_cell(String::new(), pos, size)
+            }
+            TableValue::Row(values) => self.place_row(values, pos, size),
+            TableValue::Column(values) => self.place_column(values, pos, size),
+        }
+    }
+
+    fn place_row(&mut self, values: Vec<TableValue>, pos: (usize, usize), size: (usize, usize)) {
+        let (row, mut col) = pos;
+        let (height, width) = size;
+
+        let count = values.len();
+        for (i, value) in values.into_iter().enumerate() {
+            let value_width = if i + 1 == count {
+                width - (col - pos.1)
+            } else {
+                value.shape().1
+            };
+
+            self.place(value, (row, col), (height, value_width));
+            col += value_width;
+        }
+    }
+
+    fn place_column(&mut self, values: Vec<TableValue>, pos: (usize, usize), size: (usize, usize)) {
+        let (mut row, col) = pos;
+        let (height, width) = size;
+
+        let count = values.len();
+        for (i, value) in values.into_iter().enumerate() {
+            let value_height = if i + 1 == count {
+                height - (row - pos.0)
+            } else {
+                value.shape().0
+            };
+
+            self.place(value, (row, col), (value_height, width));
+            row += value_height;
+        }
+    }
+
+    fn place_cell(&mut self, text: String, pos: (usize, usize), size: (usize, usize)) {
+        let (row, col) = pos;
+        let (height, width) = size;
+
+        self.cells[row][col] = text;
+
+        if width > 1 {
+            self.column_spans.push((pos, width));
+        }
+
+        if height > 1 {
+            self.row_spans.push((pos, height));
+        }
+    }
+}
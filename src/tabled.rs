@@ -107,7 +107,7 @@ macro_rules! default_table {
 
 default_table!(&str = borrowed);
 default_table!(str = borrowed);
-default_table!(String);
+default_table!(String = borrowed);
 
 default_table!(char);
 
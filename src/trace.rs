@@ -0,0 +1,188 @@
+//! This module contains a [`SpanSummary`] layer which collects timings of [`tracing`] spans
+//! so they can be shown as a [`Table`].
+//!
+//! [`tracing`]: https://docs.rs/tracing
+//! [`Table`]: crate::Table
+
+use std::{
+    collections::BTreeMap,
+    convert::TryFrom,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use papergrid::records::{cell_info::CellInfo, vec_records::VecRecords};
+use tracing_core::{span, Subscriber};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+use crate::{builder::Builder, Table};
+
+/// [`SpanSummary`] is a [`Layer`] which collects timings of closed spans
+/// grouped by their target and name.
+///
+/// A time is counted only while a span is entered,
+/// so a time an async task is idle is not included.
+///
+/// A [`SpanSummary`] can be cloned to keep a handle to it after it's given to a subscriber,
+/// all clones share the same data.
+///
+/// # Example
+///
+/// ```
+/// use tabled::trace::SpanSummary;
+/// use tracing_subscriber::{layer::SubscriberExt, Registry};
+///
+/// let summary = SpanSummary::new();
+/// let subscriber = Registry::default().with(summary.clone());
+///
+/// tracing::subscriber::with_default(subscriber, || {
+///     for _ in 0..3 {
+///         let _span = tracing::info_span!("request").entered();
+///     }
+/// });
+///
+/// let table = summary.table();
+/// assert_eq!(table.shape(), (2, 7));
+///
+/// println!("{}", table);
+/// ```
+///
+/// [`Layer`]: tracing_subscriber::Layer
+#[derive(Debug, Default, Clone)]
+pub struct SpanSummary {
+    stats: Arc<Mutex<BTreeMap<(&'static str, &'static str), SpanStats>>>,
+}
+
+impl SpanSummary {
+    /// Creates a new [`SpanSummary`] instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a table of collected timings.
+    ///
+    /// It has `target`, `span`, `count`, `total`, `mean`, `min` and `max` columns,
+    /// and a row per span sorted by a target and a name.
+    pub fn table(&self) -> Table<VecRecords<CellInfo<'static>>> {
+        let stats = self.lock();
+
+        let mut builder = Builder::with_capacity(stats.len(), 7);
+        builder.set_columns(["target", "span", "count", "total", "mean", "min", "max"]);
+
+        for ((target, name), stats) in stats.iter() {
+            let mean = stats.total / u32::try_from(stats.count).unwrap_or(u32::MAX);
+
+            builder.add_record([
+                target.to_string(),
+                name.to_string(),
+                stats.count.to_string(),
+                format_duration(stats.total),
+                format_duration(mean),
+                format_duration(stats.min),
+                format_duration(stats.max),
+            ]);
+        }
+
+        builder.build()
+    }
+
+    /// Drops all collected timings.
+    ///
+    /// It can be used to show a summary periodically.
+    pub fn reset(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<(&'static str, &'static str), SpanStats>> {
+        // a panic while the lock is held can't leave stats in an inconsistent state
+        self.stats.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl<S> Layer<S> for SpanSummary
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, _: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(Timing::default());
+        }
+    }
+
+    fn on_enter(&self, id: &span::Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(timing) = span.extensions_mut().get_mut::<Timing>() {
+                timing.entered = Some(Instant::now());
+            }
+        }
+    }
+
+    fn on_exit(&self, id: &span::Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(timing) = span.extensions_mut().get_mut::<Timing>() {
+                if let Some(entered) = timing.entered.take() {
+                    timing.busy += entered.elapsed();
+                }
+            }
+        }
+    }
+
+    fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+        let span = match ctx.span(&id) {
+            Some(span) => span,
+            None => return,
+        };
+
+        let busy = match span.extensions_mut().remove::<Timing>() {
+            Some(timing) => timing.busy,
+            None => return,
+        };
+
+        let metadata = span.metadata();
+        let key = (metadata.target(), metadata.name());
+
+        let mut stats = self.lock();
+        match stats.get_mut(&key) {
+            Some(stats) => stats.add(busy),
+            None => {
+                stats.insert(key, SpanStats::new(busy));
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Timing {
+    busy: Duration,
+    entered: Option<Instant>,
+}
+
+#[derive(Debug)]
+struct SpanStats {
+    count: u64,
+    total: Duration,
+    min: Duration,
+    max: Duration,
+}
+
+impl SpanStats {
+    fn new(time: Duration) -> Self {
+        Self {
+            count: 1,
+            total: time,
+            min: time,
+            max: time,
+        }
+    }
+
+    fn add(&mut self, time: Duration) {
+        self.count += 1;
+        self.total += time;
+        self.min = std::cmp::min(self.min, time);
+        self.max = std::cmp::max(self.max, time);
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
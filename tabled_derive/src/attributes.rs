@@ -11,6 +11,7 @@ pub struct Attributes {
     pub rename_all: Option<CasingStyle>,
     pub display_with: Option<String>,
     pub display_with_use_self: bool,
+    pub display_with_args: Vec<proc_macro2::TokenStream>,
     pub order: Option<usize>,
 }
 
@@ -53,9 +54,10 @@ impl Attributes {
             parse::TabledAttrKind::RenameAll(lit) => {
                 self.rename_all = Some(CasingStyle::from_lit(&lit)?);
             }
-            parse::TabledAttrKind::DisplayWith(path, use_self) => {
+            parse::TabledAttrKind::DisplayWith(path, use_self, args) => {
                 self.display_with = Some(path.value());
                 self.display_with_use_self = use_self;
+                self.display_with_args = args;
             }
             parse::TabledAttrKind::Order(value) => self.order = Some(lit_int_to_usize(&value)?),
         }
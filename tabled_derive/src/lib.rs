@@ -187,7 +187,7 @@ fn info_from_fields(
         headers.push(header);
 
         let field_name = field_name(i, field);
-        let value = get_field_fields(&field_name, &attributes);
+        let value = get_field_fields(&field_name, &field.ty, &attributes);
 
         values.push(value);
     }
@@ -337,7 +337,7 @@ fn get_type_headers(field_type: &Type, inline_prefix: &str, prefix: &str) -> Tok
     }
 }
 
-fn get_field_fields(field: &TokenStream, attr: &Attributes) -> TokenStream {
+fn get_field_fields(field: &TokenStream, field_type: &Type, attr: &Attributes) -> TokenStream {
     if attr.inline {
         return quote! { #field.fields() };
     }
@@ -345,24 +345,65 @@ fn get_field_fields(field: &TokenStream, attr: &Attributes) -> TokenStream {
     if let Some(func) = &attr.display_with {
         let func_call = match attr.display_with_use_self {
             true => use_function_with_self(func),
-            false => use_function_for(field, func),
+            false => use_function_for(field, func, &attr.display_with_args),
         };
 
         return quote!(vec![::std::borrow::Cow::from(#func_call)]);
     }
 
+    // a string is borrowed as is so no allocation is made for a cell
+    if is_string_type(field_type) {
+        return quote!(vec![::std::borrow::Cow::Borrowed(
+            ::std::convert::AsRef::<str>::as_ref(&*#field)
+        )]);
+    }
+
     quote!(vec![::std::borrow::Cow::Owned(format!("{}", #field))])
 }
 
-fn use_function_for(field: &TokenStream, function: &str) -> TokenStream {
+/// Checks whether a type is `&str`, `String` or `Cow<str>`.
+fn is_string_type(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => is_str_type(&reference.elem),
+        Type::Paren(paren) => is_string_type(&paren.elem),
+        Type::Group(group) => is_string_type(&group.elem),
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = match path.path.segments.last() {
+                Some(segment) => segment,
+                None => return false,
+            };
+
+            match &segment.arguments {
+                syn::PathArguments::None => segment.ident == "String",
+                syn::PathArguments::AngleBracketed(args) if segment.ident == "Cow" => {
+                    args.args.iter().any(|arg| match arg {
+                        syn::GenericArgument::Type(ty) => is_str_type(ty),
+                        _ => false,
+                    })
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+fn is_str_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path.qself.is_none() && path.path.is_ident("str"),
+        _ => false,
+    }
+}
+
+fn use_function_for(field: &TokenStream, function: &str, args: &[TokenStream]) -> TokenStream {
     let path: syn::Result<syn::ExprPath> = syn::parse_str(function);
     match path {
         Ok(path) => {
-            quote! { #path(&#field) }
+            quote! { #path(&#field #(, #args)*) }
         }
         Err(_) => {
             let function = Ident::new(function, proc_macro2::Span::call_site());
-            quote! { #function(&#field) }
+            quote! { #function(&#field #(, #args)*) }
         }
     }
 }
@@ -1,7 +1,8 @@
-use proc_macro2::{Ident, Span};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
 use syn::{
-    parenthesized, parse::Parse, punctuated::Punctuated, token, Attribute, LitBool, LitInt, LitStr,
-    Token,
+    parenthesized, parse::Parse, punctuated::Punctuated, token, Attribute, Expr, LitBool, LitInt,
+    LitStr, Token,
 };
 
 pub fn parse_attributes(
@@ -15,6 +16,7 @@ pub fn parse_attributes(
 }
 
 pub struct TabledAttr {
+    #[allow(dead_code)]
     pub ident: Ident,
     pub kind: TabledAttrKind,
 }
@@ -31,7 +33,7 @@ pub enum TabledAttrKind {
     Inline(LitBool, Option<LitStr>),
     Rename(LitStr),
     RenameAll(LitStr),
-    DisplayWith(LitStr, bool),
+    DisplayWith(LitStr, bool, Vec<TokenStream>),
     Order(LitInt),
 }
 
@@ -51,7 +53,9 @@ impl Parse for TabledAttr {
                 match name_str.as_str() {
                     "rename" => return Ok(Self::new(name, Rename(lit))),
                     "rename_all" => return Ok(Self::new(name, RenameAll(lit))),
-                    "display_with" => return Ok(Self::new(name, DisplayWith(lit, false))),
+                    "display_with" => {
+                        return Ok(Self::new(name, DisplayWith(lit, false, Vec::new())))
+                    }
                     _ => {}
                 }
             }
@@ -89,19 +93,26 @@ impl Parse for TabledAttr {
 
                 match name_str.as_str() {
                     "display_with" => {
-                        let use_self = if nested.peek(Token![,]) {
+                        let mut use_self = false;
+                        let mut args = Vec::new();
+                        if nested.peek(Token![,]) {
                             let _comma = nested.parse::<Token![,]>()?;
-                            if nested.peek(syn::Ident) {
-                                let ident = nested.parse::<syn::Ident>()?;
-                                ident == "args"
+
+                            // a single `args` is kept to pass `&self` instead of a field
+                            let fork = nested.fork();
+                            let is_self_arg =
+                                matches!(fork.parse::<Ident>(), Ok(ident) if ident == "args");
+                            if is_self_arg && fork.is_empty() {
+                                nested.parse::<Ident>()?;
+                                use_self = true;
                             } else {
-                                false
+                                let exprs =
+                                    Punctuated::<Expr, Token![,]>::parse_terminated(&nested)?;
+                                args = exprs.iter().map(display_with_arg).collect();
                             }
-                        } else {
-                            false
-                        };
+                        }
 
-                        return Ok(Self::new(name, DisplayWith(lit, use_self)));
+                        return Ok(Self::new(name, DisplayWith(lit, use_self, args)));
                     }
                     "inline" => {
                         return Ok(Self::new(
@@ -136,3 +147,35 @@ impl Parse for TabledAttr {
         ))
     }
 }
+
+/// Converts an argument of `display_with` into a function argument.
+///
+/// `self` and its fields are passed by a reference, other expressions are passed as they are.
+fn display_with_arg(expr: &Expr) -> TokenStream {
+    if !is_self_expr(expr) {
+        return expr.to_token_stream();
+    }
+
+    // `self` is respanned to refer to a receiver of a generated method,
+    // which matters in case an attribute is passed through a `macro_rules`
+    let expr = expr
+        .to_token_stream()
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Ident(ident) if ident == "self" => {
+                TokenTree::Ident(Ident::new("self", Span::call_site()))
+            }
+            token => token,
+        })
+        .collect::<TokenStream>();
+
+    quote! { &#expr }
+}
+
+fn is_self_expr(expr: &Expr) -> bool {
+    match expr {
+        Expr::Path(path) => path.qself.is_none() && path.path.is_ident("self"),
+        Expr::Field(field) => is_self_expr(&field.base),
+        _ => false,
+    }
+}
@@ -1,7 +1,7 @@
 use tabled::{
     locator::ByColumnName,
     object::{Columns, Rows, Segment},
-    Alignment, Modify, Padding, Style,
+    Alignment, Modify, Padding, Style, Table,
 };
 
 use crate::util::{create_table, init_table, test_table};
@@ -136,3 +136,19 @@ test_table!(
         " 1 |   1-0    |   1-1    |   1-2    "
         " 2 |   2-0    |   2-1    |   2-2    "
 );
+
+test_table!(
+    auto_alignment,
+    Table::new([
+        ("1,024", "x", "", "10:30"),
+        ("-3.5", "12", "", "2023-01-02"),
+        ("7%", "", "", "11:00"),
+    ])
+    .with(Style::psql())
+    .with(Alignment::auto()),
+    " &str  | &str | &str | &str       "
+    "-------+------+------+------------"
+    " 1,024 | x    |      |      10:30 "
+    "  -3.5 | 12   |      | 2023-01-02 "
+    "    7% |      |      |      11:00 "
+);
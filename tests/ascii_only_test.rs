@@ -0,0 +1,80 @@
+use tabled::{format::Format, object::Segment, AsciiOnly, BorderText, Modify, Panel, Style, Table};
+
+use crate::util::test_table;
+
+mod util;
+
+test_table!(
+    ascii_only_transliterates_cells,
+    Table::new([("Ærø", "café — “quoted” …", "5 × 3 → 15")]).with(AsciiOnly),
+    "+------+---------------------+-------------+"
+    "| &str | &str                | &str        |"
+    "+------+---------------------+-------------+"
+    "| AEro | cafe - \"quoted\" ... | 5 x 3 -> 15 |"
+    "+------+---------------------+-------------+"
+);
+
+test_table!(
+    ascii_only_replaces_unknown_characters,
+    Table::new(["你好", "😀"]).with(Style::psql()).with(AsciiOnly),
+    " &str "
+    "------"
+    " ??   "
+    " ?    "
+);
+
+test_table!(
+    ascii_only_replaces_borders,
+    Table::new([1, 2])
+        .with(Style::extended())
+        .with(Panel::header("numbers"))
+        .with(Style::correct_spans())
+        .with(AsciiOnly),
+    "+---------+"
+    "| numbers |"
+    "+---------+"
+    "| i32     |"
+    "+---------+"
+    "| 1       |"
+    "+---------+"
+    "| 2       |"
+    "+---------+"
+);
+
+test_table!(
+    ascii_only_replaces_border_text,
+    Table::new([1])
+        .with(Style::rounded())
+        .with(BorderText::first("┤ñ├"))
+        .with(AsciiOnly),
+    "+n+---+"
+    "| i32 |"
+    "+-----+"
+    "| 1   |"
+    "+-----+"
+);
+
+test_table!(
+    ascii_only_replaces_text_set_later_keeping_width,
+    Table::new(["a"])
+        .with(Style::psql())
+        .with(AsciiOnly)
+        .with(Modify::new(Segment::all()).with(Format::new(|_| String::from("数")))),
+    " ?? "
+    "----"
+    " ?? "
+);
+
+#[test]
+fn ascii_only_flag() {
+    let mut table = Table::new([1]);
+    assert!(!table.is_ascii_only());
+
+    table.with(Style::modern()).with(AsciiOnly);
+    assert!(table.is_ascii_only());
+
+    let mut buf = Vec::new();
+    table.build_into(&mut buf).unwrap();
+    assert!(buf.is_ascii());
+    assert_eq!(buf, table.to_string().as_bytes());
+}
@@ -0,0 +1,80 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    display::AsciiDocTable,
+    object::{Cell, Columns},
+    Alignment, Modify, Span,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    asciidoc,
+    create_table::<2, 2>().to_asciidoc(),
+    "[cols=\"^,^,^\",options=\"header\"]"
+    "|==="
+    "|N |column 0 |column 1"
+    "|0 |0-0 |0-1"
+    "|1 |1-0 |1-1"
+    "|==="
+);
+
+test_table!(
+    asciidoc_without_header,
+    AsciiDocTable::new(&Builder::from_iter([["a", "b"], ["c", "d"]]).build()),
+    "[cols=\"<,<\"]"
+    "|==="
+    "|a |b"
+    "|c |d"
+    "|==="
+);
+
+test_table!(
+    asciidoc_alignment,
+    Builder::from_iter([["a", "b"], ["c", "d"]])
+        .build()
+        .with(Modify::new(Columns::single(1)).with(Alignment::right()))
+        .with(Modify::new(Cell(1, 1)).with(Alignment::center()))
+        .to_asciidoc(),
+    "[cols=\"<,>\"]"
+    "|==="
+    "|a |b"
+    "|c ^|d"
+    "|==="
+);
+
+test_table!(
+    asciidoc_span,
+    Builder::from_iter([["a", "b", "c"], ["d", "e", "f"], ["g", "h", "i"]])
+        .build()
+        .with(Modify::new(Cell(0, 0)).with(Span::column(2)))
+        .with(Modify::new(Cell(0, 2)).with(Span::row(2)))
+        .to_asciidoc(),
+    "[cols=\"<,<,<\"]"
+    "|==="
+    "2+|a .2+|c"
+    "|d |e"
+    "|g |h |i"
+    "|==="
+);
+
+test_table!(
+    asciidoc_escape,
+    Builder::from_iter([["a|b", "c\nd"]]).build().to_asciidoc(),
+    "[cols=\"<,<\"]"
+    "|==="
+    "|a\\|b |c +"
+    "d"
+    "|==="
+);
+
+test_table!(
+    asciidoc_empty,
+    Builder::default().build().to_asciidoc(),
+    "[cols=\"\"]"
+    "|==="
+    "|==="
+);
@@ -756,3 +756,262 @@ fn qc_table_is_consistent(data: Vec<Vec<isize>>) -> bool {
         .all(|line_width| line_width == lines[0].len());
     lines_has_the_same_length
 }
+
+test_table!(
+    builder_runtime_shape_test,
+    {
+        let data = "id;name\n0;Grodno;Belarus\n1\n2;Minsk";
+        let mut lines = data.lines();
+
+        let mut builder = Builder::default();
+        builder.set_columns(lines.next().unwrap().split(';'));
+        for line in lines {
+            builder.add_record(line.split(';'));
+        }
+
+        builder.build()
+    },
+    "+----+--------+---------+"
+    "| id | name   |         |"
+    "+----+--------+---------+"
+    "| 0  | Grodno | Belarus |"
+    "+----+--------+---------+"
+    "| 1  |        |         |"
+    "+----+--------+---------+"
+    "| 2  | Minsk  |         |"
+    "+----+--------+---------+"
+);
+
+test_table!(
+    clean_different_row_length,
+    Builder::from_iter(vec![vec!["a", "", ""], vec!["", ""], vec!["c"], vec![]]).clean().clone().build(),
+    "+---+"
+    "| a |"
+    "+---+"
+    "| c |"
+    "+---+"
+);
+
+test_table!(
+    clean_different_row_length_with_columns,
+    Builder::from_iter(vec![vec!["", "b"], vec![""]]).set_columns(["1", "2", "3"]).clean().clone().build(),
+    "+---+"
+    "| 2 |"
+    "+---+"
+    "| b |"
+    "+---+"
+);
+
+test_table!(
+    with_capacity,
+    Builder::with_capacity(10, 10)
+        .add_record(["1", "2"])
+        .add_record(["a"])
+        .clone()
+        .build(),
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+    "| a |   |"
+    "+---+---+"
+);
+
+test_table!(
+    append,
+    {
+        let mut builder = Builder::from_iter([["a", "b"]]);
+        builder.append(Builder::from_iter(vec![vec!["c", "d", "e"], vec!["f"]]));
+        builder.build()
+    },
+    "+---+---+---+"
+    "| a | b |   |"
+    "+---+---+---+"
+    "| c | d | e |"
+    "+---+---+---+"
+    "| f |   |   |"
+    "+---+---+---+"
+);
+
+test_table!(
+    append_takes_columns,
+    {
+        let mut other = Builder::from_iter([["c", "d"]]);
+        other.set_columns(["1", "2"]);
+
+        let mut builder = Builder::from_iter([["a", "b"]]);
+        builder.remove_columns().append(other);
+        builder.build()
+    },
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+    "| c | d |"
+    "+---+---+"
+);
+
+test_table!(
+    merge,
+    {
+        let mut other = Builder::from_iter(vec![vec!["3", "1", "ignored"], vec!["33"]]);
+        other.set_columns(["c", "a", "a"]);
+
+        let mut builder = Builder::from_iter([["1", "2"]]);
+        builder.set_columns(["a", "b"]).set_default_text("-");
+        builder.merge(other);
+        builder.build()
+    },
+    "+---+---+----+---------+"
+    "| a | b | c  | a       |"
+    "+---+---+----+---------+"
+    "| 1 | 2 | -  | -       |"
+    "+---+---+----+---------+"
+    "| 1 | - | 3  | ignored |"
+    "+---+---+----+---------+"
+    "| - | - | 33 | -       |"
+    "+---+---+----+---------+"
+);
+
+test_table!(
+    merge_without_columns,
+    {
+        let mut builder = Builder::from_iter([["1", "2"]]);
+        builder.set_columns(["a", "b"]);
+        builder.merge(Builder::from_iter([["3", "4"]]));
+        builder.build()
+    },
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+    "| 3 | 4 |"
+    "+---+---+"
+);
+
+test_table!(
+    set_span,
+    {
+        let mut builder = Builder::default();
+        builder.set_columns(["a", "b", "c"]);
+        builder.add_record(["section"]);
+        builder.add_record(["1", "2", "3"]);
+        builder.set_span(1, 0, 3);
+        builder.set_span(0, 1, 2);
+        builder.build()
+    },
+    "+---+-------+"
+    "| a | b     |"
+    "+---+-------+"
+    "| section   |"
+    "+---+---+---+"
+    "| 1 | 2 | 3 |"
+    "+---+---+---+"
+);
+
+test_table!(
+    set_row_span,
+    {
+        let mut builder = Builder::default();
+        builder.add_record(["a", "1"]);
+        builder.add_record(["", "2"]);
+        builder.add_record(["", "3"]);
+        builder.set_row_span(0, 0, 3);
+        builder.build()
+    },
+    "+---+---+"
+    "| a | 1 |"
+    "|   +---+"
+    "|   | 2 |"
+    "|   +---+"
+    "|   | 3 |"
+    "+---+---+"
+);
+
+test_table!(
+    set_span_out_of_bounds,
+    {
+        let mut builder = Builder::default();
+        builder.add_record(["a", "b"]);
+        builder.set_span(0, 0, 3);
+        builder.set_span(5, 0, 2);
+        builder.set_row_span(0, 1, 2);
+        builder.build()
+    },
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+);
+
+test_table!(
+    set_span_dropped_by_index,
+    {
+        let mut builder = Builder::default();
+        builder.add_record(["a", "b"]);
+        builder.set_span(0, 0, 2);
+        builder.index().build()
+    },
+    "+---+---+---+"
+    "|   | 0 | 1 |"
+    "+---+---+---+"
+    "| 0 | a | b |"
+    "+---+---+---+"
+);
+
+test_table!(
+    from_map,
+    Builder::from_map(std::collections::BTreeMap::from_iter([("b", 2), ("a", 1)])).build(),
+    "+-----+-------+"
+    "| key | value |"
+    "+-----+-------+"
+    "| a   | 1     |"
+    "+-----+-------+"
+    "| b   | 2     |"
+    "+-----+-------+"
+);
+
+test_table!(
+    from_map_empty,
+    Builder::from_map(std::collections::BTreeMap::<String, String>::new()).build(),
+    "+-----+-------+"
+    "| key | value |"
+    "+-----+-------+"
+);
+
+test_table!(
+    from_maps,
+    Builder::from_maps([
+        std::collections::BTreeMap::from_iter([("b", 1), ("a", 2)]),
+        std::collections::BTreeMap::from_iter([("c", 3)]),
+        std::collections::BTreeMap::from_iter([("c", 4), ("a", 5)]),
+    ])
+    .build(),
+    "+---+---+---+"
+    "| a | b | c |"
+    "+---+---+---+"
+    "| 2 | 1 |   |"
+    "+---+---+---+"
+    "|   |   | 3 |"
+    "+---+---+---+"
+    "| 5 |   | 4 |"
+    "+---+---+---+"
+);
+
+test_table!(
+    from_maps_vec_of_pairs,
+    Builder::from_maps(vec![vec![("z", "1"), ("y", "2")], vec![("x", "3"), ("z", "4")]]).build(),
+    "+---+---+---+"
+    "| z | y | x |"
+    "+---+---+---+"
+    "| 1 | 2 |   |"
+    "+---+---+---+"
+    "| 4 |   | 3 |"
+    "+---+---+---+"
+);
+
+test_table!(
+    from_maps_empty,
+    Builder::from_maps(Vec::<Vec<(String, String)>>::new()).build(),
+    ""
+);
@@ -0,0 +1,88 @@
+use tabled::{papergrid::AlignmentHorizontal, Caption, Margin, Style};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    caption_top_and_bottom,
+    create_table::<1, 2>()
+        .with(Style::psql())
+        .with(Caption::top("title"))
+        .with(Caption::bottom("1 row")),
+    "          title          "
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   0-0    |   0-1    "
+    "          1 row          "
+);
+
+test_table!(
+    caption_alignment,
+    create_table::<1, 2>()
+        .with(Style::psql())
+        .with(Caption::top("left").alignment(AlignmentHorizontal::Left))
+        .with(Caption::bottom("right").alignment(AlignmentHorizontal::Right)),
+    "left                     "
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   0-0    |   0-1    "
+    "                    right"
+);
+
+test_table!(
+    caption_multiline,
+    create_table::<1, 1>().with(Style::psql()).with(Caption::top("a\nlonger")),
+    "      a       "
+    "    longer    "
+    " N | column 0 "
+    "---+----------"
+    " 0 |   0-0    "
+);
+
+test_table!(
+    caption_wider_than_table,
+    create_table::<1, 1>().with(Style::psql()).with(Caption::bottom("a very long caption")),
+    " N | column 0 "
+    "---+----------"
+    " 0 |   0-0    "
+    "a very long caption"
+);
+
+test_table!(
+    caption_is_replaced,
+    create_table::<1, 1>()
+        .with(Style::psql())
+        .with(Caption::top("first"))
+        .with(Caption::top("second")),
+    "    second    "
+    " N | column 0 "
+    "---+----------"
+    " 0 |   0-0    "
+);
+
+test_table!(
+    caption_includes_margin,
+    create_table::<1, 1>()
+        .with(Style::psql())
+        .with(Margin::new(2, 2, 0, 0))
+        .with(Caption::top("title")),
+    "      title       "
+    "   N | column 0   "
+    "  ---+----------  "
+    "   0 |   0-0      "
+);
+
+#[test]
+fn caption_build_into() {
+    let mut table = create_table::<1, 1>();
+    table.with(Caption::top("title"));
+
+    let mut buf = Vec::new();
+    table.build_into(&mut buf).unwrap();
+    assert_eq!(buf, table.to_string().as_bytes());
+
+    let mut text = String::new();
+    table.build_into_fmt(&mut text).unwrap();
+    assert_eq!(text, table.to_string());
+}
@@ -1,6 +1,6 @@
 #![cfg(feature = "macros")]
 
-use tabled::{col, format::Format, object::Segment, row, Alignment, Modify, Padding};
+use tabled::{col, format::Format, object::Segment, row, table, Alignment, Modify, Padding, Style};
 
 use crate::util::{create_table, test_table};
 
@@ -252,3 +252,27 @@ test_table!(
     "| false  |"
     "+--------+"
 );
+
+test_table!(
+    row_col_dashboard_test,
+    row!(
+        col!(table!(["cpu"; "12%"]), table!(["mem"; "3.1G"; "swap"])),
+        table!(["disk", "free"; "/", "40G"; "/home", "210G"; "/tmp", "2G"])
+    )
+    .with(Style::blank()),
+    " +----------+   +-------+------+ "
+    " | +-----+  |   | disk  | free | "
+    " | | cpu |  |   +-------+------+ "
+    " | +-----+  |   | /     | 40G  | "
+    " | | 12% |  |   +-------+------+ "
+    " | +-----+  |   | /home | 210G | "
+    " +----------+   +-------+------+ "
+    " | +------+ |   | /tmp  | 2G   | "
+    " | | mem  | |   +-------+------+ "
+    " | +------+ |                    "
+    " | | 3.1G | |                    "
+    " | +------+ |                    "
+    " | | swap | |                    "
+    " | +------+ |                    "
+    " +----------+                    "
+);
@@ -0,0 +1,52 @@
+use tabled::{ColumnOrder, Style};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    column_order_by_names_test,
+    create_table::<2, 2>().with(ColumnOrder::new(["column 1", "N"])),
+    "+----------+---+----------+"
+    "| column 1 | N | column 0 |"
+    "+----------+---+----------+"
+    "|   0-1    | 0 |   0-0    |"
+    "+----------+---+----------+"
+    "|   1-1    | 1 |   1-0    |"
+    "+----------+---+----------+"
+);
+
+test_table!(
+    column_order_by_indexes_test,
+    create_table::<2, 2>().with(ColumnOrder::indexes([2, 1, 0])),
+    "+----------+----------+---+"
+    "| column 1 | column 0 | N |"
+    "+----------+----------+---+"
+    "|   0-1    |   0-0    | 0 |"
+    "+----------+----------+---+"
+    "|   1-1    |   1-0    | 1 |"
+    "+----------+----------+---+"
+);
+
+test_table!(
+    column_order_unknown_and_duplicate_columns_test,
+    create_table::<1, 2>()
+        .with(ColumnOrder::new(["unknown", "column 0", "column 0"]))
+        .with(ColumnOrder::indexes([10, 2, 2]))
+        .with(Style::psql()),
+    " column 1 | column 0 | N "
+    "----------+----------+---"
+    "   0-1    |   0-0    | 0 "
+);
+
+test_table!(
+    column_order_empty_test,
+    create_table::<2, 2>().with(ColumnOrder::indexes([])),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
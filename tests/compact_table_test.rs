@@ -0,0 +1,263 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::{self, Write},
+};
+
+use tabled::{
+    alignment::AlignmentHorizontal, builder::Builder, CompactTable, Padding, Style, Table,
+};
+
+use util::{static_table, test_table};
+
+mod util;
+
+const DATA: [[&str; 3]; 3] = [
+    ["name", "designed by", "year"],
+    ["C", "Dennis Ritchie", "1972"],
+    ["Rust", "Graydon Hoare", "2010"],
+];
+
+macro_rules! test_style {
+    ($test:ident, $style:expr) => {
+        #[test]
+        fn $test() {
+            let mut table = Builder::from(
+                DATA.iter()
+                    .map(|row| row.iter().map(|s| s.to_string()).collect())
+                    .collect::<Vec<Vec<String>>>(),
+            )
+            .build();
+            table.with($style);
+
+            let compact = CompactTable::from(DATA).style($style);
+
+            assert_eq!(compact.to_string(), table.to_string());
+        }
+    };
+}
+
+test_style!(style_ascii, Style::ascii());
+test_style!(style_modern, Style::modern());
+test_style!(style_rounded, Style::rounded());
+test_style!(style_psql, Style::psql());
+test_style!(style_markdown, Style::markdown());
+test_style!(style_re_structured_text, Style::re_structured_text());
+test_style!(style_extended, Style::extended());
+test_style!(style_dots, Style::dots());
+test_style!(style_blank, Style::blank());
+test_style!(style_empty, Style::empty());
+
+test_table!(
+    default_style,
+    CompactTable::from(DATA),
+    "+------+----------------+------+"
+    "| name | designed by    | year |"
+    "+------+----------------+------+"
+    "| C    | Dennis Ritchie | 1972 |"
+    "+------+----------------+------+"
+    "| Rust | Graydon Hoare  | 2010 |"
+    "+------+----------------+------+"
+);
+
+test_table!(
+    widths,
+    CompactTable::new(DATA).widths([2, 6, 4]),
+    "+----+--------+------+"
+    "| na | design | year |"
+    "+----+--------+------+"
+    "| C  | Dennis | 1972 |"
+    "+----+--------+------+"
+    "| Ru | Graydo | 2010 |"
+    "+----+--------+------+"
+);
+
+test_table!(
+    collected_iterator,
+    CompactTable::new((1..=3).map(|i| [["a", "b", "c"][i - 1]; 2]).collect::<Vec<_>>()).widths([1, 3]),
+    "+---+-----+"
+    "| a | a   |"
+    "+---+-----+"
+    "| b | b   |"
+    "+---+-----+"
+    "| c | c   |"
+    "+---+-----+"
+);
+
+test_table!(
+    alignment_right,
+    CompactTable::from(DATA).alignment(AlignmentHorizontal::Right),
+    "+------+----------------+------+"
+    "| name |    designed by | year |"
+    "+------+----------------+------+"
+    "|    C | Dennis Ritchie | 1972 |"
+    "+------+----------------+------+"
+    "| Rust |  Graydon Hoare | 2010 |"
+    "+------+----------------+------+"
+);
+
+test_table!(
+    alignment_center,
+    CompactTable::from(DATA).alignment(AlignmentHorizontal::Center),
+    "+------+----------------+------+"
+    "| name |  designed by   | year |"
+    "+------+----------------+------+"
+    "|  C   | Dennis Ritchie | 1972 |"
+    "+------+----------------+------+"
+    "| Rust | Graydon Hoare  | 2010 |"
+    "+------+----------------+------+"
+);
+
+test_table!(
+    padding,
+    CompactTable::from([["a", "b"]]).padding(Padding::new(2, 0, 1, 1).set_fill('<', '>', '^', 'v')),
+    "+---+---+"
+    "|^^^|^^^|"
+    "|<<a|<<b|"
+    "|vvv|vvv|"
+    "+---+---+"
+);
+
+test_table!(
+    multiline,
+    CompactTable::from([["a\nb", "c"], ["d", "e\nf\ng"]]),
+    "+---+---+"
+    "| a | c |"
+    "| b |   |"
+    "+---+---+"
+    "| d | e |"
+    "|   | f |"
+    "|   | g |"
+    "+---+---+"
+);
+
+test_table!(
+    wide_chars,
+    CompactTable::new([["你好", "ab"]]).widths([3, 2]),
+    "+-----+----+"
+    "| 你  | ab |"
+    "+-----+----+"
+);
+
+test_table!(
+    missing_cells,
+    CompactTable::new(vec![vec!["1"], vec!["2", "3"]]).widths([1, 1]),
+    "+---+---+"
+    "| 1 |   |"
+    "+---+---+"
+    "| 2 | 3 |"
+    "+---+---+"
+);
+
+test_table!(empty, CompactTable::from([[""; 0]; 2]), "");
+
+test_table!(
+    empty_rows,
+    CompactTable::new(Vec::<[&str; 2]>::new()).widths([1, 1]),
+    ""
+);
+
+#[test]
+fn same_as_table() {
+    let table = Table::new(DATA.iter().map(|row| (row[0], row[1]))).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+------+----------------+"
+            "| &str | &str           |"
+            "+------+----------------+"
+            "| name | designed by    |"
+            "+------+----------------+"
+            "| C    | Dennis Ritchie |"
+            "+------+----------------+"
+            "| Rust | Graydon Hoare  |"
+            "+------+----------------+"
+        )
+    );
+
+    let compact = CompactTable::from([
+        ["&str", "&str"],
+        ["name", "designed by"],
+        ["C", "Dennis Ritchie"],
+        ["Rust", "Graydon Hoare"],
+    ]);
+
+    assert_eq!(compact.to_string(), table);
+}
+
+#[test]
+fn no_allocations() {
+    let table = CompactTable::from(DATA).style(Style::psql());
+
+    let mut buf = Buffer::default();
+
+    ALLOCATIONS.with(|count| count.set(0));
+    write!(buf, "{}", table).unwrap();
+    let count = ALLOCATIONS.with(|count| count.get());
+
+    assert_eq!(count, 0);
+    assert_eq!(
+        buf.as_str(),
+        static_table!(
+            " name | designed by    | year "
+            "------+----------------+------"
+            " C    | Dennis Ritchie | 1972 "
+            " Rust | Graydon Hoare  | 2010 "
+        )
+    );
+}
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+struct Buffer {
+    data: [u8; 1024],
+    len: usize,
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Self {
+            data: [0; 1024],
+            len: 0,
+        }
+    }
+}
+
+impl Buffer {
+    fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.data[..self.len]).unwrap()
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.data.len() {
+            return Err(fmt::Error);
+        }
+
+        self.data[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    display::CsvTable,
+    object::{Cell, Rows},
+    Disable, Modify, Span,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    to_csv,
+    create_table::<2, 2>().to_csv(','),
+    "N,column 0,column 1"
+    "0,0-0,0-1"
+    "1,1-0,1-1"
+);
+
+test_table!(
+    to_csv_tab,
+    create_table::<1, 2>().to_csv('\t'),
+    "N\tcolumn 0\tcolumn 1"
+    "0\t0-0\t0-1"
+);
+
+test_table!(
+    to_csv_quotes,
+    Builder::from_iter([["a,b", "\"c\"", "d\ne", "f\r", "g h"]]).build().to_csv(','),
+    "\"a,b\",\"\"\"c\"\"\",\"d"
+    "e\",\"f\r\",g h"
+);
+
+test_table!(
+    to_csv_quotes_delimiter,
+    Builder::from_iter([["a,b", "c;d"]]).build().to_csv(';'),
+    "a,b;\"c;d\""
+);
+
+test_table!(
+    to_csv_span,
+    Builder::from_iter([["a", "b", "c"], ["d", "e", "f"]])
+        .build()
+        .with(Modify::new(Cell(0, 0)).with(Span::column(2)))
+        .to_csv(','),
+    "a,,c"
+    "d,e,f"
+);
+
+test_table!(
+    to_csv_after_disable,
+    create_table::<3, 2>().with(Disable::row(Rows::single(2))).to_csv(','),
+    "N,column 0,column 1"
+    "0,0-0,0-1"
+    "2,2-0,2-1"
+);
+
+test_table!(
+    csv_table_delimiter,
+    CsvTable::new(&create_table::<1, 1>()).delimiter('|'),
+    "N|column 0"
+    "0|0-0"
+);
+
+test_table!(to_csv_empty, Builder::default().build().to_csv(','), "");
+
+#[cfg(feature = "csv")]
+mod from_csv {
+    use tabled::{builder::Builder, Table};
+
+    use crate::util::test_table;
+
+    test_table!(
+        from_csv,
+        Table::from_csv(&mut csv::Reader::from_reader("a,b\n1,2\n3,4".as_bytes())).unwrap(),
+        "+---+---+"
+        "| a | b |"
+        "+---+---+"
+        "| 1 | 2 |"
+        "+---+---+"
+        "| 3 | 4 |"
+        "+---+---+"
+    );
+
+    test_table!(
+        from_csv_quoted,
+        Table::from_csv(&mut csv::Reader::from_reader("a,b\n\"1,2\",\"x \"\"y\"\"\"\n".as_bytes())).unwrap(),
+        "+-----+-------+"
+        "| a   | b     |"
+        "+-----+-------+"
+        "| 1,2 | x \"y\" |"
+        "+-----+-------+"
+    );
+
+    test_table!(
+        from_csv_without_headers,
+        {
+            let mut reader = csv::ReaderBuilder::new()
+                .has_headers(false)
+                .from_reader("a,b\n1,2".as_bytes());
+
+            let builder = Builder::from_csv(&mut reader).unwrap();
+            let table = builder.build();
+            assert!(!table.has_header());
+            table
+        },
+        "+---+---+"
+        "| a | b |"
+        "+---+---+"
+        "| 1 | 2 |"
+        "+---+---+"
+    );
+
+    test_table!(
+        from_csv_ragged_rows,
+        {
+            let mut reader = csv::ReaderBuilder::new()
+                .flexible(true)
+                .from_reader("a,b\n1\n2,3,4".as_bytes());
+
+            let mut builder = Builder::from_csv(&mut reader).unwrap();
+            builder.set_default_text("-");
+            builder.build()
+        },
+        "+---+---+---+"
+        "| a | b | - |"
+        "+---+---+---+"
+        "| 1 | - | - |"
+        "+---+---+---+"
+        "| 2 | 3 | 4 |"
+        "+---+---+---+"
+    );
+
+    test_table!(
+        from_csv_records,
+        {
+            let mut reader = csv::Reader::from_reader("a,b\n1,2\n3,4".as_bytes());
+            Builder::from_csv_records(reader.records()).unwrap().build()
+        },
+        "+---+---+"
+        "| 1 | 2 |"
+        "+---+---+"
+        "| 3 | 4 |"
+        "+---+---+"
+    );
+
+    test_table!(
+        from_csv_records_filtered,
+        {
+            let mut reader = csv::Reader::from_reader("a,b\n1,2\n3,4\n5,6".as_bytes());
+            let records = reader
+                .records()
+                .filter(|r| r.as_ref().map_or(true, |r| &r[0] != "3"));
+            Builder::from_csv_records(records).unwrap().build()
+        },
+        "+---+---+"
+        "| 1 | 2 |"
+        "+---+---+"
+        "| 5 | 6 |"
+        "+---+---+"
+    );
+
+    #[test]
+    fn from_csv_records_error() {
+        let mut reader = csv::Reader::from_reader("a,b\n1,2\n3".as_bytes());
+        assert!(Builder::from_csv_records(reader.records()).is_err());
+    }
+
+    #[test]
+    fn from_csv_ragged_rows_not_flexible() {
+        let mut reader = csv::Reader::from_reader("a,b\n1".as_bytes());
+        assert!(Table::from_csv(&mut reader).is_err());
+    }
+
+    test_table!(
+        from_csv_empty,
+        Table::from_csv(&mut csv::Reader::from_reader("".as_bytes())).unwrap(),
+        ""
+    );
+}
@@ -0,0 +1,76 @@
+use std::iter::FromIterator;
+
+use tabled::{builder::Builder, Dedup, Style};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+fn build_table() -> tabled::Table {
+    let mut builder = Builder::default();
+    builder.set_columns(["method", "code"]);
+    builder.add_record(["GET", "200"]);
+    builder.add_record(["GET", "200"]);
+    builder.add_record(["GET", "404"]);
+    builder.add_record(["POST", "500"]);
+    builder.add_record(["POST", "500"]);
+    builder.add_record(["GET", "200"]);
+    builder.build()
+}
+
+test_table!(
+    dedup_consecutive_test,
+    build_table().with(Dedup::consecutive()).with(Style::psql()),
+    " method | code "
+    "--------+------"
+    " GET    | 200  "
+    " GET    | 404  "
+    " POST   | 500  "
+    " GET    | 200  "
+);
+
+test_table!(
+    dedup_all_test,
+    build_table().with(Dedup::all()).with(Style::psql()),
+    " method | code "
+    "--------+------"
+    " GET    | 200  "
+    " GET    | 404  "
+    " POST   | 500  "
+);
+
+test_table!(
+    dedup_does_not_touch_header_test,
+    Builder::from_iter([["a"], ["a"], ["a"]]).build().with(Dedup::all()),
+    "+---+"
+    "| a |"
+    "+---+"
+);
+
+test_table!(
+    dedup_with_header_equal_to_data_test,
+    {
+        let mut builder = Builder::default();
+        builder.set_columns(["a"]);
+        builder.add_record(["a"]);
+        builder.build()
+    }
+    .with(Dedup::all()),
+    "+---+"
+    "| a |"
+    "+---+"
+    "| a |"
+    "+---+"
+);
+
+test_table!(
+    dedup_no_duplicates_test,
+    create_table::<2, 2>().with(Dedup::all()),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
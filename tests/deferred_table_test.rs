@@ -0,0 +1,127 @@
+use tabled::{
+    object::{Columns, Rows},
+    width::{MinWidth, Width},
+    Alignment, Height, Modify, Padding, Panel, Style, Table,
+};
+
+use util::{create_table, test_table};
+
+mod util;
+
+#[test]
+fn same_as_eager() {
+    let mut eager = create_table::<3, 3>();
+    eager
+        .with(Style::modern())
+        .with(Modify::new(Rows::first()).with(Alignment::left()))
+        .with(Padding::new(2, 0, 0, 0))
+        .with(Width::truncate(25))
+        .with(MinWidth::new(30));
+
+    let table = create_table::<3, 3>()
+        .deferred()
+        .with(Style::modern())
+        .with(Modify::new(Rows::first()).with(Alignment::left()))
+        .with(Padding::new(2, 0, 0, 0))
+        .with(Width::truncate(25))
+        .with(MinWidth::new(30));
+
+    assert_eq!(table.build().to_string(), eager.to_string());
+}
+
+#[test]
+fn settings_are_not_applied_before_build() {
+    let table = create_table::<3, 3>()
+        .deferred()
+        .with(Panel::header("Hello World"));
+
+    assert_eq!(table.count_settings(), 1);
+
+    let table = table.build();
+
+    assert_eq!(table.count_rows(), 5);
+}
+
+#[test]
+fn settings_are_applied_in_order() {
+    let table = Table::new([["a", "b"]])
+        .deferred()
+        .with(Style::psql())
+        .with(Style::markdown());
+
+    assert_eq!(
+        table.build().to_string(),
+        "| 0 | 1 |\n\
+         |---|---|\n\
+         | a | b |"
+    );
+}
+
+#[test]
+fn overridden_settings_are_merged() {
+    let mut eager = create_table::<3, 3>();
+    eager
+        .with(Alignment::left())
+        .with(Width::list([3, 10, 10, 10]))
+        .with(Alignment::top())
+        .with(Height::list([2, 1, 1, 1]))
+        .with(Alignment::right())
+        .with(Width::list([5, 12, 12, 12]))
+        .with(Alignment::bottom());
+
+    let table = create_table::<3, 3>()
+        .deferred()
+        .with(Alignment::left())
+        .with(Width::list([3, 10, 10, 10]))
+        .with(Alignment::top())
+        .with(Height::list([2, 1, 1, 1]))
+        .with(Alignment::right())
+        .with(Width::list([5, 12, 12, 12]))
+        .with(Alignment::bottom());
+
+    assert_eq!(table.count_settings(), 4);
+    assert_eq!(table.build().to_string(), eager.to_string());
+}
+
+#[test]
+fn settings_of_unknown_kind_are_not_merged() {
+    let table = Table::new([["a", "b"]])
+        .deferred()
+        .with(Alignment::left())
+        .with(Style::psql())
+        .with(Alignment::right())
+        .with(Width::list([1, 1]))
+        .with(Width::list([1]));
+
+    assert_eq!(table.count_settings(), 5);
+}
+
+test_table!(
+    borrowed_settings,
+    {
+        let suffix = String::from("..");
+        let table = create_table::<2, 2>()
+            .deferred()
+            .with(Style::ascii())
+            .with(Modify::new(Columns::new(1..)).with(Width::truncate(4).suffix(suffix.as_str())));
+
+        Table::from(table)
+    },
+    "+---+------+------+"
+    "| N | co.. | co.. |"
+    "+---+------+------+"
+    "| 0 | 0-0  | 0-1  |"
+    "+---+------+------+"
+    "| 1 | 1-0  | 1-1  |"
+    "+---+------+------+"
+);
+
+test_table!(
+    no_settings,
+    Table::new([["a", "b"]]).deferred().build(),
+    "+---+---+"
+    "| 0 | 1 |"
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+);
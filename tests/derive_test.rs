@@ -198,6 +198,21 @@ mod tupple {
         }
     );
 
+    test_tuple!(
+        display_option_args,
+        t: { u8 #[tabled(display_with("display_option", self.0, "none"))] Option<sstr> },
+        init: { 0 None },
+        expected: ["0", "1"], ["0", "none of 0"],
+        pre: {
+            fn display_option(o: &Option<sstr>, n: &u8, default: &str) -> String {
+                match o {
+                    Some(s) => format!("some {}", s),
+                    None => format!("{} of {}", default, n),
+                }
+            }
+        }
+    );
+
     test_tuple!(
         display_option_self_3,
         t: { u8 #[tabled(display_with("display_option", args))] Option<sstr> },
@@ -628,6 +643,48 @@ mod structure {
         init: { f1: 0, f2: Some("v2") }
         expected: ["f1", "f2"], ["0", "some v2"]
     );
+    test_struct!(
+        display_with_arguments,
+        t: {
+            #[tabled(display_with("format_size", self.unit, true))]
+            size: u64,
+            #[tabled(skip)]
+            unit: sstr,
+        }
+        pre: {
+            fn format_size(size: &u64, unit: &sstr, short: bool) -> String {
+                if short {
+                    format!("{}{}", size, &unit[..1])
+                } else {
+                    format!("{} {}", size, unit)
+                }
+            }
+        }
+        init: { size: 10, unit: "kilobytes" }
+        expected: ["size"], ["10k"]
+    );
+    test_struct!(
+        display_with_arguments_self_and_constant,
+        t: {
+            f1: u8,
+            #[tabled(display_with("Self::display_option", self, PREFIX))]
+            f2: Option<sstr>,
+        }
+        pre: {
+            const PREFIX: &str = "some";
+
+            impl TestType {
+                fn display_option(o: &Option<sstr>, t: &Self, prefix: &str) -> String {
+                    match o {
+                        Some(s) => format!("{} {} {}", prefix, s, t.f1),
+                        None => "none".to_string(),
+                    }
+                }
+            }
+        }
+        init: { f1: 0, f2: Some("v2") }
+        expected: ["f1", "f2"], ["0", "some v2 0"]
+    );
     test_struct!(order_0, t: { #[tabled(order = 0)] f0: u8, f1: u8, f2: u8 } init: { f0: 0, f1: 1, f2: 2 } expected: ["f0", "f1", "f2"], ["0", "1", "2"]);
     test_struct!(order_1, t: { #[tabled(order = 1)] f0: u8, f1: u8, f2: u8 } init: { f0: 0, f1: 1, f2: 2 } expected: ["f1", "f0", "f2"], ["1", "0", "2"]);
     test_struct!(order_2, t: { #[tabled(order = 2)] f0: u8, f1: u8, f2: u8 } init: { f0: 0, f1: 1, f2: 2 } expected: ["f1", "f2", "f0"], ["1", "2", "0"]);
@@ -834,3 +891,57 @@ fn rename_all_gets_last_value() {
     assert_eq!(Struct1::headers(), vec!["Field"],);
     assert_eq!(st.fields(), vec!["789"]);
 }
+
+#[test]
+fn string_fields_are_borrowed() {
+    use std::borrow::Cow;
+
+    #[derive(Tabled)]
+    struct Struct<'a> {
+        name: &'a str,
+        owned: String,
+        cow: Cow<'a, str>,
+        full_path: std::borrow::Cow<'static, str>,
+        number: usize,
+    }
+
+    let st = Struct {
+        name: "tabled",
+        owned: String::from("0.10.0"),
+        cow: Cow::Owned(String::from("MIT")),
+        full_path: Cow::Borrowed("tables"),
+        number: 1,
+    };
+
+    let fields = st.fields();
+    assert_eq!(fields, vec!["tabled", "0.10.0", "MIT", "tables", "1"]);
+    assert!(matches!(fields[0], Cow::Borrowed(_)));
+    assert!(matches!(fields[1], Cow::Borrowed(_)));
+    assert!(matches!(fields[2], Cow::Borrowed(_)));
+    assert!(matches!(fields[3], Cow::Borrowed(_)));
+    assert!(matches!(fields[4], Cow::Owned(_)));
+}
+
+#[test]
+fn string_fields_of_inlined_variant_are_borrowed() {
+    use std::borrow::Cow;
+
+    #[derive(Tabled)]
+    enum Enum<'a> {
+        #[tabled(inline)]
+        Borrowed(&'a str),
+        #[tabled(inline)]
+        Owned { name: String },
+    }
+
+    let fields = Enum::Borrowed("tabled").fields();
+    assert_eq!(fields, vec!["tabled", ""]);
+    assert!(matches!(fields[0], Cow::Borrowed(_)));
+
+    let owned = Enum::Owned {
+        name: String::from("tabled"),
+    };
+    let fields = owned.fields();
+    assert_eq!(fields, vec!["", "tabled"]);
+    assert!(matches!(fields[1], Cow::Borrowed(_)));
+}
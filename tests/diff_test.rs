@@ -0,0 +1,82 @@
+use std::iter::FromIterator;
+
+use tabled::{builder::Builder, Style, Table, TableDiff};
+
+use crate::util::test_table;
+
+mod util;
+
+test_table!(
+    diff_by_position,
+    TableDiff::new(
+        &Table::new([("serde", "1.0.150"), ("rand", "0.8.5"), ("log", "0.4.17")]),
+        &Table::new([("serde", "1.0.152"), ("rand", "0.8.5")]),
+    )
+    .build()
+    .with(Style::psql()),
+    "   | &str  | &str              "
+    "---+-------+-------------------"
+    " ~ | serde | 1.0.150 → 1.0.152 "
+    "   | rand  | 0.8.5             "
+    " - | log   | 0.4.17            "
+);
+
+test_table!(
+    diff_by_key,
+    TableDiff::new(
+        &Table::new([("serde", "1.0.150"), ("rand", "0.8.5"), ("log", "0.4.17")]),
+        &Table::new([("tokio", "1.24.1"), ("rand", "0.8.5"), ("serde", "1.0.152")]),
+    )
+    .key(0)
+    .build()
+    .with(Style::psql()),
+    "   | &str  | &str              "
+    "---+-------+-------------------"
+    " + | tokio | 1.24.1            "
+    "   | rand  | 0.8.5             "
+    " ~ | serde | 1.0.150 → 1.0.152 "
+    " - | log   | 0.4.17            "
+);
+
+test_table!(
+    diff_by_repeated_key,
+    TableDiff::new(
+        &Table::new([("serde", "1.0.150"), ("serde", "1.0.151"), ("log", "0.4.17")]),
+        &Table::new([("serde", "1.0.152"), ("serde", "1.0.151"), ("serde", "1.0.153")]),
+    )
+    .key(0)
+    .build()
+    .with(Style::psql()),
+    "   | &str  | &str              "
+    "---+-------+-------------------"
+    " ~ | serde | 1.0.150 → 1.0.152 "
+    "   | serde | 1.0.151           "
+    " + | serde | 1.0.153           "
+    " - | log   | 0.4.17            "
+);
+
+test_table!(
+    diff_without_header,
+    TableDiff::new(
+        &Builder::from_iter([["a", "1"], ["b", "2"]]).build(),
+        &Builder::from_iter([["a", "1"], ["b", "3"], ["c", "4"]]).build(),
+    )
+    .build()
+    .with(Style::psql()),
+    "   | a | 1     "
+    "---+---+-------"
+    " ~ | b | 2 → 3 "
+    " + | c | 4     "
+);
+
+#[cfg(feature = "color")]
+#[test]
+fn diff_colored() {
+    let diff = TableDiff::new(&Table::new([("a", 1)]), &Table::new([("a", 2), ("b", 3)]))
+        .colored()
+        .to_string();
+
+    assert!(diff.contains("\u{1b}[33m1 → 2\u{1b}[39m"));
+    assert!(diff.contains("\u{1b}[32mb\u{1b}[39m"));
+    assert!(!diff.contains("\u{1b}[33ma"));
+}
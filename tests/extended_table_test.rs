@@ -1,14 +1,14 @@
 use crate::util::{create_vector, static_table};
-use tabled::{display::ExpandedDisplay, Tabled};
+use tabled::{display::ExtendedTable, Tabled};
 
 #[cfg(feature = "color")]
 use owo_colors::{AnsiColors, OwoColorize};
 
 mod util;
 
-macro_rules! assert_expanded_display {
+macro_rules! assert_extended_table {
     ( $data:expr, $expected:expr ) => {
-        let table = ExpandedDisplay::new($data).to_string();
+        let table = ExtendedTable::new($data).to_string();
         assert_eq!(table, $expected);
     };
 }
@@ -34,7 +34,7 @@ macro_rules! build_tabled_type {
 
 #[test]
 fn display() {
-    assert_expanded_display!(
+    assert_extended_table!(
         create_vector::<3, 3>(),
         static_table!(
             "-[ RECORD 0 ]-"
@@ -60,7 +60,7 @@ fn display() {
 fn display_empty_records() {
     build_tabled_type!(TestType, 3, ["He", "123", "asd"], ["1", "2", "3"]);
     let data: Vec<TestType> = vec![];
-    assert_expanded_display!(data, "");
+    assert_extended_table!(data, "");
 }
 
 #[test]
@@ -78,20 +78,20 @@ fn display_empty() {
         }
     );
     let data: Vec<TestType> = vec![];
-    assert_expanded_display!(data, "");
+    assert_extended_table!(data, "");
 }
 
 #[test]
 fn display_empty_2() {
     build_tabled_type!(EmptyType, 0, [""; 0], [""; 0]);
-    assert_expanded_display!(&[EmptyType], "-[ RECORD 0 ]-");
+    assert_extended_table!(&[EmptyType], "-[ RECORD 0 ]-");
 }
 
 #[test]
 fn display_dynamic_header_template() {
     {
         build_tabled_type!(TestType, 3, ["He", "123", "asd"], ["1", "2", "3"]);
-        assert_expanded_display!(
+        assert_extended_table!(
             &[TestType],
             static_table!(
                 "-[ RECORD 0 ]-"
@@ -103,7 +103,7 @@ fn display_dynamic_header_template() {
     }
     {
         build_tabled_type!(TestType, 3, ["He", "123", "asd"], ["11", "2222222", "3"]);
-        assert_expanded_display!(
+        assert_extended_table!(
             &[TestType],
             static_table!(
                 "-[ RECORD 0 ]-"
@@ -120,7 +120,7 @@ fn display_dynamic_header_template() {
             ["HeheHehe", "123", "asd"],
             ["11", "2222222", "3"]
         );
-        assert_expanded_display!(
+        assert_extended_table!(
             &[TestType],
             static_table!(
                 "-[ RECORD 0 ]-----"
@@ -132,7 +132,7 @@ fn display_dynamic_header_template() {
     }
     {
         build_tabled_type!(TestType, 3, ["He", "123", "asd"], ["11111111111", "2", "3"]);
-        assert_expanded_display!(
+        assert_extended_table!(
             &[TestType],
             static_table!(
                 "-[ RECORD 0 ]----"
@@ -149,7 +149,7 @@ fn display_dynamic_header_template() {
             ["He", "123", "asd"],
             ["1111111111111", "2", "3"]
         );
-        assert_expanded_display!(
+        assert_extended_table!(
             &[TestType],
             static_table!(
                 "-[ RECORD 0 ]-+----"
@@ -166,7 +166,7 @@ fn display_dynamic_header_template() {
             ["He", "123", "asd"],
             ["11111111111111111111111111111", "2", "3"]
         );
-        assert_expanded_display!(
+        assert_extended_table!(
             &[TestType],
             static_table!(
                 "-[ RECORD 0 ]-----------------+----"
@@ -178,8 +178,8 @@ fn display_dynamic_header_template() {
     }
     {
         build_tabled_type!(TestType, 3, ["22"], ["11111111111"]);
-        assert_expanded_display!(
-            std::iter::repeat(TestType).take(11),
+        assert_extended_table!(
+            (0..11).map(|_| TestType),
             static_table!(
                 "-[ RECORD 0 ]---"
                 "11111111111 | 22"
@@ -211,7 +211,7 @@ fn display_dynamic_header_template() {
 #[test]
 fn display_multiline_field() {
     build_tabled_type!(TestType, 3, ["1", "2", "3"], ["Hello\nWorld", "123", "asd"]);
-    assert_expanded_display!(
+    assert_extended_table!(
         [TestType],
         static_table!(
             "-[ RECORD 0 ]---"
@@ -229,7 +229,7 @@ fn display_multiline_record_value() {
     data[0][1] = "123".to_string();
     data[0][2] = "asd".to_string();
 
-    assert_expanded_display!(
+    assert_extended_table!(
         data,
         static_table!(
             "-[ RECORD 0 ]----------"
@@ -251,7 +251,7 @@ fn display_with_truncate() {
     let mut data = create_vector::<3, 3>();
     data[0][0] = String::from("a long string");
 
-    let mut table = ExpandedDisplay::new(&data);
+    let mut table = ExtendedTable::new(&data);
     table.truncate(14, "");
     let table = table.to_string();
 
@@ -282,7 +282,7 @@ fn truncate_with_suffix() {
     let mut data = create_vector::<3, 3>();
     data[0][0] = String::from("a long string");
 
-    let mut table = ExpandedDisplay::new(&data);
+    let mut table = ExtendedTable::new(&data);
     table.truncate(15, "..");
     let table = table.to_string();
 
@@ -318,7 +318,7 @@ fn truncate_big_fields() {
     );
     let data: Vec<TestType> = vec![TestType, TestType];
 
-    let mut table = ExpandedDisplay::new(&data);
+    let mut table = ExtendedTable::new(&data);
     table.truncate(14, "..");
     let table = table.to_string();
 
@@ -336,7 +336,7 @@ fn truncate_big_fields() {
         )
     );
 
-    let mut table = ExpandedDisplay::new(&data);
+    let mut table = ExtendedTable::new(&data);
     table.truncate(15, "..");
     let table = table.to_string();
 
@@ -354,7 +354,7 @@ fn truncate_big_fields() {
         )
     );
 
-    let mut table = ExpandedDisplay::new(&data);
+    let mut table = ExtendedTable::new(&data);
     table.truncate(0, "..");
     let table = table.to_string();
 
@@ -372,7 +372,7 @@ fn truncate_big_fields() {
         )
     );
 
-    let mut table = ExpandedDisplay::new(&data);
+    let mut table = ExtendedTable::new(&data);
     table.truncate(20, "......");
     let table = table.to_string();
 
@@ -396,7 +396,7 @@ fn truncate_too_small() {
     let mut data = create_vector::<3, 3>();
     data[0][0] = String::from("a long string");
 
-    let mut table = ExpandedDisplay::new(&data);
+    let mut table = ExtendedTable::new(&data);
     let success = table.truncate(2, "");
     assert!(!success);
 
@@ -436,7 +436,7 @@ fn display_colored() {
         .to_string();
     data[2][2] = "https://endeavouros.com/".blue().underline().to_string();
 
-    assert_expanded_display!(
+    assert_extended_table!(
         data,
         static_table!(
             "-[ RECORD 0 ]------------------------------------------------------------"
@@ -469,7 +469,7 @@ fn display_with_truncate_colored() {
         .to_string();
     data[1][2] = "https://www.opensuse.org/".to_string();
 
-    let mut table = ExpandedDisplay::new(&data);
+    let mut table = ExtendedTable::new(&data);
     table.truncate(20, "");
     let table = table.to_string();
 
@@ -489,3 +489,33 @@ fn display_with_truncate_colored() {
         )
     );
 }
+
+#[test]
+fn from_table_test() {
+    let table = tabled::Table::new(create_vector::<2, 2>());
+    let table = ExtendedTable::from(table).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "-[ RECORD 0 ]-"
+            "N        | 0"
+            "column 0 | 0-0"
+            "column 1 | 0-1"
+            "-[ RECORD 1 ]-"
+            "N        | 1"
+            "column 0 | 1-0"
+            "column 1 | 1-1"
+        )
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn expanded_display_alias_test() {
+    let data = create_vector::<1, 1>();
+    assert_eq!(
+        tabled::display::ExpandedDisplay::new(&data).to_string(),
+        ExtendedTable::new(&data).to_string()
+    );
+}
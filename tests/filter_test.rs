@@ -0,0 +1,100 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    locator::ByColumnName,
+    object::{Columns, LastColumn},
+    Filter, Style,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+fn build_table() -> tabled::Table {
+    let mut builder = Builder::default();
+    builder.set_columns(["name", "status", "code"]);
+    builder.add_record(["web", "OK", "200"]);
+    builder.add_record(["db", "DOWN", "500"]);
+    builder.add_record(["cache", "OK", "200"]);
+    builder.add_record(["queue", "SLOW", "200"]);
+    builder.build()
+}
+
+test_table!(
+    filter_by_column_name_test,
+    build_table().with(Filter::by_column(ByColumnName::new("status"), |v| v != "OK")),
+    "+-------+--------+------+"
+    "| name  | status | code |"
+    "+-------+--------+------+"
+    "| db    | DOWN   | 500  |"
+    "+-------+--------+------+"
+    "| queue | SLOW   | 200  |"
+    "+-------+--------+------+"
+);
+
+test_table!(
+    filter_by_column_index_test,
+    build_table().with(Filter::by_column(LastColumn, |v| v == "500")).with(Style::psql()),
+    " name | status | code "
+    "------+--------+------"
+    " db   | DOWN   | 500  "
+);
+
+test_table!(
+    filter_by_several_columns_test,
+    build_table().with(Filter::by_column(Columns::new(..), |v| v == "web" || v == "SLOW")),
+    "+-------+--------+------+"
+    "| name  | status | code |"
+    "+-------+--------+------+"
+    "| web   | OK     | 200  |"
+    "+-------+--------+------+"
+    "| queue | SLOW   | 200  |"
+    "+-------+--------+------+"
+);
+
+test_table!(
+    filter_by_unknown_column_test,
+    build_table().with(Filter::by_column(ByColumnName::new("unknown"), |_| false)),
+    "+-------+--------+------+"
+    "| name  | status | code |"
+    "+-------+--------+------+"
+    "| web   | OK     | 200  |"
+    "+-------+--------+------+"
+    "| db    | DOWN   | 500  |"
+    "+-------+--------+------+"
+    "| cache | OK     | 200  |"
+    "+-------+--------+------+"
+    "| queue | SLOW   | 200  |"
+    "+-------+--------+------+"
+);
+
+test_table!(
+    filter_rows_test,
+    build_table().with(Filter::rows(|row: &[&str]| row[1] == "OK" && row[2] == "200")),
+    "+-------+--------+------+"
+    "| name  | status | code |"
+    "+-------+--------+------+"
+    "| web   | OK     | 200  |"
+    "+-------+--------+------+"
+    "| cache | OK     | 200  |"
+    "+-------+--------+------+"
+);
+
+test_table!(
+    filter_rows_all_test,
+    create_table::<3, 2>().with(Filter::rows(|_: &[&str]| false)),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    filter_rows_without_header_test,
+    Builder::from_iter([["1"], ["2"], ["3"]]).build().with(Filter::rows(|row: &[&str]| row[0] != "1")),
+    "+---+"
+    "| 2 |"
+    "+---+"
+    "| 3 |"
+    "+---+"
+);
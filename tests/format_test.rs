@@ -1,7 +1,9 @@
 use tabled::{
-    format::Format,
+    format::{
+        BoolFormat, ByteSize, DurationFormat, Format, Hyperlink, Locale, NumberFormat, Placeholder,
+    },
     object::{Cell, Columns, Object, Rows, Segment},
-    Alignment, Modify, Padding, Style,
+    Alignment, Modify, Padding, Style, Table,
 };
 
 use crate::util::{create_table, init_table, test_table};
@@ -256,3 +258,279 @@ mod color {
         "   |          |          | \u{1b}[32m/en\u{1b}[39m      "
     );
 }
+
+test_table!(
+    set_str_test,
+    create_table::<2, 2>().with(Modify::new(Rows::new(1..)).with("x")),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| x |    x     |    x     |"
+    "+---+----------+----------+"
+    "| x |    x     |    x     |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    number_format_grouping_and_precision,
+    Table::new([("a", "1234567"), ("b", "-1234.5"), ("c", "N/A")])
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(1)).with(NumberFormat::new().thousands(',').precision(1))),
+    " &str | &str        "
+    "------+-------------"
+    " a    | 1,234,567.0 "
+    " b    | -1,234.5    "
+    " c    | N/A         "
+);
+
+test_table!(
+    number_format_keeps_digits,
+    Table::new(["999", "1000", "-0.125", "+12"])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(NumberFormat::new().thousands('_'))),
+    " &str   "
+    "--------"
+    " 999    "
+    " 1_000  "
+    " -0.125 "
+    " 12     "
+);
+
+test_table!(
+    number_format_scientific,
+    Table::new(["12345", "0.00015"])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(NumberFormat::new().scientific().precision(2))),
+    " &str    "
+    "---------"
+    " 1.23e4  "
+    " 1.50e-4 "
+);
+
+test_table!(
+    number_format_sign,
+    Table::new(["5", "-5", "0"])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(NumberFormat::new().align_sign()))
+        .with(Modify::new(Rows::single(3)).with(NumberFormat::new().plus_sign())),
+    " &str "
+    "------"
+    "  5   "
+    " -5   "
+    " +0   "
+);
+
+test_table!(
+    number_format_locale,
+    Table::new(["1234.5", "-0.25"])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(NumberFormat::new().precision(2).locale(&Locale::DE))),
+    " &str     "
+    "----------"
+    " 1.234,50 "
+    " -0,25    "
+);
+
+#[test]
+fn locale_find() {
+    assert_eq!(Locale::find("de-AT"), Some(Locale::DE));
+    assert_eq!(Locale::find("FR"), Some(Locale::FR));
+    assert_eq!(Locale::find("xx"), None);
+    assert_eq!(Locale::EN.month(12), Some("December"));
+    assert_eq!(Locale::EN.month(0), None);
+    assert_eq!(Locale::EN.short_month(9), Some("Sep"));
+    assert_eq!(Locale::EN.short_month(13), None);
+}
+
+#[cfg(feature = "num-format")]
+#[test]
+fn locale_from_num_format() {
+    let format = |locale| {
+        NumberFormat::new()
+            .precision(1)
+            .locale(&Locale::from(locale))
+    };
+
+    assert_eq!(Locale::from(num_format::Locale::de), Locale::DE);
+    assert_eq!(
+        format(num_format::Locale::fr_CA)
+            .format("-1234567.25")
+            .as_deref(),
+        Some("-1\u{a0}234\u{a0}567,2")
+    );
+    assert_eq!(
+        format(num_format::Locale::hi).format("12345678").as_deref(),
+        Some("1,23,45,678.0")
+    );
+    assert_eq!(
+        format(num_format::Locale::en_US_POSIX)
+            .format("12345678")
+            .as_deref(),
+        Some("12345678.0")
+    );
+    assert_eq!(
+        Locale::from(num_format::Locale::fr_CA).month(7),
+        Some("juillet")
+    );
+    assert_eq!(Locale::from(num_format::Locale::ja).month(7), Some("July"));
+}
+
+test_table!(
+    byte_size_si,
+    Table::new(["999", "1000", "1234567", "-1", "10 MB"])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(ByteSize::si().precision(2))),
+    " &str    "
+    "---------"
+    " 999 B   "
+    " 1.00 kB "
+    " 1.23 MB "
+    " -1      "
+    " 10 MB   "
+);
+
+#[test]
+fn byte_size_largest_unit() {
+    assert_eq!(
+        ByteSize::binary().format(&u64::MAX.to_string()).as_deref(),
+        Some("16.0 EiB")
+    );
+    assert_eq!(
+        ByteSize::si().format(&u64::MAX.to_string()).as_deref(),
+        Some("18.4 EB")
+    );
+}
+
+#[test]
+fn byte_size_rounded_up_to_next_unit() {
+    assert_eq!(ByteSize::si().format("999999").as_deref(), Some("1.0 MB"));
+    assert_eq!(ByteSize::si().format("999949").as_deref(), Some("999.9 kB"));
+    assert_eq!(
+        ByteSize::binary().format("1048575").as_deref(),
+        Some("1.0 MiB")
+    );
+    assert_eq!(
+        ByteSize::si().precision(0).format("999500").as_deref(),
+        Some("1 MB")
+    );
+}
+
+test_table!(
+    duration_seconds,
+    Table::new(["0", "59", "86401", "x"])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(DurationFormat::seconds())),
+    " &str  "
+    "-------"
+    " 0s    "
+    " 59s   "
+    " 1d 1s "
+    " x     "
+);
+
+#[cfg(feature = "chrono")]
+test_table!(
+    datetime_format,
+    Table::new([
+        "2023-02-01T12:30:00+02:00",
+        "2023-02-01 10:30:00.5 UTC",
+        "2023-02-01",
+        "0",
+        "yesterday",
+    ])
+    .with(Style::psql())
+    .with(Modify::new(Rows::new(1..)).with(tabled::format::DateTimeFormat::new("%Y-%m-%d %H:%M"))),
+    " &str             "
+    "------------------"
+    " 2023-02-01 10:30 "
+    " 2023-02-01 10:30 "
+    " 2023-02-01 00:00 "
+    " 1970-01-01 00:00 "
+    " yesterday        "
+);
+
+#[cfg(feature = "chrono")]
+test_table!(
+    datetime_format_locale,
+    Table::new(["2023-03-01T00:00:00Z"])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(tabled::format::DateTimeFormat::new("%d %B %Y, %b").locale(&Locale::DE))),
+    " &str               "
+    "--------------------"
+    " 01 März 2023, März "
+);
+
+#[cfg(feature = "chrono")]
+#[test]
+fn datetime_format_locale_short_months() {
+    let format = tabled::format::DateTimeFormat::new("%b %h").locale(&Locale::FR);
+
+    assert_eq!(format.format("2023-06-01").as_deref(), Some("juin juin"));
+    assert_eq!(format.format("2023-07-01").as_deref(), Some("juil. juil."));
+}
+
+test_table!(
+    bool_format_selection,
+    Table::new([(true, "true"), (false, "False")])
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(0)).with(BoolFormat::yes_no())),
+    " bool | &str  "
+    "------+-------"
+    " yes  | true  "
+    " no   | False "
+);
+
+test_table!(
+    bool_format_table,
+    Table::new([(true, "true"), (false, "False")])
+        .with(Style::psql())
+        .with(BoolFormat::new("+", "-")),
+    " bool | &str  "
+    "------+-------"
+    " +    | +     "
+    " -    | False "
+);
+
+test_table!(
+    placeholder_skips_spanned_cells,
+    Table::new([("", "", "1"), ("a", "\n", "")])
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 0)).with(tabled::Span::column(2)))
+        .with(Placeholder::new("-")),
+    " &str | &str | &str "
+    "------+------+------"
+    " -           | 1    "
+    " a    | -    | -    "
+);
+
+test_table!(
+    placeholder_selection,
+    Table::new([("", "")])
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(1)).with(Placeholder::new("N/A"))),
+    " &str | &str "
+    "------+------"
+    "      | N/A  "
+);
+
+test_table!(
+    hyperlink_test,
+    Table::new([("ls", "list\nfiles"), ("", "")])
+        .with(Style::blank())
+        .with(Modify::new(Rows::new(1..)).with(Hyperlink::new(|cmd| {
+            format!("man:{}", cmd.replace('\n', "-"))
+        }))),
+    " &str   &str  "
+    " \u{1b}]8;;man:ls\u{1b}\\ls\u{1b}]8;;\u{1b}\\     \u{1b}]8;;man:list-files\u{1b}\\list\u{1b}]8;;\u{1b}\\  "
+    "        \u{1b}]8;;man:list-files\u{1b}\\files\u{1b}]8;;\u{1b}\\ "
+    "              "
+);
+
+#[test]
+fn hyperlink_with_empty_url_test() {
+    let table = Table::new([("ls", "list files")])
+        .with(Modify::new(Segment::all()).with(Hyperlink::new(|_| String::new())))
+        .to_string();
+
+    assert!(!table.contains('\u{1b}'));
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "color")]
+
+use tabled::{heatmap::Heatmap, object::Columns, Style, Table};
+
+use crate::util::test_table;
+
+mod util;
+
+test_table!(
+    heatmap_foreground,
+    Table::new([("a", 0), ("b", 5), ("c", 10)])
+        .with(Style::psql())
+        .with(Heatmap::new(Columns::single(1)).gradient((0, 0, 0), (200, 100, 0))),
+    " &str | i32 "
+    "------+-----"
+    " a    | \u{1b}[38;2;0;0;0m0\u{1b}[39m   "
+    " b    | \u{1b}[38;2;100;50;0m5\u{1b}[39m   "
+    " c    | \u{1b}[38;2;200;100;0m10\u{1b}[39m  "
+);
+
+test_table!(
+    heatmap_background_skips_not_numbers,
+    Table::new(["1", "x", "3"])
+        .with(Style::psql())
+        .with(Heatmap::new(Columns::single(0)).gradient((0, 0, 0), (255, 255, 255)).background()),
+    " &str "
+    "------"
+    " \u{1b}[48;2;0;0;0m1\u{1b}[49m    "
+    " x    "
+    " \u{1b}[48;2;255;255;255m3\u{1b}[49m    "
+);
+
+test_table!(
+    heatmap_same_values,
+    Table::new([7, 7])
+        .with(Style::psql())
+        .with(Heatmap::new(Columns::single(0))),
+    " i32 "
+    "-----"
+    " \u{1b}[38;2;0;255;0m7\u{1b}[39m   "
+    " \u{1b}[38;2;0;255;0m7\u{1b}[39m   "
+);
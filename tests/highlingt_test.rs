@@ -408,3 +408,16 @@ fn highlingt_complex_figures() {
         ),
     );
 }
+
+#[cfg(feature = "color")]
+test_table!(
+    highlingt_colored_border,
+    create_table::<1, 1>()
+        .with(Style::ascii())
+        .with(Highlight::new(Cell(0, 0), Border::filled('*')).color(tabled::color::Color::FG_RED)),
+    "\u{1b}[31m*****\u{1b}[39m----------+"
+    "\u{1b}[31m*\u{1b}[39m N \u{1b}[31m*\u{1b}[39m column 0 |"
+    "\u{1b}[31m*****\u{1b}[39m----------+"
+    "| 0 |   0-0    |"
+    "+---+----------+"
+);
@@ -0,0 +1,202 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    display::{HtmlAttribute, HtmlTable},
+    format::Hyperlink,
+    object::{Cell, Columns, Rows},
+    Alignment, Modify, Panel, Sort, Span,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    html,
+    create_table::<1, 1>().with(Alignment::left()).to_html(),
+    "<table>"
+    "  <thead>"
+    "    <tr>"
+    "      <th>N</th>"
+    "      <th>column 0</th>"
+    "    </tr>"
+    "  </thead>"
+    "  <tbody>"
+    "    <tr>"
+    "      <td>0</td>"
+    "      <td>0-0</td>"
+    "    </tr>"
+    "  </tbody>"
+    "</table>"
+);
+
+test_table!(
+    html_without_header,
+    HtmlTable::new(&Builder::from_iter([["a", "b"], ["c", "d"]]).build()),
+    "<table>"
+    "  <tbody>"
+    "    <tr>"
+    "      <td>a</td>"
+    "      <td>b</td>"
+    "    </tr>"
+    "    <tr>"
+    "      <td>c</td>"
+    "      <td>d</td>"
+    "    </tr>"
+    "  </tbody>"
+    "</table>"
+);
+
+test_table!(
+    html_class,
+    HtmlTable::new(&Builder::from_iter([["a"]]).build()).class("data \"wide\""),
+    "<table class=\"data &quot;wide&quot;\">"
+    "  <tbody>"
+    "    <tr>"
+    "      <td>a</td>"
+    "    </tr>"
+    "  </tbody>"
+    "</table>"
+);
+
+test_table!(
+    html_alignment_and_span,
+    Builder::from_iter([["a", "b", "c"], ["d", "e", "f"]])
+        .build()
+        .with(Modify::new(Cell(0, 0)).with(Span::column(2)))
+        .with(Modify::new(Cell(0, 2)).with(Span::row(2)))
+        .with(Modify::new(Columns::single(0)).with(Alignment::center()))
+        .with(Modify::new(Columns::single(1)).with(Alignment::right()))
+        .to_html(),
+    "<table>"
+    "  <tbody>"
+    "    <tr>"
+    "      <td colspan=\"2\" style=\"text-align: center;\">a</td>"
+    "      <td rowspan=\"2\">c</td>"
+    "    </tr>"
+    "    <tr>"
+    "      <td style=\"text-align: center;\">d</td>"
+    "      <td style=\"text-align: right;\">e</td>"
+    "    </tr>"
+    "  </tbody>"
+    "</table>"
+);
+
+test_table!(
+    html_escape,
+    Builder::from_iter([["<a> & \"b\"", "c\nd"]]).build().to_html(),
+    "<table>"
+    "  <tbody>"
+    "    <tr>"
+    "      <td>&lt;a&gt; &amp; &quot;b&quot;</td>"
+    "      <td>c<br>d</td>"
+    "    </tr>"
+    "  </tbody>"
+    "</table>"
+);
+
+test_table!(
+    html_hyperlink,
+    Builder::from_iter([["tabled", "docs\nsite", ""]])
+        .build()
+        .with(Modify::new(Cell(0, 0)).with(Hyperlink::new(|name| format!("https://crates.io/crates/{}", name))))
+        .with(Modify::new(Columns::new(1..)).with(Hyperlink::new(|_| String::from("https://docs.rs"))))
+        .to_html(),
+    "<table>"
+    "  <tbody>"
+    "    <tr>"
+    "      <td><a href=\"https://crates.io/crates/tabled\">tabled</a></td>"
+    "      <td><a href=\"https://docs.rs\">docs</a><br><a href=\"https://docs.rs\">site</a></td>"
+    "      <td></td>"
+    "    </tr>"
+    "  </tbody>"
+    "</table>"
+);
+
+test_table!(
+    html_empty,
+    Builder::default().build().to_html(),
+    "<table>"
+    "</table>"
+);
+
+test_table!(
+    html_attributes,
+    Builder::from_iter([["a", "b"], ["c", "d"]])
+        .build()
+        .with(Modify::new(Cell(0, 0)).with(HtmlAttribute::title("a \"tooltip\"")))
+        .with(Modify::new(Cell(0, 0)).with(HtmlAttribute::class("first")))
+        .with(Modify::new(Cell(0, 0)).with(HtmlAttribute::class("primary")))
+        .with(Modify::new(Rows::single(1)).with(HtmlAttribute::data("row", "1")))
+        .to_html(),
+    "<table>"
+    "  <tbody>"
+    "    <tr>"
+    "      <td title=\"a &quot;tooltip&quot;\" class=\"primary\">a</td>"
+    "      <td>b</td>"
+    "    </tr>"
+    "    <tr>"
+    "      <td data-row=\"1\">c</td>"
+    "      <td data-row=\"1\">d</td>"
+    "    </tr>"
+    "  </tbody>"
+    "</table>"
+);
+
+test_table!(
+    html_style_attribute_is_merged_with_alignment,
+    Builder::from_iter([["a", "b"]])
+        .build()
+        .with(Modify::new(Columns::new(..)).with(HtmlAttribute::new("style", "color: red;")))
+        .with(Modify::new(Cell(0, 1)).with(Alignment::right()))
+        .to_html(),
+    "<table>"
+    "  <tbody>"
+    "    <tr>"
+    "      <td style=\"color: red;\">a</td>"
+    "      <td style=\"text-align: right; color: red;\">b</td>"
+    "    </tr>"
+    "  </tbody>"
+    "</table>"
+);
+
+test_table!(
+    html_attributes_follow_rows,
+    Builder::from_iter([["name"], ["b"], ["a"], ["c"]])
+        .build()
+        .with(Modify::new(Cell(1, 0)).with(HtmlAttribute::title("b")))
+        .with(Modify::new(Cell(3, 0)).with(HtmlAttribute::title("c")))
+        .with(Sort::by_column(0))
+        .with(Panel::header("names"))
+        .to_html(),
+    "<table>"
+    "  <tbody>"
+    "    <tr>"
+    "      <td>names</td>"
+    "    </tr>"
+    "    <tr>"
+    "      <td>a</td>"
+    "    </tr>"
+    "    <tr>"
+    "      <td title=\"b\">b</td>"
+    "    </tr>"
+    "    <tr>"
+    "      <td title=\"c\">c</td>"
+    "    </tr>"
+    "    <tr>"
+    "      <td>name</td>"
+    "    </tr>"
+    "  </tbody>"
+    "</table>"
+);
+
+#[test]
+fn html_attributes_are_ignored_by_terminal_output() {
+    let mut table = Builder::from_iter([["a"]]).build();
+    let expected = table.to_string();
+
+    table.with(Modify::new(Cell(0, 0)).with(HtmlAttribute::title("tooltip")));
+
+    assert_eq!(table.to_string(), expected);
+}
@@ -1,6 +1,6 @@
 use std::iter::FromIterator;
 
-use tabled::{builder::Builder, Table};
+use tabled::{builder::Builder, object::Rows, Disable, Table};
 
 use crate::util::{create_vector, test_table};
 
@@ -195,3 +195,79 @@ fn builder_index_no_name_transpose_transpose() {
 
     assert_eq!(orig_table, two_times_transposed_table,);
 }
+
+test_table!(
+    builder_index_start,
+    Table::builder(create_vector::<3, 1>()).index().set_start(10).clone().build(),
+    "+----+---+----------+"
+    "|    | N | column 0 |"
+    "+----+---+----------+"
+    "| 10 | 0 | 0-0      |"
+    "+----+---+----------+"
+    "| 11 | 1 | 1-0      |"
+    "+----+---+----------+"
+    "| 12 | 2 | 2-0      |"
+    "+----+---+----------+"
+);
+
+test_table!(
+    builder_index_start_ignored_for_column_index,
+    Table::builder(create_vector::<2, 1>()).index().set_index(0).set_start(10).clone().build(),
+    "+---+----------+"
+    "|   | column 0 |"
+    "+---+----------+"
+    "| N |          |"
+    "+---+----------+"
+    "| 0 | 0-0      |"
+    "+---+----------+"
+    "| 1 | 1-0      |"
+    "+---+----------+"
+);
+
+test_table!(
+    builder_index_different_row_length,
+    Builder::from_iter(vec![vec!["a", "b", "c"], vec!["d"]]).set_default_text("-").clone().index().set_index(2).clone().build(),
+    "+---+---+---+"
+    "|   | 0 | 1 |"
+    "+---+---+---+"
+    "| 2 | - | - |"
+    "+---+---+---+"
+    "| c | a | b |"
+    "+---+---+---+"
+    "| - | d | - |"
+    "+---+---+---+"
+);
+
+test_table!(
+    builder_index_different_row_length_transpose,
+    Builder::from_iter(vec![vec!["a", "b"], vec!["c"]]).set_default_text("-").clone().index().transpose().clone().build(),
+    "+---+---+---+"
+    "|   | 0 | 1 |"
+    "+---+---+---+"
+    "| 0 | a | c |"
+    "+---+---+---+"
+    "| 1 | b | - |"
+    "+---+---+---+"
+);
+
+test_table!(
+    builder_index_transpose_single_record,
+    Builder::from_iter([["Ivan", "32"]]).set_columns(["name", "age"]).clone().index().transpose().clone().build(),
+    "+------+------+"
+    "|      | 0    |"
+    "+------+------+"
+    "| name | Ivan |"
+    "+------+------+"
+    "| age  | 32   |"
+    "+------+------+"
+);
+
+test_table!(
+    builder_index_transpose_single_record_without_header,
+    Builder::from_iter([["Ivan", "32"]]).set_columns(["name", "age"]).clone().index().transpose().clone().build().with(Disable::row(Rows::first())),
+    "+------+------+"
+    "| name | Ivan |"
+    "+------+------+"
+    "| age  | 32   |"
+    "+------+------+"
+);
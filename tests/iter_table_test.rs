@@ -0,0 +1,190 @@
+use tabled::{
+    builder::Builder, object::Segment, Alignment, IterTable, Modify, Padding, Style, Table,
+};
+
+use util::{static_table, test_table};
+
+mod util;
+
+fn data() -> Vec<Vec<&'static str>> {
+    vec![
+        vec!["name", "count"],
+        vec!["apple", "1"],
+        vec!["banana", "10"],
+        vec!["cherry", "100"],
+    ]
+}
+
+macro_rules! test_style {
+    ($test:ident, $style:expr) => {
+        #[test]
+        fn $test() {
+            let mut table = Builder::from(to_strings(data())).build();
+            table.with($style);
+
+            let iter_table = IterTable::new(data()).style($style);
+
+            assert_eq!(iter_table.to_string(), table.to_string());
+        }
+    };
+}
+
+fn to_strings(data: Vec<Vec<&str>>) -> Vec<Vec<String>> {
+    data.into_iter()
+        .map(|row| row.into_iter().map(String::from).collect())
+        .collect()
+}
+
+test_style!(style_ascii, Style::ascii());
+test_style!(style_modern, Style::modern());
+test_style!(style_rounded, Style::rounded());
+test_style!(style_psql, Style::psql());
+test_style!(style_markdown, Style::markdown());
+test_style!(style_re_structured_text, Style::re_structured_text());
+test_style!(style_extended, Style::extended());
+test_style!(style_blank, Style::blank());
+test_style!(style_empty, Style::empty());
+
+test_table!(
+    default_style,
+    IterTable::new(data()),
+    "+--------+-------+"
+    "| name   | count |"
+    "+--------+-------+"
+    "| apple  | 1     |"
+    "+--------+-------+"
+    "| banana | 10    |"
+    "+--------+-------+"
+    "| cherry | 100   |"
+    "+--------+-------+"
+);
+
+test_table!(
+    fixed_width,
+    IterTable::new(data()).width(3),
+    "+-----+-----+"
+    "| nam | cou |"
+    "+-----+-----+"
+    "| app | 1   |"
+    "+-----+-----+"
+    "| ban | 10  |"
+    "+-----+-----+"
+    "| che | 100 |"
+    "+-----+-----+"
+);
+
+test_table!(
+    sniff,
+    IterTable::new(data()).sniff(2),
+    "+-------+-------+"
+    "| name  | count |"
+    "+-------+-------+"
+    "| apple | 1     |"
+    "+-------+-------+"
+    "| banan | 10    |"
+    "+-------+-------+"
+    "| cherr | 100   |"
+    "+-------+-------+"
+);
+
+test_table!(
+    multiline,
+    IterTable::new(vec![vec!["a\nb", "c"], vec!["d", "e\nf\ng"]]),
+    "+---+---+"
+    "| a | c |"
+    "| b |   |"
+    "+---+---+"
+    "| d | e |"
+    "|   | f |"
+    "|   | g |"
+    "+---+---+"
+);
+
+test_table!(
+    multiline_truncated,
+    IterTable::new(vec![vec!["abc\nde"]]).width(2),
+    "+----+"
+    "| ab |"
+    "| de |"
+    "+----+"
+);
+
+test_table!(
+    different_row_lengths,
+    IterTable::new(vec![vec!["1", "2"], vec!["3"], vec!["4", "5", "6"]]),
+    "+---+---+---+"
+    "| 1 | 2 |   |"
+    "+---+---+---+"
+    "| 3 |   |   |"
+    "+---+---+---+"
+    "| 4 | 5 | 6 |"
+    "+---+---+---+"
+);
+
+test_table!(
+    different_row_lengths_not_sniffed,
+    IterTable::new(vec![vec!["1", "2"], vec!["3"], vec!["4", "5", "6"]]).sniff(1),
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+    "| 3 |   |"
+    "+---+---+"
+    "| 4 | 5 |"
+    "+---+---+"
+);
+
+test_table!(
+    single_row,
+    IterTable::new(vec![vec!["1", "2"]]).style(Style::modern()),
+    "┌───┬───┐"
+    "│ 1 │ 2 │"
+    "└───┴───┘"
+);
+
+test_table!(empty, IterTable::new(Vec::<Vec<String>>::new()), "");
+
+test_table!(empty_rows, IterTable::new(vec![Vec::<String>::new()]), "");
+
+#[test]
+fn config_from_table() {
+    let mut table = Table::new([0]);
+    table
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Alignment::right()))
+        .with(Modify::new(Segment::all()).with(Padding::new(2, 0, 0, 0)));
+
+    let iter_table = IterTable::new(data()).config(table.get_config().clone());
+
+    assert_eq!(
+        iter_table.to_string(),
+        static_table!(
+            "    name|  count"
+            "--------+-------"
+            "   apple|      1"
+            "  banana|     10"
+            "  cherry|    100"
+        )
+    );
+}
+
+#[test]
+fn build() {
+    let rows = (0..1000).map(|i| vec![i.to_string(), (i * 2).to_string()]);
+
+    let mut buf = Vec::new();
+    IterTable::new(rows).sniff(10).build(&mut buf).unwrap();
+
+    let table = Builder::from(
+        (0..1000)
+            .map(|i| vec![i.to_string(), (i * 2).to_string()])
+            .collect::<Vec<_>>(),
+    )
+    .build()
+    .to_string();
+
+    let output = String::from_utf8(buf).unwrap();
+
+    assert_eq!(output.lines().count(), table.lines().count());
+    assert_eq!(output.lines().nth(1), Some("| 0 | 0  |"));
+    assert_eq!(output.lines().last(), Some("+---+----+"));
+}
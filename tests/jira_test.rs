@@ -0,0 +1,40 @@
+use std::iter::FromIterator;
+
+use tabled::{builder::Builder, display::JiraTable, object::Cell, Modify, Span};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    jira,
+    create_table::<2, 2>().to_jira(),
+    "||N||column 0||column 1||"
+    "|0|0-0|0-1|"
+    "|1|1-0|1-1|"
+);
+
+test_table!(
+    jira_without_header,
+    JiraTable::new(&Builder::from_iter([["a", "b"], ["c", "d"]]).build()),
+    "|a|b|"
+    "|c|d|"
+);
+
+test_table!(
+    jira_escape,
+    Builder::from_iter([["a|b", "c\nd", ""]]).build().to_jira(),
+    "|a\\|b|c \\\\ d| |"
+);
+
+test_table!(
+    jira_span,
+    Builder::from_iter([["a", "b"], ["c", "d"]])
+        .build()
+        .with(Modify::new(Cell(1, 0)).with(Span::column(2)))
+        .to_jira(),
+    "|a|b|"
+    "|c| |"
+);
+
+test_table!(jira_empty, Builder::default().build().to_jira(), "");
@@ -0,0 +1,161 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    display::JsonTable,
+    object::{Cell, Columns},
+    Disable, Modify, Reverse, Span,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    to_json,
+    create_table::<2, 2>().to_json(),
+    r#"[{"N":"0","column 0":"0-0","column 1":"0-1"},{"N":"1","column 0":"1-0","column 1":"1-1"}]"#
+);
+
+test_table!(
+    to_json_without_header,
+    Builder::from_iter([["a", "b"], ["c", "d"]])
+        .build()
+        .to_json(),
+    r#"[["a","b"],["c","d"]]"#
+);
+
+test_table!(
+    to_json_objects,
+    JsonTable::new(&Builder::from_iter([["a", "b"], ["c", "d"]]).build()).objects(),
+    r#"[{"a":"c","b":"d"}]"#
+);
+
+test_table!(
+    to_json_objects_unique_keys,
+    JsonTable::new(
+        &Builder::from_iter([["a", "", "a", "", "a_1"], ["1", "2", "3", "4", "5"]]).build()
+    )
+    .objects(),
+    r#"[{"a":"1","_1":"2","a_1":"3","_2":"4","a_1_1":"5"}]"#
+);
+
+test_table!(
+    to_json_arrays,
+    JsonTable::new(&create_table::<1, 1>()).arrays(),
+    r#"[["N","column 0"],["0","0-0"]]"#
+);
+
+test_table!(
+    to_json_escape,
+    Builder::from_iter([["a\"b\\c", "d\ne\tf\r", "\u{1}", "é"]])
+        .build()
+        .to_json(),
+    r#"[["a\"b\\c","d\ne\tf\r","\u0001","é"]]"#
+);
+
+test_table!(
+    to_json_after_changes,
+    create_table::<2, 2>()
+        .with(Disable::column(Columns::single(1)))
+        .with(Reverse::rows())
+        .to_json(),
+    r#"[{"N":"1","column 1":"1-1"},{"N":"0","column 1":"0-1"}]"#
+);
+
+test_table!(
+    to_json_span,
+    Builder::from_iter([["a", "b"], ["c", "d"]])
+        .build()
+        .with(Modify::new(Cell(1, 0)).with(Span::column(2)))
+        .to_json(),
+    r#"[["a","b"],["c",""]]"#
+);
+
+test_table!(
+    to_json_only_header,
+    {
+        let mut builder = Builder::default();
+        builder.set_columns(["a", "b"]);
+        builder.build().to_json()
+    },
+    "[]"
+);
+
+test_table!(to_json_empty, Builder::default().build().to_json(), "[]");
+
+#[cfg(feature = "json")]
+mod from_json {
+    use serde_json::json;
+    use tabled::{builder::Builder, Table};
+
+    use crate::util::test_table;
+
+    test_table!(
+        from_json,
+        Table::from_json(&json!([{ "a": 1, "b": "x" }, { "a": 2, "b": null }])),
+        "+---+---+"
+        "| a | b |"
+        "+---+---+"
+        "| 1 | x |"
+        "+---+---+"
+        "| 2 |   |"
+        "+---+---+"
+    );
+
+    test_table!(
+        from_json_keys_union,
+        Table::from_json(&json!([{ "a": 1 }, { "b": true }, { "a": 3, "c": [1, 2] }])),
+        "+---+------+-------+"
+        "| a | b    | c     |"
+        "+---+------+-------+"
+        "| 1 |      |       |"
+        "+---+------+-------+"
+        "|   | true |       |"
+        "+---+------+-------+"
+        "| 3 |      | [1,2] |"
+        "+---+------+-------+"
+    );
+
+    test_table!(
+        from_json_nested,
+        Table::from_json(&json!([{ "a": { "b": { "c": 1 }, "d": 2 } }])),
+        "+-------+-----+"
+        "| a.b.c | a.d |"
+        "+-------+-----+"
+        "| 1     | 2   |"
+        "+-------+-----+"
+    );
+
+    test_table!(
+        from_json_with_depth,
+        Builder::from_json_with_depth(&json!([{ "a": { "b": { "c": 1 }, "d": 2 } }]), 1).build(),
+        "+---------+-----+"
+        "| a.b     | a.d |"
+        "+---------+-----+"
+        "| {\"c\":1} | 2   |"
+        "+---------+-----+"
+    );
+
+    test_table!(
+        from_json_object,
+        Table::from_json(&json!({ "a": 1, "b": 2 })),
+        "+---+---+"
+        "| a | b |"
+        "+---+---+"
+        "| 1 | 2 |"
+        "+---+---+"
+    );
+
+    test_table!(
+        from_json_arrays,
+        Table::from_json(&json!([[1, "a"], [2, "b"]])),
+        "+---+---+"
+        "| 1 | a |"
+        "+---+---+"
+        "| 2 | b |"
+        "+---+---+"
+    );
+
+    test_table!(from_json_empty, Table::from_json(&json!([])), "");
+}
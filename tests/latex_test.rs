@@ -0,0 +1,109 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    display::LatexTable,
+    object::{Cell, Columns},
+    Alignment, Modify, Span,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    latex,
+    LatexTable::new(&create_table::<2, 2>()),
+    r"\begin{tabular}{ccc}"
+    r"\hline"
+    r"N & column 0 & column 1 \\"
+    r"\hline"
+    r"0 & 0-0 & 0-1 \\"
+    r"1 & 1-0 & 1-1 \\"
+    r"\hline"
+    r"\end{tabular}"
+);
+
+test_table!(
+    latex_booktabs,
+    LatexTable::new(&create_table::<1, 1>()).booktabs(),
+    r"\begin{tabular}{cc}"
+    r"\toprule"
+    r"N & column 0 \\"
+    r"\midrule"
+    r"0 & 0-0 \\"
+    r"\bottomrule"
+    r"\end{tabular}"
+);
+
+test_table!(
+    latex_without_header,
+    LatexTable::new(&Builder::from_iter([["a", "b"], ["c", "d"]]).build()),
+    r"\begin{tabular}{ll}"
+    r"\hline"
+    r"a & b \\"
+    r"c & d \\"
+    r"\hline"
+    r"\end{tabular}"
+);
+
+test_table!(
+    latex_escape,
+    LatexTable::new(&Builder::from_iter([[r"a&b", "50%", "$x_1$"], ["#{}", r"~^\", "text"]]).build()),
+    r"\begin{tabular}{lll}"
+    r"\hline"
+    r"a\&b & 50\% & \$x\_1\$ \\"
+    r"\#\{\} & \textasciitilde{}\textasciicircum{}\textbackslash{} & text \\"
+    r"\hline"
+    r"\end{tabular}"
+);
+
+test_table!(
+    latex_alignment,
+    LatexTable::new(
+        Builder::from_iter([["a", "b"], ["c", "d"]])
+            .build()
+            .with(Modify::new(Columns::single(1)).with(Alignment::right()))
+            .with(Modify::new(Cell(0, 1)).with(Alignment::center()))
+    ),
+    r"\begin{tabular}{lr}"
+    r"\hline"
+    r"a & \multicolumn{1}{c}{b} \\"
+    r"c & d \\"
+    r"\hline"
+    r"\end{tabular}"
+);
+
+test_table!(
+    latex_span,
+    LatexTable::new(
+        Builder::from_iter([["a", "b", "c"], ["d", "e", "f"]])
+            .build()
+            .with(Modify::new(Cell(0, 0)).with(Span::column(2)))
+    ),
+    r"\begin{tabular}{lll}"
+    r"\hline"
+    r"\multicolumn{2}{l}{a} & c \\"
+    r"d & e & f \\"
+    r"\hline"
+    r"\end{tabular}"
+);
+
+test_table!(
+    latex_multiline,
+    LatexTable::new(&Builder::from_iter([["a\nb", "c"]]).build()),
+    r"\begin{tabular}{ll}"
+    r"\hline"
+    r"\shortstack[l]{a \\ b} & c \\"
+    r"\hline"
+    r"\end{tabular}"
+);
+
+test_table!(
+    latex_empty,
+    LatexTable::new(&Builder::default().build()),
+    r"\begin{tabular}{}"
+    r"\hline"
+    r"\hline"
+    r"\end{tabular}"
+);
@@ -0,0 +1,104 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    display::MediaWikiTable,
+    object::{Cell, Columns},
+    Alignment, Modify, Span,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    mediawiki,
+    create_table::<1, 1>().with(Alignment::left()).to_mediawiki(),
+    "{| class=\"wikitable\""
+    "|-"
+    "! N"
+    "! column 0"
+    "|-"
+    "| 0"
+    "| 0-0"
+    "|}"
+);
+
+test_table!(
+    mediawiki_without_header,
+    MediaWikiTable::new(&Builder::from_iter([["a", "b"], ["c", "d"]]).build()),
+    "{| class=\"wikitable\""
+    "|-"
+    "| a"
+    "| b"
+    "|-"
+    "| c"
+    "| d"
+    "|}"
+);
+
+test_table!(
+    mediawiki_class,
+    MediaWikiTable::new(&Builder::from_iter([["a"]]).build()).class("wikitable sortable"),
+    "{| class=\"wikitable sortable\""
+    "|-"
+    "| a"
+    "|}"
+);
+
+test_table!(
+    mediawiki_without_class,
+    MediaWikiTable::new(&Builder::from_iter([["a"]]).build()).class(""),
+    "{|"
+    "|-"
+    "| a"
+    "|}"
+);
+
+test_table!(
+    mediawiki_alignment,
+    Builder::from_iter([["a", "b"]])
+        .build()
+        .with(Modify::new(Columns::single(0)).with(Alignment::center()))
+        .with(Modify::new(Columns::single(1)).with(Alignment::right()))
+        .to_mediawiki(),
+    "{| class=\"wikitable\""
+    "|-"
+    "| style=\"text-align: center;\" | a"
+    "| style=\"text-align: right;\" | b"
+    "|}"
+);
+
+test_table!(
+    mediawiki_span,
+    Builder::from_iter([["a", "b", "c"], ["d", "e", "f"]])
+        .build()
+        .with(Modify::new(Cell(0, 0)).with(Span::column(2)))
+        .with(Modify::new(Cell(0, 2)).with(Span::row(2)))
+        .to_mediawiki(),
+    "{| class=\"wikitable\""
+    "|-"
+    "| colspan=\"2\" | a"
+    "| rowspan=\"2\" | c"
+    "|-"
+    "| d"
+    "| e"
+    "|}"
+);
+
+test_table!(
+    mediawiki_escape,
+    Builder::from_iter([["a|b", "c\nd"]]).build().to_mediawiki(),
+    "{| class=\"wikitable\""
+    "|-"
+    "| a&#124;b"
+    "| c<br />d"
+    "|}"
+);
+
+test_table!(
+    mediawiki_empty,
+    Builder::default().build().to_mediawiki(),
+    "{| class=\"wikitable\""
+    "|}"
+);
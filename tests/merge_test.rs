@@ -13,9 +13,9 @@ test_table!(
     "| 0 | 1     |"
     "+---+---+---+"
     "| 1     | 2 |"
-    "+---+---+---+"
+    "+-------+---+"
     "| 1         |"
-    "+---+---+---+"
+    "+-----------+"
 );
 
 test_table!(
@@ -43,10 +43,10 @@ test_table!(
     Table::new([[0, 3, 5], [0, 3, 3], [0, 2, 3]]).with(Merge::vertical()),
     "+---+---+---+"
     "| 0 | 1 | 2 |"
-    "+   +---+---+"
+    "|   +---+---+"
     "|   | 3 | 5 |"
-    "+   +   +---+"
-    "+   +---+ 3 +"
+    "|   |   +---+"
+    "|   +---+ 3 |"
     "|   | 2 |   |"
     "+---+---+---+"
 );
@@ -56,9 +56,9 @@ test_table!(
     Table::new([[0, 3, 2], [0, 3, 3], [0, 2, 3]]).with(Merge::vertical()),
     "+---+---+---+"
     "| 0 | 1 | 2 |"
-    "+   +---+   +"
-    "+   + 3 +---+"
-    "+   +---+ 3 +"
+    "|   +---+   |"
+    "|   | 3 +---+"
+    "|   +---+ 3 |"
     "|   | 2 |   |"
     "+---+---+---+"
 );
@@ -102,13 +102,13 @@ test_table!(
     Table::new([[0, 1, 1], [1, 1, 2], [1, 1, 1]]).with(Merge::horizontal()).with(Merge::vertical()),
     "+---+---+---+"
     "| 0 | 1 | 2 |"
-    "+   +---+---+"
+    "|   +---+---+"
     "|   | 1     |"
     "+---+---+---+"
     "| 1     | 2 |"
-    "+---+---+---+"
+    "+-------+---+"
     "| 1         |"
-    "+---+---+---+"
+    "+-----------+"
 );
 
 test_table!(
@@ -132,9 +132,9 @@ test_table!(
     "| 0 | 1  | 2  |"
     "+---+----+----+"
     "| 3 | 4  | 5  |"
-    "+   +----+----+"
+    "|   +----+----+"
     "|   | 3  | 8  |"
-    "+   +----+----+"
+    "|   +----+----+"
     "|   | 10 | 11 |"
     "+---+----+----+"
 );
@@ -188,9 +188,9 @@ test_table!(
     "| 0 | 1 | 2 |"
     "+---+---+---+"
     "| 0         |"
-    "+---+---+---+"
+    "+-------+---+"
     "| 0     | 1 |"
     "+---+---+---+"
     "| 2 | 0     |"
-    "+---+---+---+"
+    "+---+-------+"
 );
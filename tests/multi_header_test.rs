@@ -0,0 +1,80 @@
+use tabled::{builder::Builder, MultiHeader, Style};
+
+use crate::util::test_table;
+
+mod util;
+
+fn build_table<const N: usize>(columns: [&'static str; N]) -> tabled::Table {
+    let mut builder = Builder::default();
+    builder.set_columns(columns);
+    builder.add_record((0..N).map(|i| i.to_string()));
+    builder.build()
+}
+
+test_table!(
+    multi_header_split_test,
+    build_table(["id", "Q1.Jan", "Q1.Feb", "Q2.Apr", "Q2.May"]).with(MultiHeader::split(".")),
+    "+----+-----------+-----------+"
+    "| id | Q1        | Q2        |"
+    "|    +-----+-----+-----+-----+"
+    "|    | Jan | Feb | Apr | May |"
+    "+----+-----+-----+-----+-----+"
+    "| 0  | 1   | 2   | 3   | 4   |"
+    "+----+-----+-----+-----+-----+"
+);
+
+test_table!(
+    multi_header_split_3_levels_test,
+    build_table(["2022.Q1.Jan", "2022.Q1.Feb", "2022.Q2.Apr", "total"]).with(MultiHeader::split(".")).with(Style::modern()).with(Style::correct_spans()),
+    "┌─────────────────┬───────┐"
+    "│ 2022            │ total │"
+    "├───────────┬─────┤       │"
+    "│ Q1        │ Q2  │       │"
+    "├─────┬─────┼─────┤       │"
+    "│ Jan │ Feb │ Apr │       │"
+    "├─────┼─────┼─────┼───────┤"
+    "│ 0   │ 1   │ 2   │ 3     │"
+    "└─────┴─────┴─────┴───────┘"
+);
+
+test_table!(
+    multi_header_split_not_adjacent_test,
+    build_table(["a.x", "b", "a.y"]).with(MultiHeader::split(".")),
+    "+---+---+---+"
+    "| a | b | a |"
+    "+---+   +---+"
+    "| x |   | y |"
+    "+---+---+---+"
+    "| 0 | 1 | 2 |"
+    "+---+---+---+"
+);
+
+test_table!(
+    multi_header_split_no_delimiter_test,
+    build_table(["a", "b", "c"]).with(MultiHeader::split(".")),
+    "+---+---+---+"
+    "| a | b | c |"
+    "+---+---+---+"
+    "| 0 | 1 | 2 |"
+    "+---+---+---+"
+);
+
+test_table!(
+    multi_header_groups_test,
+    build_table(["a", "b", "c", "d"]).with(MultiHeader::groups([("group", 2)])),
+    "+-------+---+---+"
+    "| group | c | d |"
+    "+---+---+   |   |"
+    "| a | b |   |   |"
+    "+---+---+---+---+"
+    "| 0 | 1 | 2 | 3 |"
+    "+---+---+---+---+"
+);
+
+test_table!(
+    multi_header_groups_empty_table_test,
+    Builder::default()
+        .build()
+        .with(MultiHeader::groups([("group", 2)])),
+    ""
+);
@@ -0,0 +1,83 @@
+#![cfg(feature = "ndarray")]
+
+use ndarray::{arr2, Array2};
+use tabled::{builder::ArrayBuilder, Table};
+
+use crate::util::test_table;
+
+mod util;
+
+test_table!(
+    from_array,
+    Table::from(&arr2(&[[1, 20, 300], [4000, 5, 60]])),
+    "+------+----+-----+"
+    "|    1 | 20 | 300 |"
+    "+------+----+-----+"
+    "| 4000 |  5 |  60 |"
+    "+------+----+-----+"
+);
+
+test_table!(
+    from_array_view,
+    Table::from(&arr2(&[[1, 2], [3, 4], [5, 6]]).t()),
+    "+---+---+---+"
+    "| 1 | 3 | 5 |"
+    "+---+---+---+"
+    "| 2 | 4 | 6 |"
+    "+---+---+---+"
+);
+
+test_table!(
+    from_array_empty,
+    Table::from(&Array2::<f64>::zeros((0, 3))),
+    ""
+);
+
+test_table!(
+    array_column_labels,
+    {
+        let array = arr2(&[[1.5, -2.0], [10.0, 0.125]]);
+        let mut builder = ArrayBuilder::new(&array);
+        builder.column_labels(["a", "b"]);
+        builder.build()
+    },
+    "+-----+-------+"
+    "|   a |     b |"
+    "+-----+-------+"
+    "| 1.5 |    -2 |"
+    "+-----+-------+"
+    "|  10 | 0.125 |"
+    "+-----+-------+"
+);
+
+test_table!(
+    array_row_labels,
+    {
+        let array = arr2(&[[1, 2], [30, 40]]);
+        let mut builder = ArrayBuilder::new(&array);
+        builder.row_labels(0..);
+        builder.build()
+    },
+    "+---+----+----+"
+    "| 0 |  1 |  2 |"
+    "+---+----+----+"
+    "| 1 | 30 | 40 |"
+    "+---+----+----+"
+);
+
+test_table!(
+    array_missing_labels,
+    {
+        let array = arr2(&[[1, 2], [30, 40]]);
+        let mut builder = ArrayBuilder::new(&array);
+        builder.row_labels(["row"]).column_labels(["col"]);
+        builder.build()
+    },
+    "+-----+-----+----+"
+    "|     | col |    |"
+    "+-----+-----+----+"
+    "| row |   1 |  2 |"
+    "+-----+-----+----+"
+    "|     |  30 | 40 |"
+    "+-----+-----+----+"
+);
@@ -1,7 +1,9 @@
 use tabled::{
-    object::{Cell, Object, Rows, Segment},
+    locator::ByColumnName,
+    object::{Cell, Columns, Object, Rows, Segment},
     style::HorizontalLine,
-    Alignment, Border, Highlight, Modify, Panel, Style,
+    Alignment, Border, Dedup, Filter, Footer, Highlight, Modify, Panel, Reverse, Sort, Style,
+    Table,
 };
 
 use crate::util::{create_table, new_table, test_table};
@@ -127,9 +129,9 @@ test_table!(
 test_table!(
     panel_style_uses_most_left_and_right_cell_styles,
     new_table(&[(0, 1)]).with(Panel::horizontal(0).text("Numbers")).with(Style::modern()),
-    "┌─────┬─────┐"
+    "┌───────────┐"
     "│  Numbers  │"
-    "├─────┼─────┤"
+    "├─────┬─────┤"
     "│ i32 │ i32 │"
     "├─────┼─────┤"
     "│  0  │  1  │"
@@ -144,7 +146,7 @@ test_table!(
         .with(Modify::new(Cell(0, 0)).with(Alignment::center())),
     "┌───────────┐"
     "│  Numbers  │"
-    "├─────┬─────┤"
+    "├───────────┤"
     "│ i32 │ i32 │"
     "├─────┼─────┤"
     "│  0  │  1  │"
@@ -364,3 +366,139 @@ test_table!(
     "     | 1 |   1-0    |     |   1-1    |   1-2    |     "
     "     | 2 |   2-0    |     |   2-1    |   2-2    |     "
 );
+
+test_table!(
+    footer_sum,
+    Table::new([("a", 1, "1.5"), ("b", 2, "x"), ("c", 3, "2.25")])
+        .with(Style::psql())
+        .with(Footer::sum(Columns::new(1..))),
+    " &str  | i32 | &str "
+    "-------+-----+------"
+    " a     | 1   | 1.5  "
+    " b     | 2   | x    "
+    " c     | 3   | 2.25 "
+    " Total | 6   | 3.75 "
+);
+
+test_table!(
+    footer_avg_by_name_with_label,
+    Table::new([("a", 1), ("b", 2), ("c", 2)])
+        .with(Style::psql())
+        .with(Footer::avg(ByColumnName::new("i32")).label("mean")),
+    " &str | i32  "
+    "------+------"
+    " a    | 1    "
+    " b    | 2    "
+    " c    | 2    "
+    " mean | 1.67 "
+);
+
+test_table!(
+    footer_min,
+    Table::new([("a", -1), ("b", 20)])
+        .with(Style::psql())
+        .with(Footer::min(Columns::single(1))),
+    " &str | i32 "
+    "------+-----"
+    " a    | -1  "
+    " b    | 20  "
+    " Min  | -1  "
+);
+
+test_table!(
+    footer_count_skips_empty_cells,
+    Table::new([("a", -1), ("", 20), ("c", 3)])
+        .with(Style::psql())
+        .with(Footer::count(Columns::new(..))),
+    " &str | i32 "
+    "------+-----"
+    " a    | -1  "
+    "      | 20  "
+    " c    | 3   "
+    " 2    | 3   "
+);
+
+test_table!(
+    footer_aggregates_skip_footers,
+    Table::new([("a", 1), ("b", 2)])
+        .with(Style::psql())
+        .with(Footer::sum(Columns::single(1)))
+        .with(Footer::max(Columns::single(1)))
+        .with(Panel::footer("2"))
+        .with(Footer::count(Columns::single(1))),
+    " &str  | i32 "
+    "-------+-----"
+    " a     | 1   "
+    " b     | 2   "
+    " Total | 3   "
+    " Max   | 2   "
+    " 2           "
+    " Count | 2   "
+);
+
+test_table!(
+    footer_aggregates_after_removed_footer,
+    Table::new([("a", 1), ("b", 2), ("c", 3)])
+        .with(Style::psql())
+        .with(Footer::sum(Columns::single(1)))
+        .with(Filter::by_column(Columns::single(0), |v| v != "Total"))
+        .with(Footer::sum(Columns::single(1))),
+    " &str  | i32 "
+    "-------+-----"
+    " a     | 1   "
+    " b     | 2   "
+    " c     | 3   "
+    " Total | 6   "
+);
+
+test_table!(
+    footer_is_not_sorted,
+    Table::new([("a", 1), ("b", 2)])
+        .with(Style::psql())
+        .with(Footer::sum(Columns::single(1)))
+        .with(Sort::by_column(1).numeric().desc()),
+    " &str  | i32 "
+    "-------+-----"
+    " b     | 2   "
+    " a     | 1   "
+    " Total | 3   "
+);
+
+test_table!(
+    footer_is_not_reversed,
+    Table::new([("a", 1), ("b", 2)])
+        .with(Style::psql())
+        .with(Footer::sum(Columns::single(1)))
+        .with(Reverse::rows()),
+    " &str  | i32 "
+    "-------+-----"
+    " b     | 2   "
+    " a     | 1   "
+    " Total | 3   "
+);
+
+test_table!(
+    footer_is_not_deduplicated,
+    Table::new([("a", 1), ("a", 1)])
+        .with(Style::psql())
+        .with(Footer::max(Columns::single(1)))
+        .with(Footer::max(Columns::single(1)))
+        .with(Dedup::consecutive()),
+    " &str | i32 "
+    "------+-----"
+    " a    | 1   "
+    " Max  | 1   "
+    " Max  | 1   "
+);
+
+test_table!(
+    footer_sum_overflow,
+    Table::new([("a", i128::MAX), ("b", 1)])
+        .with(Style::psql())
+        .with(Footer::sum(Columns::single(1))),
+    " &str  | i128                                       "
+    "-------+--------------------------------------------"
+    " a     | 170141183460469231731687303715884105727    "
+    " b     | 1                                          "
+    " Total | 170141183460469231731687303715884105728.00 "
+);
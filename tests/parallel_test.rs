@@ -0,0 +1,81 @@
+#![cfg(feature = "parallel")]
+
+use tabled::{
+    builder::Builder,
+    object::{Cell, Segment},
+    Modify, Padding, Panel, Span, Style, Table,
+};
+
+use util::create_table;
+
+mod util;
+
+fn data() -> Vec<Vec<String>> {
+    (0..500)
+        .map(|i| {
+            vec![
+                i.to_string(),
+                "a\nmultiline\ncell".repeat(i % 3),
+                "你好".repeat(i % 7),
+            ]
+        })
+        .collect()
+}
+
+#[test]
+fn same_as_sequential() {
+    let table = Builder::from(data()).build();
+
+    let mut parallel = table.clone();
+    parallel.estimate_parallel();
+
+    assert_eq!(parallel.to_string(), table.to_string());
+    assert_eq!(parallel.total_width(), table.total_width());
+    assert_eq!(parallel.total_height(), table.total_height());
+}
+
+#[test]
+fn same_as_sequential_with_padding() {
+    let mut table = Builder::from(data()).build();
+    table
+        .with(Style::modern())
+        .with(Modify::new(Segment::all()).with(Padding::new(2, 1, 1, 0)));
+
+    let mut parallel = table.clone();
+    parallel.estimate_parallel();
+
+    assert_eq!(parallel.to_string(), table.to_string());
+}
+
+#[test]
+fn same_as_sequential_with_spans() {
+    let mut table = create_table::<10, 4>();
+    table
+        .with(Panel::header(
+            "A long text of a header which spans all columns",
+        ))
+        .with(Modify::new(Cell(3, 0)).with(Span::column(2)));
+
+    let mut parallel = table.clone();
+    parallel.estimate_parallel();
+
+    assert_eq!(parallel.to_string(), table.to_string());
+}
+
+#[test]
+fn estimation_is_dropped_after_change() {
+    let mut table = Table::new(["a", "b"]);
+    table.estimate_parallel();
+    table.with(Padding::new(3, 3, 0, 0));
+
+    assert_eq!(
+        table.to_string(),
+        "+----------+\n\
+         |   &str   |\n\
+         +----------+\n\
+         |   a      |\n\
+         +----------+\n\
+         |   b      |\n\
+         +----------+"
+    );
+}
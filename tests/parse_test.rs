@@ -0,0 +1,201 @@
+use std::str::FromStr;
+
+use tabled::{
+    builder::Builder,
+    object::{Cell, Segment},
+    Alignment, Modify, Panel, Span, Style, Table,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+macro_rules! test_round_trip {
+    ($name:ident, $style:expr) => {
+        #[test]
+        fn $name() {
+            let mut table = create_table::<3, 3>();
+            table.with($style);
+
+            let mut parsed = Table::from_str(&table.to_string()).unwrap();
+            parsed
+                .with(Modify::new(Segment::all()).with(Alignment::center()))
+                .with($style);
+
+            assert_eq!(parsed.to_string(), table.to_string());
+        }
+    };
+}
+
+test_round_trip!(round_trip_ascii, Style::ascii());
+test_round_trip!(round_trip_modern, Style::modern());
+test_round_trip!(round_trip_rounded, Style::rounded());
+test_round_trip!(round_trip_extended, Style::extended());
+test_round_trip!(round_trip_sharp, Style::sharp());
+test_round_trip!(round_trip_psql, Style::psql());
+test_round_trip!(round_trip_markdown, Style::markdown());
+test_round_trip!(round_trip_ascii_rounded, Style::ascii_rounded());
+test_round_trip!(round_trip_org, Style::org());
+
+test_table!(
+    from_rendered_blank,
+    Builder::from_rendered(&create_table::<2, 2>().with(Style::blank()).to_string()).build(),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 | 0-0      | 0-1      |"
+    "+---+----------+----------+"
+    "| 1 | 1-0      | 1-1      |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    from_rendered_re_structured_text,
+    Builder::from_rendered(&create_table::<1, 2>().with(Style::re_structured_text()).to_string()).build(),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 | 0-0      | 0-1      |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    from_rendered_alignment,
+    Builder::from_rendered(
+        &create_table::<2, 2>()
+            .with(Modify::new(Segment::all()).with(Alignment::right()))
+            .to_string()
+    )
+    .build(),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 | 0-0      | 0-1      |"
+    "+---+----------+----------+"
+    "| 1 | 1-0      | 1-1      |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    from_rendered_multiline,
+    Builder::from_rendered(
+        "+-----+-------+\n\
+         | a   | b     |\n\
+         +-----+-------+\n\
+         | 1   | hello |\n\
+         |     | world |\n\
+         +-----+-------+\n\
+         | 2   | 3     |\n\
+         +-----+-------+"
+    )
+    .build(),
+    "+---+-------+"
+    "| a | b     |"
+    "+---+-------+"
+    "| 1 | hello |"
+    "|   | world |"
+    "+---+-------+"
+    "| 2 | 3     |"
+    "+---+-------+"
+);
+
+test_table!(
+    from_rendered_without_header,
+    Builder::from_rendered(
+        "| a | b |\n\
+         | 1 | 2 |"
+    )
+    .build(),
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+);
+
+#[test]
+fn from_rendered_header_detection() {
+    let table = Builder::from_rendered("| a | b |\n|---|---|\n| 1 | 2 |").build();
+    assert!(table.has_header());
+
+    let table = Builder::from_rendered("| a | b |\n| 1 | 2 |").build();
+    assert!(!table.has_header());
+}
+
+test_table!(
+    from_rendered_column_span,
+    {
+        let mut table = create_table::<3, 3>();
+        table
+            .with(Modify::new(Cell(1, 0)).with(Span::column(2)))
+            .with(Modify::new(Cell(2, 1)).with(Span::column(3)));
+        Builder::from_rendered(&table.to_string()).build()
+    },
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0            | 0-1      | 0-2      |"
+    "+---+----------+----------+----------+"
+    "| 1 | 1-0                            |"
+    "+---+----------+----------+----------+"
+    "| 2 | 2-0      | 2-1      | 2-2      |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    from_rendered_panel,
+    {
+        let mut table = create_table::<3, 2>();
+        table.with(Panel::header("Numbers"));
+        Builder::from_rendered(&table.to_string()).build()
+    },
+    "+-------------------------+"
+    "| Numbers                 |"
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 | 0-0      | 0-1      |"
+    "+---+----------+----------+"
+    "| 1 | 1-0      | 1-1      |"
+    "+---+----------+----------+"
+    "| 2 | 2-0      | 2-1      |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    from_rendered_wide_chars,
+    Builder::from_rendered(
+        "┌──────┬───┐\n\
+         │ 👋🌍 │ 中 │\n\
+         └──────┴───┘"
+    )
+    .build(),
+    "+------+----+"
+    "| 👋🌍 | 中 |"
+    "+------+----+"
+);
+
+test_table!(
+    from_rendered_combining_chars,
+    Builder::from_rendered(
+        "+------+----+\n\
+         | name | id |\n\
+         +------+----+\n\
+         | cafe\u{301} | 1  |\n\
+         +------+----+"
+    )
+    .build(),
+    "+------+----+"
+    "| name | id |"
+    "+------+----+"
+    "| cafe\u{301} | 1  |"
+    "+------+----+"
+);
+
+test_table!(from_rendered_empty, Builder::from_rendered("").build(), "");
+
+test_table!(
+    from_rendered_only_lines,
+    Builder::from_rendered("+---+\n+---+").build(),
+    ""
+);
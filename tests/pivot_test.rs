@@ -0,0 +1,52 @@
+use tabled::{
+    locator::ByColumnName,
+    object::{Column, Columns},
+    Pivot, Style, Table,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+test_table!(
+    pivot_count,
+    Table::new([("GET", 200), ("POST", 500), ("GET", 404), ("GET", 200)])
+        .with(Pivot::by(Column::from(0)))
+        .with(Style::psql()),
+    " &str | count "
+    "------+-------"
+    " GET  | 3     "
+    " POST | 1     "
+);
+
+test_table!(
+    pivot_avg,
+    Table::new([("a", "x", 1), ("b", "y", 2), ("a", "z", 4)])
+        .with(Pivot::by(Columns::single(0)).avg(ByColumnName::new("i32")))
+        .with(Style::psql()),
+    " &str | avg(i32) "
+    "------+----------"
+    " a    | 2.50     "
+    " b    | 2        "
+);
+
+test_table!(
+    pivot_single_column,
+    Table::new(["b", "a", "b"])
+        .with(Pivot::by(Columns::single(0)).sum(Columns::single(1)).count())
+        .with(Style::psql()),
+    " &str | count "
+    "------+-------"
+    " b    | 2     "
+    " a    | 1     "
+);
+
+test_table!(
+    pivot_not_found,
+    Table::new(["b"])
+        .with(Pivot::by(ByColumnName::new("none")))
+        .with(Style::psql()),
+    " &str "
+    "------"
+    " b    "
+);
@@ -0,0 +1,129 @@
+#![cfg(feature = "polars")]
+
+use polars::prelude::*;
+use tabled::{builder::DataFrameBuilder, Table};
+
+use crate::util::test_table;
+
+mod util;
+
+fn frame() -> DataFrame {
+    df!(
+        "id" => [1, 2, 3, 4],
+        "name" => [Some("Grodno"), Some("Minsk"), None, Some("Brest")],
+        "area" => [142.1, 409.5, 0.5, 146.1],
+    )
+    .unwrap()
+}
+
+test_table!(
+    from_polars,
+    Table::from_polars(&frame()),
+    "+-----+--------+-------+"
+    "| id  | name   | area  |"
+    "+-----+--------+-------+"
+    "| i32 | str    | f64   |"
+    "+-----+--------+-------+"
+    "| 1   | Grodno | 142.1 |"
+    "+-----+--------+-------+"
+    "| 2   | Minsk  | 409.5 |"
+    "+-----+--------+-------+"
+    "| 3   | null   | 0.5   |"
+    "+-----+--------+-------+"
+    "| 4   | Brest  | 146.1 |"
+    "+-----+--------+-------+"
+);
+
+test_table!(
+    from_polars_hide_dtypes,
+    {
+        let frame = frame();
+        let mut builder = DataFrameBuilder::new(&frame);
+        builder.hide_dtypes();
+        builder.build()
+    },
+    "+----+--------+-------+"
+    "| id | name   | area  |"
+    "+----+--------+-------+"
+    "| 1  | Grodno | 142.1 |"
+    "+----+--------+-------+"
+    "| 2  | Minsk  | 409.5 |"
+    "+----+--------+-------+"
+    "| 3  | null   | 0.5   |"
+    "+----+--------+-------+"
+    "| 4  | Brest  | 146.1 |"
+    "+----+--------+-------+"
+);
+
+test_table!(
+    from_polars_truncate,
+    {
+        let frame = frame();
+        let mut builder = DataFrameBuilder::new(&frame);
+        builder.truncate(2, 1);
+        builder.build()
+    },
+    "+-----+--------+-------+"
+    "| id  | name   | area  |"
+    "+-----+--------+-------+"
+    "| i32 | str    | f64   |"
+    "+-----+--------+-------+"
+    "| 1   | Grodno | 142.1 |"
+    "+-----+--------+-------+"
+    "| 2   | Minsk  | 409.5 |"
+    "+-----+--------+-------+"
+    "| …   | …      | …     |"
+    "+-----+--------+-------+"
+    "| 4   | Brest  | 146.1 |"
+    "+-----+--------+-------+"
+);
+
+test_table!(
+    from_polars_truncate_not_needed,
+    {
+        let frame = frame();
+        let mut builder = DataFrameBuilder::new(&frame);
+        builder.hide_dtypes().truncate(2, 2);
+        builder.build()
+    },
+    "+----+--------+-------+"
+    "| id | name   | area  |"
+    "+----+--------+-------+"
+    "| 1  | Grodno | 142.1 |"
+    "+----+--------+-------+"
+    "| 2  | Minsk  | 409.5 |"
+    "+----+--------+-------+"
+    "| 3  | null   | 0.5   |"
+    "+----+--------+-------+"
+    "| 4  | Brest  | 146.1 |"
+    "+----+--------+-------+"
+);
+
+test_table!(
+    from_polars_truncate_to_nothing,
+    {
+        let frame = frame();
+        let mut builder = DataFrameBuilder::new(&frame);
+        builder.hide_dtypes().truncate(0, 0);
+        builder.build()
+    },
+    "+----+------+------+"
+    "| id | name | area |"
+    "+----+------+------+"
+    "| …  | …    | …    |"
+    "+----+------+------+"
+);
+
+test_table!(
+    from_polars_series,
+    Table::from_polars(&Series::new("n".into(), [true, false]).into_frame()),
+    "+-------+"
+    "| n     |"
+    "+-------+"
+    "| bool  |"
+    "+-------+"
+    "| true  |"
+    "+-------+"
+    "| false |"
+    "+-------+"
+);
@@ -0,0 +1,39 @@
+use tabled::{PoolTable, Style, Table};
+
+use crate::util::test_table;
+
+mod util;
+
+test_table!(
+    pool_table_default_style,
+    PoolTable::new([vec!["title"], vec!["a", "b"]]),
+    "+-------+"
+    "| title |"
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+);
+
+test_table!(
+    pool_table_rows_of_different_lengths,
+    PoolTable::new([vec!["1", "2", "3"], vec!["4", "5"], vec![], vec!["6", "7", "8", "9"]])
+        .style(Style::modern()),
+    "┌─────┬────┬────┐"
+    "│ 1   │ 2  │ 3  │"
+    "├─────┴─┬──┴────┤"
+    "│ 4     │ 5     │"
+    "├───────┴───────┤"
+    "│               │"
+    "├───┬───┬───┬───┤"
+    "│ 6 │ 7 │ 8 │ 9 │"
+    "└───┴───┴───┴───┘"
+);
+
+test_table!(
+    pool_table_into_table,
+    Table::from(PoolTable::new([vec!["key", "value"], vec!["a long banner"]]))
+        .with(Style::markdown()),
+    "| key  | value  |"
+    "|------|--------|"
+    "| a long banner |"
+);
@@ -0,0 +1,107 @@
+#![cfg(feature = "ratatui")]
+
+use std::iter::FromIterator;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::Widget,
+};
+use tabled::{builder::Builder, display::TableWidget, Style as TableStyle};
+
+use crate::util::create_table;
+
+mod util;
+
+fn render<W: Widget>(widget: W, width: u16, height: u16) -> Buffer {
+    let area = Rect::new(0, 0, width, height);
+    let mut buf = Buffer::empty(area);
+    widget.render(area, &mut buf);
+    buf
+}
+
+#[test]
+fn render_table() {
+    let table = create_table::<1, 2>();
+
+    assert_eq!(
+        render(&table, 27, 5),
+        Buffer::with_lines([
+            "+---+----------+----------+",
+            "| N | column 0 | column 1 |",
+            "+---+----------+----------+",
+            "| 0 |   0-0    |   0-1    |",
+            "+---+----------+----------+",
+        ])
+    );
+}
+
+#[test]
+fn render_table_clipped() {
+    let mut table = create_table::<2, 1>();
+    table.with(TableStyle::modern());
+
+    assert_eq!(
+        render(TableWidget::new(&table), 8, 3),
+        Buffer::with_lines(["┌───┬───", "│ N │ co", "├───┼───"])
+    );
+}
+
+#[test]
+fn render_table_larger_area() {
+    let table = create_table::<0, 0>();
+
+    assert_eq!(
+        render(&table, 7, 4),
+        Buffer::with_lines(["+---+  ", "| N |  ", "+---+  ", "       "])
+    );
+}
+
+#[test]
+fn text_ansi_styles() {
+    let table = Builder::from_iter([[
+        "\u{1b}[31mred\u{1b}[0m",
+        "\u{1b}[1;4;38;2;1;2;3mrgb\u{1b}[0m",
+        "\u{1b}[3;48;5;200mbg\u{1b}[23;49m",
+    ]])
+    .build();
+
+    let text = TableWidget::new(&table).to_text();
+
+    assert_eq!(text.lines.len(), 3);
+    assert_eq!(
+        text.lines[1],
+        Line::from(vec![
+            Span::raw("| "),
+            Span::styled("red", Style::default().fg(Color::Red)),
+            Span::raw(" | "),
+            Span::styled(
+                "rgb",
+                Style::default()
+                    .fg(Color::Rgb(1, 2, 3))
+                    .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+            ),
+            Span::raw(" | "),
+            Span::styled(
+                "bg",
+                Style::default()
+                    .bg(Color::Indexed(200))
+                    .add_modifier(Modifier::ITALIC)
+            ),
+            Span::raw(" |"),
+        ])
+    );
+}
+
+#[test]
+fn text_base_style() {
+    let table = create_table::<0, 0>();
+
+    let text = TableWidget::new(&table)
+        .style(Style::default().fg(Color::Green))
+        .to_text();
+
+    assert_eq!(text.style, Style::default().fg(Color::Green));
+}
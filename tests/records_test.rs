@@ -0,0 +1,131 @@
+use tabled::{
+    builder::Builder,
+    object::{Rows, Segment},
+    records::{ExternalRecords, TextRecords},
+    Alignment, Modify, Padding, Style,
+};
+
+use util::test_table;
+
+mod util;
+
+test_table!(
+    vec_records,
+    Builder::custom(ExternalRecords::new(vec![vec!["name", "count"], vec!["apple", "1"]])).build(),
+    "+-------+-------+"
+    "| name  | count |"
+    "+-------+-------+"
+    "| apple | 1     |"
+    "+-------+-------+"
+);
+
+test_table!(
+    array_records,
+    Builder::custom(ExternalRecords::new([["a", "b"], ["c", "d"]])).build(),
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+    "| c | d |"
+    "+---+---+"
+);
+
+test_table!(
+    slice_records,
+    {
+        const DATA: &[[&str; 2]] = &[["1", "2"]];
+        Builder::custom(ExternalRecords::new(DATA)).build()
+    },
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+);
+
+test_table!(
+    missing_cells,
+    Builder::custom(ExternalRecords::new(vec![vec!["1", "2"], vec!["3"]])).build(),
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+    "| 3 |   |"
+    "+---+---+"
+);
+
+test_table!(
+    multiline,
+    Builder::custom(ExternalRecords::new([["a\nbb", "c"], ["d", "你好\n\ne"]])).build(),
+    "+----+------+"
+    "| a  | c    |"
+    "| bb |      |"
+    "+----+------+"
+    "| d  | 你好 |"
+    "|    |      |"
+    "|    | e    |"
+    "+----+------+"
+);
+
+test_table!(
+    with_settings,
+    Builder::custom(ExternalRecords::new([["name", "count"], ["apple", "1"]]))
+        .build()
+        .with(Style::modern())
+        .with(Modify::new(Segment::all()).with(Alignment::right()))
+        .with(Modify::new(Rows::first()).with(Padding::new(2, 2, 0, 0))),
+    "┌────────┬─────────┐"
+    "│  name  │  count  │"
+    "├────────┼─────────┤"
+    "│  apple │       1 │"
+    "└────────┴─────────┘"
+);
+
+test_table!(
+    empty,
+    Builder::custom(ExternalRecords::new(Vec::<Vec<String>>::new())).build(),
+    ""
+);
+
+#[test]
+fn same_as_vec_records() {
+    let data = vec![
+        vec!["Debian", "1993", "\tapt"],
+        vec!["Arch", "2002", "pacman\nyay"],
+    ];
+
+    let external = Builder::custom(ExternalRecords::new(&data)).build();
+    let table = Builder::from(
+        data.iter()
+            .map(|row| row.iter().map(|s| s.to_string()).collect())
+            .collect::<Vec<Vec<String>>>(),
+    )
+    .build();
+
+    assert_eq!(external.to_string(), table.to_string());
+    assert_eq!(external.total_width(), table.total_width());
+    assert_eq!(external.total_height(), table.total_height());
+}
+
+#[test]
+fn custom_records() {
+    struct Column(Vec<u8>);
+
+    impl TextRecords for Column {
+        fn count_rows(&self) -> usize {
+            self.0.len()
+        }
+
+        fn count_columns(&self) -> usize {
+            1
+        }
+
+        fn get_text(&self, (row, _): (usize, usize)) -> &str {
+            std::str::from_utf8(&self.0[row..row + 1]).unwrap()
+        }
+    }
+
+    let records = ExternalRecords::new(Column(b"xyz".to_vec()));
+    assert_eq!(records.get_ref().count_rows(), 3);
+
+    let mut table = Builder::custom(records).build();
+    table.with(Style::psql());
+
+    assert_eq!(table.to_string(), " x \n---\n y \n z ");
+}
@@ -0,0 +1,65 @@
+use std::iter::FromIterator;
+
+use tabled::{builder::Builder, Reverse, Style};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+test_table!(
+    reverse_rows_test,
+    create_table::<3, 2>().with(Reverse::rows()),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 2 |   2-0    |   2-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    reverse_columns_test,
+    create_table::<3, 2>().with(Reverse::columns()),
+    "+----------+----------+---+"
+    "| column 1 | column 0 | N |"
+    "+----------+----------+---+"
+    "|   0-1    |   0-0    | 0 |"
+    "+----------+----------+---+"
+    "|   1-1    |   1-0    | 1 |"
+    "+----------+----------+---+"
+    "|   2-1    |   2-0    | 2 |"
+    "+----------+----------+---+"
+);
+
+test_table!(
+    reverse_rows_without_header_test,
+    Builder::from_iter([["1"], ["2"], ["3"], ["4"]]).build().with(Reverse::rows()).with(Style::psql()),
+    " 4 "
+    "---"
+    " 3 "
+    " 2 "
+    " 1 "
+);
+
+test_table!(
+    reverse_rows_only_header_test,
+    create_table::<0, 2>().with(Reverse::rows()),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    reverse_twice_test,
+    create_table::<2, 2>().with(Reverse::rows()).with(Reverse::columns()).with(Reverse::rows()).with(Reverse::columns()),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
@@ -0,0 +1,241 @@
+use tabled::{
+    object::{Columns, Rows},
+    Dedup, Disable, Extract, Filter, Modify, Panel, Pivot, Reverse, Rotate, Selection, Sort, Split,
+    Style, Table, Viewport, Width,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+const DATA: [(&str, u8); 4] = [("cargo", 3), ("rustc", 1), ("rustup", 2), ("rustfmt", 4)];
+
+test_table!(
+    selection_without_tracking,
+    {
+        let mut table = Table::new(DATA);
+        table.with(Style::psql());
+        let selection = Selection::number(&mut table, "#");
+        assert_eq!(selection.indexes(), [0, 1, 2, 3]);
+        table
+    },
+    " # | &str    | u8 "
+    "---+---------+----"
+    " 1 | cargo   | 3  "
+    " 2 | rustc   | 1  "
+    " 3 | rustup  | 2  "
+    " 4 | rustfmt | 4  "
+);
+
+test_table!(
+    selection_after_sort_and_filter,
+    {
+        let mut table = Table::new(DATA);
+        table
+            .with(Selection)
+            .with(Sort::by_column(1).numeric().desc())
+            .with(Filter::by_column(Columns::single(0), |text| text.starts_with("rust")))
+            .with(Style::psql());
+        let selection = Selection::number(&mut table, "no");
+        assert_eq!(selection.indexes(), [3, 2, 1]);
+        assert_eq!(selection.get(1), Some(3));
+        assert_eq!(selection.get(0), None);
+        assert_eq!(selection.get(4), None);
+        table
+    },
+    " no | &str    | u8 "
+    "----+---------+----"
+    " 1  | rustfmt | 4  "
+    " 2  | rustup  | 2  "
+    " 3  | rustc   | 1  "
+);
+
+test_table!(
+    selection_page,
+    {
+        let mut table = Table::new(DATA);
+        table.with(Selection).with(Reverse::rows()).with(Style::psql());
+        let mut page = table.page(1, 2).unwrap();
+        let selection = Selection::number(&mut page, "#");
+        assert_eq!(selection.indexes(), [1, 0]);
+        page
+    },
+    " # | &str  | u8 "
+    "---+-------+----"
+    " 1 | rustc | 1  "
+    " 2 | cargo | 3  "
+);
+
+test_table!(
+    selection_skips_added_rows,
+    {
+        let mut table = Table::new(DATA);
+        table
+            .with(Selection)
+            .with(Panel::footer("end"))
+            .with(Style::psql());
+        let selection = Selection::number(&mut table, "#");
+        assert_eq!(selection.len(), 4);
+        table
+    },
+    " # | &str    | u8 "
+    "---+---------+----"
+    " 1 | cargo   | 3  "
+    " 2 | rustc   | 1  "
+    " 3 | rustup  | 2  "
+    " 4 | rustfmt | 4  "
+    "   | end          "
+);
+
+test_table!(
+    selection_is_not_a_part_of_content,
+    {
+        let data = [("cargo", 3), ("rustc", 1), ("cargo", 3), ("rustfmt", 4)];
+
+        let mut table = Table::new(data);
+        table
+            .with(Selection)
+            .with(Dedup::all())
+            .with(Modify::new(Columns::last()).with(Width::truncate(1)))
+            .with(Style::psql());
+        let selection = Selection::number(&mut table, "#");
+        assert_eq!(selection.indexes(), [0, 1, 3]);
+        table
+    },
+    " # | &str    | i "
+    "---+---------+---"
+    " 1 | cargo   | 3 "
+    " 2 | rustc   | 1 "
+    " 3 | rustfmt | 4 "
+);
+
+test_table!(
+    selection_without_header,
+    {
+        let mut table = tabled::builder::Builder::from(vec![vec!["a".to_owned()], vec!["b".to_owned()]]).build();
+        table.with(Selection).with(Reverse::rows()).with(Style::psql());
+        let selection = Selection::number(&mut table, "#");
+        assert_eq!(selection.indexes(), [1, 0]);
+        table
+    },
+    " 1 | b "
+    "---+---"
+    " 2 | a "
+);
+
+test_table!(
+    selection_after_disable,
+    {
+        let mut table = Table::new(DATA);
+        table.with(Selection).with(Disable::row(Rows::single(1))).with(Style::psql());
+        let selection = Selection::number(&mut table, "#");
+        assert_eq!(selection.indexes(), [1, 2, 3]);
+        table
+    },
+    " # | &str    | u8 "
+    "---+---------+----"
+    " 1 | rustc   | 1  "
+    " 2 | rustup  | 2  "
+    " 3 | rustfmt | 4  "
+);
+
+test_table!(
+    selection_after_extract,
+    {
+        let mut table = Table::new(DATA);
+        table.with(Selection).with(Extract::rows(2..)).with(Style::psql());
+        let selection = Selection::number(&mut table, "#");
+        assert_eq!(selection.indexes(), [2, 3]);
+        table
+    },
+    " # | rustc   | 1 "
+    "---+---------+---"
+    " 1 | rustup  | 2 "
+    " 2 | rustfmt | 4 "
+);
+
+test_table!(
+    selection_after_viewport,
+    {
+        let mut table = Table::new(DATA);
+        table.with(Selection).with(Viewport::new(2..4, ..)).with(Style::psql());
+        let selection = Selection::number(&mut table, "#");
+        assert_eq!(selection.indexes(), [2, 3]);
+        table
+    },
+    " # | &str    | u8 "
+    "---+---------+----"
+    " 1 | rustup  | 2  "
+    " 2 | rustfmt | 4  "
+);
+
+test_table!(
+    selection_after_split,
+    {
+        let mut table = Table::new(DATA);
+        table.with(Selection).with(Split::rows(3)).with(Style::psql());
+        let selection = Selection::number(&mut table, "#");
+        assert_eq!(selection.indexes(), [0, 1]);
+        table
+    },
+    " # | &str  | u8 | rustup  | 2 "
+    "---+-------+----+---------+---"
+    " 1 | cargo | 3  | rustfmt | 4 "
+    " 2 | rustc | 1  |         |   "
+);
+
+test_table!(
+    selection_after_split_zip,
+    {
+        let mut table = Table::new(DATA);
+        table.with(Selection).with(Split::columns(1).zip()).with(Style::psql());
+        let selection = Selection::number(&mut table, "#");
+        assert_eq!(selection.indexes(), [0, 1, 2, 3]);
+        table
+    },
+    " # | &str    "
+    "---+---------"
+    "   | u8      "
+    " 1 | cargo   "
+    "   | 3       "
+    " 2 | rustc   "
+    "   | 1       "
+    " 3 | rustup  "
+    "   | 2       "
+    " 4 | rustfmt "
+    "   | 4       "
+);
+
+test_table!(
+    selection_after_rotate,
+    {
+        let mut table = Table::new(DATA);
+        table.with(Selection).with(Rotate::Bottom).with(Style::psql());
+        let selection = Selection::number(&mut table, "#");
+        assert_eq!(selection.indexes(), [2, 1, 0]);
+        table
+    },
+    " # | rustfmt | 4  "
+    "---+---------+----"
+    " 1 | rustup  | 2  "
+    " 2 | rustc   | 1  "
+    " 3 | cargo   | 3  "
+    "   | &str    | u8 "
+);
+
+test_table!(
+    selection_after_pivot,
+    {
+        let mut table = Table::new(DATA);
+        table.with(Selection).with(Pivot::by(Columns::single(1))).with(Style::psql());
+        let selection = Selection::number(&mut table, "#");
+        assert!(selection.is_empty());
+        table
+    },
+    " # | u8 | count "
+    "---+----+-------"
+    "   | 3  | 1     "
+    "   | 1  | 1     "
+    "   | 2  | 1     "
+    "   | 4  | 1     "
+);
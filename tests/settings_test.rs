@@ -0,0 +1,44 @@
+use tabled::{
+    object::{Columns, Rows},
+    Alignment, Modify, Padding, Settings, Style, Table, Width,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+test_table!(
+    settings_empty,
+    Table::new([(1, 2)]).with(Settings::empty()),
+    "+-----+-----+"
+    "| i32 | i32 |"
+    "+-----+-----+"
+    "| 1   | 2   |"
+    "+-----+-----+"
+);
+
+test_table!(
+    settings_applied_in_order,
+    Table::new([("tabled", "rust"), ("papergrid", "rust")]).with(
+        Settings::new(Style::markdown(), Padding::new(0, 0, 0, 0))
+            .with(Modify::new(Rows::first()).with(Alignment::right()))
+            .with(Modify::new(Columns::single(0)).with(Width::truncate(5)))
+    ),
+    "| &str|&str|"
+    "|-----|----|"
+    "|table|rust|"
+    "|paper|rust|"
+);
+
+#[test]
+fn settings_reused() {
+    let settings = Settings::default()
+        .with(Style::psql())
+        .with(Modify::new(Rows::first()).with(Alignment::center()));
+
+    let table1 = Table::new([("a", 1)]).with(settings.clone()).to_string();
+    let table2 = Table::new([("a", 1)]).with(settings).to_string();
+
+    assert_eq!(table1, table2);
+    assert_eq!(table1, " &str | i32 \n------+-----\n a    | 1   ");
+}
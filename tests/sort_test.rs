@@ -0,0 +1,152 @@
+use std::iter::FromIterator;
+
+use tabled::{builder::Builder, Sort, Style};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+fn build_table<const N: usize>(rows: [[&'static str; N]; 4]) -> tabled::Table {
+    let mut builder = Builder::default();
+    builder.set_columns((0..N).map(|i| i.to_string()));
+    for row in rows {
+        builder.add_record(row);
+    }
+
+    builder.build()
+}
+
+test_table!(
+    sort_lexicographic_test,
+    build_table([["b", "2"], ["a", "10"], ["c", "1"], ["a", "3"]]).with(Sort::by_column(0)),
+    "+---+----+"
+    "| 0 | 1  |"
+    "+---+----+"
+    "| a | 10 |"
+    "+---+----+"
+    "| a | 3  |"
+    "+---+----+"
+    "| b | 2  |"
+    "+---+----+"
+    "| c | 1  |"
+    "+---+----+"
+);
+
+test_table!(
+    sort_lexicographic_desc_test,
+    build_table([["b", "2"], ["a", "10"], ["c", "1"], ["a", "3"]]).with(Sort::by_column(0).desc()),
+    "+---+----+"
+    "| 0 | 1  |"
+    "+---+----+"
+    "| c | 1  |"
+    "+---+----+"
+    "| b | 2  |"
+    "+---+----+"
+    "| a | 10 |"
+    "+---+----+"
+    "| a | 3  |"
+    "+---+----+"
+);
+
+test_table!(
+    sort_numeric_test,
+    build_table([["b", "2"], ["a", "10"], ["c", "-1.5"], ["a", "x"]]).with(Sort::by_column(1).numeric()),
+    "+---+------+"
+    "| 0 | 1    |"
+    "+---+------+"
+    "| c | -1.5 |"
+    "+---+------+"
+    "| b | 2    |"
+    "+---+------+"
+    "| a | 10   |"
+    "+---+------+"
+    "| a | x    |"
+    "+---+------+"
+);
+
+test_table!(
+    sort_numeric_desc_test,
+    build_table([["b", "2"], ["a", "10"], ["c", "1"], ["a", "3"]]).with(Sort::by_column(1).numeric().desc()).with(Style::psql()),
+    " 0 | 1  "
+    "---+----"
+    " a | 10 "
+    " a | 3  "
+    " b | 2  "
+    " c | 1  "
+);
+
+test_table!(
+    sort_numeric_nan_test,
+    build_table([["b", "NaN"], ["a", "10"], ["c", "inf"], ["a", "-2"]]).with(Sort::by_column(1).numeric()).with(Style::psql()),
+    " 0 | 1   "
+    "---+-----"
+    " a | -2  "
+    " a | 10  "
+    " b | NaN "
+    " c | inf "
+);
+
+test_table!(
+    sort_numeric_desc_keeps_text_last_test,
+    build_table([["b", "x"], ["a", "10"], ["c", "NaN"], ["a", "3"]]).with(Sort::by_column(1).numeric().desc()).with(Style::psql()),
+    " 0 | 1   "
+    "---+-----"
+    " a | 10  "
+    " a | 3   "
+    " b | x   "
+    " c | NaN "
+);
+
+test_table!(
+    sort_custom_comparator_test,
+    build_table([["bb", "2"], ["a", "10"], ["cccc", "1"], ["ddd", "3"]]).with(Sort::by_column_with(0, |a: &str, b: &str| b.len().cmp(&a.len()))),
+    "+------+----+"
+    "| 0    | 1  |"
+    "+------+----+"
+    "| cccc | 1  |"
+    "+------+----+"
+    "| ddd  | 3  |"
+    "+------+----+"
+    "| bb   | 2  |"
+    "+------+----+"
+    "| a    | 10 |"
+    "+------+----+"
+);
+
+test_table!(
+    sort_without_header_test,
+    Builder::from_iter([["b"], ["c"], ["a"]]).build().with(Sort::by_column(0)),
+    "+---+"
+    "| a |"
+    "+---+"
+    "| b |"
+    "+---+"
+    "| c |"
+    "+---+"
+);
+
+test_table!(
+    sort_column_out_of_bounds_test,
+    create_table::<2, 2>().with(Sort::by_column(10).desc()),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    sort_desc_by_index_test,
+    create_table::<3, 2>().with(Sort::by_column(0).desc()),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 2 |   2-0    |   2-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+);
@@ -1,4 +1,5 @@
 use tabled::{
+    builder::Builder,
     object::{Cell, Columns, Segment},
     Alignment, Border, Highlight, Modify, Padding, Panel, Span, Style, Table,
 };
@@ -14,7 +15,7 @@ test_table!(
         .with(Modify::new(Segment::all()).with(Alignment::left()))
         .with(Modify::new(Columns::single(0)).with(Span::column(2))),
     " N | column 1 | column 2 "
-    "-+-+----------+----------"
+    "---+----------+----------"
     " 0 | 0-1      | 0-2      "
     " 1 | 1-1      | 1-2      "
     " 2 | 2-1      | 2-2      "
@@ -27,7 +28,7 @@ test_table!(
         .with(Modify::new(Segment::all()).with(Alignment::left()))
         .with(Modify::new(Columns::new(1..2)).with(Span::column(2))),
     " N | column 0 | column 2 "
-    "---+-----+----+----------"
+    "---+----------+----------"
     " 0 | 0-0      | 0-2      "
     " 1 | 1-0      | 1-2      "
     " 2 | 2-0      | 2-2      "
@@ -40,7 +41,7 @@ test_table!(
         .with(Modify::new(Segment::all()).with(Alignment::left()))
         .with(Modify::new(Columns::single(0)).with(Span::column(4))),
     " N "
-    "+++"
+    "---"
     " 0 "
     " 1 "
     " 2 "
@@ -268,17 +269,17 @@ test_table!(
                 .with(Span::column(4))
                 .with(|_: &str| "span 4 columns".to_string()),
         ),
-    "┌───────────────┬───────────────┬───────────────┬───────────────┬───────────────┐"
+    "┌───────────────────────────────────────────────────────────────────────────────┐"
     "│                              span all 5 columns                               │"
-    "├───────────────┼───────────────┼───────────────┼───────────────┼───────────────┤"
+    "├───────────────────────────────────────────────────────────────┬───────────────┤"
     "│                        span 4 columns                         │ just 1 column │"
-    "├───────────────┼───────────────┼───────────────┼───────────────┼───────────────┤"
+    "├───────────────────────────────────────────────┬───────────────┴───────────────┤"
     "│                span 3 columns                 │        span 2 columns         │"
-    "├───────────────┼───────────────┼───────────────┼───────────────┼───────────────┤"
+    "├───────────────────────────────┬───────────────┴───────────────────────────────┤"
     "│        span 3 columns         │                span 3 columns                 │"
-    "├───────────────┼───────────────┼───────────────┼───────────────┼───────────────┤"
+    "├───────────────┬───────────────┴───────────────────────────────────────────────┤"
     "│ just 1 column │                        span 4 columns                         │"
-    "├───────────────┼───────────────┼───────────────┼───────────────┼───────────────┤"
+    "├───────────────┼───────────────┬───────────────┬───────────────┬───────────────┤"
     "│ just 1 column │ just 1 column │ just 1 column │ just 1 column │ just 1 column │"
     "└───────────────┴───────────────┴───────────────┴───────────────┴───────────────┘"
 );
@@ -289,9 +290,9 @@ test_table!(
         .with(Panel::horizontal(0).text("Tabled Releases"))
         .with(Modify::new(Cell(1, 0)).with(Span::column(2)))
         .with(Style::ascii()),
-    "+-----+-----+-----+"
+    "+-----------------+"
     "| Tabled Releases |"
-    "+-----+-----+-----+"
+    "+-----------+-----+"
     "|     0     |  2  |"
     "+-----+-----+-----+"
     "|  1  |  2  |  3  |"
@@ -304,7 +305,7 @@ test_table!(
         .with(Panel::horizontal(0).text("Tabled Releases"))
         .with(Modify::new(Cell(2, 0)).with(Span::column(2)))
         .with(Style::ascii()),
-    "+-----+-----+-----+"
+    "+-----------------+"
     "| Tabled Releases |"
     "+-----+-----+-----+"
     "|  0  |  1  |  2  |"
@@ -322,11 +323,11 @@ test_table!(
         .with(Modify::new(Cell(1, 0)).with(Span::column(2)))
         .with(Modify::new(Cell(2, 0)).with(Span::column(2)))
         .with(Style::ascii()),
-    "+-----+-----+-----+"
+    "+-----------------+"
     "| Tabled Releases |"
-    "+-----+-----+-----+"
+    "+-----------+-----+"
     "|     0     |  2  |"
-    "+-----+-----+-----+"
+    "+-----------+-----+"
     "|     1     |  3  |"
     "+-----+-----+-----+"
     "|  4  |  5  |  6  |"
@@ -611,7 +612,7 @@ mod row {
             assert_eq!(
                 table,
                 static_table!(
-                    " N + column 0 + column 1 + column 2 "
+                    " N | column 0 | column 1 | column 2 "
                     " 1 | 1-0      | 1-1      | 1-2      "
                     " 2 | 2-0      | 2-1      | 2-2      "
                 )
@@ -643,7 +644,7 @@ mod row {
                 .with(Modify::new(Rows::single(0)).with(Span::row(4)))
                 .to_string();
 
-            assert_eq!(table, " N + column 0 + column 1 + column 2 ");
+            assert_eq!(table, " N | column 0 | column 1 | column 2 ");
         }
     }
 
@@ -1080,7 +1081,7 @@ fn highlight_row_col_span_test() {
         static_table!(
             "┌───*********───┐"
             "│ 0 * 1 │ 2 * 3 │"
-            "├───*───┼───*───┤"
+            "├───*───┴───*───┤"
             "│ 1 * 2     * 0 │"
             "│   * 2     *   │"
             "├───* 2     *───┤"
@@ -1205,3 +1206,47 @@ test_table!(
     "| 2 |   2-0    |   2-1    |   2-2    |"
     "+---+----------+----------+----------+"
 );
+
+test_table!(
+    span_column_drops_intersections_of_modern_style,
+    {
+        let mut builder = Builder::default();
+        builder.add_record(["span"]);
+        builder.add_record(["1", "2", "3"]);
+        builder.add_record(["4", "5", "6"]);
+
+        let mut table = builder.build();
+        table
+            .with(Style::modern())
+            .with(Modify::new(Cell(0, 0)).with(Span::column(3)))
+            .with(Modify::new(Cell(2, 1)).with(Span::column(2)));
+        table
+    },
+    "┌───────────┐"
+    "│ span      │"
+    "├───┬───┬───┤"
+    "│ 1 │ 2 │ 3 │"
+    "├───┼───┴───┤"
+    "│ 4 │ 5     │"
+    "└───┴───────┘"
+);
+
+test_table!(
+    span_row_drops_intersections_of_modern_style,
+    {
+        let mut builder = Builder::default();
+        builder.add_record(["1", "2", "3"]);
+        builder.add_record(["4", "5", "6"]);
+
+        let mut table = builder.build();
+        table
+            .with(Style::modern())
+            .with(Modify::new(Cell(0, 1)).with(Span::row(2)));
+        table
+    },
+    "┌───┬───┬───┐"
+    "│ 1 │ 2 │ 3 │"
+    "├───┤   ├───┤"
+    "│ 4 │   │ 6 │"
+    "└───┴───┴───┘"
+);
@@ -0,0 +1,232 @@
+use std::iter::FromIterator;
+
+use tabled::{builder::Builder, Split, Style};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+fn build_table() -> tabled::Table {
+    Builder::from_iter([
+        ["a", "b", "c", "d", "e"],
+        ["1", "2", "3", "4", "5"],
+        ["6", "7", "8", "9", "0"],
+    ])
+    .build()
+}
+
+test_table!(
+    split_columns_test,
+    build_table().with(Split::columns(3)).with(Style::psql()),
+    " a | b | c "
+    "---+---+---"
+    " 1 | 2 | 3 "
+    " 6 | 7 | 8 "
+    " d | e |   "
+    " 4 | 5 |   "
+    " 9 | 0 |   "
+);
+
+test_table!(
+    split_columns_zip_test,
+    build_table().with(Split::columns(3).zip()).with(Style::psql()),
+    " a | b | c "
+    "---+---+---"
+    " d | e |   "
+    " 1 | 2 | 3 "
+    " 4 | 5 |   "
+    " 6 | 7 | 8 "
+    " 9 | 0 |   "
+);
+
+test_table!(
+    split_rows_test,
+    build_table().with(Split::rows(2)).with(Style::psql()),
+    " a | b | c | d | e | 6 | 7 | 8 | 9 | 0 "
+    "---+---+---+---+---+---+---+---+---+---"
+    " 1 | 2 | 3 | 4 | 5 |   |   |   |   |   "
+);
+
+test_table!(
+    split_rows_zip_test,
+    build_table().with(Split::rows(2).zip()).with(Style::psql()),
+    " a | 6 | b | 7 | c | 8 | d | 9 | e | 0 "
+    "---+---+---+---+---+---+---+---+---+---"
+    " 1 |   | 2 |   | 3 |   | 4 |   | 5 |   "
+);
+
+test_table!(
+    split_lines_test,
+    build_table().with(Split::lines(5)),
+    "+---+---+---+---+---+---+---+---+---+---+"
+    "| a | b | c | d | e | 6 | 7 | 8 | 9 | 0 |"
+    "+---+---+---+---+---+---+---+---+---+---+"
+    "| 1 | 2 | 3 | 4 | 5 |   |   |   |   |   |"
+    "+---+---+---+---+---+---+---+---+---+---+"
+);
+
+test_table!(
+    split_lines_multiline_test,
+    Builder::from_iter([["a\nb\nc"], ["d"], ["e"], ["f"]]).build().with(Split::lines(5)),
+    "+---+---+---+"
+    "| a | d | f |"
+    "| b |   |   |"
+    "| c |   |   |"
+    "+---+---+---+"
+    "|   | e |   |"
+    "+---+---+---+"
+);
+
+test_table!(
+    split_lines_too_small_test,
+    Builder::from_iter([["a"], ["b"]]).build().with(Split::lines(1)),
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+);
+
+test_table!(
+    split_bigger_than_table_test,
+    create_table::<2, 2>().with(Split::columns(10)),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    split_columns_key_column_test,
+    build_table().with(Split::columns(2).key_column(0)).with(Style::psql()),
+    " a | b | c "
+    "---+---+---"
+    " 1 | 2 | 3 "
+    " 6 | 7 | 8 "
+    " a | d | e "
+    " 1 | 4 | 5 "
+    " 6 | 9 | 0 "
+);
+
+test_table!(
+    split_columns_key_column_zip_test,
+    build_table().with(Split::columns(3).key_column(2).zip()).with(Style::psql()),
+    " c | a | b | d "
+    "---+---+---+---"
+    " c | e |   |   "
+    " 3 | 1 | 2 | 4 "
+    " 3 | 5 |   |   "
+    " 8 | 6 | 7 | 9 "
+    " 8 | 0 |   |   "
+);
+
+test_table!(
+    split_columns_key_column_single_chunk_test,
+    build_table().with(Split::columns(10).key_column(4)).with(Style::psql()),
+    " e | a | b | c | d "
+    "---+---+---+---+---"
+    " 5 | 1 | 2 | 3 | 4 "
+    " 0 | 6 | 7 | 8 | 9 "
+);
+
+test_table!(
+    split_columns_key_column_only_test,
+    Builder::from_iter([["a"], ["1"]]).build().with(Split::columns(0).key_column(0)).with(Style::psql()),
+    " a "
+    "---"
+    " 1 "
+);
+
+#[test]
+fn split_columns_key_column_only_chunks_test() {
+    let table = Builder::from_iter([["a"], ["1"]]).build();
+    let chunks = Split::columns(0).key_column(0).chunks(&table);
+
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(chunks[0].to_string(), table.to_string());
+}
+
+test_table!(
+    split_columns_unknown_key_column_test,
+    build_table().with(Split::columns(3).key_column(10)).with(Style::psql()),
+    " a | b | c "
+    "---+---+---"
+    " 1 | 2 | 3 "
+    " 6 | 7 | 8 "
+    " d | e |   "
+    " 4 | 5 |   "
+    " 9 | 0 |   "
+);
+
+#[test]
+fn split_chunks_test() {
+    let table = build_table();
+
+    let chunks = Split::rows(2)
+        .chunks(&table)
+        .into_iter()
+        .map(|chunk| chunk.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        chunks,
+        [
+            util::static_table!(
+                "+---+---+---+---+---+"
+                "| a | b | c | d | e |"
+                "+---+---+---+---+---+"
+                "| 1 | 2 | 3 | 4 | 5 |"
+                "+---+---+---+---+---+"
+            ),
+            util::static_table!(
+                "+---+---+---+---+---+"
+                "| 6 | 7 | 8 | 9 | 0 |"
+                "+---+---+---+---+---+"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_chunks_empty_test() {
+    assert!(Split::columns(2)
+        .chunks(&Builder::default().build())
+        .is_empty());
+}
+
+#[test]
+fn split_chunks_key_column_test() {
+    let table = build_table();
+
+    let chunks = Split::columns(3)
+        .key_column(0)
+        .chunks(&table)
+        .into_iter()
+        .map(|chunk| chunk.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        chunks,
+        [
+            util::static_table!(
+                "+---+---+---+---+"
+                "| a | b | c | d |"
+                "+---+---+---+---+"
+                "| 1 | 2 | 3 | 4 |"
+                "+---+---+---+---+"
+                "| 6 | 7 | 8 | 9 |"
+                "+---+---+---+---+"
+            ),
+            util::static_table!(
+                "+---+---+"
+                "| a | e |"
+                "+---+---+"
+                "| 1 | 5 |"
+                "+---+---+"
+                "| 6 | 0 |"
+                "+---+---+"
+            ),
+        ]
+    );
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "sqlx-sqlite")]
+
+use sqlx::{sqlite::SqliteRow, Connection, SqliteConnection};
+use tabled::{builder::Builder, Table};
+
+use crate::util::test_table;
+
+mod util;
+
+fn query(sql: &str) -> Vec<SqliteRow> {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        sqlx::query(
+            "CREATE TABLE cities (id INTEGER, name TEXT, area REAL, data BLOB);
+             INSERT INTO cities VALUES (1, 'Grodno', 142.1, NULL);
+             INSERT INTO cities VALUES (2, 'Minsk', 409.5, x'00');
+             INSERT INTO cities VALUES (3, NULL, NULL, NULL);",
+        )
+        .execute(&mut conn)
+        .await
+        .unwrap();
+
+        sqlx::query(sql).fetch_all(&mut conn).await.unwrap()
+    })
+}
+
+test_table!(
+    from_sqlx_rows,
+    Table::from_sqlx_rows(&query("SELECT id, name, area FROM cities")),
+    "+----+--------+-------+"
+    "| id | name   | area  |"
+    "+----+--------+-------+"
+    "| 1  | Grodno | 142.1 |"
+    "+----+--------+-------+"
+    "| 2  | Minsk  | 409.5 |"
+    "+----+--------+-------+"
+    "| 3  | NULL   | NULL  |"
+    "+----+--------+-------+"
+);
+
+test_table!(
+    from_sqlx_rows_unknown_type,
+    Builder::from_sqlx_rows(&query("SELECT id, data FROM cities")).build(),
+    "+----+--------+"
+    "| id | data   |"
+    "+----+--------+"
+    "| 1  | NULL   |"
+    "+----+--------+"
+    "| 2  | <BLOB> |"
+    "+----+--------+"
+    "| 3  | NULL   |"
+    "+----+--------+"
+);
+
+test_table!(
+    from_sqlx_rows_expression,
+    Table::from_sqlx_rows(&query("SELECT count(*) AS n, max(area) FROM cities")),
+    "+---+-----------+"
+    "| n | max(area) |"
+    "+---+-----------+"
+    "| 3 | 409.5     |"
+    "+---+-----------+"
+);
+
+test_table!(
+    from_sqlx_rows_empty,
+    Table::from_sqlx_rows(&query("SELECT id FROM cities WHERE id > 3")),
+    ""
+);
@@ -0,0 +1,43 @@
+use tabled::{StripColors, Table};
+
+use crate::util::test_table;
+
+mod util;
+
+test_table!(
+    strip_colors_test,
+    Table::new(["\u{1b}[31mred\u{1b}[39m", "\u{1b}[1m\u{1b}[44mbold\nblue\u{1b}[49m\u{1b}[22m"]).with(StripColors),
+    "+------+"
+    "| &str |"
+    "+------+"
+    "| red  |"
+    "+------+"
+    "| bold |"
+    "| blue |"
+    "+------+"
+);
+
+test_table!(
+    strip_colors_hyperlink_test,
+    Table::new(["\u{1b}]8;;https://example.com\u{1b}\\link\u{1b}]8;;\u{1b}\\", "\u{1b}]8;;file:///\u{7}file\u{1b}]8;;\u{7}"]).with(StripColors),
+    "+------+"
+    "| &str |"
+    "+------+"
+    "| link |"
+    "+------+"
+    "| file |"
+    "+------+"
+);
+
+#[cfg(feature = "color")]
+test_table!(
+    strip_colors_cell_test,
+    Table::new(["\u{1b}[31mred\u{1b}[39m", "\u{1b}[32mgreen\u{1b}[39m"]).with(tabled::Modify::new(tabled::object::Rows::single(1)).with(StripColors)),
+    "+-------+"
+    "| &str  |"
+    "+-------+"
+    "| red   |"
+    "+-------+"
+    "| \u{1b}[32mgreen\u{1b}[39m |"
+    "+-------+"
+);
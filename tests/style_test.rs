@@ -6,10 +6,13 @@ use tabled::{
     builder::Builder,
     format::Format,
     object::{Cell, Columns, Rows, Segment},
-    style::{BorderChar, HorizontalLine, Line, Offset, RawStyle, VerticalLine},
+    style::{BorderChar, HorizontalLine, Line, Offset, On, RawStyle, VerticalLine},
     Border, BorderText, Highlight, Modify, Padding, Span, Style, Table,
 };
 
+#[cfg(feature = "color")]
+use tabled::color::{Color, Colorization};
+
 mod util;
 
 test_table!(
@@ -46,6 +49,24 @@ test_table!(
     "| 2 |   2-0    |   2-1    |   2-2    |"
 );
 
+test_table!(
+    org_style,
+    create_table::<3, 3>().with(Style::org()),
+    "| N | column 0 | column 1 | column 2 |"
+    "|---+----------+----------+----------|"
+    "| 0 |   0-0    |   0-1    |   0-2    |"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "| 2 |   2-0    |   2-1    |   2-2    |"
+);
+
+test_table!(
+    org_style_without_header,
+    Builder::from_iter([["a", "b"], ["c", "d"]]).build().with(Style::org()),
+    "| a | b |"
+    "|---+---|"
+    "| c | d |"
+);
+
 test_table!(
     modern_style,
     create_table::<3, 3>().with(Style::modern()),
@@ -2193,6 +2214,100 @@ test_table!(
     " N \u{1b}[42m|\u{1b}[49m column 0 \u{1b}[42m|\u{1b}[49m column 1 \u{1b}[42m|\u{1b}[49m column 2 \n\u{1b}[42m---+----------+----------+----------\u{1b}[49m\n 0 \u{1b}[42m|\u{1b}[49m   0-0    \u{1b}[42m|\u{1b}[49m   0-1    \u{1b}[42m|\u{1b}[49m   0-2    \n 1 \u{1b}[42m|\u{1b}[49m   1-0    \u{1b}[42m|\u{1b}[49m   1-1    \u{1b}[42m|\u{1b}[49m   1-2    \n 2 \u{1b}[42m|\u{1b}[49m   2-0    \u{1b}[42m|\u{1b}[49m   2-1    \u{1b}[42m|\u{1b}[49m   2-2    "
 );
 
+#[cfg(feature = "color")]
+test_table!(
+    text_color_of_cells,
+    {
+        use std::convert::TryFrom;
+        use owo_colors::OwoColorize;
+        use tabled::color::Color;
+
+        let color = Color::try_from(' '.on_green().to_string()).unwrap();
+
+        create_table::<3, 3>().with(Style::psql()).with(Modify::new(Segment::all()).with(color.text()))
+    },
+    " \u{1b}[42mN\u{1b}[49m | \u{1b}[42mcolumn 0\u{1b}[49m | \u{1b}[42mcolumn 1\u{1b}[49m | \u{1b}[42mcolumn 2\u{1b}[49m "
+    "---+----------+----------+----------"
+    " \u{1b}[42m0\u{1b}[49m |   \u{1b}[42m0-0\u{1b}[49m    |   \u{1b}[42m0-1\u{1b}[49m    |   \u{1b}[42m0-2\u{1b}[49m    "
+    " \u{1b}[42m1\u{1b}[49m |   \u{1b}[42m1-0\u{1b}[49m    |   \u{1b}[42m1-1\u{1b}[49m    |   \u{1b}[42m1-2\u{1b}[49m    "
+    " \u{1b}[42m2\u{1b}[49m |   \u{1b}[42m2-0\u{1b}[49m    |   \u{1b}[42m2-1\u{1b}[49m    |   \u{1b}[42m2-2\u{1b}[49m    "
+);
+
+#[cfg(feature = "color")]
+test_table!(
+    text_color_combined_multiline,
+    Table::new(["a\nb"]).with(Modify::new(Cell(1, 0)).with((tabled::color::Color::FG_RED | tabled::color::Color::BOLD).text())),
+    "+------+"
+    "| &str |"
+    "+------+"
+    "| \u{1b}[31m\u{1b}[1ma\u{1b}[22m\u{1b}[39m    |"
+    "| \u{1b}[31m\u{1b}[1mb\u{1b}[22m\u{1b}[39m    |"
+    "+------+"
+);
+
+#[cfg(feature = "color")]
+test_table!(
+    text_color_by_content,
+    Table::new([1, 91, 100]).with(Modify::new(Segment::all()).with(tabled::color::Color::by(|text, (row, _)| {
+        match text.parse::<u8>() {
+            Ok(value) if value > 90 && row != 3 => Some(tabled::color::Color::FG_RED),
+            _ => None,
+        }
+    }))),
+    "+-----+"
+    "| i32 |"
+    "+-----+"
+    "| 1   |"
+    "+-----+"
+    "| \u{1b}[31m91\u{1b}[39m  |"
+    "+-----+"
+    "| 100 |"
+    "+-----+"
+);
+
+#[cfg(feature = "color")]
+test_table!(
+    colorization_columns,
+    Builder::from_iter([["name", "status", "name"], ["a", "b", "c"]])
+        .build()
+        .with(Colorization::columns([("name", Color::FG_RED), ("missing", Color::FG_BLUE)])),
+    "+------+--------+------+"
+    "| \u{1b}[31mname\u{1b}[39m | status | \u{1b}[31mname\u{1b}[39m |"
+    "+------+--------+------+"
+    "| \u{1b}[31ma\u{1b}[39m    | b      | \u{1b}[31mc\u{1b}[39m    |"
+    "+------+--------+------+"
+);
+
+#[cfg(feature = "color")]
+test_table!(
+    colorization_never,
+    create_table::<1, 1>()
+        .with(Colorization::never())
+        .with(Color::BG_GREEN)
+        .with(Modify::new(Segment::all()).with(Color::FG_RED.text()))
+        .with(Colorization::columns([("N", Color::FG_BLUE)])),
+    "+---+----------+"
+    "| N | column 0 |"
+    "+---+----------+"
+    "| 0 |   0-0    |"
+    "+---+----------+"
+);
+
+#[cfg(feature = "color")]
+test_table!(
+    colorization_never_after_colors,
+    create_table::<1, 1>()
+        .with(Color::BG_GREEN)
+        .with(Modify::new(Segment::all()).with(Color::FG_RED.text()))
+        .with(Colorization::columns([("N", Color::FG_BLUE)]))
+        .with(Colorization::never()),
+    "+---+----------+"
+    "| N | column 0 |"
+    "+---+----------+"
+    "| 0 |   0-0    |"
+    "+---+----------+"
+);
+
 test_table!(
     verticals_0,
     create_table::<3, 3>()
@@ -2605,3 +2720,62 @@ test_table!(
     "|     ! |xxxx"
     "+-------+xxxx"
 );
+
+#[cfg(feature = "color")]
+test_table!(
+    text_color_rgb_and_downgrade,
+    create_table::<1, 2>()
+        .with(Modify::new(Columns::single(1)).with(Color::rgb(255, 128, 0).text()))
+        .with(Modify::new(Columns::single(2)).with(Color::bg_ansi256(196).to_ansi16().text())),
+    "+---+----------+----------+"
+    "| N | \u{1b}[38;2;255;128;0mcolumn 0\u{1b}[39m | \u{1b}[101mcolumn 1\u{1b}[49m |"
+    "+---+----------+----------+"
+    "| 0 |   \u{1b}[38;2;255;128;0m0-0\u{1b}[39m    |   \u{1b}[101m0-1\u{1b}[49m    |"
+    "+---+----------+----------+"
+);
+
+#[cfg(all(feature = "color", feature = "owo-colors"))]
+test_table!(
+    text_color_from_owo_colors_style,
+    create_table::<1, 1>().with(
+        Modify::new(Cell(1, 1))
+            .with(Color::from(owo_colors::Style::new().red().bold()).text())
+    ),
+    "+---+----------+"
+    "| N | column 0 |"
+    "+---+----------+"
+    "| 0 |   \u{1b}[31;1m0-0\u{1b}[0m    |"
+    "+---+----------+"
+);
+
+#[cfg(all(feature = "color", feature = "anstyle"))]
+test_table!(
+    text_color_from_anstyle_style,
+    create_table::<1, 1>().with(
+        Modify::new(Cell(1, 1))
+            .with(Color::from(anstyle::AnsiColor::Red.on_default().bold()).text())
+    ),
+    "+---+----------+"
+    "| N | column 0 |"
+    "+---+----------+"
+    "| 0 |   \u{1b}[1m\u{1b}[31m0-0\u{1b}[0m    |"
+    "+---+----------+"
+);
+
+static THEME: Style<On, On, On, On, On, On> = Style::modern()
+    .top_left_corner('╭')
+    .top_right_corner('╮')
+    .bottom_left_corner('╰')
+    .bottom_right_corner('╯');
+
+const FRAME: Border = Border::filled('*').top('=').bottom('=');
+
+test_table!(
+    const_style_test,
+    create_table::<1, 2>().with(&THEME).with(Modify::new(Cell(1, 1)).with(FRAME)),
+    "╭───┬──────────┬──────────╮"
+    "│ N │ column 0 │ column 1 │"
+    "├───*==========*──────────┤"
+    "│ 0 *   0-0    *   0-1    │"
+    "╰───*==========*──────────╯"
+);
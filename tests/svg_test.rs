@@ -0,0 +1,82 @@
+#![cfg(feature = "image")]
+
+use std::iter::FromIterator;
+
+use tabled::{builder::Builder, display::SvgTable, format::Format, object::Cell, Modify, Style};
+
+use crate::util::test_table;
+
+mod util;
+
+test_table!(
+    svg,
+    Builder::from_iter([["a", "b"]]).build().to_svg(),
+    r#"<svg xmlns="http://www.w3.org/2000/svg" width="75.6" height="50.4" viewBox="0 0 75.6 50.4">"#
+    r##"<g font-family="monospace" font-size="14" fill="#000000">"##
+    r#"<text x="0" y="14.0" xml:space="preserve" textLength="75.6" lengthAdjust="spacingAndGlyphs">+---+---+</text>"#
+    r#"<text x="0" y="30.8" xml:space="preserve" textLength="75.6" lengthAdjust="spacingAndGlyphs">| a | b |</text>"#
+    r#"<text x="0" y="47.6" xml:space="preserve" textLength="75.6" lengthAdjust="spacingAndGlyphs">+---+---+</text>"#
+    r#"</g>"#
+    r#"</svg>"#
+);
+
+test_table!(
+    svg_settings,
+    SvgTable::new(Builder::from_iter([["a"]]).build().with(Style::blank()))
+        .font_size(10)
+        .font_family("Fira Code")
+        .foreground("white")
+        .background("#1e1e1e"),
+    r#"<svg xmlns="http://www.w3.org/2000/svg" width="18.0" height="12.0" viewBox="0 0 18.0 12.0">"#
+    r##"<rect width="100%" height="100%" fill="#1e1e1e"/>"##
+    r#"<g font-family="Fira Code" font-size="10" fill="white">"#
+    r#"<text x="0" y="10.0" xml:space="preserve" textLength="18.0" lengthAdjust="spacingAndGlyphs"> a </text>"#
+    r#"</g>"#
+    r#"</svg>"#
+);
+
+test_table!(
+    svg_escape,
+    SvgTable::new(Builder::from_iter([["<&>"]]).build().with(Style::blank())),
+    r#"<svg xmlns="http://www.w3.org/2000/svg" width="42.0" height="16.8" viewBox="0 0 42.0 16.8">"#
+    r##"<g font-family="monospace" font-size="14" fill="#000000">"##
+    r#"<text x="0" y="14.0" xml:space="preserve" textLength="42.0" lengthAdjust="spacingAndGlyphs"> &lt;&amp;&gt; </text>"#
+    r#"</g>"#
+    r#"</svg>"#
+);
+
+#[test]
+fn svg_colors() {
+    let mut table = Builder::from_iter([["a", "b", "c"]]).build();
+    table
+        .with(Style::blank())
+        .with(Modify::new(Cell(0, 0)).with(Format::new(|s| format!("\u{1b}[31m{}\u{1b}[39m", s))))
+        .with(Modify::new(Cell(0, 1)).with(Format::new(|s| {
+            format!("\u{1b}[1;38;2;1;2;3m{}\u{1b}[0m", s)
+        })))
+        .with(Modify::new(Cell(0, 2)).with(Format::new(|s| format!("\u{1b}[4m{}\u{1b}[24m", s))));
+
+    let svg = table.to_svg();
+
+    assert!(
+        svg.contains(r##"<tspan fill="#cd0000">a</tspan>"##),
+        "{}",
+        svg
+    );
+    assert!(
+        svg.contains(r##"<tspan fill="#010203" font-weight="bold">b</tspan>"##),
+        "{}",
+        svg
+    );
+    assert!(svg.contains("  c"), "{}", svg);
+    assert!(!svg.contains('\u{1b}'), "{}", svg);
+}
+
+test_table!(
+    svg_empty,
+    Builder::default().build().to_svg(),
+    r#"<svg xmlns="http://www.w3.org/2000/svg" width="0.0" height="0.0" viewBox="0 0 0.0 0.0">"#
+    r##"<g font-family="monospace" font-size="14" fill="#000000">"##
+    r#"</g>"#
+    r#"</svg>"#
+);
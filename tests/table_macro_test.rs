@@ -0,0 +1,39 @@
+#![cfg(feature = "macros")]
+
+use tabled::{table, Style};
+
+use crate::util::test_table;
+
+mod util;
+
+test_table!(
+    table_macro_test,
+    table!(["name", "year"; "tabled", 2020; "papergrid", 2021]),
+    "+-----------+------+"
+    "| name      | year |"
+    "+-----------+------+"
+    "| tabled    | 2020 |"
+    "+-----------+------+"
+    "| papergrid | 2021 |"
+    "+-----------+------+"
+);
+
+test_table!(
+    table_macro_header_only_test,
+    table!(["a", "b", "c",]).with(Style::psql()),
+    " a | b | c "
+);
+
+test_table!(
+    table_macro_different_lengths_test,
+    table!(["key"; 1, 2; 3;]).with(Style::psql()),
+    " key |   "
+    "-----+---"
+    " 1   | 2 "
+    " 3   |   "
+);
+
+#[test]
+fn table_macro_has_header() {
+    assert!(table!(["a"; 1]).has_header());
+}
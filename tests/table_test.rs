@@ -1,6 +1,10 @@
 use std::iter::FromIterator;
 
-use tabled::{builder::Builder, Height, Padding, Style, Table, Width};
+use tabled::{
+    builder::Builder,
+    object::{Cell, Rows},
+    Caption, CellRect, Disable, Height, Margin, Modify, Padding, Span, Style, Table, Width,
+};
 
 use crate::util::{create_table, test_table};
 
@@ -764,6 +768,29 @@ fn multiline_table_test2() {
     );
 }
 
+#[cfg(feature = "color")]
+test_table!(
+    table_closes_unclosed_color,
+    Table::new(["\u{1b}[31mred", "green"]),
+    "+-------+"
+    "| &str  |"
+    "+-------+"
+    "| \u{1b}[31mred\u{1b}[0m   |"
+    "+-------+"
+    "| green |"
+    "+-------+"
+);
+
+#[cfg(feature = "color")]
+test_table!(
+    table_restores_color_on_next_line,
+    Table::new(["\u{1b}[1;31mbold\nred\u{1b}[0m"]).with(Style::psql()),
+    " &str "
+    "------"
+    " \u{1b}[1;31mbold\u{1b}[22m\u{1b}[39m "
+    " \u{1b}[1m\u{1b}[31mred\u{1b}[0m  "
+);
+
 test_table!(
     table_1x1_empty,
     {
@@ -820,3 +847,321 @@ test_table!(
     "├┼┤"
     "└┴┘"
 );
+
+test_table!(
+    table_insert_row_test,
+    create_table::<2, 2>().insert_row(1, ["x", "y", "z", "ignored"]).insert_row(4, ["last"]),
+    "+------+----------+----------+"
+    "|  N   | column 0 | column 1 |"
+    "+------+----------+----------+"
+    "|  x   |    y     |    z     |"
+    "+------+----------+----------+"
+    "|  0   |   0-0    |   0-1    |"
+    "+------+----------+----------+"
+    "|  1   |   1-0    |   1-1    |"
+    "+------+----------+----------+"
+    "| last |          |          |"
+    "+------+----------+----------+"
+);
+
+test_table!(
+    table_insert_column_test,
+    create_table::<2, 2>().insert_column(0, "i", ["a", "b", "ignored"]).insert_column(4, "total", ["1"]),
+    "+---+---+----------+----------+-------+"
+    "| i | N | column 0 | column 1 | total |"
+    "+---+---+----------+----------+-------+"
+    "| a | 0 |   0-0    |   0-1    |   1   |"
+    "+---+---+----------+----------+-------+"
+    "| b | 1 |   1-0    |   1-1    |       |"
+    "+---+---+----------+----------+-------+"
+);
+
+test_table!(
+    table_insert_column_without_header_test,
+    Builder::from_iter([["a"], ["b"]]).build().insert_column(1, "ignored", ["1", "2"]),
+    "+---+---+"
+    "| a | 1 |"
+    "+---+---+"
+    "| b | 2 |"
+    "+---+---+"
+);
+
+#[test]
+#[should_panic]
+fn table_insert_row_out_of_bounds_test() {
+    create_table::<2, 2>().insert_row(4, ["x"]);
+}
+
+#[test]
+#[should_panic]
+fn table_insert_column_out_of_bounds_test() {
+    create_table::<2, 2>().insert_column(4, "x", ["y"]);
+}
+
+#[test]
+fn table_pages_test() {
+    let table = create_table::<3, 1>();
+
+    assert_eq!(table.count_pages(2), 2);
+
+    let pages = table
+        .pages(2)
+        .map(|page| page.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        pages,
+        [
+            util::static_table!(
+                "+---+----------+"
+                "| N | column 0 |"
+                "+---+----------+"
+                "| 0 |   0-0    |"
+                "+---+----------+"
+                "| 1 |   1-0    |"
+                "+---+----------+"
+            ),
+            util::static_table!(
+                "+---+----------+"
+                "| N | column 0 |"
+                "+---+----------+"
+                "| 2 |   2-0    |"
+                "+---+----------+"
+            ),
+        ]
+    );
+
+    assert_eq!(table.page(1, 2).unwrap().to_string(), pages[1]);
+    assert!(table.page(2, 2).is_none());
+}
+
+#[test]
+fn table_pages_without_header_test() {
+    let table = Builder::from_iter([["a"], ["b"], ["c"]]).build();
+
+    assert_eq!(table.count_pages(1), 3);
+    assert_eq!(
+        table.page(1, 1).unwrap().to_string(),
+        util::static_table!(
+            "+---+"
+            "| b |"
+            "+---+"
+        )
+    );
+}
+
+#[test]
+fn table_pages_empty_test() {
+    let table = create_table::<0, 1>();
+
+    assert_eq!(table.count_pages(10), 1);
+    assert_eq!(table.pages(10).len(), 1);
+    assert_eq!(
+        table.page(0, 10).unwrap().to_string(),
+        util::static_table!(
+            "+---+----------+"
+            "| N | column 0 |"
+            "+---+----------+"
+        )
+    );
+}
+
+#[test]
+#[should_panic]
+fn table_pages_zero_rows_test() {
+    create_table::<1, 1>().pages(0);
+}
+
+#[test]
+fn table_layout_test() {
+    let mut table = create_table::<2, 2>();
+    table
+        .with(Style::psql())
+        .with(Margin::new(2, 0, 1, 0))
+        .with(Modify::new(Cell(1, 0)).with(Span::column(2)))
+        .with(Modify::new(Cell(1, 2)).with(Height::increase(2)));
+
+    let layout = table.layout();
+
+    assert_eq!(layout.column_widths(), [3, 10, 10]);
+    assert_eq!(layout.row_heights(), [1, 2, 1]);
+    assert_eq!(layout.total_width(), 27);
+    assert_eq!(layout.total_height(), 6);
+    assert_eq!(
+        layout.cell((0, 2)),
+        Some(CellRect {
+            x: 17,
+            y: 1,
+            width: 10,
+            height: 1
+        })
+    );
+    assert_eq!(
+        layout.cell((1, 0)),
+        Some(CellRect {
+            x: 2,
+            y: 3,
+            width: 14,
+            height: 2
+        })
+    );
+    assert_eq!(layout.cell((1, 1)), None);
+    assert_eq!(layout.cell((3, 0)), None);
+
+    let output = table.to_string();
+    let lines = output.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), layout.total_height());
+    assert_eq!(&lines[1][17..27], " column 1 ");
+    assert_eq!(&lines[3][2..16], "      0       ");
+    assert_eq!(&lines[5][17..27], "   1-1    ");
+}
+
+test_table!(
+    table_from_vec_of_vec_test,
+    Table::from(vec![vec![String::from("a"), String::from("b")], vec![String::from("c")]]),
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+    "| c |   |"
+    "+---+---+"
+);
+
+test_table!(
+    table_from_builder_test,
+    Table::from(Builder::from_iter((0..2).map(|i| (0..3).map(move |j| (i * j).to_string())))),
+    "+---+---+---+"
+    "| 0 | 0 | 0 |"
+    "+---+---+---+"
+    "| 0 | 1 | 2 |"
+    "+---+---+---+"
+);
+
+#[test]
+fn table_build_into_test() {
+    let mut table = create_table::<3, 3>();
+    table
+        .with(Style::modern())
+        .with(Modify::new(Cell(1, 0)).with(Span::column(2)));
+
+    let mut buf = Vec::new();
+    table.build_into(&mut buf).unwrap();
+    assert_eq!(String::from_utf8(buf).unwrap(), table.to_string());
+
+    let mut text = String::from("text");
+    table.build_into_fmt(&mut text).unwrap();
+    assert_eq!(text, format!("text{}", table));
+}
+
+#[test]
+fn table_build_into_empty_test() {
+    let table = Table::new(Vec::<String>::new())
+        .with(Disable::row(Rows::first()))
+        .clone();
+
+    let mut buf = Vec::new();
+    table.build_into(&mut buf).unwrap();
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn table_build_into_error_test() {
+    struct FailingWriter;
+
+    impl std::io::Write for FailingWriter {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::Other.into())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let err = create_table::<1, 1>()
+        .build_into(FailingWriter)
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::Other);
+}
+
+#[test]
+fn table_from_iter_borrows_strings_test() {
+    use tabled::papergrid::records::Records;
+
+    let data = vec![String::from("hello"), String::from("world")];
+    let table = Table::from_iter(&data);
+
+    assert_eq!(
+        table.get_records().get_text((1, 0)).as_ptr(),
+        data[0].as_ptr()
+    );
+    assert_eq!(
+        table.get_records().get_text((2, 0)).as_ptr(),
+        data[1].as_ptr()
+    );
+}
+
+#[test]
+fn table_set_str_borrows_test() {
+    use tabled::papergrid::records::Records;
+
+    let text: &'static str = "text";
+    let mut table = create_table::<2, 2>();
+    table.with(Modify::new(Cell(1, 1)).with(text));
+
+    assert_eq!(table.get_records().get_text((1, 1)).as_ptr(), text.as_ptr());
+}
+
+#[test]
+fn table_render_is_not_reallocated_test() {
+    let table = create_table::<100, 5>().render();
+    assert_eq!(table, create_table::<100, 5>().to_string());
+    assert_eq!(table.capacity(), table.len());
+
+    let table = create_table::<100, 5>()
+        .with(Style::psql())
+        .with(Padding::new(1, 1, 1, 1))
+        .with(Margin::new(2, 1, 1, 2))
+        .with(Caption::top("a long caption of a table\nwith 2 lines"))
+        .with(Caption::bottom(""))
+        .render();
+    assert!(table.contains("a long caption of a table"));
+    assert_eq!(table.capacity(), table.len());
+}
+
+test_table!(
+    table_modify_test,
+    create_table::<2, 2>()
+        .modify(Rows::first(), Padding::new(2, 2, 0, 0))
+        .modify(Cell(1, 1), "text")
+        .with(Style::psql()),
+    "  N  |  column 0  |  column 1  "
+    "-----+------------+------------"
+    "  0  |    text    |    0-1     "
+    "  1  |    1-0     |    1-1     "
+);
+
+test_table!(
+    table_with_tuple_test,
+    create_table::<2, 2>().with((
+        Style::markdown(),
+        Modify::new(Rows::first()).with(Padding::new(0, 0, 0, 0)),
+        Width::truncate(24),
+    )),
+    "| N |column 0|column 1|"
+    "|---|--------|--------|"
+    "| 0 |  0-0   |  0-1   |"
+    "| 1 |  1-0   |  1-1   |"
+);
+
+test_table!(
+    table_kv_test,
+    Table::kv(&("localhost", 8080, String::from("multi\nline"))).with(Style::blank()),
+    " &str     localhost "
+    " i32      8080      "
+    " String   multi     "
+    "          line      "
+);
+
+#[test]
+fn table_kv_has_no_header_test() {
+    assert!(!Table::kv(&(1, 2)).has_header());
+}
@@ -0,0 +1,75 @@
+use std::{collections::BTreeMap, iter::FromIterator};
+
+use tabled::{style::StyleCorrectSpan, Style, Table, TableValue};
+
+use crate::util::test_table;
+
+mod util;
+
+test_table!(
+    table_value_cell,
+    Table::from(TableValue::from("hello")),
+    "+-------+"
+    "| hello |"
+    "+-------+"
+);
+
+test_table!(
+    table_value_nested_lists,
+    Table::from(TableValue::Row(vec![
+        TableValue::from_iter(vec!["1", "2", "3"]),
+        TableValue::from_iter(vec![
+            TableValue::from_iter(vec!["a", "b"]),
+            TableValue::Row(vec!["c".into(), "d".into()]),
+        ]),
+    ])),
+    "+---+-------+"
+    "| 1 | a     |"
+    "+---+-------+"
+    "| 2 | b     |"
+    "+---+---+---+"
+    "| 3 | c | d |"
+    "+---+---+---+"
+);
+
+test_table!(
+    table_value_stretch_both,
+    Table::from(TableValue::Row(vec![
+        TableValue::from("total"),
+        TableValue::from_iter(vec![
+            TableValue::Row(vec!["a".into(), "1".into()]),
+            TableValue::Row(vec!["b".into(), "2".into()]),
+        ]),
+    ]))
+    .with(Style::modern())
+    .with(StyleCorrectSpan),
+    "┌───────┬───┬───┐"
+    "│ total │ a │ 1 │"
+    "│       ├───┼───┤"
+    "│       │ b │ 2 │"
+    "└───────┴───┴───┘"
+);
+
+#[test]
+fn table_value_map_of_lists() {
+    let mut map = BTreeMap::new();
+    map.insert("dependencies", vec!["serde", "papergrid"]);
+    map.insert("dev-dependencies", vec![]);
+
+    let value = map
+        .into_iter()
+        .map(|(key, list)| TableValue::Row(vec![key.into(), TableValue::from_iter(list)]))
+        .collect::<TableValue>();
+
+    assert_eq!(value.shape(), (3, 2));
+    assert_eq!(
+        Table::from(value).to_string(),
+        "+------------------+-----------+\n\
+         | dependencies     | serde     |\n\
+         |                  +-----------+\n\
+         |                  | papergrid |\n\
+         +------------------+-----------+\n\
+         | dev-dependencies |           |\n\
+         +------------------+-----------+"
+    );
+}
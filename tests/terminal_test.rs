@@ -0,0 +1,62 @@
+#![cfg(feature = "terminal")]
+
+use tabled::{Style, Table, Terminal};
+
+use crate::util::test_table;
+
+mod util;
+
+const DATA: [(&str, &str); 2] = [("Zürich", "12°C"), ("Kraków", "9°C")];
+
+const COLORED_DATA: [(&str, &str); 2] = [("\u{1b}[32mZürich\u{1b}[39m", "12°C"), ("Kraków", "9°C")];
+
+test_table!(
+    terminal_capable,
+    Table::new(DATA).with(Style::rounded()).with(Terminal::new(true, true)),
+    "╭────────┬──────╮"
+    "│ &str   │ &str │"
+    "├────────┼──────┤"
+    "│ Zürich │ 12°C │"
+    "│ Kraków │ 9°C  │"
+    "╰────────┴──────╯"
+);
+
+test_table!(
+    terminal_without_unicode,
+    Table::new(DATA).with(Style::rounded()).with(Terminal::new(false, true)),
+    "+--------+------+"
+    "| &str   | &str |"
+    "+--------+------+"
+    "| Zurich | 12?C |"
+    "| Krakow | 9?C  |"
+    "+--------+------+"
+);
+
+test_table!(
+    terminal_without_colors,
+    Table::new(COLORED_DATA).with(Style::rounded()).with(Terminal::new(true, false)),
+    "╭────────┬──────╮"
+    "│ &str   │ &str │"
+    "├────────┼──────┤"
+    "│ Zürich │ 12°C │"
+    "│ Kraków │ 9°C  │"
+    "╰────────┴──────╯"
+);
+
+test_table!(
+    terminal_plain,
+    Table::new(COLORED_DATA).with(Style::rounded()).with(Terminal::new(false, false)),
+    "+--------+------+"
+    "| &str   | &str |"
+    "+--------+------+"
+    "| Zurich | 12?C |"
+    "| Krakow | 9?C  |"
+    "+--------+------+"
+);
+
+#[test]
+fn terminal_capabilities() {
+    let terminal = Terminal::new(true, false);
+    assert!(terminal.supports_unicode());
+    assert!(!terminal.supports_colors());
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "tracing")]
+
+use papergrid::records::Records;
+use tabled::trace::SpanSummary;
+use tracing_subscriber::{layer::SubscriberExt, Registry};
+
+fn collect<F: FnOnce()>(summary: &SpanSummary, f: F) {
+    let subscriber = Registry::default().with(summary.clone());
+    tracing::subscriber::with_default(subscriber, f);
+}
+
+fn column(summary: &SpanSummary, column: usize) -> Vec<String> {
+    let table = summary.table();
+    let records = table.get_records();
+    (0..records.count_rows())
+        .map(|row| records.get_text((row, column)).to_owned())
+        .collect()
+}
+
+#[test]
+fn span_summary() {
+    let summary = SpanSummary::new();
+    collect(&summary, || {
+        for _ in 0..3 {
+            let _span = tracing::info_span!("request").entered();
+            let _span = tracing::debug_span!("db").entered();
+        }
+
+        let _span = tracing::info_span!(target: "app", "startup").entered();
+    });
+
+    assert_eq!(summary.table().shape(), (4, 7));
+    assert_eq!(
+        column(&summary, 0),
+        ["target", "app", "tracing_test", "tracing_test"]
+    );
+    assert_eq!(column(&summary, 1), ["span", "startup", "db", "request"]);
+    assert_eq!(column(&summary, 2), ["count", "1", "3", "3"]);
+}
+
+#[test]
+fn span_summary_counts_only_closed_spans() {
+    let summary = SpanSummary::new();
+    collect(&summary, || {
+        let _outer = tracing::info_span!("outer").entered();
+        let _span = tracing::info_span!("inner").entered();
+        drop(_span);
+
+        assert_eq!(column(&summary, 1), ["span", "inner"]);
+    });
+
+    assert_eq!(column(&summary, 1), ["span", "inner", "outer"]);
+}
+
+#[test]
+fn span_summary_time_of_entered_span() {
+    let summary = SpanSummary::new();
+    collect(&summary, || {
+        let span = tracing::info_span!("sleep");
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        span.in_scope(|| std::thread::sleep(std::time::Duration::from_millis(5)));
+    });
+
+    let total = &column(&summary, 3)[1];
+    assert!(total.ends_with("ms"), "{}", total);
+
+    let total = total.trim_end_matches("ms").parse::<f64>().unwrap();
+    assert!((5.0..20.0).contains(&total), "{}", total);
+}
+
+#[test]
+fn span_summary_reset() {
+    let summary = SpanSummary::new();
+    collect(&summary, || {
+        let _span = tracing::info_span!("request").entered();
+    });
+
+    assert_eq!(summary.table().shape(), (2, 7));
+
+    summary.reset();
+
+    assert_eq!(summary.table().shape(), (1, 7));
+    assert_eq!(
+        summary.table().to_string(),
+        "+--------+------+-------+-------+------+-----+-----+\n\
+         | target | span | count | total | mean | min | max |\n\
+         +--------+------+-------+-------+------+-----+-----+"
+    );
+}
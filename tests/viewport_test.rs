@@ -0,0 +1,89 @@
+use std::iter::FromIterator;
+
+use tabled::{builder::Builder, Style, Table, Viewport};
+
+use crate::util::test_table;
+
+mod util;
+
+fn grid(count_rows: usize, count_cols: usize) -> Builder<'static> {
+    Builder::from_iter(
+        (0..count_rows).map(|row| (0..count_cols).map(move |col| format!("{}-{}", row, col))),
+    )
+}
+
+test_table!(
+    viewport_without_markers,
+    grid(5, 5).build().with(Viewport::new(1..3, 2..4)),
+    "+-----+-----+"
+    "| 1-2 | 1-3 |"
+    "+-----+-----+"
+    "| 2-2 | 2-3 |"
+    "+-----+-----+"
+);
+
+test_table!(
+    viewport_markers_only_where_cut,
+    grid(3, 3).build().with(Viewport::new(..2, 1..).marker("…")).with(Style::psql()),
+    " … | 0-1 | 0-2 "
+    "---+-----+-----"
+    " … | 1-1 | 1-2 "
+    " … | …   | …   "
+);
+
+test_table!(
+    viewport_keeps_header,
+    Table::new([[1, 2], [3, 4], [5, 6]]).with(Viewport::new(2.., ..)).with(Style::psql()),
+    " 0 | 1 "
+    "---+---"
+    " 5 | 6 "
+);
+
+test_table!(
+    viewport_out_of_bounds,
+    grid(2, 2).build().with(Viewport::new(1..10, 1..10).marker("…")).with(Style::psql()),
+    " … | …   "
+    "---+-----"
+    " … | 1-1 "
+);
+
+test_table!(
+    viewport_empty_window,
+    grid(2, 2).build().with(Viewport::new(5.., 5..)),
+    ""
+);
+
+test_table!(
+    viewport_cuts_column_span,
+    {
+        let mut builder = grid(2, 4);
+        builder.set_span(0, 0, 3);
+        builder
+            .build()
+            .with(Viewport::new(.., 1..))
+            .with(Style::modern().off_horizontal())
+            .with(Style::correct_spans())
+    },
+    "┌───────────┬─────┐"
+    "│ 0-0       │ 0-3 │"
+    "│ 1-1 │ 1-2 │ 1-3 │"
+    "└─────┴─────┴─────┘"
+);
+
+test_table!(
+    viewport_cuts_row_span,
+    {
+        let mut builder = grid(4, 2);
+        builder.set_row_span(0, 1, 4);
+        builder
+            .build()
+            .with(Viewport::new(2.., ..))
+            .with(Style::modern())
+            .with(Style::correct_spans())
+    },
+    "┌─────┬─────┐"
+    "│ 2-0 │ 0-1 │"
+    "├─────┤     │"
+    "│ 3-0 │     │"
+    "└─────┴─────┘"
+);
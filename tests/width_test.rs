@@ -4,12 +4,12 @@ use std::iter::FromIterator;
 use tabled::ModifyObject;
 
 use tabled::{
-    formatting::TrimStrategy,
+    formatting::{AmbiguousWide, TrimStrategy},
     object::{Cell, Columns, Object, Rows, Segment},
     papergrid::util::string_width_multiline,
     peaker::{PriorityMax, PriorityMin},
-    width::{Justify, MinWidth, SuffixLimit, Width},
-    Alignment, Margin, Modify, Padding, Panel, Span, Style,
+    width::{CharReplacement, Justify, MinWidth, SuffixLimit, Width},
+    Alignment, Margin, Modify, Padding, Panel, Span, Style, Table,
 };
 
 use crate::util::{create_table, init_table, is_lines_equal, new_table, static_table};
@@ -2282,6 +2282,280 @@ fn min_width_is_used_after_margin() {
     );
 }
 
+#[test]
+fn width_is_estimated_after_padding_change() {
+    let mut table = create_table::<3, 3>();
+    table
+        .with(Style::markdown())
+        .with(Width::truncate(100))
+        .with(Padding::new(2, 2, 0, 0))
+        .with(Width::truncate(100));
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            "|  N  |  column 0  |  column 1  |  column 2  |"
+            "|-----|------------|------------|------------|"
+            "|  0  |    0-0     |    0-1     |    0-2     |"
+            "|  1  |    1-0     |    1-1     |    1-2     |"
+            "|  2  |    2-0     |    2-1     |    2-2     |"
+        )
+    );
+    assert_eq!(table.total_width(), 46);
+}
+
+#[test]
+fn width_is_estimated_after_records_change() {
+    use tabled::papergrid::{records::RecordsMut, width::CfgWidthFunction};
+
+    let mut table = create_table::<3, 3>();
+    table.with(Style::markdown()).with(MinWidth::new(10));
+
+    let ctrl = CfgWidthFunction::new(4);
+    table
+        .get_records_mut()
+        .set((1, 1), String::from("a long text"), &ctrl);
+
+    table.with(Width::truncate(100));
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            "| N |  column 0   | column 1 | column 2 |"
+            "|---|-------------|----------|----------|"
+            "| 0 | a long text |   0-1    |   0-2    |"
+            "| 1 |     1-0     |   1-1    |   1-2    |"
+            "| 2 |     2-0     |   2-1    |   2-2    |"
+        )
+    );
+}
+
+#[test]
+fn width_is_not_changed_by_alignment_between_settings() {
+    let mut table = create_table::<3, 3>();
+    table
+        .with(Style::markdown())
+        .with(Width::truncate(30))
+        .with(Alignment::left())
+        .with(Width::increase(40));
+
+    assert_eq!(string_width_multiline(&table.to_string()), 40);
+    assert_eq!(table.total_width(), 40);
+}
+
+#[test]
+fn truncate_with_char_replacement() {
+    let data = [["你好", "😳😳😳"]];
+
+    let table = Table::new(data)
+        .with(Style::markdown())
+        .with(CharReplacement::new('…'))
+        .with(Modify::new(Segment::all()).with(Width::truncate(3)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| 0   | 1   |"
+            "|-----|-----|"
+            "| 你… | 😳… |"
+        )
+    );
+}
+
+#[test]
+fn truncate_without_char_replacement() {
+    let data = [["你好", "😳😳😳"]];
+
+    let table = Table::new(data)
+        .with(Style::markdown())
+        .with(CharReplacement::none())
+        .with(Modify::new(Segment::all()).with(Width::truncate(3)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| 0  | 1  |"
+            "|----|----|"
+            "| 你 | 😳 |"
+        )
+    );
+}
+
+#[test]
+fn wrap_with_char_replacement() {
+    let data = [["你好世界"]];
+
+    let table = Table::new(data)
+        .with(Style::markdown())
+        .with(CharReplacement::new(' '))
+        .with(Modify::new(Segment::all()).with(Width::wrap(3)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| 0   |"
+            "|-----|"
+            "| 你  |"
+            "| 世  |"
+        )
+    );
+}
+
+#[test]
+fn char_replacement_is_default_replacement_character() {
+    let table = Table::new([["你好"]]);
+    assert_eq!(table.get_char_replacement(), Some('\u{FFFD}'));
+
+    let table = Table::new([["你好"]])
+        .with(Modify::new(Segment::all()).with(Width::truncate(3)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+-----+"
+            "| 0   |"
+            "+-----+"
+            "| 你� |"
+            "+-----+"
+        )
+    );
+}
+
+#[test]
+fn constraints_clamp_columns() {
+    let data = [["a", "long text in a cell"]];
+
+    let table = Table::new(data)
+        .with(Style::markdown())
+        .with(Width::constraints().min(0, 5).max(1, 10))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| 0   | 1        |"
+            "|-----|----------|"
+            "| a   | long tex |"
+            "|     | t in a c |"
+            "|     | ell      |"
+        )
+    );
+}
+
+#[test]
+fn constraints_grow_by_weight() {
+    let data = [["a", "b", "c"]];
+
+    let table = Table::new(data)
+        .with(Style::markdown())
+        .with(Width::constraints().grow(0, 0).grow(2, 2).total(22))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| 0 | 1    | 2       |"
+            "|---|------|---------|"
+            "| a | b    | c       |"
+        )
+    );
+}
+
+#[test]
+fn constraints_grow_up_to_max() {
+    let data = [["a", "b"]];
+
+    let table = Table::new(data)
+        .with(Style::markdown())
+        .with(Width::constraints().max(0, 5).total(20))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| 0   | 1          |"
+            "|-----|------------|"
+            "| a   | b          |"
+        )
+    );
+}
+
+#[test]
+fn constraints_shrink_keeping_min() {
+    let data = [["some text", "another text"]];
+
+    let table = Table::new(data)
+        .with(Style::markdown())
+        .with(Width::constraints().min(0, 11).total(20))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| 0         | 1    |"
+            "|-----------|------|"
+            "| some text | anot |"
+            "|           | her  |"
+            "|           | text |"
+        )
+    );
+}
+
+#[test]
+fn constraints_on_empty_table() {
+    let table = Table::new([[""; 0]; 0])
+        .with(Width::constraints().min(0, 10).total(20))
+        .to_string();
+
+    assert_eq!(table, "");
+}
+
+#[test]
+fn wrap_ambiguous_wide() {
+    let data = [["±½→±½→"]];
+
+    let table = Table::new(data)
+        .with(Style::markdown())
+        .with(AmbiguousWide(true))
+        .with(Modify::new(Segment::all()).with(Width::wrap(4)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| 0    |"
+            "|------|"
+            "| ±½ |"
+            "| →± |"
+            "| ½→ |"
+        )
+    );
+}
+
+#[test]
+fn truncate_ambiguous_wide() {
+    let data = [["±½→±½→"]];
+
+    let table = Table::new(data)
+        .with(Style::markdown())
+        .with(AmbiguousWide(true))
+        .with(Modify::new(Segment::all()).with(Width::truncate(4)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| 0    |"
+            "|------|"
+            "| ±½ |"
+        )
+    );
+}
+
 #[cfg(feature = "derive")]
 mod derived {
     use super::*;
@@ -0,0 +1,72 @@
+#![cfg(feature = "xlsx")]
+
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    object::{Cell, Columns},
+    rust_xlsxwriter::Workbook,
+    Alignment, Modify, Span, Table,
+};
+
+use crate::util::create_table;
+
+mod util;
+
+fn to_buffer<R>(table: &Table<R>) -> Vec<u8>
+where
+    R: tabled::papergrid::records::Records,
+{
+    let mut workbook = Workbook::new();
+    table
+        .write_xlsx_worksheet(workbook.add_worksheet())
+        .unwrap();
+    workbook.save_to_buffer().unwrap()
+}
+
+#[test]
+fn to_xlsx() {
+    let path = std::env::temp_dir().join("tabled_xlsx_test.xlsx");
+    create_table::<3, 3>().to_xlsx(&path).unwrap();
+
+    let data = std::fs::read(&path).unwrap();
+    assert!(data.starts_with(b"PK"));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn to_xlsx_with_spans() {
+    let mut table = Builder::from_iter([["a", "b", "c"], ["d", "e", "f"], ["g", "h", "i"]]).build();
+    table
+        .with(Modify::new(Cell(0, 0)).with(Span::column(2)))
+        .with(Modify::new(Cell(1, 2)).with(Span::row(2)))
+        .with(Modify::new(Columns::single(1)).with(Alignment::right()));
+
+    let data = to_buffer(&table);
+    assert!(data.starts_with(b"PK"));
+}
+
+#[test]
+fn to_xlsx_multiline() {
+    let table = Builder::from_iter([["a\nb", "c"]]).build();
+
+    let data = to_buffer(&table);
+    assert!(data.starts_with(b"PK"));
+}
+
+#[test]
+fn to_xlsx_empty() {
+    let table = Builder::default().build();
+
+    let data = to_buffer(&table);
+    assert!(data.starts_with(b"PK"));
+}
+
+#[test]
+fn to_xlsx_wrong_path() {
+    let path = std::env::temp_dir()
+        .join("tabled_not_existing_dir")
+        .join("table.xlsx");
+    assert!(create_table::<1, 1>().to_xlsx(path).is_err());
+}